@@ -19,6 +19,8 @@ pub mod sorted_join;
 #[cfg(feature = "strings")]
 pub mod string;
 pub mod take_agg;
+#[cfg(feature = "dtype-array")]
+pub mod vector;
 #[cfg(feature = "timezones")]
 mod time;
 
@@ -148,8 +148,10 @@ where
     }
 }
 
+/// The weighted quantile `p` of `buf`, holding `(value, weight)` pairs sorted by value,
+/// where `wsum` is the sum of the weights.
 #[inline]
-fn compute_wq<T>(buf: &[(T, f64)], p: f64, wsum: f64, interp: QuantileInterpolOptions) -> T
+pub fn compute_wq<T>(buf: &[(T, f64)], p: f64, wsum: f64, interp: QuantileInterpolOptions) -> T
 where
     T: Debug
         + NativeType
@@ -189,6 +189,7 @@ impl<'a, T: NativeType + IsFloat + PartialOrd> MinMaxWindow<'a, T> {
     unsafe fn update(&mut self, start: usize, end: usize) -> Option<T> {
         // recompute min
         if start >= self.last_end {
+            self.null_count = 0;
             self.extremum = self.compute_extremum_and_update_null_count(start, end);
             self.last_end = end;
             self.last_start = start;
@@ -1,4 +1,4 @@
-use arrow::array::{UInt32Array, Utf8Array};
+use arrow::array::{BinaryArray, UInt32Array, Utf8Array};
 use arrow::buffer::Buffer;
 use arrow::datatypes::DataType;
 
@@ -16,6 +16,17 @@ pub fn string_lengths(array: &Utf8Array<i64>) -> ArrayRef {
     Box::new(array)
 }
 
+pub fn binary_lengths(array: &BinaryArray<i64>) -> ArrayRef {
+    let values = array
+        .offsets()
+        .as_slice()
+        .windows(2)
+        .map(|x| (x[1] - x[0]) as u32);
+    let values: Buffer<_> = Vec::from_trusted_len_iter(values).into();
+    let array = UInt32Array::new(DataType::UInt32, values, array.validity().cloned());
+    Box::new(array)
+}
+
 pub fn string_nchars(array: &Utf8Array<i64>) -> ArrayRef {
     let values = array.values_iter().map(|x| x.chars().count() as u32);
     let values: Buffer<_> = Vec::from_trusted_len_iter(values).into();
@@ -0,0 +1,91 @@
+//! Row-wise vector operations on the contiguous values of fixed size lists.
+use multiversion::multiversion;
+use num_traits::Float;
+
+// independent accumulators, so that the reductions are auto-vectorized
+const LANES: usize = 8;
+
+#[multiversion(targets = "simd")]
+fn dot_impl<T: Float>(a: &[T], b: &[T]) -> T {
+    let a_chunks = a.chunks_exact(LANES);
+    let b_chunks = b.chunks_exact(LANES);
+    let tail = a_chunks
+        .remainder()
+        .iter()
+        .zip(b_chunks.remainder())
+        .fold(T::zero(), |acc, (a, b)| acc + *a * *b);
+
+    let mut acc = [T::zero(); LANES];
+    for (a, b) in a_chunks.zip(b_chunks) {
+        for ((acc, a), b) in acc.iter_mut().zip(a).zip(b) {
+            *acc = *acc + *a * *b;
+        }
+    }
+    acc.iter().fold(tail, |acc, v| acc + *v)
+}
+
+/// Dot product of two slices of equal length.
+pub fn dot<T: Float>(a: &[T], b: &[T]) -> T {
+    assert_eq!(a.len(), b.len());
+    dot_impl(a, b)
+}
+
+/// Apply `f` on the rows of `a` and `b` that are `width` values wide. If `b` is a single row,
+/// it is paired with every row of `a`.
+fn binary_rows<T, F>(a: &[T], b: &[T], width: usize, f: F) -> Vec<T>
+where
+    T: Float,
+    F: Fn(&[T], &[T]) -> T,
+{
+    assert!(width > 0);
+    if b.len() == width {
+        a.chunks_exact(width).map(|a| f(a, b)).collect()
+    } else {
+        assert_eq!(a.len(), b.len());
+        a.chunks_exact(width)
+            .zip(b.chunks_exact(width))
+            .map(|(a, b)| f(a, b))
+            .collect()
+    }
+}
+
+/// Dot product of every row of `width` values in `a` with the same row in `b`. If `b` is a
+/// single row, it is used for every row of `a`.
+pub fn dot_rows<T: Float>(a: &[T], b: &[T], width: usize) -> Vec<T> {
+    binary_rows(a, b, width, dot)
+}
+
+/// Cosine similarity of every row of `width` values in `a` with the same row in `b`. If `b` is
+/// a single row, it is used for every row of `a`. Rows with a zero norm have a NaN similarity.
+pub fn cosine_similarity_rows<T: Float>(a: &[T], b: &[T], width: usize) -> Vec<T> {
+    binary_rows(a, b, width, |a, b| {
+        dot(a, b) / (dot(a, a).sqrt() * dot(b, b).sqrt())
+    })
+}
+
+/// Euclidean norm of every row of `width` values in `a`.
+pub fn l2_norm_rows<T: Float>(a: &[T], width: usize) -> Vec<T> {
+    assert!(width > 0);
+    a.chunks_exact(width).map(|a| dot(a, a).sqrt()).collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_vector_rows() {
+        let a = [1.0f32, 2.0, 3.0, 4.0, 0.0, 0.0];
+        assert_eq!(dot_rows(&a, &[1.0, 1.0, 1.0], 3), &[6.0, 4.0]);
+        assert_eq!(l2_norm_rows(&[3.0f64, 4.0], 2), &[5.0]);
+
+        let sim = cosine_similarity_rows(&[1.0f64, 0.0, 1.0, 1.0], &[1.0, 0.0, 0.0, 1.0], 2);
+        assert_eq!(sim[0], 1.0);
+        assert!((sim[1] - 0.5f64.sqrt()).abs() < 1e-12);
+
+        // longer than the number of lanes
+        let a = (0..19).map(|v| v as f64).collect::<Vec<_>>();
+        let expected = a.iter().map(|v| v * v).sum::<f64>();
+        assert_eq!(dot(&a, &a), expected);
+    }
+}
@@ -1,6 +1,104 @@
+#[cfg(feature = "timezones")]
+use chrono::{Duration, LocalResult, NaiveDateTime, Offset, TimeZone};
+#[cfg(feature = "timezones")]
+use polars_error::polars_bail;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "timezones")]
+use crate::error::PolarsResult;
+
 // a placeholder type for when timezones are not enabled
 #[cfg(not(feature = "timezones"))]
 #[derive(Copy, Clone)]
 pub enum Tz {}
 #[cfg(feature = "timezones")]
 pub use chrono_tz::Tz;
+
+/// How to localize a datetime that occurs twice in a time zone,
+/// i.e. when the clocks are set back.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Ambiguous {
+    /// Take the first occurrence, i.e. the one before the clocks are set back.
+    Earliest,
+    /// Take the second occurrence, i.e. the one after the clocks are set back.
+    Latest,
+    /// Raise an error.
+    #[default]
+    Raise,
+    /// Set the datetime to null.
+    Null,
+}
+
+/// How to localize a datetime that doesn't exist in a time zone,
+/// i.e. when the clocks are set forward.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum NonExistent {
+    /// Take the first datetime after the gap, i.e. the moment the clocks are set forward.
+    ShiftForward,
+    /// Take the last datetime before the gap.
+    ShiftBackward,
+    /// Set the datetime to null.
+    Null,
+    /// Raise an error.
+    #[default]
+    Raise,
+}
+
+/// The (UTC) moment at which the clocks were set forward over the non-existent local `ndt`.
+#[cfg(feature = "timezones")]
+fn dst_gap_end(ndt: NaiveDateTime, tz: &Tz) -> NaiveDateTime {
+    let offset_at = |utc: NaiveDateTime| tz.offset_from_utc_datetime(&utc).fix();
+    let after = offset_at(ndt + Duration::days(1));
+    let before = offset_at(ndt - Duration::days(1));
+
+    // the transition lies between the moments at which the local time would be `ndt`
+    // with the offset after and with the offset before the gap
+    let mut lo = ndt - Duration::seconds(after.local_minus_utc() as i64);
+    let mut hi = ndt - Duration::seconds(before.local_minus_utc() as i64);
+    while hi - lo > Duration::nanoseconds(1) {
+        let mid = lo + (hi - lo) / 2;
+        if offset_at(mid) == after {
+            hi = mid
+        } else {
+            lo = mid
+        }
+    }
+    hi
+}
+
+/// Convert the local datetime `ndt` in time zone `tz` to UTC.
+///
+/// Returns `None` if `ndt` is ambiguous or non-existent and should be set to null.
+#[cfg(feature = "timezones")]
+pub fn localize_datetime_opt(
+    ndt: NaiveDateTime,
+    tz: &Tz,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
+) -> PolarsResult<Option<NaiveDateTime>> {
+    // e.g. '2021-01-01 03:00' -> '2021-01-01 03:00CDT'
+    match tz.from_local_datetime(&ndt) {
+        LocalResult::Single(dt) => Ok(Some(dt.naive_utc())),
+        LocalResult::Ambiguous(dt_earliest, dt_latest) => match ambiguous {
+            Ambiguous::Earliest => Ok(Some(dt_earliest.naive_utc())),
+            Ambiguous::Latest => Ok(Some(dt_latest.naive_utc())),
+            Ambiguous::Null => Ok(None),
+            Ambiguous::Raise => polars_bail!(ComputeError:
+                "datetime '{}' is ambiguous in time zone '{}'. \
+                Please use `ambiguous` to tell how it should be localized.", ndt, tz
+            ),
+        },
+        LocalResult::None => match non_existent {
+            NonExistent::ShiftForward => Ok(Some(dst_gap_end(ndt, tz))),
+            NonExistent::ShiftBackward => Ok(Some(dst_gap_end(ndt, tz) - Duration::nanoseconds(1))),
+            NonExistent::Null => Ok(None),
+            NonExistent::Raise => polars_bail!(ComputeError:
+                "datetime '{}' is non-existent in time zone '{}'. \
+                Please use `non_existent` to tell how it should be localized.", ndt, tz
+            ),
+        },
+    }
+}
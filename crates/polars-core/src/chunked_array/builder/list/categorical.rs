@@ -45,3 +45,50 @@ impl ListBuilderTrait for ListCategoricalChunkedBuilder {
         ca
     }
 }
+
+/// An Enum has fixed categories, so every appended series must have the same Enum dtype and
+/// the rev map is kept as is.
+pub(super) struct ListEnumChunkedBuilder {
+    inner: ListPrimitiveChunkedBuilder<UInt32Type>,
+    enum_dtype: DataType,
+}
+
+impl ListEnumChunkedBuilder {
+    pub(super) fn new(
+        name: &str,
+        capacity: usize,
+        values_capacity: usize,
+        logical_type: DataType,
+    ) -> Self {
+        assert!(
+            matches!(logical_type, DataType::Enum(Some(_))),
+            "expected enum type"
+        );
+        let inner =
+            ListPrimitiveChunkedBuilder::new(name, capacity, values_capacity, logical_type.clone());
+        Self {
+            inner,
+            enum_dtype: logical_type,
+        }
+    }
+}
+
+impl ListBuilderTrait for ListEnumChunkedBuilder {
+    fn append_series(&mut self, s: &Series) -> PolarsResult<()> {
+        polars_ensure!(
+            matches!(s.dtype(), DataType::Enum(Some(_))) && s.dtype() == &self.enum_dtype,
+            ComputeError: "cannot combine Enum values with different categories"
+        );
+        self.inner.append_series(s)
+    }
+
+    fn append_null(&mut self) {
+        self.inner.append_null()
+    }
+
+    fn finish(&mut self) -> ListChunked {
+        let mut ca = self.inner.finish();
+        unsafe { ca.set_dtype(DataType::List(Box::new(self.enum_dtype.clone()))) }
+        ca
+    }
+}
@@ -102,6 +102,15 @@ pub fn get_list_builder(
                 inner_type_logical.clone(),
             )))
         }
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Enum(_) => {
+            return Ok(Box::new(ListEnumChunkedBuilder::new(
+                name,
+                list_capacity,
+                value_capacity,
+                inner_type_logical.clone(),
+            )))
+        }
         _ => {}
     }
 
@@ -84,6 +84,43 @@ fn cast_single_to_struct(
     Ok(StructChunked::new_unchecked(name, &new_fields).into_series())
 }
 
+#[cfg(feature = "dtype-categorical")]
+fn enum_rev_map(rev_map: &Option<Arc<RevMapping>>) -> PolarsResult<Arc<RevMapping>> {
+    match rev_map {
+        Some(rev_map) => Ok(rev_map.clone()),
+        None => polars_bail!(ComputeError: "cannot cast to an 'Enum' without categories"),
+    }
+}
+
+/// Map every string to the index of its category, raising on values that are not declared.
+#[cfg(feature = "dtype-categorical")]
+fn utf8_to_enum(ca: &Utf8Chunked, rev_map: Arc<RevMapping>) -> PolarsResult<CategoricalChunked> {
+    let categories = rev_map.get_categories();
+    let lookup: PlHashMap<&str, u32> = categories
+        .values_iter()
+        .enumerate()
+        .map(|(idx, cat)| (cat, idx as u32))
+        .collect();
+    let mut idx: UInt32Chunked = ca
+        .into_iter()
+        .map(|opt_s| {
+            opt_s
+                .map(|s| {
+                    lookup.get(s).copied().ok_or_else(|| {
+                        polars_err!(
+                            ComputeError:
+                            "value '{}' is not one of the categories of the 'Enum'", s
+                        )
+                    })
+                })
+                .transpose()
+        })
+        .collect::<PolarsResult<_>>()?;
+    idx.rename(ca.name());
+    // safety: every index comes from the categories
+    Ok(unsafe { CategoricalChunked::from_enum_cats_and_rev_map_unchecked(idx, rev_map) })
+}
+
 impl<T> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -109,6 +146,27 @@ where
                 let ca = unsafe { &*(self as *const ChunkedArray<T> as *const UInt32Chunked) };
                 CategoricalChunked::from_global_indices(ca.clone()).map(|ca| ca.into_series())
             }
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(rev_map) => {
+                let rev_map = enum_rev_map(rev_map)?;
+                polars_ensure!(
+                    self.dtype().is_integer(),
+                    ComputeError: "cannot cast {} to 'Enum'", self.dtype()
+                );
+                let idx = self.cast(&DataType::UInt32)?;
+                let idx = idx.u32()?;
+                if let Some(max) = idx.max() {
+                    polars_ensure!(
+                        (max as usize) < rev_map.len(),
+                        ComputeError: "index {} is out of bounds for an Enum with {} categories", max, rev_map.len()
+                    );
+                }
+                // safety: indices are checked to be in bounds
+                Ok(unsafe {
+                    CategoricalChunked::from_enum_cats_and_rev_map_unchecked(idx.clone(), rev_map)
+                }
+                .into_series())
+            }
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => cast_single_to_struct(self.name(), &self.chunks, fields),
             _ => cast_impl_inner(self.name(), &self.chunks, data_type, checked).map(|mut s| {
@@ -163,6 +221,23 @@ where
                     polars_bail!(ComputeError: "cannot cast numeric types to 'Categorical'");
                 }
             }
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(Some(rev_map)) => {
+                if self.dtype() == &DataType::UInt32 {
+                    // safety:
+                    // we are guarded by the type system.
+                    let ca = unsafe { &*(self as *const ChunkedArray<T> as *const UInt32Chunked) };
+                    Ok(unsafe {
+                        CategoricalChunked::from_enum_cats_and_rev_map_unchecked(
+                            ca.clone(),
+                            rev_map.clone(),
+                        )
+                    }
+                    .into_series())
+                } else {
+                    polars_bail!(ComputeError: "cannot cast numeric types to 'Enum'");
+                }
+            }
             _ => self.cast_impl(data_type, false),
         }
     }
@@ -179,6 +254,11 @@ impl ChunkCast for Utf8Chunked {
                 let ca = builder.finish();
                 Ok(ca.into_series())
             }
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(rev_map) => {
+                let rev_map = enum_rev_map(rev_map)?;
+                utf8_to_enum(self, rev_map).map(|ca| ca.into_series())
+            }
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => cast_single_to_struct(self.name(), &self.chunks, fields),
             #[cfg(feature = "dtype-decimal")]
@@ -144,6 +144,13 @@ pub(crate) fn merge_rev_map(
 
 impl CategoricalChunked {
     pub(crate) fn merge_categorical_map(&self, other: &Self) -> PolarsResult<Arc<RevMapping>> {
+        if self.is_enum() || other.is_enum() {
+            polars_ensure!(
+                self.dtype() == other.dtype(),
+                ComputeError: "cannot combine Enum values with different categories"
+            );
+            return Ok(self.get_rev_map().clone());
+        }
         merge_rev_map(self.get_rev_map(), other.get_rev_map())
     }
 }
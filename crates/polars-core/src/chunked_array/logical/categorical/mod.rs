@@ -83,8 +83,9 @@ impl CategoricalChunked {
         }
     }
 
+    /// Enums always sort in the order their categories were declared.
     pub(crate) fn use_lexical_sort(&self) -> bool {
-        self.bit_settings.contains(BitSettings::LEXICAL_SORT)
+        self.bit_settings.contains(BitSettings::LEXICAL_SORT) && !self.is_enum()
     }
 
     /// Create a [`CategoricalChunked`] from an array of `idx` and an existing [`RevMapping`]:  `rev_map`.
@@ -94,9 +95,35 @@ impl CategoricalChunked {
     pub unsafe fn from_cats_and_rev_map_unchecked(
         idx: UInt32Chunked,
         rev_map: Arc<RevMapping>,
+    ) -> Self {
+        Self::_from_cats_and_rev_map_unchecked(idx, rev_map, false)
+    }
+
+    /// Create a [`CategoricalChunked`] of dtype [`DataType::Enum`] from an array of `idx` and the
+    /// [`RevMapping`] that holds the declared categories: `rev_map`.
+    ///
+    /// # Safety
+    /// Invariant in `v < rev_map.len() for v in idx` must be hold.
+    pub unsafe fn from_enum_cats_and_rev_map_unchecked(
+        idx: UInt32Chunked,
+        rev_map: Arc<RevMapping>,
+    ) -> Self {
+        Self::_from_cats_and_rev_map_unchecked(idx, rev_map, true)
+    }
+
+    /// # Safety
+    /// Invariant in `v < rev_map.len() for v in idx` must be hold.
+    pub(crate) unsafe fn _from_cats_and_rev_map_unchecked(
+        idx: UInt32Chunked,
+        rev_map: Arc<RevMapping>,
+        is_enum: bool,
     ) -> Self {
         let mut logical = Logical::<UInt32Type, _>::new_logical::<CategoricalType>(idx);
-        logical.2 = Some(DataType::Categorical(Some(rev_map)));
+        logical.2 = if is_enum {
+            Some(DataType::Enum(Some(rev_map)))
+        } else {
+            Some(DataType::Categorical(Some(rev_map)))
+        };
         Self {
             logical,
             bit_settings: Default::default(),
@@ -106,12 +133,21 @@ impl CategoricalChunked {
     /// # Safety
     /// The existing index values must be in bounds of the new [`RevMapping`].
     pub(crate) unsafe fn set_rev_map(&mut self, rev_map: Arc<RevMapping>, keep_fast_unique: bool) {
-        self.logical.2 = Some(DataType::Categorical(Some(rev_map)));
+        self.logical.2 = if self.is_enum() {
+            Some(DataType::Enum(Some(rev_map)))
+        } else {
+            Some(DataType::Categorical(Some(rev_map)))
+        };
         if !keep_fast_unique {
             self.set_fast_unique(false)
         }
     }
 
+    /// Check if this is a [`DataType::Enum`], i.e. the categories are fixed.
+    pub fn is_enum(&self) -> bool {
+        matches!(self.dtype(), DataType::Enum(_))
+    }
+
     pub(crate) fn can_fast_unique(&self) -> bool {
         self.bit_settings.contains(BitSettings::ORIGINAL) && self.logical.chunks.len() == 1
     }
@@ -126,7 +162,9 @@ impl CategoricalChunked {
 
     /// Get a reference to the mapping of categorical types to the string values.
     pub fn get_rev_map(&self) -> &Arc<RevMapping> {
-        if let DataType::Categorical(Some(rev_map)) = &self.logical.2.as_ref().unwrap() {
+        if let DataType::Categorical(Some(rev_map)) | DataType::Enum(Some(rev_map)) =
+            &self.logical.2.as_ref().unwrap()
+        {
             rev_map
         } else {
             panic!("implementation error")
@@ -155,6 +193,7 @@ impl LogicalType for CategoricalChunked {
 
     unsafe fn get_any_value_unchecked(&self, i: usize) -> AnyValue<'_> {
         match self.logical.0.get_unchecked(i) {
+            Some(i) if self.is_enum() => AnyValue::Enum(i, self.get_rev_map(), SyncPtr::new_null()),
             Some(i) => AnyValue::Categorical(i, self.get_rev_map(), SyncPtr::new_null()),
             None => AnyValue::Null,
         }
@@ -190,7 +229,22 @@ impl LogicalType for CategoricalChunked {
                 Ok(ca.into_series())
             }
             #[cfg(feature = "dtype-categorical")]
+            DataType::Categorical(_) if self.is_enum() => {
+                // The declared categories become a local rev map of the categorical
+                let ca = unsafe {
+                    CategoricalChunked::from_cats_and_rev_map_unchecked(
+                        self.logical().clone(),
+                        self.get_rev_map().clone(),
+                    )
+                };
+                Ok(ca.into_series())
+            }
+            #[cfg(feature = "dtype-categorical")]
             DataType::Categorical(_) => Ok(self.clone().into_series()),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(_) if self.dtype() == dtype => Ok(self.clone().into_series()),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(_) => self.cast(&DataType::Utf8)?.cast(dtype),
             _ => self.logical.cast(dtype),
         }
     }
@@ -228,6 +282,7 @@ mod test {
     use std::convert::TryFrom;
 
     use super::*;
+    use crate::chunked_array::builder::get_list_builder;
     use crate::{enable_string_cache, reset_string_cache, SINGLE_LOCK};
 
     #[test]
@@ -289,6 +344,89 @@ mod test {
         assert_eq!(out.n_unique().unwrap(), 2);
     }
 
+    #[test]
+    fn test_enum() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        reset_string_cache();
+        enable_string_cache(false);
+
+        let categories = Utf8Array::<i64>::from_slice(["low", "mid", "high"]);
+        let dtype = create_enum_dtype(categories.clone())?;
+        assert!(create_enum_dtype(Utf8Array::<i64>::from_slice(["a", "a"])).is_err());
+
+        let s = Series::new("a", &["high", "low", "mid", "low"]).cast(&dtype)?;
+        assert_eq!(s.dtype(), &dtype);
+        // physical values follow the declaration order
+        assert_eq!(
+            Vec::from(s.to_physical_repr().u32()?),
+            &[Some(2), Some(0), Some(1), Some(0)]
+        );
+        let sorted = s.sort(false);
+        let sorted = sorted.categorical()?;
+        let vals = sorted.iter_str().map(|v| v.unwrap()).collect::<Vec<_>>();
+        assert_eq!(vals, &["low", "low", "mid", "high"]);
+
+        // values outside of the categories raise
+        assert!(Series::new("a", &["low", "extreme"]).cast(&dtype).is_err());
+
+        // an independently created dtype with the same categories can be appended
+        // without a global string cache
+        let other = create_enum_dtype(categories)?;
+        let s2 = Series::new("b", &["mid"]).cast(&other)?;
+        let mut appended = s.clone();
+        appended.append(&s2)?;
+        assert_eq!(appended.dtype(), &dtype);
+        assert_eq!(appended.str_value(4)?, "mid");
+
+        let other = create_enum_dtype(Utf8Array::<i64>::from_slice(["mid", "low", "high"]))?;
+        let s3 = Series::new("c", &["mid"]).cast(&other)?;
+        assert!(s.clone().append(&s3).is_err());
+
+        // the values know they belong to an enum
+        let av = s.get(0)?;
+        assert!(matches!(av, AnyValue::Enum(2, _, _)));
+        assert_eq!(av.dtype(), DataType::Enum(None));
+        assert_eq!(av.get_str(), Some("high"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_enum_concat() -> PolarsResult<()> {
+        let _lock = SINGLE_LOCK.lock();
+        reset_string_cache();
+        enable_string_cache(false);
+
+        let categories = Utf8Array::<i64>::from_slice(["low", "mid", "high"]);
+        let dtype = create_enum_dtype(categories.clone())?;
+        let other = create_enum_dtype(categories)?;
+        let df_a = DataFrame::new(vec![Series::new("a", &["high", "low"]).cast(&dtype)?])?;
+        let df_b = DataFrame::new(vec![Series::new("a", &["mid"]).cast(&other)?])?;
+
+        let out = df_a.vstack(&df_b)?.agg_chunks();
+        let a = out.column("a")?;
+        assert_eq!(a.dtype(), &dtype);
+        let vals = a.categorical()?.iter_str().collect::<Vec<_>>();
+        assert_eq!(vals, &[Some("high"), Some("low"), Some("mid")]);
+
+        // concatenating into a list keeps the enum
+        let mut builder = get_list_builder(&dtype, 3, 2, "a")?;
+        builder.append_series(df_a.column("a")?)?;
+        builder.append_series(df_b.column("a")?)?;
+        let list = builder.finish();
+        assert_eq!(list.dtype(), &DataType::List(Box::new(dtype.clone())));
+        let values = list.explode()?;
+        assert_eq!(values.dtype(), &dtype);
+        assert_eq!(values.str_value(2)?, "mid");
+
+        // enums with other categories can't be concatenated
+        let other = create_enum_dtype(Utf8Array::<i64>::from_slice(["mid", "low", "high"]))?;
+        let df_c = DataFrame::new(vec![Series::new("a", &["mid"]).cast(&other)?])?;
+        assert!(df_a.vstack(&df_c).is_err());
+        let mut builder = get_list_builder(&dtype, 2, 2, "a")?;
+        assert!(builder.append_series(df_c.column("a")?).is_err());
+        Ok(())
+    }
+
     #[test]
     fn test_categorical_flow() -> PolarsResult<()> {
         let _lock = SINGLE_LOCK.lock();
@@ -10,8 +10,9 @@ impl CategoricalChunked {
             new_chunks(&mut self.logical.chunks, &other.logical().chunks, len);
             return Ok(());
         }
-        let is_local_different_source =
-            match (self.get_rev_map().as_ref(), other.get_rev_map().as_ref()) {
+        // Enums with equal categories share their physical representation
+        let is_local_different_source = !self.is_enum()
+            && match (self.get_rev_map().as_ref(), other.get_rev_map().as_ref()) {
                 (RevMapping::Local(arr_l), RevMapping::Local(arr_r)) => !std::ptr::eq(arr_l, arr_r),
                 _ => false,
             };
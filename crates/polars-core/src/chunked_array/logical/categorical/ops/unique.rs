@@ -16,8 +16,11 @@ impl CategoricalChunked {
             // safety:
             // we only removed some indexes so we are still in bounds
             unsafe {
-                let mut out =
-                    CategoricalChunked::from_cats_and_rev_map_unchecked(ca, cat_map.clone());
+                let mut out = CategoricalChunked::_from_cats_and_rev_map_unchecked(
+                    ca,
+                    cat_map.clone(),
+                    self.is_enum(),
+                );
                 out.set_fast_unique(true);
                 Ok(out)
             }
@@ -26,9 +29,10 @@ impl CategoricalChunked {
             // safety:
             // we only removed some indexes so we are still in bounds
             unsafe {
-                Ok(CategoricalChunked::from_cats_and_rev_map_unchecked(
+                Ok(CategoricalChunked::_from_cats_and_rev_map_unchecked(
                     ca,
                     cat_map.clone(),
+                    self.is_enum(),
                 ))
             }
         }
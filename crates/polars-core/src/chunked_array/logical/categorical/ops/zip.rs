@@ -7,7 +7,7 @@ impl CategoricalChunked {
         other: &CategoricalChunked,
     ) -> PolarsResult<Self> {
         let cats = match &**self.get_rev_map() {
-            RevMapping::Local(rev_map) => {
+            RevMapping::Local(rev_map) if !self.is_enum() => {
                 // the logic for merging the rev maps will concatenate utf8 arrays
                 // to make sure the indexes still make sense we need to offset the right hand side
                 self.logical()
@@ -20,8 +20,10 @@ impl CategoricalChunked {
         // Safety:
         // we checked the rev_maps.
         unsafe {
-            Ok(CategoricalChunked::from_cats_and_rev_map_unchecked(
-                cats, new_state,
+            Ok(CategoricalChunked::_from_cats_and_rev_map_unchecked(
+                cats,
+                new_state,
+                self.is_enum(),
             ))
         }
     }
@@ -3,7 +3,6 @@ use polars_row::{convert_columns, RowsEncoded, SortField};
 use polars_utils::iter::EnumerateIdxTrait;
 
 use super::*;
-#[cfg(feature = "dtype-struct")]
 use crate::utils::_split_offsets;
 use crate::POOL;
 
@@ -70,7 +69,7 @@ pub fn _get_rows_encoded_compat_array(by: &Series) -> PolarsResult<ArrayRef> {
 
     let out = match by.dtype() {
         #[cfg(feature = "dtype-categorical")]
-        DataType::Categorical(_) => {
+        DataType::Categorical(_) | DataType::Enum(_) => {
             let ca = by.categorical().unwrap();
             if ca.use_lexical_sort() {
                 by.to_arrow(0)
@@ -83,7 +82,6 @@ pub fn _get_rows_encoded_compat_array(by: &Series) -> PolarsResult<ArrayRef> {
     Ok(out)
 }
 
-#[cfg(feature = "dtype-struct")]
 pub(crate) fn encode_rows_vertical(by: &[Series]) -> PolarsResult<BinaryChunked> {
     let n_threads = POOL.current_num_threads();
     let len = by[0].len();
@@ -41,9 +41,10 @@ impl CategoricalChunked {
                     // safety:
                     // we only reordered the indexes so we are still in bounds
                     unsafe {
-                        CategoricalChunked::from_cats_and_rev_map_unchecked(
+                        CategoricalChunked::_from_cats_and_rev_map_unchecked(
                             self.logical().clone(),
                             Arc::new(rev_map),
+                            self.is_enum(),
                         )
                     }
                 }
@@ -72,9 +73,10 @@ impl CategoricalChunked {
                     // safety:
                     // we only reordered the indexes so we are still in bounds
                     unsafe {
-                        CategoricalChunked::from_cats_and_rev_map_unchecked(
+                        CategoricalChunked::_from_cats_and_rev_map_unchecked(
                             cats,
                             self.get_rev_map().clone(),
+                            self.is_enum(),
                         )
                     }
                 }
@@ -84,9 +86,10 @@ impl CategoricalChunked {
             // safety:
             // we only reordered the indexes so we are still in bounds
             unsafe {
-                CategoricalChunked::from_cats_and_rev_map_unchecked(
+                CategoricalChunked::_from_cats_and_rev_map_unchecked(
                     cats,
                     self.get_rev_map().clone(),
+                    self.is_enum(),
                 )
             }
         }
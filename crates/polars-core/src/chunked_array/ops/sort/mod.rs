@@ -20,9 +20,9 @@ use rayon::prelude::*;
 pub use slice::*;
 
 use crate::prelude::compare_inner::PartialOrdInner;
-#[cfg(feature = "dtype-struct")]
-use crate::prelude::sort::arg_sort_multiple::_get_rows_encoded_ca;
-use crate::prelude::sort::arg_sort_multiple::{arg_sort_multiple_impl, args_validate};
+use crate::prelude::sort::arg_sort_multiple::{
+    _get_rows_encoded_ca, arg_sort_multiple_impl, args_validate,
+};
 use crate::prelude::*;
 use crate::series::IsSorted;
 use crate::utils::{CustomIterTools, NoNull};
@@ -584,17 +584,34 @@ impl ChunkSort<BinaryType> for BinaryChunked {
     }
 }
 
+/// Sort nested data by their row encoding.
+pub(crate) fn arg_sort_row_fmt(s: &Series, options: SortOptions) -> PolarsResult<IdxCa> {
+    let bin = _get_rows_encoded_ca(
+        s.name(),
+        &[s.clone()],
+        &[options.descending],
+        options.nulls_last,
+    )?;
+    Ok(bin.arg_sort(Default::default()))
+}
+
 #[cfg(feature = "dtype-struct")]
 impl StructChunked {
-    pub(crate) fn arg_sort(&self, options: SortOptions) -> IdxCa {
-        let bin = _get_rows_encoded_ca(
-            self.name(),
-            &[self.clone().into_series()],
-            &[options.descending],
-            options.nulls_last,
-        )
-        .unwrap();
-        bin.arg_sort(Default::default())
+    pub(crate) fn arg_sort(&self, options: SortOptions) -> PolarsResult<IdxCa> {
+        arg_sort_row_fmt(&self.clone().into_series(), options)
+    }
+}
+
+impl ListChunked {
+    pub(crate) fn arg_sort(&self, options: SortOptions) -> PolarsResult<IdxCa> {
+        arg_sort_row_fmt(&self.clone().into_series(), options)
+    }
+}
+
+#[cfg(feature = "dtype-array")]
+impl ArrayChunked {
+    pub(crate) fn arg_sort(&self, options: SortOptions) -> PolarsResult<IdxCa> {
+        arg_sort_row_fmt(&self.clone().into_series(), options)
     }
 }
 
@@ -670,7 +687,7 @@ pub(crate) fn convert_sort_column_multi_sort(s: &Series) -> PolarsResult<Series>
     use DataType::*;
     let out = match s.dtype() {
         #[cfg(feature = "dtype-categorical")]
-        Categorical(_) => s.rechunk(),
+        Categorical(_) | Enum(_) => s.rechunk(),
         Binary | Boolean => s.clone(),
         Utf8 => s.cast(&Binary).unwrap(),
         #[cfg(feature = "dtype-struct")]
@@ -683,6 +700,9 @@ pub(crate) fn convert_sort_column_multi_sort(s: &Series) -> PolarsResult<Series>
                 .collect::<PolarsResult<Vec<_>>>()?;
             return StructChunked::new(ca.name(), &new_fields).map(|ca| ca.into_series());
         }
+        List(_) => s.cast(&row_encodable_dtype(s.dtype()))?,
+        #[cfg(feature = "dtype-array")]
+        Array(_, _) => s.cast(&row_encodable_dtype(s.dtype()))?,
         _ => {
             let phys = s.to_physical_repr().into_owned();
             polars_ensure!(
@@ -695,6 +715,31 @@ pub(crate) fn convert_sort_column_multi_sort(s: &Series) -> PolarsResult<Series>
     Ok(out)
 }
 
+/// The data type the values of a nested column are cast to before they are row encoded.
+fn row_encodable_dtype(dtype: &DataType) -> DataType {
+    use DataType::*;
+    match dtype {
+        // categoricals are encoded by their string values
+        #[cfg(feature = "dtype-categorical")]
+        Categorical(_) => dtype.clone(),
+        // enums are ordered by their declared categories
+        #[cfg(feature = "dtype-categorical")]
+        Enum(_) => UInt32,
+        Utf8 => Binary,
+        List(inner) => List(Box::new(row_encodable_dtype(inner))),
+        #[cfg(feature = "dtype-array")]
+        Array(inner, width) => Array(Box::new(row_encodable_dtype(inner)), *width),
+        #[cfg(feature = "dtype-struct")]
+        Struct(fields) => Struct(
+            fields
+                .iter()
+                .map(|fld| Field::new(fld.name(), row_encodable_dtype(fld.data_type())))
+                .collect(),
+        ),
+        dt => dt.to_physical(),
+    }
+}
+
 pub fn _broadcast_descending(n_cols: usize, descending: &mut Vec<bool>) {
     if n_cols > descending.len() && descending.len() == 1 {
         while n_cols != descending.len() {
@@ -871,6 +916,33 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn test_sort_list() {
+        let s = Series::new(
+            "a",
+            &[
+                Series::new("", &["b"]),
+                Series::new("", &["a", "c"]),
+                Series::new("", &["a"]),
+                Series::new("", &["a", "b"]),
+            ],
+        );
+        let idx = s.arg_sort(SortOptions::default());
+        assert_eq!(idx.into_no_null_iter().collect::<Vec<_>>(), &[2, 3, 1, 0]);
+        let idx = s.arg_sort(SortOptions {
+            descending: true,
+            ..Default::default()
+        });
+        assert_eq!(idx.into_no_null_iter().collect::<Vec<_>>(), &[0, 1, 3, 2]);
+
+        let sorted = s.sort(false).explode().unwrap();
+        let sorted = sorted.utf8().unwrap();
+        assert_eq!(
+            sorted.into_no_null_iter().collect::<Vec<_>>(),
+            &["a", "a", "b", "a", "c", "b"]
+        );
+    }
+
     #[test]
     fn test_sort_utf8() {
         let ca = Utf8Chunked::new("a", &[Some("a"), None, Some("c"), None, Some("b")]);
@@ -61,6 +61,8 @@ pub enum SerializableDataType {
     Unknown,
     #[cfg(feature = "dtype-categorical")]
     Categorical,
+    #[cfg(feature = "dtype-categorical")]
+    Enum(Option<Vec<String>>),
     #[cfg(feature = "object")]
     Object(String),
 }
@@ -93,6 +95,14 @@ impl From<&DataType> for SerializableDataType {
             Struct(flds) => Self::Struct(flds.clone()),
             #[cfg(feature = "dtype-categorical")]
             Categorical(_) => Self::Categorical,
+            #[cfg(feature = "dtype-categorical")]
+            Enum(rev_map) => Self::Enum(rev_map.as_ref().map(|rev_map| {
+                rev_map
+                    .get_categories()
+                    .values_iter()
+                    .map(|s| s.to_string())
+                    .collect()
+            })),
             #[cfg(feature = "object")]
             Object(name) => Self::Object(name.to_string()),
             dt => panic!("{dt:?} not supported"),
@@ -127,6 +137,13 @@ impl From<SerializableDataType> for DataType {
             Struct(flds) => Self::Struct(flds),
             #[cfg(feature = "dtype-categorical")]
             Categorical => Self::Categorical(None),
+            #[cfg(feature = "dtype-categorical")]
+            Enum(None) => Self::Enum(None),
+            #[cfg(feature = "dtype-categorical")]
+            Enum(Some(categories)) => {
+                let categories = Utf8Array::<i64>::from_iter_values(categories.iter());
+                Self::Enum(Some(Arc::new(RevMapping::Local(categories))))
+            }
             #[cfg(feature = "object")]
             Object(_) => Self::Object("unknown"),
         }
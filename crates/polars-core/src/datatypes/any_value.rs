@@ -72,6 +72,11 @@ pub enum AnyValue<'a> {
     // If syncptr is_null the data is in the rev-map
     // otherwise it is in the array pointer
     Categorical(u32, &'a RevMapping, SyncPtr<Utf8Array<i64>>),
+    /// A value of a [`DataType::Enum`], the rev-map holds its declared categories.
+    /// If syncptr is_null the data is in the rev-map
+    /// otherwise it is in the array pointer
+    #[cfg(feature = "dtype-categorical")]
+    Enum(u32, &'a RevMapping, SyncPtr<Utf8Array<i64>>),
     /// Nested type, contains arrays that are filled with one of the datatypes.
     List(Series),
     #[cfg(feature = "dtype-array")]
@@ -365,6 +370,8 @@ impl<'a> AnyValue<'a> {
             Utf8(_) => DataType::Utf8,
             #[cfg(feature = "dtype-categorical")]
             Categorical(_, _, _) => DataType::Categorical(None),
+            #[cfg(feature = "dtype-categorical")]
+            Enum(_, _, _) => DataType::Enum(None),
             List(s) => DataType::List(Box::new(s.dtype().clone())),
             #[cfg(feature = "dtype-struct")]
             Struct(_, _, fields) => DataType::Struct(fields.to_vec()),
@@ -689,7 +696,7 @@ impl<'a> AnyValue<'a> {
             AnyValue::Utf8(s) => Some(s),
             AnyValue::Utf8Owned(s) => Some(s),
             #[cfg(feature = "dtype-categorical")]
-            AnyValue::Categorical(idx, rev, arr) => {
+            AnyValue::Categorical(idx, rev, arr) | AnyValue::Enum(idx, rev, arr) => {
                 let s = if arr.is_null() {
                     rev.get(*idx)
                 } else {
@@ -770,6 +777,12 @@ impl PartialEq for AnyValue<'_> {
                 }
                 _ => false,
             },
+            #[cfg(feature = "dtype-categorical")]
+            (Enum(idx_l, rev_l, _), Enum(idx_r, rev_r, _)) => {
+                idx_l == idx_r
+                    && (std::ptr::eq(*rev_l, *rev_r)
+                        || rev_l.get_categories() == rev_r.get_categories())
+            }
             #[cfg(feature = "dtype-duration")]
             (Duration(l, tu_l), Duration(r, tu_r)) => l == r && tu_l == tu_r,
             #[cfg(feature = "dtype-struct")]
@@ -46,6 +46,11 @@ pub enum DataType {
     // The RevMapping has the internal state.
     // This is ignored with casts, comparisons, hashing etc.
     Categorical(Option<Arc<RevMapping>>),
+    #[cfg(feature = "dtype-categorical")]
+    /// A categorical type whose categories are fixed and declared up front.
+    /// The physical value is the index of the category in that declaration,
+    /// so sorting follows the declaration order and no string cache is needed.
+    Enum(Option<Arc<RevMapping>>),
     #[cfg(feature = "dtype-struct")]
     Struct(Vec<Field>),
     // some logical types we cannot know statically, e.g. Datetime
@@ -71,6 +76,11 @@ impl PartialEq for DataType {
                 // Don't include rev maps in comparisons
                 #[cfg(feature = "dtype-categorical")]
                 (Categorical(_), Categorical(_)) => true,
+                // An Enum without categories matches any Enum
+                #[cfg(feature = "dtype-categorical")]
+                (Enum(Some(l)), Enum(Some(r))) => {
+                    Arc::ptr_eq(l, r) || l.get_categories() == r.get_categories()
+                }
                 (Datetime(tu_l, tz_l), Datetime(tu_r, tz_r)) => tu_l == tu_r && tz_l == tz_r,
                 (List(left_inner), List(right_inner)) => left_inner == right_inner,
                 #[cfg(feature = "dtype-duration")]
@@ -129,7 +139,7 @@ impl DataType {
             Duration(_) => Int64,
             Time => Int64,
             #[cfg(feature = "dtype-categorical")]
-            Categorical(_) => UInt32,
+            Categorical(_) | Enum(_) => UInt32,
             List(dt) => List(Box::new(dt.to_physical())),
             #[cfg(feature = "dtype-struct")]
             Struct(fields) => {
@@ -178,7 +188,7 @@ impl DataType {
             #[cfg(feature = "object")]
             DataType::Object(_) => false,
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => false,
+            DataType::Categorical(_) | DataType::Enum(_) => false,
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(_) => false,
             #[cfg(feature = "dtype-decimal")]
@@ -253,7 +263,7 @@ impl DataType {
             #[cfg(feature = "object")]
             Object(_) => panic!("cannot convert object to arrow"),
             #[cfg(feature = "dtype-categorical")]
-            Categorical(_) => ArrowDataType::Dictionary(
+            Categorical(_) | Enum(_) => ArrowDataType::Dictionary(
                 IntegerType::UInt32,
                 Box::new(ArrowDataType::LargeUtf8),
                 false,
@@ -267,6 +277,31 @@ impl DataType {
         }
     }
 
+    /// Check if this [`DataType`] is a [`DataType::Categorical`] or a [`DataType::Enum`].
+    pub fn is_categorical(&self) -> bool {
+        #[cfg(feature = "dtype-categorical")]
+        {
+            matches!(self, DataType::Categorical(_) | DataType::Enum(_))
+        }
+        #[cfg(not(feature = "dtype-categorical"))]
+        {
+            false
+        }
+    }
+
+    /// Check if this [`DataType`] is a container type: a list, array or struct.
+    pub fn is_nested(&self) -> bool {
+        use DataType::*;
+        match self {
+            List(_) => true,
+            #[cfg(feature = "dtype-array")]
+            Array(_, _) => true,
+            #[cfg(feature = "dtype-struct")]
+            Struct(_) => true,
+            _ => false,
+        }
+    }
+
     pub fn is_nested_null(&self) -> bool {
         use DataType::*;
         match self {
@@ -330,6 +365,8 @@ impl Display for DataType {
             DataType::Object(s) => s,
             #[cfg(feature = "dtype-categorical")]
             DataType::Categorical(_) => "cat",
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(_) => "enum",
             #[cfg(feature = "dtype-struct")]
             DataType::Struct(fields) => return write!(f, "struct[{}]", fields.len()),
             DataType::Unknown => "unknown",
@@ -361,3 +398,20 @@ pub fn merge_dtypes(left: &DataType, right: &DataType) -> PolarsResult<DataType>
         _ => polars_bail!(ComputeError: "unable to merge datatypes"),
     })
 }
+
+/// Create a [`DataType::Enum`] from the given categories.
+/// The order of `categories` determines the physical ordering of the values.
+#[cfg(feature = "dtype-categorical")]
+pub fn create_enum_dtype(categories: Utf8Array<i64>) -> PolarsResult<DataType> {
+    polars_ensure!(
+        categories.null_count() == 0,
+        ComputeError: "Enum categories must not contain nulls"
+    );
+    let mut seen = PlHashSet::with_capacity(categories.len());
+    for cat in categories.values_iter() {
+        polars_ensure!(seen.insert(cat), Duplicate: "Enum categories must be unique; found duplicate '{}'", cat);
+    }
+    Ok(DataType::Enum(Some(Arc::new(RevMapping::Local(
+        categories,
+    )))))
+}
@@ -305,6 +305,16 @@ impl Debug for Series {
             DataType::Categorical(_) => {
                 format_array!(f, self.categorical().unwrap(), "cat", self.name(), "Series")
             }
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(_) => {
+                format_array!(
+                    f,
+                    self.categorical().unwrap(),
+                    "enum",
+                    self.name(),
+                    "Series"
+                )
+            }
             #[cfg(feature = "dtype-struct")]
             dt @ DataType::Struct(_) => format_array!(
                 f,
@@ -866,7 +876,7 @@ impl Display for AnyValue<'_> {
                 write!(f, "{nt}")
             }
             #[cfg(feature = "dtype-categorical")]
-            AnyValue::Categorical(_, _, _) => {
+            AnyValue::Categorical(_, _, _) | AnyValue::Enum(_, _, _) => {
                 let s = self.get_str().unwrap();
                 write!(f, "\"{s}\"")
             }
@@ -4,6 +4,8 @@ use std::ops::Sub;
 use num_traits::Bounded;
 use polars_arrow::index::IdxSize;
 
+use super::AsofStrategy;
+
 pub(super) fn join_asof_forward_with_tolerance<T: PartialOrd + Copy + Debug + Sub<Output = T>>(
     left: &[T],
     right: &[T],
@@ -237,6 +239,109 @@ pub(super) fn join_asof_nearest<T: PartialOrd + Copy + Debug + Sub<Output = T> +
     out
 }
 
+/// The tolerance of an asof join, either the same for all left rows or given per left row.
+/// Rows without a tolerance match regardless of the distance.
+pub(super) enum Tolerance<'a, T> {
+    None,
+    Global(T),
+    PerRow(&'a [Option<T>]),
+}
+
+impl<T: Copy> Tolerance<'_, T> {
+    #[inline]
+    pub(super) fn get(&self, idx_left: usize) -> Option<T> {
+        match self {
+            Tolerance::None => None,
+            Tolerance::Global(tolerance) => Some(*tolerance),
+            Tolerance::PerRow(tolerances) => tolerances[idx_left],
+        }
+    }
+}
+
+/// Find the position of the match of `val_l` in the sorted `right` values.
+///
+/// This does a binary search, so unlike the merge based kernels above the left values don't
+/// have to be sorted.
+pub(super) fn asof_search<T>(
+    val_l: T,
+    right: &[T],
+    strategy: AsofStrategy,
+    tolerance: Option<T>,
+    allow_exact_matches: bool,
+) -> Option<usize>
+where
+    T: PartialOrd + Copy + Sub<Output = T>,
+{
+    // last right value smaller than (or equal to) left
+    let backward = || {
+        let n = if allow_exact_matches {
+            right.partition_point(|val_r| *val_r <= val_l)
+        } else {
+            right.partition_point(|val_r| *val_r < val_l)
+        };
+        n.checked_sub(1)
+    };
+    // first right value larger than (or equal to) left
+    let forward = || {
+        let n = if allow_exact_matches {
+            right.partition_point(|val_r| *val_r < val_l)
+        } else {
+            right.partition_point(|val_r| *val_r <= val_l)
+        };
+        (n < right.len()).then_some(n)
+    };
+
+    let (idx, dist) = match strategy {
+        AsofStrategy::Backward => backward().map(|idx| (idx, val_l - right[idx])),
+        AsofStrategy::Forward => forward().map(|idx| (idx, right[idx] - val_l)),
+        AsofStrategy::Nearest => match (backward(), forward()) {
+            (Some(b), Some(f)) => {
+                let dist_b = val_l - right[b];
+                let dist_f = right[f] - val_l;
+                // on a tie we take the larger value, and the last of equal values
+                if dist_f < dist_b || (dist_f == dist_b && right[f] > right[b]) {
+                    Some((f, dist_f))
+                } else {
+                    Some((b, dist_b))
+                }
+            }
+            (Some(b), None) => Some((b, val_l - right[b])),
+            (None, Some(f)) => Some((f, right[f] - val_l)),
+            (None, None) => None,
+        },
+    }?;
+    match tolerance {
+        Some(tolerance) if dist > tolerance => None,
+        _ => Some(idx),
+    }
+}
+
+/// Asof join of all `left` values on the sorted `right` values.
+pub(super) fn join_asof_search<T>(
+    left: &[T],
+    right: &[T],
+    strategy: AsofStrategy,
+    tolerance: &Tolerance<T>,
+    allow_exact_matches: bool,
+) -> Vec<Option<IdxSize>>
+where
+    T: PartialOrd + Copy + Sub<Output = T>,
+{
+    left.iter()
+        .enumerate()
+        .map(|(idx_l, &val_l)| {
+            asof_search(
+                val_l,
+                right,
+                strategy,
+                tolerance.get(idx_l),
+                allow_exact_matches,
+            )
+            .map(|idx_r| idx_r as IdxSize)
+        })
+        .collect()
+}
+
 pub(super) fn join_asof_forward<T: PartialOrd + Copy + Debug>(
     left: &[T],
     right: &[T],
@@ -315,4 +420,27 @@ mod test {
         assert_eq!(tuples.len(), a.len());
         assert_eq!(tuples, &[Some(0), Some(0), Some(1), Some(2), None]);
     }
+
+    #[test]
+    fn test_asof_search() {
+        let a = [-1, 1, 2, 4, 6];
+        let b = [1, 2, 4, 5];
+        let tolerances = [Some(5), Some(0), Some(0), None, Some(0)];
+        let search = |strategy, tolerance, allow_exact_matches| {
+            join_asof_search(&a, &b, strategy, &tolerance, allow_exact_matches)
+        };
+
+        let tuples = search(AsofStrategy::Backward, Tolerance::None, false);
+        assert_eq!(tuples, &[None, None, Some(0), Some(1), Some(3)]);
+        let tuples = search(AsofStrategy::Forward, Tolerance::None, false);
+        assert_eq!(tuples, &[Some(0), Some(1), Some(2), Some(3), None]);
+
+        let tuples = search(AsofStrategy::Nearest, Tolerance::None, true);
+        assert_eq!(tuples, &[Some(0), Some(0), Some(1), Some(2), Some(3)]);
+        let tuples = search(AsofStrategy::Nearest, Tolerance::Global(1), true);
+        assert_eq!(tuples, &[None, Some(0), Some(1), Some(2), Some(3)]);
+
+        let tuples = search(AsofStrategy::Nearest, Tolerance::PerRow(&tolerances), false);
+        assert_eq!(tuples, &[Some(0), None, None, Some(3), None]);
+    }
 }
@@ -1,764 +1,238 @@
-use std::fmt::Debug;
-use std::hash::Hash;
-use std::ops::Sub;
+use std::cmp::Ordering;
 
-use ahash::RandomState;
-use arrow::types::NativeType;
-use num_traits::{Bounded, Zero};
 use rayon::prelude::*;
 use smartstring::alias::String as SmartString;
 
 use super::*;
-use crate::frame::groupby::hashing::HASHMAP_INIT_SIZE;
 #[cfg(feature = "dtype-categorical")]
 use crate::frame::hash_join::_check_categorical_src;
-use crate::frame::hash_join::{
-    build_tables, get_hash_tbl_threaded_join_partitioned, multiple_keys as mk, prepare_bytes,
-};
-use crate::hashing::{df_rows_to_hashes_threaded_vertical, AsU64};
-use crate::utils::{split_ca, split_df};
+use crate::prelude::sort::arg_sort_multiple::encode_rows_vertical;
 use crate::POOL;
 
-pub(super) unsafe fn join_asof_backward_with_indirection_and_tolerance<
-    T: PartialOrd + Copy + Sub<Output = T> + Debug,
->(
-    val_l: T,
-    right: &[T],
-    offsets: &[IdxSize],
-    tolerance: T,
-) -> (Option<IdxSize>, usize) {
-    if offsets.is_empty() {
-        return (None, 0);
-    }
-    let mut previous_idx = *offsets.get_unchecked(0);
-    let first = *right.get_unchecked(previous_idx as usize);
-    if val_l < first {
-        (None, 0)
-    } else {
-        for (idx, &offset) in offsets.iter().enumerate() {
-            let val_r = *right.get_unchecked(offset as usize);
-
-            // the point that is larger is not allowed
-            if val_r > val_l {
-                // compute the distance of previous point, that one was still backwards
-                let previous_value = *right.get_unchecked(previous_idx as usize);
-                let dist = val_l - previous_value;
-                return if dist > tolerance {
-                    (None, idx)
-                } else {
-                    (Some(previous_idx), idx)
-                };
-            }
-            previous_idx = offset
-        }
-        // check remaining values that still suffice the distance constraint
-        let previous_value = *right.get_unchecked(previous_idx as usize);
-        let dist = val_l - previous_value;
-        if dist > tolerance {
-            (None, offsets.len())
-        } else {
-            (Some(previous_idx), offsets.len())
-        }
-    }
-}
-
-pub(super) unsafe fn join_asof_forward_with_indirection_and_tolerance<
-    T: PartialOrd + Copy + Sub<Output = T> + Debug,
->(
-    val_l: T,
-    right: &[T],
-    offsets: &[IdxSize],
-    tolerance: T,
-) -> (Option<IdxSize>, usize) {
-    if offsets.is_empty() {
-        return (None, 0);
-    }
-    let last_offset = *offsets.get_unchecked(offsets.len() - 1);
-    let last_value = *right.get_unchecked(last_offset as usize);
-    if val_l <= last_value {
-        for (idx, &offset) in offsets.iter().enumerate() {
-            let val_r = *right.get_unchecked(offset as usize);
-            if val_r >= val_l {
-                let dist = val_r - val_l;
-                return if dist > tolerance {
-                    (None, idx)
-                } else {
-                    (Some(offset), idx)
-                };
-            }
-        }
-    }
-    (None, offsets.len())
-}
-
-pub(super) unsafe fn join_asof_backward_with_indirection<T: PartialOrd + Copy + Debug>(
-    val_l: T,
-    right: &[T],
-    offsets: &[IdxSize],
-    // only there to have the same function signature
-    _: T,
-) -> (Option<IdxSize>, usize) {
-    if offsets.is_empty() {
-        return (None, 0);
-    }
-    let mut previous = *offsets.get_unchecked(0);
-    let first = *right.get_unchecked(previous as usize);
-    if val_l < first {
-        (None, 0)
-    } else {
-        for (idx, &offset) in offsets.iter().enumerate() {
-            let val_r = *right.get_unchecked(offset as usize);
-            if val_r > val_l {
-                return (Some(previous), idx);
-            }
-            previous = offset
-        }
-        (Some(previous), offsets.len())
-    }
-}
-
-pub(super) unsafe fn join_asof_forward_with_indirection<T: PartialOrd + Copy + Debug>(
-    val_l: T,
-    right: &[T],
-    offsets: &[IdxSize],
-    // only there to have the same function signature
-    _: T,
-) -> (Option<IdxSize>, usize) {
-    if offsets.is_empty() {
-        return (None, 0);
-    }
-    let last_offset = *offsets.get_unchecked(offsets.len() - 1);
-    let last_value = *right.get_unchecked(last_offset as usize);
-    if val_l <= last_value {
-        for (idx, &offset) in offsets.iter().enumerate() {
-            let val_r = *right.get_unchecked(offset as usize);
-            if val_r >= val_l {
-                return (Some(offset), idx);
-            }
-        }
-    }
-    (None, offsets.len())
-}
-
-pub(super) unsafe fn join_asof_nearest_with_indirection<
-    T: PartialOrd + Copy + Debug + Sub<Output = T> + Bounded,
->(
-    val_l: T,
-    right: &[T],
-    offsets: &[IdxSize],
-    // only there to have the same function signature
-    _: T,
-) -> (Option<IdxSize>, usize) {
-    if offsets.is_empty() {
-        return (None, 0);
-    }
-    let max_possible_dist = <T as Bounded>::max_value();
-    let mut dist: T = max_possible_dist;
-    let mut prev_offset: IdxSize = 0;
-    for (idx, &offset) in offsets.iter().enumerate() {
-        let val_r = *right.get_unchecked(offset as usize);
-        // This is (val_r - val_l).abs(), but works on strings/dates
-        let dist_curr = if val_r > val_l {
-            val_r - val_l
-        } else {
-            val_l - val_r
-        };
-        if dist_curr <= dist {
-            // candidate for match
-            dist = dist_curr;
-        } else {
-            // note for a nearest-match, we can re-match on the same val_r next time,
-            // so we need to rewind the idx by 1
-            return (Some(prev_offset), idx - 1);
-        }
-        prev_offset = offset;
-    }
-
-    // if we've reached the end with nearest and haven't returned, it means that the last item was the closest
-    // note for a nearest-match, we can re-match on the same val_r next time,
-    // so we need to rewind the idx by 1
-    (Some(offsets[offsets.len() - 1]), offsets.len() - 1)
-}
-
-// process the group taken by the `by` operation and keep track of the offset.
-// we don't process a group at once but per `index_left` we find the `right_index` and keep track
-// of the offsets we have already processed in a separate hashmap. Then on a next iteration we can
-// continue from that offsets location.
-#[allow(clippy::too_many_arguments)]
-#[allow(clippy::type_complexity)]
-fn process_group<K, T>(
-    k: K,
-    idx_left: IdxSize,
-    tolerance: T,
-    indexes_right: &[IdxSize],
-    right_tbl_offsets: &mut PlHashMap<K, (usize, Option<IdxSize>)>,
-    join_asof_fn: unsafe fn(T, &[T], &[IdxSize], T) -> (Option<IdxSize>, usize),
-    left_asof: &[T],
-    right_asof: &[T],
-    results: &mut Vec<Option<IdxSize>>,
-    forward: bool,
-) where
-    K: Hash + PartialEq + Eq,
-    T: NativeType + Sub<Output = T> + PartialOrd + Zero,
-{
-    let (offset_slice, mut previous_join_idx) =
-        *right_tbl_offsets.get(&k).unwrap_or(&(0usize, None));
-    debug_assert!((idx_left as usize) < left_asof.len());
-    let val_l = unsafe { *left_asof.get_unchecked(idx_left as usize) };
-    // Safety;
-    // elide bound checks
-    let (join_idx, offset_slice_add) =
-        unsafe { join_asof_fn(val_l, right_asof, &indexes_right[offset_slice..], tolerance) };
-    let offset_slice = offset_slice + offset_slice_add;
-
-    match join_idx {
-        Some(_) => {
-            results.push(join_idx);
-            right_tbl_offsets.insert(k, (offset_slice, join_idx));
-        }
-        None => {
-            if forward {
-                previous_join_idx = None;
-            }
-            if tolerance > Zero::zero() {
-                if let Some(idx) = previous_join_idx {
-                    debug_assert!((idx as usize) < right_asof.len());
-                    let val_r = unsafe { *right_asof.get_unchecked(idx as usize) };
-                    let dist = val_l - val_r;
-                    if dist > tolerance {
-                        previous_join_idx = None;
-                    }
-                }
-            }
-            results.push(previous_join_idx)
-        }
-    }
-}
-
-fn asof_join_by_numeric<T, S>(
-    by_left: &ChunkedArray<S>,
-    by_right: &ChunkedArray<S>,
-    left_asof: &ChunkedArray<T>,
-    right_asof: &ChunkedArray<T>,
-    tolerance: Option<AnyValue<'static>>,
-    strategy: AsofStrategy,
-) -> PolarsResult<Vec<Option<IdxSize>>>
-where
-    T: PolarsNumericType,
-    S: PolarsNumericType,
-    S::Native: Hash + Eq + AsU64,
-{
-    #[allow(clippy::type_complexity)]
-    let (join_asof_fn, tolerance, forward): (
-        unsafe fn(T::Native, &[T::Native], &[IdxSize], T::Native) -> (Option<IdxSize>, usize),
-        _,
-        _,
-    ) = match (tolerance, strategy) {
-        (Some(tolerance), AsofStrategy::Backward) => {
-            let tol = tolerance.extract::<T::Native>().unwrap();
-            (
-                join_asof_backward_with_indirection_and_tolerance,
-                tol,
-                false,
-            )
-        }
-        (None, AsofStrategy::Backward) => (
-            join_asof_backward_with_indirection,
-            T::Native::zero(),
-            false,
-        ),
-        (Some(tolerance), AsofStrategy::Forward) => {
-            let tol = tolerance.extract::<T::Native>().unwrap();
-            (join_asof_forward_with_indirection_and_tolerance, tol, true)
-        }
-        (None, AsofStrategy::Forward) => {
-            (join_asof_forward_with_indirection, T::Native::zero(), true)
-        }
-        (_, AsofStrategy::Nearest) => {
-            (join_asof_nearest_with_indirection, T::Native::zero(), false)
-        }
-    };
-
-    let left_asof = left_asof.rechunk();
-    let err = |_: PolarsError| {
-        polars_err!(
-            ComputeError: "keys are not allowed to have null values in asof join"
-        )
-    };
-    let left_asof = left_asof.cont_slice().map_err(err)?;
-
-    let right_asof = right_asof.rechunk();
-    let right_asof = right_asof.cont_slice().map_err(err)?;
-
-    let n_threads = POOL.current_num_threads();
-    let splitted_left = split_ca(by_left, n_threads).unwrap();
-    let splitted_right = split_ca(by_right, n_threads).unwrap();
-
-    let vals_left = splitted_left
-        .iter()
-        .map(|ca| ca.cont_slice().unwrap())
-        .collect::<Vec<_>>();
-    let vals_right = splitted_right
-        .iter()
-        .map(|ca| ca.cont_slice().unwrap())
-        .collect::<Vec<_>>();
-
-    let hash_tbls = build_tables(vals_right);
-
-    // we determine the offset so that we later know which index to store in the join tuples
-    let offsets = vals_left
-        .iter()
-        .map(|ph| ph.len())
-        .scan(0, |state, val| {
-            let out = *state;
-            *state += val;
-            Some(out)
-        })
-        .collect::<Vec<_>>();
-
-    let n_tables = hash_tbls.len() as u64;
-    debug_assert!(n_tables.is_power_of_two());
-
-    // next we probe the right relation
-    Ok(POOL.install(|| {
-        vals_left
-            .into_par_iter()
-            .zip(offsets)
-            // probes_hashes: Vec<u64> processed by this thread
-            // offset: offset index
-            .flat_map(|(vals_left, offset)| {
-                // local reference
-                let hash_tbls = &hash_tbls;
-
-                // assume the result tuples equal length of the no. of hashes processed by this thread.
-                let mut results = Vec::with_capacity(vals_left.len());
-
-                let mut right_tbl_offsets = PlHashMap::with_capacity(HASHMAP_INIT_SIZE);
-
-                vals_left.iter().enumerate().for_each(|(idx_a, k)| {
-                    let idx_a = (idx_a + offset) as IdxSize;
-                    // probe table that contains the hashed value
-                    let current_probe_table = unsafe {
-                        get_hash_tbl_threaded_join_partitioned(k.as_u64(), hash_tbls, n_tables)
-                    };
-
-                    // we already hashed, so we don't have to hash again.
-                    let value = current_probe_table.get(k);
-
-                    match value {
-                        // left and right matches
-                        Some(indexes_b) => {
-                            process_group(
-                                *k,
-                                idx_a,
-                                tolerance,
-                                indexes_b,
-                                &mut right_tbl_offsets,
-                                join_asof_fn,
-                                left_asof,
-                                right_asof,
-                                &mut results,
-                                forward,
-                            );
-                        }
-                        // only left values, right = null
-                        None => results.push(None),
-                    }
-                });
-                results
-            })
-            .collect()
-    }))
-}
-
-fn asof_join_by_binary<T>(
-    by_left: &BinaryChunked,
-    by_right: &BinaryChunked,
-    left_asof: &ChunkedArray<T>,
-    right_asof: &ChunkedArray<T>,
-    tolerance: Option<AnyValue<'static>>,
-    strategy: AsofStrategy,
-) -> Vec<Option<IdxSize>>
+/// Sort the right row indices of every `by` group on their asof key.
+fn sort_groups<T>(groups: &mut [Vec<IdxSize>], right_asof: &ChunkedArray<T>)
 where
     T: PolarsNumericType,
 {
-    #[allow(clippy::type_complexity)]
-    let (join_asof_fn, tolerance, forward): (
-        unsafe fn(T::Native, &[T::Native], &[IdxSize], T::Native) -> (Option<IdxSize>, usize),
-        _,
-        _,
-    ) = match (tolerance, strategy) {
-        (Some(tolerance), AsofStrategy::Backward) => {
-            let tol = tolerance.extract::<T::Native>().unwrap();
-            (
-                join_asof_backward_with_indirection_and_tolerance,
-                tol,
-                false,
-            )
-        }
-        (None, AsofStrategy::Backward) => (
-            join_asof_backward_with_indirection,
-            T::Native::zero(),
-            false,
-        ),
-        (Some(tolerance), AsofStrategy::Forward) => {
-            let tol = tolerance.extract::<T::Native>().unwrap();
-            (join_asof_forward_with_indirection_and_tolerance, tol, true)
-        }
-        (None, AsofStrategy::Forward) => {
-            (join_asof_forward_with_indirection, T::Native::zero(), true)
-        }
-        (_, AsofStrategy::Nearest) => {
-            (join_asof_nearest_with_indirection, T::Native::zero(), false)
-        }
-    };
-
-    let left_asof = left_asof.rechunk();
-    let left_asof = left_asof.cont_slice().unwrap();
-
     let right_asof = right_asof.rechunk();
     let right_asof = right_asof.cont_slice().unwrap();
-
-    let n_threads = POOL.current_num_threads();
-    let splitted_by_left = split_ca(by_left, n_threads).unwrap();
-    let splitted_right = split_ca(by_right, n_threads).unwrap();
-
-    let hb = RandomState::default();
-    let vals_left = prepare_bytes(&splitted_by_left, &hb);
-    let vals_right = prepare_bytes(&splitted_right, &hb);
-
-    let hash_tbls = build_tables(vals_right);
-
-    // we determine the offset so that we later know which index to store in the join tuples
-    let offsets = vals_left
-        .iter()
-        .map(|ph| ph.len())
-        .scan(0, |state, val| {
-            let out = *state;
-            *state += val;
-            Some(out)
-        })
-        .collect::<Vec<_>>();
-
-    let n_tables = hash_tbls.len() as u64;
-    debug_assert!(n_tables.is_power_of_two());
-
-    // next we probe the right relation
+    // the sort is stable, so of equal keys a backward search
+    // matches the last row and a forward search the first row
     POOL.install(|| {
-        vals_left
-            .into_par_iter()
-            .zip(offsets)
-            // probes_hashes: Vec<u64> processed by this thread
-            // offset: offset index
-            .flat_map(|(vals_left, offset)| {
-                // local reference
-                let hash_tbls = &hash_tbls;
-
-                // assume the result tuples equal length of the no. of hashes processed by this thread.
-                let mut results = Vec::with_capacity(vals_left.len());
-
-                let mut right_tbl_offsets = PlHashMap::with_capacity(HASHMAP_INIT_SIZE);
-
-                vals_left.iter().enumerate().for_each(|(idx_a, k)| {
-                    let idx_a = (idx_a + offset) as IdxSize;
-                    // probe table that contains the hashed value
-                    let current_probe_table = unsafe {
-                        get_hash_tbl_threaded_join_partitioned(k.as_u64(), hash_tbls, n_tables)
-                    };
-
-                    // we already hashed, so we don't have to hash again.
-                    let value = current_probe_table.get(k);
-
-                    match value {
-                        // left and right matches
-                        Some(indexes_b) => {
-                            process_group(
-                                *k,
-                                idx_a,
-                                tolerance,
-                                indexes_b,
-                                &mut right_tbl_offsets,
-                                join_asof_fn,
-                                left_asof,
-                                right_asof,
-                                &mut results,
-                                forward,
-                            );
-                        }
-                        // only left values, right = null
-                        None => results.push(None),
-                    }
-                });
-                results
+        groups.par_iter_mut().for_each(|idx| {
+            idx.sort_by(|a, b| {
+                right_asof[*a as usize]
+                    .partial_cmp(&right_asof[*b as usize])
+                    .unwrap_or(Ordering::Equal)
             })
-            .collect()
-    })
+        })
+    });
 }
 
-// TODO! optimize this. This does a full scan backwards. Use the same strategy as in the single `by`
-// implementations
-fn asof_join_by_multiple<T>(
-    a: &mut DataFrame,
-    b: &mut DataFrame,
+/// Search the match of every left row in the right rows of its `by` group.
+#[allow(clippy::too_many_arguments)]
+fn probe_groups<T>(
+    left_keys: &BinaryChunked,
     left_asof: &ChunkedArray<T>,
-    right_asof: &ChunkedArray<T>,
-    tolerance: Option<AnyValue<'static>>,
+    right: &AsofJoinByRight,
     strategy: AsofStrategy,
-) -> Vec<Option<IdxSize>>
+    tolerance: Option<AnyValue<'static>>,
+    tolerance_column: Option<&Series>,
+    allow_exact_matches: bool,
+) -> PolarsResult<Vec<Option<IdxSize>>>
 where
     T: PolarsNumericType,
 {
-    #[allow(clippy::type_complexity)]
-    let (join_asof_fn, tolerance, forward): (
-        unsafe fn(T::Native, &[T::Native], &[IdxSize], T::Native) -> (Option<IdxSize>, usize),
-        _,
-        _,
-    ) = match (tolerance, strategy) {
-        (Some(tolerance), AsofStrategy::Backward) => {
-            let tol = tolerance.extract::<T::Native>().unwrap();
-            (
-                join_asof_backward_with_indirection_and_tolerance,
-                tol,
-                false,
-            )
-        }
-        (None, AsofStrategy::Backward) => (
-            join_asof_backward_with_indirection,
-            T::Native::zero(),
-            false,
-        ),
-        (Some(tolerance), AsofStrategy::Forward) => {
-            let tol = tolerance.extract::<T::Native>().unwrap();
-            (join_asof_forward_with_indirection_and_tolerance, tol, true)
-        }
-        (None, AsofStrategy::Forward) => {
-            (join_asof_forward_with_indirection, T::Native::zero(), true)
-        }
-        (_, AsofStrategy::Nearest) => {
-            (join_asof_nearest_with_indirection, T::Native::zero(), false)
-        }
-    };
     let left_asof = left_asof.rechunk();
-    let left_asof = left_asof.cont_slice().unwrap();
-
-    let right_asof = right_asof.rechunk();
-    let right_asof = right_asof.cont_slice().unwrap();
-
-    let n_threads = POOL.current_num_threads();
-    let dfs_a = split_df(a, n_threads).unwrap();
-    let dfs_b = split_df(b, n_threads).unwrap();
-
-    let (build_hashes, random_state) = df_rows_to_hashes_threaded_vertical(&dfs_b, None).unwrap();
-    let (probe_hashes, _) =
-        df_rows_to_hashes_threaded_vertical(&dfs_a, Some(random_state)).unwrap();
-
-    let hash_tbls = mk::create_probe_table(&build_hashes, b);
-    // early drop to reduce memory pressure
-    drop(build_hashes);
-
-    let n_tables = hash_tbls.len() as u64;
-    let offsets = mk::get_offsets(&probe_hashes);
+    let left_asof = left_asof.cont_slice()?;
+    let right_vals = right.right_vals.unpack::<T>()?;
+    let right_vals = right_vals.cont_slice()?;
+
+    let tolerances = tolerance_column
+        .map(|s| s.unpack::<T>().map(|ca| ca.into_iter().collect::<Vec<_>>()))
+        .transpose()?;
+    let tolerance = match (&tolerances, tolerance) {
+        (Some(tolerances), _) => Tolerance::PerRow(tolerances),
+        (None, Some(tolerance)) => Tolerance::Global(tolerance.extract().unwrap()),
+        (None, None) => Tolerance::None,
+    };
 
-    // next we probe the other relation
-    // code duplication is because we want to only do the swap check once
-    POOL.install(|| {
-        probe_hashes
-            .into_par_iter()
-            .zip(offsets)
-            .flat_map(|(probe_hashes, offset)| {
-                // local reference
-                let hash_tbls = &hash_tbls;
-
-                // assume the result tuples equal length of the no. of hashes processed by this thread.
-                let mut results = Vec::with_capacity(probe_hashes.len());
-                let mut right_tbl_offsets = PlHashMap::with_capacity(HASHMAP_INIT_SIZE);
-
-                let local_offset = offset;
-
-                let mut idx_a = local_offset as IdxSize;
-                for probe_hashes in probe_hashes.data_views() {
-                    for (idx, &h) in probe_hashes.iter().enumerate() {
-                        debug_assert!(idx + offset < left_asof.len());
-                        // probe table that contains the hashed value
-                        let current_probe_table = unsafe {
-                            get_hash_tbl_threaded_join_partitioned(h, hash_tbls, n_tables)
-                        };
-
-                        let entry = current_probe_table.raw_entry().from_hash(h, |idx_hash| {
-                            let idx_b = idx_hash.idx;
-                            // Safety:
-                            // indices in a join operation are always in bounds.
-                            unsafe { mk::compare_df_rows2(a, b, idx_a as usize, idx_b as usize) }
-                        });
-
-                        match entry {
-                            // left and right matches
-                            Some((k, indexes_b)) => {
-                                process_group(
-                                    // take the first idx as unique identifier of that group.
-                                    k.idx,
-                                    idx_a,
-                                    tolerance,
-                                    indexes_b,
-                                    &mut right_tbl_offsets,
-                                    join_asof_fn,
-                                    left_asof,
-                                    right_asof,
-                                    &mut results,
-                                    forward,
-                                );
-                            }
-                            // only left values, right = null
-                            None => results.push(None),
-                        }
-                        idx_a += 1;
-                    }
-                }
-
-                results
+    let left_keys = left_keys.into_no_null_iter().collect::<Vec<_>>();
+    let out = POOL.install(|| {
+        left_keys
+            .par_iter()
+            .enumerate()
+            .map(|(idx_l, key)| {
+                let &(offset, len) = right.groups.get(*key)?;
+                asof_search(
+                    left_asof[idx_l],
+                    &right_vals[offset..offset + len],
+                    strategy,
+                    tolerance.get(idx_l),
+                    allow_exact_matches,
+                )
+                .map(|pos| right.idx_right[offset + pos])
             })
             .collect()
-    })
+    });
+    Ok(out)
 }
 
-#[allow(clippy::too_many_arguments)]
-fn dispatch_join<T: PolarsNumericType>(
-    left_asof: &ChunkedArray<T>,
-    right_asof: &ChunkedArray<T>,
-    left_by_s: &Series,
-    right_by_s: &Series,
-    left_by: &mut DataFrame,
-    right_by: &mut DataFrame,
-    strategy: AsofStrategy,
-    tolerance: Option<AnyValue<'static>>,
-) -> PolarsResult<Vec<Option<IdxSize>>> {
-    let out = if left_by.width() == 1 {
-        match left_by_s.dtype() {
-            DataType::Utf8 => asof_join_by_binary(
-                &left_by_s.utf8().unwrap().as_binary(),
-                &right_by_s.utf8().unwrap().as_binary(),
-                left_asof,
-                right_asof,
-                tolerance,
-                strategy,
-            ),
-            DataType::Binary => asof_join_by_binary(
-                left_by_s.binary().unwrap(),
-                right_by_s.binary().unwrap(),
-                left_asof,
-                right_asof,
-                tolerance,
-                strategy,
-            ),
-            _ => {
-                if left_by_s.bit_repr_is_large() {
-                    let left_by = left_by_s.bit_repr_large();
-                    let right_by = right_by_s.bit_repr_large();
-                    asof_join_by_numeric(
-                        &left_by, &right_by, left_asof, right_asof, tolerance, strategy,
-                    )?
-                } else {
-                    let left_by = left_by_s.bit_repr_small();
-                    let right_by = right_by_s.bit_repr_small();
-                    asof_join_by_numeric(
-                        &left_by, &right_by, left_asof, right_asof, tolerance, strategy,
-                    )?
-                }
-            }
+/// The right table of an asof join with `by` columns, grouped on its `by` keys.
+///
+/// The groups are built once, so that the table can be probed by many left tables,
+/// like the chunks of the left table in the streaming engine.
+#[doc(hidden)]
+pub struct AsofJoinByRight {
+    /// The right table without its `by` columns.
+    other: DataFrame,
+    right_on: SmartString,
+    right_asof: Series,
+    by_dtypes: Vec<DataType>,
+    /// The right row indices ordered by group and within a group by asof key.
+    idx_right: Vec<IdxSize>,
+    /// The physical asof keys of `idx_right`.
+    right_vals: Series,
+    /// The `(offset, len)` of every group in `idx_right`, keyed by the row encoded `by` values.
+    groups: PlHashMap<Vec<u8>, (usize, usize)>,
+}
+
+impl AsofJoinByRight {
+    pub fn new(
+        other: &DataFrame,
+        right_on: &str,
+        right_by: Vec<SmartString>,
+    ) -> PolarsResult<Self> {
+        polars_ensure!(
+            !right_by.is_empty(),
+            ComputeError: "asof join expects at least one 'by' column"
+        );
+        let right_asof = other.column(right_on)?.clone();
+        polars_ensure!(
+            right_asof.null_count() == 0,
+            ComputeError: "asof join must not have null values in 'on' arguments"
+        );
+        let right_by = other.select(&right_by)?;
+        let by_dtypes = right_by.dtypes();
+        let by = right_by
+            .get_columns()
+            .iter()
+            .map(|s| s.to_physical_repr().into_owned())
+            .collect::<Vec<_>>();
+        let keys = encode_rows_vertical(&by)?;
+
+        let mut first = PlHashMap::new();
+        let mut groups: Vec<Vec<IdxSize>> = vec![];
+        for (idx, key) in keys.into_no_null_iter().enumerate() {
+            let group = *first.entry(key).or_insert_with(|| {
+                groups.push(vec![]);
+                groups.len() - 1
+            });
+            groups[group].push(idx as IdxSize);
         }
-    } else {
-        for (lhs, rhs) in left_by.get_columns().iter().zip(right_by.get_columns()) {
-            polars_ensure!(lhs.dtype() == rhs.dtype(),
-                ComputeError: "mismatching dtypes in 'on' parameter of asof-join: `{}` and `{}`", lhs.dtype(), rhs.dtype()
-            );
-            #[cfg(feature = "dtype-categorical")]
-            _check_categorical_src(lhs.dtype(), rhs.dtype())?;
+
+        let right_phys = right_asof.to_physical_repr();
+        polars_ensure!(
+            right_phys.dtype().is_numeric(),
+            InvalidOperation: "asof join only supported on numeric/temporal keys"
+        );
+        with_match_physical_numeric_polars_type!(right_phys.dtype(), |$T| {
+            let ca: &ChunkedArray<$T> = right_phys.as_ref().as_ref().as_ref();
+            sort_groups(&mut groups, ca)
+        });
+
+        let mut offsets = Vec::with_capacity(groups.len());
+        let mut offset = 0;
+        for group in &groups {
+            offsets.push((offset, group.len()));
+            offset += group.len();
         }
-        asof_join_by_multiple(
-            left_by, right_by, left_asof, right_asof, tolerance, strategy,
-        )
-    };
-    Ok(out)
+        let idx_right = groups.into_iter().flatten().collect::<Vec<_>>();
+        let right_vals = right_phys
+            .take(&IdxCa::from_vec("", idx_right.clone()))?
+            .rechunk();
+        let groups = first
+            .into_iter()
+            .map(|(key, group)| (key.to_vec(), offsets[group]))
+            .collect();
+
+        let other = other.drop_many(&right_by.get_column_names());
+
+        Ok(AsofJoinByRight {
+            other,
+            right_on: right_on.into(),
+            right_asof,
+            by_dtypes,
+            idx_right,
+            right_vals,
+            groups,
+        })
+    }
 }
 
 impl DataFrame {
+    /// Asof join on a right table that is grouped on its `by` columns already.
     #[allow(clippy::too_many_arguments)]
     #[doc(hidden)]
-    pub fn _join_asof_by(
+    pub fn _join_asof_by_right(
         &self,
-        other: &DataFrame,
+        right: &AsofJoinByRight,
         left_on: &str,
-        right_on: &str,
         left_by: Vec<SmartString>,
-        right_by: Vec<SmartString>,
         strategy: AsofStrategy,
         tolerance: Option<AnyValue<'static>>,
+        tolerance_column: Option<&str>,
+        allow_exact_matches: bool,
         suffix: Option<&str>,
         slice: Option<(i64, usize)>,
     ) -> PolarsResult<DataFrame> {
-        let left_asof = self.column(left_on)?.to_physical_repr();
-        let right_asof = other.column(right_on)?.to_physical_repr();
-        let right_asof_name = right_asof.name();
-        let left_asof_name = left_asof.name();
-
-        check_asof_columns(
-            &left_asof,
-            &right_asof,
-            left_by.is_empty() && right_by.is_empty(),
-        )?;
-
-        let mut left_by = self.select(left_by)?;
-        let mut right_by = other.select(right_by)?;
-
-        unsafe {
-            for (l, r) in left_by
-                .get_columns_mut()
-                .iter_mut()
-                .zip(right_by.get_columns_mut().iter_mut())
-            {
+        polars_ensure!(
+            !left_by.is_empty() && left_by.len() == right.by_dtypes.len(),
+            ComputeError: "asof join expects the same non-zero number of 'by' columns on both sides, got {} and {}",
+            left_by.len(), right.by_dtypes.len()
+        );
+        let left_asof = self.column(left_on)?;
+        check_asof_columns(left_asof, &right.right_asof, false)?;
+        let tolerance_column = tolerance_column
+            .map(|name| tolerance_to_physical(self.column(name)?, left_asof.dtype()))
+            .transpose()?;
+        let left_asof = left_asof.to_physical_repr();
+
+        let left_by = self
+            .select(left_by)?
+            .get_columns()
+            .iter()
+            .zip(&right.by_dtypes)
+            .map(|(l, r)| {
+                polars_ensure!(l.dtype() == r,
+                    ComputeError: "mismatching dtypes in 'by' parameter of asof-join: `{}` and `{}`", l.dtype(), r
+                );
                 #[cfg(feature = "dtype-categorical")]
-                _check_categorical_src(l.dtype(), r.dtype())?;
-                *l = l.to_physical_repr().into_owned();
-                *r = r.to_physical_repr().into_owned();
-            }
-        }
-
-        let left_by_s = left_by.get_columns()[0].to_physical_repr().into_owned();
-        let right_by_s = right_by.get_columns()[0].to_physical_repr().into_owned();
+                _check_categorical_src(l.dtype(), r)?;
+                Ok(l.to_physical_repr().into_owned())
+            })
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let left_keys = encode_rows_vertical(&left_by)?;
 
         let right_join_tuples = with_match_physical_numeric_polars_type!(left_asof.dtype(), |$T| {
             let left_asof: &ChunkedArray<$T> = left_asof.as_ref().as_ref().as_ref();
-            let right_asof: &ChunkedArray<$T> = right_asof.as_ref().as_ref().as_ref();
-
-            dispatch_join(
+            probe_groups(
+                &left_keys,
                 left_asof,
-                right_asof,
-                &left_by_s,
-                &right_by_s,
-                &mut left_by,
-                &mut right_by,
+                right,
                 strategy,
-                tolerance
+                tolerance,
+                tolerance_column.as_ref(),
+                allow_exact_matches,
             )
         })?;
 
-        let mut drop_these = right_by.get_column_names();
-        if left_asof_name == right_asof_name {
-            drop_these.push(right_asof_name);
-        }
-
-        let cols = other
-            .get_columns()
-            .iter()
-            .filter_map(|s| {
-                if drop_these.contains(&s.name()) {
-                    None
-                } else {
-                    Some(s.clone())
-                }
-            })
-            .collect();
-        let other = DataFrame::new_no_checks(cols);
+        // drop right join column
+        let other = if left_on == right.right_on.as_str() {
+            Cow::Owned(right.other.drop_many(&[right.right_on.as_str()]))
+        } else {
+            Cow::Borrowed(&right.other)
+        };
 
         let mut left = self.clone();
         let mut right_join_tuples = &*right_join_tuples;
@@ -781,9 +255,45 @@ impl DataFrame {
         _finish_join(left, right_df, suffix)
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[doc(hidden)]
+    pub fn _join_asof_by(
+        &self,
+        other: &DataFrame,
+        left_on: &str,
+        right_on: &str,
+        left_by: Vec<SmartString>,
+        right_by: Vec<SmartString>,
+        strategy: AsofStrategy,
+        tolerance: Option<AnyValue<'static>>,
+        tolerance_column: Option<&str>,
+        allow_exact_matches: bool,
+        suffix: Option<&str>,
+        slice: Option<(i64, usize)>,
+    ) -> PolarsResult<DataFrame> {
+        polars_ensure!(
+            !left_by.is_empty() && left_by.len() == right_by.len(),
+            ComputeError: "asof join expects the same non-zero number of 'by' columns on both sides, got {} and {}",
+            left_by.len(), right_by.len()
+        );
+        let right = AsofJoinByRight::new(other, right_on, right_by)?;
+        self._join_asof_by_right(
+            &right,
+            left_on,
+            left_by,
+            strategy,
+            tolerance,
+            tolerance_column,
+            allow_exact_matches,
+            suffix,
+            slice,
+        )
+    }
+
     /// This is similar to a left-join except that we match on nearest key rather than equal keys.
-    /// The keys must be sorted to perform an asof join. This is a special implementation of an asof join
-    /// that searches for the nearest keys within a subgroup set by `by`.
+    /// This is a special implementation of an asof join that searches for the nearest keys within
+    /// a subgroup set by `by`. The `by` columns may be of different dtypes and the keys don't have
+    /// to be sorted.
     #[allow(clippy::too_many_arguments)]
     pub fn join_asof_by<I, S>(
         &self,
@@ -802,7 +312,8 @@ impl DataFrame {
         let left_by = left_by.into_iter().map(|s| s.as_ref().into()).collect();
         let right_by = right_by.into_iter().map(|s| s.as_ref().into()).collect();
         self._join_asof_by(
-            other, left_on, right_on, left_by, right_by, strategy, tolerance, None, None,
+            other, left_on, right_on, left_by, right_by, strategy, tolerance, None, true, None,
+            None,
         )
     }
 }
@@ -1018,4 +529,59 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_asof_by_unsorted_multiple_keys() -> PolarsResult<()> {
+        let trades = df![
+            "time" => [48i64, 23, 48, 38],
+            "ticker" => ["GOOG", "MSFT", "GOOG", "MSFT"],
+            "venue" => [2, 1, 1, 1],
+            "max_lag" => [Some(10i64), Some(1), Some(100), None],
+        ]?;
+        let quotes = df![
+            "time" => [72i64, 41, 23, 30, 23, 48],
+            "ticker" => ["GOOG", "MSFT", "MSFT", "MSFT", "GOOG", "GOOG"],
+            "venue" => [2, 1, 1, 1, 1, 2],
+            "bid" => [720.5, 51.99, 51.95, 51.97, 720.4, 720.6],
+        ]?;
+
+        let out = trades._join_asof_by(
+            &quotes,
+            "time",
+            "time",
+            vec!["ticker".into(), "venue".into()],
+            vec!["ticker".into(), "venue".into()],
+            AsofStrategy::Backward,
+            None,
+            None,
+            true,
+            None,
+            None,
+        )?;
+        let bid = out.column("bid")?;
+        assert_eq!(
+            Vec::from(bid.f64()?),
+            &[Some(720.6), Some(51.95), Some(720.4), Some(51.97)]
+        );
+
+        let out = trades._join_asof_by(
+            &quotes,
+            "time",
+            "time",
+            vec!["ticker".into(), "venue".into()],
+            vec!["ticker".into(), "venue".into()],
+            AsofStrategy::Nearest,
+            None,
+            Some("max_lag"),
+            false,
+            None,
+            None,
+        )?;
+        let bid = out.column("bid")?;
+        assert_eq!(
+            Vec::from(bid.f64()?),
+            &[None, None, Some(720.4), Some(51.99)]
+        );
+        Ok(())
+    }
 }
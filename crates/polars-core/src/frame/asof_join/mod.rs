@@ -3,6 +3,7 @@ mod groups;
 use std::borrow::Cow;
 
 use asof::*;
+pub use groups::AsofJoinByRight;
 use num_traits::Bounded;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -11,7 +12,7 @@ use smartstring::alias::String as SmartString;
 use crate::prelude::*;
 use crate::utils::{ensure_sorted_arg, slice_slice};
 
-#[derive(Clone, Debug, PartialEq, Eq, Default)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct AsOfOptions {
     pub strategy: AsofStrategy,
@@ -23,8 +24,60 @@ pub struct AsOfOptions {
     /// - "1d6h"
     /// etc
     pub tolerance_str: Option<SmartString>,
+    /// A column of the left table that holds the tolerance of every row.
+    /// This takes precedence over `tolerance`; rows with a null tolerance have no limit.
+    pub tolerance_column: Option<SmartString>,
     pub left_by: Option<Vec<SmartString>>,
     pub right_by: Option<Vec<SmartString>>,
+    /// Whether rows with equal keys may match. If `false`, a backward search only
+    /// matches strictly smaller keys and a forward search strictly larger keys.
+    pub allow_exact_matches: bool,
+}
+
+impl Default for AsOfOptions {
+    fn default() -> Self {
+        AsOfOptions {
+            strategy: Default::default(),
+            tolerance: None,
+            tolerance_str: None,
+            tolerance_column: None,
+            left_by: None,
+            right_by: None,
+            allow_exact_matches: true,
+        }
+    }
+}
+
+/// Cast a tolerance column to the physical type of the asof key it applies to.
+fn tolerance_to_physical(tolerance: &Series, key_dtype: &DataType) -> PolarsResult<Series> {
+    let tolerance = match (key_dtype, tolerance.dtype()) {
+        #[cfg(all(feature = "dtype-datetime", feature = "dtype-duration"))]
+        (DataType::Datetime(tu, _), DataType::Duration(_)) => {
+            tolerance.cast(&DataType::Duration(*tu))?
+        }
+        #[cfg(feature = "dtype-duration")]
+        (DataType::Duration(tu), DataType::Duration(_)) => {
+            tolerance.cast(&DataType::Duration(*tu))?
+        }
+        #[cfg(all(feature = "dtype-date", feature = "dtype-duration"))]
+        (DataType::Date, DataType::Duration(_)) => {
+            use crate::chunked_array::temporal::conversion::MS_IN_DAY;
+            let ms = tolerance
+                .cast(&DataType::Duration(TimeUnit::Milliseconds))?
+                .to_physical_repr()
+                .into_owned();
+            (ms.i64().unwrap() / MS_IN_DAY).into_series()
+        }
+        #[cfg(all(feature = "dtype-time", feature = "dtype-duration"))]
+        (DataType::Time, DataType::Duration(_)) => {
+            tolerance.cast(&DataType::Duration(TimeUnit::Nanoseconds))?
+        }
+        (_, dtype) if dtype.is_numeric() => tolerance.clone(),
+        (_, dtype) => polars_bail!(
+            InvalidOperation: "asof join tolerance column should be numeric or a duration, got {}", dtype
+        ),
+    };
+    tolerance.to_physical_repr().cast(&key_dtype.to_physical())
 }
 
 fn check_asof_columns(a: &Series, b: &Series, check_sorted: bool) -> PolarsResult<()> {
@@ -73,6 +126,8 @@ where
         other: &Series,
         strategy: AsofStrategy,
         tolerance: Option<AnyValue<'static>>,
+        tolerance_column: Option<&Series>,
+        allow_exact_matches: bool,
     ) -> PolarsResult<Vec<Option<IdxSize>>> {
         let other = self.unpack_series_matching_type(other)?;
 
@@ -80,6 +135,30 @@ where
         let ca = self.rechunk();
         let other = other.rechunk();
 
+        let sorted_merge = tolerance_column.is_none()
+            && allow_exact_matches
+            && !(strategy == AsofStrategy::Nearest && tolerance.is_some());
+        if !sorted_merge {
+            let tolerances = tolerance_column
+                .map(|s| {
+                    self.unpack_series_matching_type(s)
+                        .map(|ca| ca.into_iter().collect::<Vec<_>>())
+                })
+                .transpose()?;
+            let tolerance = match (&tolerances, tolerance) {
+                (Some(tolerances), _) => Tolerance::PerRow(tolerances),
+                (None, Some(tolerance)) => Tolerance::Global(tolerance.extract().unwrap()),
+                (None, None) => Tolerance::None,
+            };
+            return Ok(join_asof_search(
+                ca.cont_slice().unwrap(),
+                other.cont_slice().unwrap(),
+                strategy,
+                &tolerance,
+                allow_exact_matches,
+            ));
+        }
+
         let out = match strategy {
             AsofStrategy::Forward => match tolerance {
                 None => join_asof_forward(ca.cont_slice().unwrap(), other.cont_slice().unwrap()),
@@ -121,6 +200,8 @@ impl DataFrame {
         right_on: &str,
         strategy: AsofStrategy,
         tolerance: Option<AnyValue<'static>>,
+        tolerance_column: Option<&str>,
+        allow_exact_matches: bool,
         suffix: Option<String>,
         slice: Option<(i64, usize)>,
     ) -> PolarsResult<DataFrame> {
@@ -128,41 +209,69 @@ impl DataFrame {
         let right_key = other.column(right_on)?;
 
         check_asof_columns(left_key, right_key, true)?;
+        let tolerance_column = tolerance_column
+            .map(|name| tolerance_to_physical(self.column(name)?, left_key.dtype()))
+            .transpose()?;
+        let tolerance_column = tolerance_column.as_ref();
         let left_key = left_key.to_physical_repr();
         let right_key = right_key.to_physical_repr();
 
         let take_idx = match left_key.dtype() {
-            DataType::Int64 => left_key
-                .i64()
-                .unwrap()
-                .join_asof(&right_key, strategy, tolerance),
-            DataType::Int32 => left_key
-                .i32()
-                .unwrap()
-                .join_asof(&right_key, strategy, tolerance),
-            DataType::UInt64 => left_key
-                .u64()
-                .unwrap()
-                .join_asof(&right_key, strategy, tolerance),
-            DataType::UInt32 => left_key
-                .u32()
-                .unwrap()
-                .join_asof(&right_key, strategy, tolerance),
-            DataType::Float32 => left_key
-                .f32()
-                .unwrap()
-                .join_asof(&right_key, strategy, tolerance),
-            DataType::Float64 => left_key
-                .f64()
-                .unwrap()
-                .join_asof(&right_key, strategy, tolerance),
+            DataType::Int64 => left_key.i64().unwrap().join_asof(
+                &right_key,
+                strategy,
+                tolerance,
+                tolerance_column,
+                allow_exact_matches,
+            ),
+            DataType::Int32 => left_key.i32().unwrap().join_asof(
+                &right_key,
+                strategy,
+                tolerance,
+                tolerance_column,
+                allow_exact_matches,
+            ),
+            DataType::UInt64 => left_key.u64().unwrap().join_asof(
+                &right_key,
+                strategy,
+                tolerance,
+                tolerance_column,
+                allow_exact_matches,
+            ),
+            DataType::UInt32 => left_key.u32().unwrap().join_asof(
+                &right_key,
+                strategy,
+                tolerance,
+                tolerance_column,
+                allow_exact_matches,
+            ),
+            DataType::Float32 => left_key.f32().unwrap().join_asof(
+                &right_key,
+                strategy,
+                tolerance,
+                tolerance_column,
+                allow_exact_matches,
+            ),
+            DataType::Float64 => left_key.f64().unwrap().join_asof(
+                &right_key,
+                strategy,
+                tolerance,
+                tolerance_column,
+                allow_exact_matches,
+            ),
             _ => {
                 let left_key = left_key.cast(&DataType::Int32).unwrap();
                 let right_key = right_key.cast(&DataType::Int32).unwrap();
-                left_key
-                    .i32()
-                    .unwrap()
-                    .join_asof(&right_key, strategy, tolerance)
+                let tolerance_column = tolerance_column
+                    .map(|s| s.cast(&DataType::Int32))
+                    .transpose()?;
+                left_key.i32().unwrap().join_asof(
+                    &right_key,
+                    strategy,
+                    tolerance,
+                    tolerance_column.as_ref(),
+                    allow_exact_matches,
+                )
             }
         }?;
 
@@ -210,6 +319,8 @@ impl DataFrame {
         tolerance: Option<AnyValue<'static>>,
         suffix: Option<String>,
     ) -> PolarsResult<DataFrame> {
-        self._join_asof(other, left_on, right_on, strategy, tolerance, suffix, None)
+        self._join_asof(
+            other, left_on, right_on, strategy, tolerance, None, true, suffix, None,
+        )
     }
 }
@@ -22,7 +22,6 @@ mod proxy;
 pub use into_groups::*;
 pub use proxy::*;
 
-#[cfg(feature = "dtype-struct")]
 use crate::prelude::sort::arg_sort_multiple::encode_rows_vertical;
 
 // This will remove the sorted flag on signed integers
@@ -31,7 +30,7 @@ fn prepare_dataframe_unsorted(by: &[Series]) -> DataFrame {
         by.iter()
             .map(|s| match s.dtype() {
                 #[cfg(feature = "dtype-categorical")]
-                DataType::Categorical(_) => s.cast(&DataType::UInt32).unwrap(),
+                DataType::Categorical(_) | DataType::Enum(_) => s.cast(&DataType::UInt32).unwrap(),
                 _ => {
                     if s.dtype().to_physical().is_numeric() {
                         let s = s.to_physical_repr();
@@ -78,13 +77,10 @@ impl DataFrame {
             let series = &by[0];
             series.group_tuples(multithreaded, sorted)
         } else {
-            #[cfg(feature = "dtype-struct")]
-            {
-                if by.iter().any(|s| matches!(s.dtype(), DataType::Struct(_))) {
-                    let rows = encode_rows_vertical(&by)?;
-                    let groups = rows.group_tuples(multithreaded, sorted)?;
-                    return Ok(GroupBy::new(self, by, groups, None));
-                }
+            if by.iter().any(|s| s.dtype().is_nested()) {
+                let rows = encode_rows_vertical(&by)?;
+                let groups = rows.group_tuples(multithreaded, sorted)?;
+                return Ok(GroupBy::new(self, by, groups, None));
             }
             let keys_df = prepare_dataframe_unsorted(&by);
             if multithreaded {
@@ -1158,6 +1154,32 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    #[cfg_attr(miri, ignore)]
+    fn test_groupby_list_keys() -> PolarsResult<()> {
+        let keys = Series::new(
+            "k",
+            &[
+                Series::new("", &["a", "b"]),
+                Series::new("", &["a"]),
+                Series::new("", &["a", "b"]),
+                Series::new("", &["b", "a"]),
+            ],
+        );
+        let df = DataFrame::new(vec![keys.clone(), Series::new("v", &[1, 2, 1, 2])])?;
+
+        let groups = df.groupby_stable(["k"])?.take_groups();
+        assert_eq!(groups.len(), 3);
+        assert_eq!(groups.take_group_firsts(), &[0, 1, 3]);
+
+        let gb = df.groupby_stable(["k", "v"])?;
+        assert_eq!(gb.get_groups().len(), 3);
+
+        assert_eq!(keys.n_unique()?, 3);
+        assert_eq!(keys.unique()?.len(), 3);
+        Ok(())
+    }
+
     #[test]
     #[cfg_attr(miri, ignore)]
     #[cfg(feature = "dtype-categorical")]
@@ -55,6 +55,10 @@ pub enum JoinType {
     Semi,
     #[cfg(feature = "semi_anti_join")]
     Anti,
+    /// Join rows whose `[start, end)` intervals overlap. The left keys are a point or
+    /// a `[start, end)` pair, the right keys a `[start, end)` pair.
+    #[cfg(feature = "interval_join")]
+    Interval,
 }
 
 impl From<JoinType> for JoinArgs {
@@ -77,6 +81,8 @@ impl Display for JoinType {
             Semi => "SEMI",
             #[cfg(feature = "semi_anti_join")]
             Anti => "ANTI",
+            #[cfg(feature = "interval_join")]
+            Interval => "INTERVAL",
         };
         write!(f, "{val}")
     }
@@ -49,7 +49,7 @@ use crate::hashing::{
     AsU64, BytesHash,
 };
 use crate::prelude::*;
-use crate::utils::{_set_partition_size, slice_slice, split_ca};
+use crate::utils::{_set_partition_size, slice_slice, split_ca, NoNull};
 use crate::POOL;
 
 pub fn default_join_ids() -> ChunkJoinOptIds {
@@ -90,10 +90,21 @@ use crate::series::IsSorted;
 
 /// If Categorical types are created without a global string cache or under
 /// a different global string cache the mapping will be incorrect.
+/// Enums can only be joined with Enums that declare the same categories.
 #[cfg(feature = "dtype-categorical")]
 pub fn _check_categorical_src(l: &DataType, r: &DataType) -> PolarsResult<()> {
-    if let (DataType::Categorical(Some(l)), DataType::Categorical(Some(r))) = (l, r) {
-        polars_ensure!(l.same_src(r), string_cache_mismatch);
+    match (l, r) {
+        (DataType::Categorical(Some(rev_l)), DataType::Categorical(Some(rev_r))) => {
+            polars_ensure!(rev_l.same_src(rev_r), string_cache_mismatch);
+        }
+        (DataType::Enum(_), DataType::Enum(_) | DataType::Categorical(_))
+        | (DataType::Categorical(_), DataType::Enum(_)) => {
+            polars_ensure!(
+                l == r,
+                ComputeError: "cannot join Enum columns with different categories: {} and {}", l, r
+            );
+        }
+        _ => {}
     }
     Ok(())
 }
@@ -330,7 +341,7 @@ impl DataFrame {
         #[cfg(feature = "dtype-categorical")]
         _check_categorical_src(s_left.dtype(), s_right.dtype())?;
 
-        let idx = s_left.hash_join_semi_anti(s_right, anti);
+        let idx = s_left.hash_join_semi_anti(s_right, anti)?;
         // Safety:
         // indices are in bounds
         Ok(unsafe { self._finish_anti_semi_join(&idx, slice) })
@@ -374,21 +385,29 @@ impl DataFrame {
             },
         );
 
-        let mut s = s_left
-            .to_physical_repr()
-            .zip_outer_join_column(&s_right.to_physical_repr(), opt_join_tuples);
+        let mut s = if s_left.dtype().is_nested() {
+            zip_outer_join_nested_column(s_left, s_right, opt_join_tuples)?
+        } else {
+            s_left
+                .to_physical_repr()
+                .zip_outer_join_column(&s_right.to_physical_repr(), opt_join_tuples)
+        };
         s.rename(s_left.name());
         let s = match s_left.dtype() {
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => {
+            DataType::Categorical(_) | DataType::Enum(_) => {
                 let ca_left = s_left.categorical().unwrap();
                 let new_rev_map = ca_left.merge_categorical_map(s_right.categorical().unwrap())?;
                 let logical = s.u32().unwrap().clone();
                 // safety:
                 // categorical maps are merged
                 unsafe {
-                    CategoricalChunked::from_cats_and_rev_map_unchecked(logical, new_rev_map)
-                        .into_series()
+                    CategoricalChunked::_from_cats_and_rev_map_unchecked(
+                        logical,
+                        new_rev_map,
+                        ca_left.is_enum(),
+                    )
+                    .into_series()
                 }
             }
             dt @ DataType::Datetime(_, _)
@@ -402,3 +421,23 @@ impl DataFrame {
         _finish_join(df_left, df_right, args.suffix.as_deref())
     }
 }
+
+/// Coalesce the key columns of an outer join on nested keys by taking from
+/// the concatenation of both columns.
+fn zip_outer_join_nested_column(
+    s_left: &Series,
+    s_right: &Series,
+    opt_join_tuples: &[(Option<IdxSize>, Option<IdxSize>)],
+) -> PolarsResult<Series> {
+    let offset = s_left.len() as IdxSize;
+    let idx: NoNull<IdxCa> = opt_join_tuples
+        .iter()
+        .map(|(left, right)| match left {
+            Some(left) => *left,
+            None => right.unwrap() + offset,
+        })
+        .collect_trusted();
+    let mut both = s_left.clone();
+    both.append(s_right)?;
+    both.take(&idx.into_inner())
+}
@@ -247,10 +247,15 @@ pub fn private_left_join_multiple_keys(
     // only needed if we have non contiguous memory
     chunk_mapping_left: Option<&[ChunkId]>,
     chunk_mapping_right: Option<&[ChunkId]>,
-) -> LeftJoinIds {
-    let mut a = DataFrame::new_no_checks(_to_physical_and_bit_repr(a.get_columns()));
-    let mut b = DataFrame::new_no_checks(_to_physical_and_bit_repr(b.get_columns()));
-    _left_join_multiple_keys(&mut a, &mut b, chunk_mapping_left, chunk_mapping_right)
+) -> PolarsResult<LeftJoinIds> {
+    let mut a = DataFrame::new_no_checks(_to_physical_and_bit_repr(a.get_columns())?);
+    let mut b = DataFrame::new_no_checks(_to_physical_and_bit_repr(b.get_columns())?);
+    Ok(_left_join_multiple_keys(
+        &mut a,
+        &mut b,
+        chunk_mapping_left,
+        chunk_mapping_right,
+    ))
 }
 
 pub fn _left_join_multiple_keys(
@@ -2,9 +2,18 @@ use num_traits::NumCast;
 
 use super::single_keys_inner::hash_join_tuples_inner;
 use super::*;
+use crate::prelude::sort::arg_sort_multiple::encode_rows_vertical;
 #[cfg(feature = "chunked_ids")]
 use crate::utils::create_chunked_index_mapping;
 
+/// Row encode nested keys so that they can be joined as binary data.
+fn encode_nested_keys(lhs: &Series, rhs: &Series) -> PolarsResult<(BinaryChunked, BinaryChunked)> {
+    Ok((
+        encode_rows_vertical(&[lhs.clone()])?,
+        encode_rows_vertical(&[rhs.clone()])?,
+    ))
+}
+
 impl Series {
     #[doc(hidden)]
     pub fn hash_join_left(
@@ -30,6 +39,10 @@ impl Series {
                 let rhs = rhs.binary().unwrap();
                 lhs.hash_join_left(rhs, validate)
             }
+            dt if dt.is_nested() => {
+                let (lhs, rhs) = encode_nested_keys(&lhs, &rhs)?;
+                lhs.hash_join_left(&rhs, validate)
+            }
             _ => {
                 if self.bit_repr_is_large() {
                     let lhs = lhs.bit_repr_large();
@@ -45,7 +58,11 @@ impl Series {
     }
 
     #[cfg(feature = "semi_anti_join")]
-    pub(super) fn hash_join_semi_anti(&self, other: &Series, anti: bool) -> Vec<IdxSize> {
+    pub(super) fn hash_join_semi_anti(
+        &self,
+        other: &Series,
+        anti: bool,
+    ) -> PolarsResult<Vec<IdxSize>> {
         let (lhs, rhs) = (self.to_physical_repr(), other.to_physical_repr());
 
         use DataType::*;
@@ -56,22 +73,26 @@ impl Series {
 
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                lhs.hash_join_semi_anti(rhs, anti)
+                Ok(lhs.hash_join_semi_anti(rhs, anti))
             }
             Binary => {
                 let lhs = lhs.binary().unwrap();
                 let rhs = rhs.binary().unwrap();
-                lhs.hash_join_semi_anti(rhs, anti)
+                Ok(lhs.hash_join_semi_anti(rhs, anti))
+            }
+            dt if dt.is_nested() => {
+                let (lhs, rhs) = encode_nested_keys(&lhs, &rhs)?;
+                Ok(lhs.hash_join_semi_anti(&rhs, anti))
             }
             _ => {
                 if self.bit_repr_is_large() {
                     let lhs = lhs.bit_repr_large();
                     let rhs = rhs.bit_repr_large();
-                    num_group_join_anti_semi(&lhs, &rhs, anti)
+                    Ok(num_group_join_anti_semi(&lhs, &rhs, anti))
                 } else {
                     let lhs = lhs.bit_repr_small();
                     let rhs = rhs.bit_repr_small();
-                    num_group_join_anti_semi(&lhs, &rhs, anti)
+                    Ok(num_group_join_anti_semi(&lhs, &rhs, anti))
                 }
             }
         }
@@ -101,6 +122,10 @@ impl Series {
                 let rhs = rhs.binary().unwrap();
                 lhs.hash_join_inner(rhs, validate)
             }
+            dt if dt.is_nested() => {
+                let (lhs, rhs) = encode_nested_keys(&lhs, &rhs)?;
+                lhs.hash_join_inner(&rhs, validate)
+            }
             _ => {
                 if self.bit_repr_is_large() {
                     let lhs = self.bit_repr_large();
@@ -138,6 +163,10 @@ impl Series {
                 let rhs = rhs.binary().unwrap();
                 lhs.hash_join_outer(rhs, validate)
             }
+            dt if dt.is_nested() => {
+                let (lhs, rhs) = encode_nested_keys(&lhs, &rhs)?;
+                lhs.hash_join_outer(&rhs, validate)
+            }
             _ => {
                 if self.bit_repr_is_large() {
                     let lhs = self.bit_repr_large();
@@ -39,7 +39,7 @@ use crate::frame::groupby::GroupsIndicator;
 use crate::hashing::df_rows_to_hashes_threaded_vertical;
 #[cfg(feature = "zip_with")]
 use crate::prelude::min_max_binary::min_max_binary_series;
-use crate::prelude::sort::{argsort_multiple_row_fmt, prepare_arg_sort};
+use crate::prelude::sort::{arg_sort_row_fmt, argsort_multiple_row_fmt, prepare_arg_sort};
 use crate::series::IsSorted;
 use crate::POOL;
 
@@ -1895,19 +1895,12 @@ impl DataFrame {
             return self.top_k_impl(k, descending, by_column, nulls_last, maintain_order);
         }
 
-        #[cfg(feature = "dtype-struct")]
-        let has_struct = by_column
-            .iter()
-            .any(|s| matches!(s.dtype(), DataType::Struct(_)));
-
-        #[cfg(not(feature = "dtype-struct"))]
-        #[allow(non_upper_case_globals)]
-        const has_struct: bool = false;
+        let has_nested = by_column.iter().any(|s| s.dtype().is_nested());
 
         // a lot of indirection in both sorting and take
         let mut df = self.clone();
         let df = df.as_single_chunk_par();
-        let mut take = match (by_column.len(), has_struct) {
+        let mut take = match (by_column.len(), has_nested) {
             (1, false) => {
                 let s = &by_column[0];
                 let options = SortOptions {
@@ -1916,21 +1909,26 @@ impl DataFrame {
                     multithreaded: parallel,
                     maintain_order,
                 };
-                // fast path for a frame with a single series
-                // no need to compute the sort indices and then take by these indices
-                // simply sort and return as frame
-                if df.width() == 1 && df.check_name_to_idx(s.name()).is_ok() {
-                    let mut out = s.sort_with(options);
-                    if let Some((offset, len)) = slice {
-                        out = out.slice(offset, len);
-                    }
+                // nested values are sorted by their row encoding, which can fail
+                if s.dtype().is_nested() {
+                    arg_sort_row_fmt(s, options)?
+                } else {
+                    // fast path for a frame with a single series
+                    // no need to compute the sort indices and then take by these indices
+                    // simply sort and return as frame
+                    if df.width() == 1 && df.check_name_to_idx(s.name()).is_ok() {
+                        let mut out = s.sort_with(options);
+                        if let Some((offset, len)) = slice {
+                            out = out.slice(offset, len);
+                        }
 
-                    return Ok(out.into_frame());
+                        return Ok(out.into_frame());
+                    }
+                    s.arg_sort(options)
                 }
-                s.arg_sort(options)
             }
             _ => {
-                if nulls_last || has_struct || std::env::var("POLARS_ROW_FMT_SORT").is_ok() {
+                if nulls_last || has_nested || std::env::var("POLARS_ROW_FMT_SORT").is_ok() {
                     argsort_multiple_row_fmt(&by_column, descending, nulls_last, parallel)?
                 } else {
                     let (first, other, descending) = prepare_arg_sort(by_column, descending)?;
@@ -47,7 +47,7 @@ impl Serialize for Series {
                 ca.serialize(serializer)
             }
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => {
+            DataType::Categorical(_) | DataType::Enum(_) => {
                 let ca = self.categorical().unwrap();
                 ca.serialize(serializer)
             }
@@ -227,6 +227,11 @@ impl<'de> Deserialize<'de> for Series {
                             .cast(&DataType::Categorical(None))
                             .unwrap())
                     }
+                    #[cfg(feature = "dtype-categorical")]
+                    dt @ DataType::Enum(_) => {
+                        let values: Vec<Option<Cow<str>>> = map.next_value()?;
+                        Ok(Series::new(&name, values).cast(&dt).unwrap())
+                    }
                     dt => {
                         panic!("{dt:?} dtype deserialization not yet implemented")
                     }
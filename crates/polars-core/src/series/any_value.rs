@@ -19,6 +19,8 @@ fn any_values_to_utf8(avs: &[AnyValue], strict: bool) -> PolarsResult<Utf8Chunke
             AnyValue::Utf8(s) => builder.append_value(s),
             AnyValue::Utf8Owned(s) => builder.append_value(s),
             AnyValue::Null => builder.append_null(),
+            #[cfg(feature = "dtype-categorical")]
+            AnyValue::Enum(_, _, _) => builder.append_value(av.get_str().unwrap()),
             AnyValue::Binary(_) | AnyValue::BinaryOwned(_) => {
                 if strict {
                     polars_bail!(ComputeError: "mixed dtypes found when building Utf8 Series")
@@ -344,6 +346,8 @@ impl Series {
 
                 ca.cast(&DataType::Categorical(None)).unwrap()
             }
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(_) => any_values_to_utf8(av, strict)?.cast(dtype)?,
             dt => panic!("{dt:?} not supported"),
         };
         s.rename(name);
@@ -431,6 +435,15 @@ impl<'a> From<&AnyValue<'a>> for DataType {
                     DataType::Categorical(Some(Arc::new(rev_map)))
                 }
             }
+            #[cfg(feature = "dtype-categorical")]
+            Enum(_, rev_map, arr) => {
+                if arr.is_null() {
+                    DataType::Enum(Some(Arc::new((*rev_map).clone())))
+                } else {
+                    let array = unsafe { arr.deref_unchecked().clone() };
+                    DataType::Enum(Some(Arc::new(RevMapping::Local(array))))
+                }
+            }
             #[cfg(feature = "object")]
             Object(o) => DataType::Object(o.type_name()),
             #[cfg(feature = "object")]
@@ -445,33 +445,188 @@ pub fn _struct_arithmetic<F: FnMut(&Series, &Series) -> Series>(
     }
 }
 
+/// Flatten `s` to `len * width` values, one row of `width` values per output row, and the
+/// validity of the rows. A non-nested series provides one value per row that is repeated
+/// `width` times.
+#[cfg(feature = "dtype-array")]
+fn flatten_array_operand(
+    s: &Series,
+    len: usize,
+    width: usize,
+) -> PolarsResult<(Series, Option<arrow::bitmap::Bitmap>)> {
+    let broadcast = s.len() == 1 && len != 1;
+    match s.dtype() {
+        DataType::Array(inner, s_width) => {
+            polars_ensure!(
+                *s_width == width,
+                ComputeError: "cannot apply arithmetic on arrays of width {} and {}",
+                s_width, width,
+            );
+            polars_ensure!(
+                inner.is_numeric(),
+                InvalidOperation:
+                "arithmetic on arrays is only supported for numeric values, got {}", inner
+            );
+            let ca = s.array().unwrap().rechunk();
+            let arr = ca.downcast_iter().next().unwrap();
+            let values = ca.get_inner();
+            let validity = arr.validity().cloned();
+            if broadcast {
+                let idx: NoNull<IdxCa> = (0..(len * width) as IdxSize)
+                    .map(|i| i % width as IdxSize)
+                    .collect();
+                let validity = arrow::array::Array::is_null(arr, 0)
+                    .then(|| arrow::bitmap::Bitmap::new_zeroed(len));
+                Ok((values.take(&idx.into_inner())?, validity))
+            } else {
+                Ok((values, validity))
+            }
+        }
+        dt if dt.is_numeric() => {
+            // a single value is broadcast by the arithmetic itself
+            if s.len() == 1 {
+                Ok((s.clone(), None))
+            } else {
+                let idx: NoNull<IdxCa> = (0..(len * width) as IdxSize)
+                    .map(|i| i / width as IdxSize)
+                    .collect();
+                Ok((s.take(&idx.into_inner())?, None))
+            }
+        }
+        dt => polars_bail!(
+            InvalidOperation: "arithmetic between an array and {} is not supported", dt
+        ),
+    }
+}
+
+/// Apply `func` on the values of fixed size lists. The other side is either a fixed size list
+/// of the same width or a numeric series of which the values are applied to every item of a row.
+/// Sides of length 1 are broadcast.
+#[cfg(feature = "dtype-array")]
+pub fn _array_arithmetic<F: FnMut(&Series, &Series) -> PolarsResult<Series>>(
+    lhs: &Series,
+    rhs: &Series,
+    mut func: F,
+) -> PolarsResult<Series> {
+    let width = match (lhs.dtype(), rhs.dtype()) {
+        (DataType::Array(_, width), _) | (_, DataType::Array(_, width)) => *width,
+        _ => unreachable!(),
+    };
+    let len = match (lhs.len(), rhs.len()) {
+        (1, len) | (len, 1) => len,
+        (len_l, len_r) => {
+            polars_ensure!(
+                len_l == len_r,
+                ComputeError: "cannot apply arithmetic on series of length {} and {}", len_l, len_r
+            );
+            len_l
+        }
+    };
+    let (values_l, validity_l) = flatten_array_operand(lhs, len, width)?;
+    let (values_r, validity_r) = flatten_array_operand(rhs, len, width)?;
+    let (values_l, values_r) = coerce_lhs_rhs(&values_l, &values_r)?;
+
+    let values = func(values_l.as_ref(), values_r.as_ref())?.rechunk();
+    let values = values.array_ref(0).clone();
+    let data_type =
+        arrow::array::FixedSizeListArray::default_datatype(values.data_type().clone(), width);
+    let validity =
+        polars_arrow::utils::combine_validities_and(validity_l.as_ref(), validity_r.as_ref());
+    let arr = arrow::array::FixedSizeListArray::new(data_type, values, validity);
+    // Safety: the inner values are numeric, so their arrow type maps to the polars dtype.
+    unsafe { Ok(ArrayChunked::from_chunks(lhs.name(), vec![Box::new(arr)]).into_series()) }
+}
+
 impl Sub for &Series {
     type Output = Series;
 
     fn sub(self, rhs: Self) -> Self::Output {
+        self.try_sub(rhs).unwrap()
+    }
+}
+
+impl Series {
+    pub fn try_add(&self, rhs: &Series) -> PolarsResult<Series> {
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (DataType::Struct(_), DataType::Struct(_)) => {
-                _struct_arithmetic(self, rhs, |a, b| a.sub(b))
+                Ok(_struct_arithmetic(self, rhs, |a, b| a.add(b)))
+            }
+            #[cfg(feature = "dtype-array")]
+            (DataType::Array(_, _), _) | (_, DataType::Array(_, _)) => {
+                _array_arithmetic(self, rhs, |a, b| a.try_add(b))
             }
             _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
-                lhs.subtract(rhs.as_ref()).expect("data types don't match")
+                let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+                lhs.add_to(rhs.as_ref())
             }
         }
     }
-}
 
-impl Series {
-    pub fn try_add(&self, rhs: &Series) -> PolarsResult<Series> {
+    pub fn try_sub(&self, rhs: &Series) -> PolarsResult<Series> {
         match (self.dtype(), rhs.dtype()) {
             #[cfg(feature = "dtype-struct")]
             (DataType::Struct(_), DataType::Struct(_)) => {
-                Ok(_struct_arithmetic(self, rhs, |a, b| a.add(b)))
+                Ok(_struct_arithmetic(self, rhs, |a, b| a.sub(b)))
+            }
+            #[cfg(feature = "dtype-array")]
+            (DataType::Array(_, _), _) | (_, DataType::Array(_, _)) => {
+                _array_arithmetic(self, rhs, |a, b| a.try_sub(b))
             }
             _ => {
                 let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
-                lhs.add_to(rhs.as_ref())
+                lhs.subtract(rhs.as_ref())
+            }
+        }
+    }
+
+    pub fn try_mul(&self, rhs: &Series) -> PolarsResult<Series> {
+        match (self.dtype(), rhs.dtype()) {
+            #[cfg(feature = "dtype-struct")]
+            (DataType::Struct(_), DataType::Struct(_)) => {
+                Ok(_struct_arithmetic(self, rhs, |a, b| a.mul(b)))
+            }
+            #[cfg(feature = "dtype-array")]
+            (DataType::Array(_, _), _) | (_, DataType::Array(_, _)) => {
+                _array_arithmetic(self, rhs, |a, b| a.try_mul(b))
+            }
+            _ => {
+                let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+                lhs.multiply(rhs.as_ref())
+            }
+        }
+    }
+
+    pub fn try_div(&self, rhs: &Series) -> PolarsResult<Series> {
+        match (self.dtype(), rhs.dtype()) {
+            #[cfg(feature = "dtype-struct")]
+            (DataType::Struct(_), DataType::Struct(_)) => {
+                Ok(_struct_arithmetic(self, rhs, |a, b| a.div(b)))
+            }
+            #[cfg(feature = "dtype-array")]
+            (DataType::Array(_, _), _) | (_, DataType::Array(_, _)) => {
+                _array_arithmetic(self, rhs, |a, b| a.try_div(b))
+            }
+            _ => {
+                let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+                lhs.divide(rhs.as_ref())
+            }
+        }
+    }
+
+    pub fn try_rem(&self, rhs: &Series) -> PolarsResult<Series> {
+        match (self.dtype(), rhs.dtype()) {
+            #[cfg(feature = "dtype-struct")]
+            (DataType::Struct(_), DataType::Struct(_)) => {
+                Ok(_struct_arithmetic(self, rhs, |a, b| a.rem(b)))
+            }
+            #[cfg(feature = "dtype-array")]
+            (DataType::Array(_, _), _) | (_, DataType::Array(_, _)) => {
+                _array_arithmetic(self, rhs, |a, b| a.try_rem(b))
+            }
+            _ => {
+                let (lhs, rhs) = coerce_lhs_rhs(self, rhs)?;
+                lhs.remainder(rhs.as_ref())
             }
         }
     }
@@ -493,16 +648,7 @@ impl Mul for &Series {
     /// let out = &s * &s;
     /// ```
     fn mul(self, rhs: Self) -> Self::Output {
-        match (self.dtype(), rhs.dtype()) {
-            #[cfg(feature = "dtype-struct")]
-            (DataType::Struct(_), DataType::Struct(_)) => {
-                _struct_arithmetic(self, rhs, |a, b| a.mul(b))
-            }
-            _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
-                lhs.multiply(rhs.as_ref()).expect("data types don't match")
-            }
-        }
+        self.try_mul(rhs).unwrap()
     }
 }
 
@@ -515,16 +661,7 @@ impl Div for &Series {
     /// let out = &s / &s;
     /// ```
     fn div(self, rhs: Self) -> Self::Output {
-        match (self.dtype(), rhs.dtype()) {
-            #[cfg(feature = "dtype-struct")]
-            (DataType::Struct(_), DataType::Struct(_)) => {
-                _struct_arithmetic(self, rhs, |a, b| a.div(b))
-            }
-            _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
-                lhs.divide(rhs.as_ref()).expect("data types don't match")
-            }
-        }
+        self.try_div(rhs).unwrap()
     }
 }
 
@@ -537,16 +674,7 @@ impl Rem for &Series {
     /// let out = &s / &s;
     /// ```
     fn rem(self, rhs: Self) -> Self::Output {
-        match (self.dtype(), rhs.dtype()) {
-            #[cfg(feature = "dtype-struct")]
-            (DataType::Struct(_), DataType::Struct(_)) => {
-                _struct_arithmetic(self, rhs, |a, b| a.rem(b))
-            }
-            _ => {
-                let (lhs, rhs) = coerce_lhs_rhs(self, rhs).expect("cannot coerce datatypes");
-                lhs.remainder(rhs.as_ref()).expect("data types don't match")
-            }
-        }
+        self.try_rem(rhs).unwrap()
     }
 }
 
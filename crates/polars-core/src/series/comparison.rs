@@ -10,7 +10,7 @@ use crate::series::arithmetic::coerce_lhs_rhs;
 
 macro_rules! impl_compare {
     ($self:expr, $rhs:expr, $method:ident) => {{
-        let (lhs, rhs) = coerce_lhs_rhs($self, $rhs).expect("cannot coerce datatypes");
+        let (lhs, rhs) = coerce_lhs_rhs($self, $rhs)?;
         let lhs = lhs.to_physical_repr();
         let rhs = rhs.to_physical_repr();
         match lhs.dtype() {
@@ -82,9 +82,15 @@ fn validate_types(left: &DataType, right: &DataType) -> PolarsResult<()> {
     use DataType::*;
     #[cfg(feature = "dtype-categorical")]
     {
-        let mismatch = matches!(left, Utf8 | Categorical(_)) && right.is_numeric()
-            || left.is_numeric() && matches!(right, Utf8 | Categorical(_));
+        let mismatch = matches!(left, Utf8 | Categorical(_) | Enum(_)) && right.is_numeric()
+            || left.is_numeric() && matches!(right, Utf8 | Categorical(_) | Enum(_));
         polars_ensure!(!mismatch, ComputeError: "cannot compare utf-8 with numeric data");
+        if let (Enum(_), Enum(_) | Categorical(_)) | (Categorical(_), Enum(_)) = (left, right) {
+            polars_ensure!(
+                left == right,
+                ComputeError: "cannot compare {} with {}; Enums must declare the same categories", left, right
+            );
+        }
     }
     #[cfg(not(feature = "dtype-categorical"))]
     {
@@ -104,7 +110,7 @@ impl ChunkCompare<&Series> for Series {
         use DataType::*;
         let mut out = match (self.dtype(), rhs.dtype(), self.len(), rhs.len()) {
             #[cfg(feature = "dtype-categorical")]
-            (Categorical(_), Utf8, _, 1) => {
+            (Categorical(_) | Enum(_), Utf8, _, 1) => {
                 return compare_cat_to_str_series(
                     self,
                     rhs,
@@ -114,7 +120,7 @@ impl ChunkCompare<&Series> for Series {
                 );
             }
             #[cfg(feature = "dtype-categorical")]
-            (Utf8, Categorical(_), 1, _) => {
+            (Utf8, Categorical(_) | Enum(_), 1, _) => {
                 return compare_cat_to_str_series(
                     rhs,
                     self,
@@ -160,7 +166,7 @@ impl ChunkCompare<&Series> for Series {
         use DataType::*;
         let mut out = match (self.dtype(), rhs.dtype(), self.len(), rhs.len()) {
             #[cfg(feature = "dtype-categorical")]
-            (Categorical(_), Utf8, _, 1) => {
+            (Categorical(_) | Enum(_), Utf8, _, 1) => {
                 return compare_cat_to_str_series(
                     self,
                     rhs,
@@ -170,7 +176,7 @@ impl ChunkCompare<&Series> for Series {
                 );
             }
             #[cfg(feature = "dtype-categorical")]
-            (Utf8, Categorical(_), 1, _) => {
+            (Utf8, Categorical(_) | Enum(_), 1, _) => {
                 return compare_cat_to_str_series(
                     rhs,
                     self,
@@ -216,7 +222,7 @@ impl ChunkCompare<&Series> for Series {
         use DataType::*;
         let mut out = match (self.dtype(), rhs.dtype(), self.len(), rhs.len()) {
             #[cfg(feature = "dtype-categorical")]
-            (Categorical(_), Utf8, _, 1) => {
+            (Categorical(_) | Enum(_), Utf8, _, 1) => {
                 return compare_cat_to_str_series(
                     self,
                     rhs,
@@ -226,7 +232,7 @@ impl ChunkCompare<&Series> for Series {
                 );
             }
             #[cfg(feature = "dtype-categorical")]
-            (Utf8, Categorical(_), 1, _) => {
+            (Utf8, Categorical(_) | Enum(_), 1, _) => {
                 return compare_cat_to_str_series(
                     rhs,
                     self,
@@ -272,7 +278,7 @@ impl ChunkCompare<&Series> for Series {
         use DataType::*;
         let mut out = match (self.dtype(), rhs.dtype(), self.len(), rhs.len()) {
             #[cfg(feature = "dtype-categorical")]
-            (Categorical(_), Utf8, _, 1) => {
+            (Categorical(_) | Enum(_), Utf8, _, 1) => {
                 return compare_cat_to_str_series(
                     self,
                     rhs,
@@ -282,7 +288,7 @@ impl ChunkCompare<&Series> for Series {
                 );
             }
             #[cfg(feature = "dtype-categorical")]
-            (Utf8, Categorical(_), 1, _) => {
+            (Utf8, Categorical(_) | Enum(_), 1, _) => {
                 return compare_cat_to_str_series(
                     rhs,
                     self,
@@ -432,7 +438,7 @@ impl ChunkCompare<&str> for Series {
         match self.dtype() {
             Utf8 => Ok(self.utf8().unwrap().equal(rhs)),
             #[cfg(feature = "dtype-categorical")]
-            Categorical(_) => {
+            Categorical(_) | Enum(_) => {
                 compare_cat_to_str_value(self, rhs, self.name(), |lhs, idx| lhs.equal(idx), false)
             }
             _ => Ok(BooleanChunked::full(self.name(), false, self.len())),
@@ -445,7 +451,7 @@ impl ChunkCompare<&str> for Series {
         match self.dtype() {
             Utf8 => Ok(self.utf8().unwrap().equal(rhs)),
             #[cfg(feature = "dtype-categorical")]
-            Categorical(_) => compare_cat_to_str_value(
+            Categorical(_) | Enum(_) => compare_cat_to_str_value(
                 self,
                 rhs,
                 self.name(),
@@ -462,7 +468,7 @@ impl ChunkCompare<&str> for Series {
         match self.dtype() {
             Utf8 => Ok(self.utf8().unwrap().not_equal(rhs)),
             #[cfg(feature = "dtype-categorical")]
-            Categorical(_) => compare_cat_to_str_value(
+            Categorical(_) | Enum(_) => compare_cat_to_str_value(
                 self,
                 rhs,
                 self.name(),
@@ -479,7 +485,7 @@ impl ChunkCompare<&str> for Series {
         match self.dtype() {
             Utf8 => Ok(self.utf8().unwrap().not_equal(rhs)),
             #[cfg(feature = "dtype-categorical")]
-            Categorical(_) => compare_cat_to_str_value(
+            Categorical(_) | Enum(_) => compare_cat_to_str_value(
                 self,
                 rhs,
                 self.name(),
@@ -1,14 +1,18 @@
 use std::any::Any;
 use std::borrow::Cow;
 
+use ahash::RandomState;
+
 use super::{private, IntoSeries, SeriesTrait};
 use crate::chunked_array::comparison::*;
 use crate::chunked_array::ops::explode::ExplodeByOffsets;
 use crate::chunked_array::AsSinglePtr;
 use crate::frame::groupby::*;
+use crate::prelude::sort::arg_sort_multiple::encode_rows_vertical;
 use crate::prelude::*;
 use crate::series::implementations::SeriesWrap;
 use crate::series::IsSorted;
+use crate::POOL;
 
 impl private::PrivateSeries for SeriesWrap<ArrayChunked> {
     fn compute_len(&mut self) {
@@ -47,6 +51,18 @@ impl private::PrivateSeries for SeriesWrap<ArrayChunked> {
     fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
         IntoGroupsProxy::group_tuples(&self.0, multithreaded, sorted)
     }
+
+    fn vec_hash(&self, random_state: RandomState, buf: &mut Vec<u64>) -> PolarsResult<()> {
+        let rows = encode_rows_vertical(&[self.0.clone().into_series()])?;
+        rows.vec_hash(random_state, buf);
+        Ok(())
+    }
+
+    fn vec_hash_combine(&self, build_hasher: RandomState, hashes: &mut [u64]) -> PolarsResult<()> {
+        let rows = encode_rows_vertical(&[self.0.clone().into_series()])?;
+        rows.vec_hash_combine(build_hasher, hashes);
+        Ok(())
+    }
 }
 
 impl SeriesTrait for SeriesWrap<ArrayChunked> {
@@ -185,6 +201,49 @@ impl SeriesTrait for SeriesWrap<ArrayChunked> {
         ChunkShift::shift(&self.0, periods).into_series()
     }
 
+    fn sort_with(&self, options: SortOptions) -> Series {
+        let idx = self.0.arg_sort(options).unwrap();
+        self.take(&idx).unwrap()
+    }
+
+    fn arg_sort(&self, options: SortOptions) -> IdxCa {
+        self.0.arg_sort(options).unwrap()
+    }
+
+    fn unique(&self) -> PolarsResult<Series> {
+        // this can called in aggregation, so this fast path can be worth a lot
+        if self.len() < 2 {
+            return Ok(self.0.clone().into_series());
+        }
+        let main_thread = POOL.current_thread_index().is_none();
+        let groups = self.group_tuples(main_thread, false)?;
+        // safety:
+        // groups are in bounds
+        Ok(unsafe { self.0.clone().into_series().agg_first(&groups) })
+    }
+
+    fn n_unique(&self) -> PolarsResult<usize> {
+        match self.len() {
+            0 => Ok(0),
+            1 => Ok(1),
+            _ => {
+                let main_thread = POOL.current_thread_index().is_none();
+                let groups = self.group_tuples(main_thread, false)?;
+                Ok(groups.len())
+            }
+        }
+    }
+
+    fn arg_unique(&self) -> PolarsResult<IdxCa> {
+        if self.len() == 1 {
+            return Ok(IdxCa::new_vec(self.name(), vec![0 as IdxSize]));
+        }
+        let main_thread = POOL.current_thread_index().is_none();
+        let groups = self.group_tuples(main_thread, false)?;
+        let first = groups.take_group_firsts();
+        Ok(IdxCa::from_vec(self.name(), first))
+    }
+
     fn _sum_as_series(&self) -> Series {
         ChunkAggSeries::sum_as_series(&self.0)
     }
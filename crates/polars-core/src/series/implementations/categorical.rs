@@ -24,7 +24,11 @@ unsafe impl IntoSeries for CategoricalChunked {
 impl SeriesWrap<CategoricalChunked> {
     fn finish_with_state(&self, keep_fast_unique: bool, cats: UInt32Chunked) -> CategoricalChunked {
         let mut out = unsafe {
-            CategoricalChunked::from_cats_and_rev_map_unchecked(cats, self.0.get_rev_map().clone())
+            CategoricalChunked::_from_cats_and_rev_map_unchecked(
+                cats,
+                self.0.get_rev_map().clone(),
+                self.0.is_enum(),
+            )
         };
         if keep_fast_unique && self.0.can_fast_unique() {
             out.set_fast_unique(true)
@@ -137,7 +141,12 @@ impl private::PrivateSeries for SeriesWrap<CategoricalChunked> {
         let cats = cats.u32().unwrap().clone();
 
         unsafe {
-            CategoricalChunked::from_cats_and_rev_map_unchecked(cats, new_rev_map).into_series()
+            CategoricalChunked::_from_cats_and_rev_map_unchecked(
+                cats,
+                new_rev_map,
+                self.0.is_enum(),
+            )
+            .into_series()
         }
     }
     fn group_tuples(&self, multithreaded: bool, sorted: bool) -> PolarsResult<GroupsProxy> {
@@ -379,6 +379,6 @@ impl SeriesTrait for SeriesWrap<StructChunked> {
     }
 
     fn arg_sort(&self, options: SortOptions) -> IdxCa {
-        self.0.arg_sort(options)
+        self.0.arg_sort(options).unwrap()
     }
 }
@@ -58,7 +58,7 @@ impl Series {
                 Box::new(arr)
             }
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(_) => {
+            DataType::Categorical(_) | DataType::Enum(_) => {
                 let ca = self.categorical().unwrap();
                 let arr = ca.logical().chunks()[chunk_idx].clone();
                 let cats = unsafe { UInt32Chunked::from_chunks("", vec![arr]) };
@@ -66,9 +66,10 @@ impl Series {
                 // safety:
                 // we only take a single chunk and change nothing about the index/rev_map mapping
                 let new = unsafe {
-                    CategoricalChunked::from_cats_and_rev_map_unchecked(
+                    CategoricalChunked::_from_cats_and_rev_map_unchecked(
                         cats,
                         ca.get_rev_map().clone(),
+                        ca.is_enum(),
                     )
                 };
 
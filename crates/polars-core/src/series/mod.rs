@@ -413,7 +413,7 @@ impl Series {
     /// * Date -> Int32
     /// * Datetime-> Int64
     /// * Time -> Int64
-    /// * Categorical -> UInt32
+    /// * Categorical/Enum -> UInt32
     /// * List(inner) -> List(physical of inner)
     ///
     pub fn to_physical_repr(&self) -> Cow<Series> {
@@ -422,7 +422,7 @@ impl Series {
             Date => Cow::Owned(self.cast(&Int32).unwrap()),
             Datetime(_, _) | Duration(_) | Time => Cow::Owned(self.cast(&Int64).unwrap()),
             #[cfg(feature = "dtype-categorical")]
-            Categorical(_) => Cow::Owned(self.cast(&UInt32).unwrap()),
+            Categorical(_) | Enum(_) => Cow::Owned(self.cast(&UInt32).unwrap()),
             List(inner) => Cow::Owned(self.cast(&List(Box::new(inner.to_physical()))).unwrap()),
             #[cfg(feature = "dtype-struct")]
             Struct(_) => {
@@ -896,7 +896,7 @@ impl Series {
             AnyValue::Utf8(s) => Cow::Borrowed(s),
             AnyValue::Null => Cow::Borrowed("null"),
             #[cfg(feature = "dtype-categorical")]
-            AnyValue::Categorical(idx, rev, arr) => {
+            AnyValue::Categorical(idx, rev, arr) | AnyValue::Enum(idx, rev, arr) => {
                 if arr.is_null() {
                     Cow::Borrowed(rev.get(idx))
                 } else {
@@ -984,7 +984,7 @@ impl Series {
             .sum();
         match self.dtype() {
             #[cfg(feature = "dtype-categorical")]
-            DataType::Categorical(Some(rv)) => match &**rv {
+            DataType::Categorical(Some(rv)) | DataType::Enum(Some(rv)) => match &**rv {
                 RevMapping::Local(arr) => size += estimated_bytes_size(arr),
                 RevMapping::Global(map, arr, _) => {
                     size +=
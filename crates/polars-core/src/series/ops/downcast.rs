@@ -134,10 +134,10 @@ impl Series {
         unpack_chunked!(self, DataType::Array(_, _) => ArrayChunked, "FixedSizeList")
     }
 
-    /// Unpack to ChunkedArray of dtype `[DataType::Categorical]`
+    /// Unpack to ChunkedArray of dtype `[DataType::Categorical]` or `[DataType::Enum]`
     #[cfg(feature = "dtype-categorical")]
     pub fn categorical(&self) -> PolarsResult<&CategoricalChunked> {
-        unpack_chunked!(self, DataType::Categorical(_) => CategoricalChunked, "Categorical")
+        unpack_chunked!(self, DataType::Categorical(_) | DataType::Enum(_) => CategoricalChunked, "Categorical")
     }
 
     /// Unpack to ChunkedArray of dtype `[DataType::Struct]`
@@ -20,6 +20,18 @@ impl Series {
                 }
                 ca.into_series()
             }
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(rev_map) => {
+                let cats = UInt32Chunked::full_null(name, size);
+                let rev_map = rev_map.clone().unwrap_or_else(|| {
+                    Arc::new(RevMapping::Local(Utf8Array::new_empty(
+                        ArrowDataType::LargeUtf8,
+                    )))
+                });
+                // safety: all values are null
+                unsafe { CategoricalChunked::from_enum_cats_and_rev_map_unchecked(cats, rev_map) }
+                    .into_series()
+            }
             #[cfg(feature = "dtype-date")]
             DataType::Date => Int32Chunked::full_null(name, size)
                 .into_date()
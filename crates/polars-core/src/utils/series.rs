@@ -1,20 +1,23 @@
+use crate::prelude::sort::arg_sort_multiple::encode_rows_vertical;
 use crate::prelude::*;
 use crate::series::unstable::UnstableSeries;
 use crate::series::IsSorted;
 
 /// Transform to physical type and coerce floating point and similar sized integer to a bit representation
 /// to reduce compiler bloat
-pub fn _to_physical_and_bit_repr(s: &[Series]) -> Vec<Series> {
+pub fn _to_physical_and_bit_repr(s: &[Series]) -> PolarsResult<Vec<Series>> {
     s.iter()
         .map(|s| {
             let physical = s.to_physical_repr();
-            match physical.dtype() {
+            Ok(match physical.dtype() {
                 DataType::Int64 => physical.bit_repr_large().into_series(),
                 DataType::Int32 => physical.bit_repr_small().into_series(),
                 DataType::Float32 => physical.bit_repr_small().into_series(),
                 DataType::Float64 => physical.bit_repr_large().into_series(),
+                // nested keys are compared by their row encoding
+                dt if dt.is_nested() => encode_rows_vertical(&[s.clone()])?.into_series(),
                 _ => physical.into_owned(),
-            }
+            })
         })
         .collect()
 }
@@ -191,6 +191,10 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
             #[cfg(feature = "dtype-time")]
             (Time, Float64) => Some(Float64),
 
+            // strings are validated against the declared categories
+            #[cfg(feature = "dtype-categorical")]
+            (dt @ Enum(_), Utf8) => Some(dt.clone()),
+
             // every known type can be casted to a string except binary
             (dt, Utf8) if dt != &DataType::Unknown && dt != &DataType::Binary => Some(Utf8),
 
@@ -235,6 +239,16 @@ pub fn get_supertype(l: &DataType, r: &DataType) -> Option<DataType> {
                 let st = get_supertype(inner, other)?;
                 Some(DataType::List(Box::new(st)))
             }
+            #[cfg(feature = "dtype-array")]
+            (Array(inner_l, width_l), Array(inner_r, width_r)) if width_l == width_r => {
+                let st = get_supertype(inner_l, inner_r)?;
+                Some(Array(Box::new(st), *width_l))
+            }
+            #[cfg(feature = "dtype-array")]
+            (Array(inner, width), rhs) if rhs.is_numeric() => {
+                let st = get_supertype(inner, rhs)?;
+                Some(Array(Box::new(st), *width))
+            }
             (_, Unknown) => Some(Unknown),
             #[cfg(feature = "dtype-struct")]
             (Struct(fields_a), Struct(fields_b)) => {
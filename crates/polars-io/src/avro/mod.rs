@@ -13,7 +13,7 @@ mod test {
     use polars_core::df;
     use polars_core::prelude::*;
 
-    use super::{write, AvroReader, AvroWriter};
+    use super::{AvroCompression, AvroReader, AvroWriter};
     use crate::prelude::*;
 
     #[test]
@@ -26,8 +26,8 @@ mod test {
 
         let compressions = vec![
             None,
-            Some(write::Compression::Deflate),
-            Some(write::Compression::Snappy),
+            Some(AvroCompression::Deflate),
+            Some(AvroCompression::Snappy),
         ];
 
         for compression in compressions.into_iter() {
@@ -98,4 +98,35 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_batched_write() -> PolarsResult<()> {
+        let df = df!(
+            "i64" => &[1, 2],
+            "f64" => &[0.1, 0.2],
+            "utf8" => &["a", "b"]
+        )?;
+
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+
+        let mut writer = AvroWriter::new(&mut buf)
+            .with_compression(Some(AvroCompression::Snappy))
+            .batched(&df.schema())?;
+        writer.write_batch(&df)?;
+        writer.write_batch(&df)?;
+        writer.finish()?;
+        buf.set_position(0);
+
+        let read_df = AvroReader::new(buf)
+            .with_columns(Some(vec!["utf8".to_string(), "i64".to_string()]))
+            .finish()?;
+
+        let expected_df = df!(
+            "utf8" => &["a", "b", "a", "b"],
+            "i64" => &[1, 2, 1, 2]
+        )?;
+        assert!(expected_df.frame_equal(&read_df));
+
+        Ok(())
+    }
 }
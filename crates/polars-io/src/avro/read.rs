@@ -105,7 +105,19 @@ where
             self.projection = Some(columns_to_projection(columns, &schema)?);
         }
 
-        let (projection, projected_schema) = if let Some(projection) = self.projection {
+        // the avro reader yields the projected columns in file order
+        let mut output_order = None;
+        let (projection, projected_schema) = if let Some(mut projection) = self.projection {
+            if !projection.windows(2).all(|w| w[0] < w[1]) {
+                output_order = Some(
+                    projection
+                        .iter()
+                        .map(|&i| schema.fields[i].name.clone())
+                        .collect::<Vec<_>>(),
+                );
+                projection.sort_unstable();
+                projection.dedup();
+            }
             let mut prj = vec![false; schema.fields.len()];
             for &index in projection.iter() {
                 prj[index] = true;
@@ -118,13 +130,17 @@ where
         let avro_reader =
             avro::read::Reader::new(&mut self.reader, metadata, schema.fields, projection);
 
-        finish_reader(
+        let df = finish_reader(
             avro_reader,
             rechunk,
             self.n_rows,
             None,
             &projected_schema,
             None,
-        )
+        )?;
+        match output_order {
+            Some(columns) => df.select(columns),
+            None => Ok(df),
+        }
     }
 }
@@ -1,11 +1,31 @@
+use arrow::io::avro::avro_schema::file::CompressedBlock;
 pub use arrow::io::avro::avro_schema::file::Compression;
+use arrow::io::avro::avro_schema::schema::Record;
 use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
 use polars_core::error::to_compute_err;
-pub use Compression as AvroCompression;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use super::*;
 
+/// Compression codec of the Avro blocks.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AvroCompression {
+    Deflate,
+    Snappy,
+}
+
+impl From<AvroCompression> for Compression {
+    fn from(value: AvroCompression) -> Self {
+        match value {
+            AvroCompression::Deflate => Compression::Deflate,
+            AvroCompression::Snappy => Compression::Snappy,
+        }
+    }
+}
+
 /// Write a DataFrame to Apache Avro format
 ///
 /// # Example
@@ -38,6 +58,23 @@ where
         self.compression = compression;
         self
     }
+
+    /// Write the Avro header and return a writer that appends `DataFrame`s as blocks.
+    pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        let record = write::to_record(&schema.to_arrow())?;
+        let compression = self.compression.map(Compression::from);
+        let mut writer = self.writer;
+        avro_schema::write::write_metadata(&mut writer, record.clone(), compression)
+            .map_err(to_compute_err)?;
+
+        Ok(BatchedWriter {
+            writer,
+            record,
+            compression,
+            data: vec![],
+            compressed_block: CompressedBlock::default(),
+        })
+    }
 }
 
 impl<W> SerWriter<W> for AvroWriter<W>
@@ -52,39 +89,68 @@ where
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = df.schema().to_arrow();
-        let record = write::to_record(&schema)?;
+        let mut writer = AvroWriter {
+            writer: &mut self.writer,
+            compression: self.compression,
+        }
+        .batched(&df.schema())?;
+        writer.write_batch(df)?;
+        writer.finish()
+    }
+}
+
+pub struct BatchedWriter<W: Write> {
+    writer: W,
+    record: Record,
+    compression: Option<Compression>,
+    // buffers that are reused between the blocks
+    data: Vec<u8>,
+    compressed_block: CompressedBlock,
+}
 
-        let mut data = vec![];
-        let mut compressed_block = avro_schema::file::CompressedBlock::default();
+impl<W: Write> BatchedWriter<W> {
+    /// Write a batch to the Avro writer, every chunk is written as a block.
+    pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         for chunk in df.iter_chunks() {
             let mut serializers = chunk
                 .iter()
-                .zip(record.fields.iter())
+                .zip(self.record.fields.iter())
                 .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
                 .collect::<Vec<_>>();
 
-            let mut block =
-                avro_schema::file::Block::new(chunk.arrays()[0].len(), std::mem::take(&mut data));
+            let mut block = avro_schema::file::Block::new(
+                chunk.arrays()[0].len(),
+                std::mem::take(&mut self.data),
+            );
             write::serialize(&mut serializers, &mut block);
-            let _was_compressed =
-                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
-                    .map_err(to_compute_err)?;
+            let _was_compressed = avro_schema::write::compress(
+                &mut block,
+                &mut self.compressed_block,
+                self.compression,
+            )
+            .map_err(to_compute_err)?;
 
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
-
-            avro_schema::write::write_block(&mut self.writer, &compressed_block)
+            avro_schema::write::write_block(&mut self.writer, &self.compressed_block)
                 .map_err(to_compute_err)?;
             // reuse block for next iteration.
-            data = block.data;
-            data.clear();
+            self.data = block.data;
+            self.data.clear();
 
             // reuse block for next iteration
-            compressed_block.data.clear();
-            compressed_block.number_of_rows = 0
+            self.compressed_block.data.clear();
+            self.compressed_block.number_of_rows = 0
         }
+        Ok(())
+    }
 
+    /// Avro files have no footer, so this only flushes the writer.
+    pub fn finish(&mut self) -> PolarsResult<()> {
+        self.writer.flush()?;
         Ok(())
     }
+
+    /// Return the underlying writer, call [`BatchedWriter::finish`] first.
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
 }
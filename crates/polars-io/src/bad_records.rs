@@ -0,0 +1,235 @@
+//! Quarantine of the records a reader could not parse.
+use std::fmt;
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+
+use polars_core::prelude::*;
+
+use crate::utils::resolve_homedir;
+
+/// A sink for the records a reader could not parse.
+///
+/// Without a sink a record that fails to parse either raises, or, with `ignore_errors`,
+/// has its unparsable fields silently replaced by nulls. With a sink the record is removed
+/// from the output and handed to the sink together with its line number and the reason it
+/// was rejected, while the read continues.
+///
+/// The sink is cheap to clone and all clones share the rejected records, so a clone can be
+/// given to a (lazy) reader and inspected once the query has run.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+///
+/// fn example() -> PolarsResult<DataFrame> {
+///     let bad_records = BadRecords::new();
+///     let df = CsvReader::from_path("iris.csv")?
+///         .with_bad_records(Some(bad_records.clone()))
+///         .finish()?;
+///     // The rejected records with the columns `line_number`, `raw` and `error`.
+///     bad_records.take()
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct BadRecords {
+    inner: Arc<Mutex<BadRecordsInner>>,
+}
+
+#[derive(Default)]
+struct BadRecordsInner {
+    n_rejected: usize,
+    line_numbers: Vec<u64>,
+    raw: Vec<String>,
+    errors: Vec<String>,
+    writer: Option<BufWriter<File>>,
+}
+
+impl BadRecords {
+    /// Collect the rejected records in memory, see [`BadRecords::take`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Write the rejected records to a CSV file at `path` with the columns `line_number`,
+    /// `raw` and `error`, instead of collecting them in memory. An existing file is truncated.
+    pub fn to_path<P: AsRef<Path>>(path: P) -> PolarsResult<Self> {
+        let path = resolve_homedir(path.as_ref());
+        let mut writer = BufWriter::new(File::create(path)?);
+        writer.write_all(b"line_number,raw,error\n")?;
+        writer.flush()?;
+        let inner = BadRecordsInner {
+            writer: Some(writer),
+            ..Default::default()
+        };
+        Ok(Self {
+            inner: Arc::new(Mutex::new(inner)),
+        })
+    }
+
+    /// The number of records rejected so far.
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().n_rejected
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Take the records collected so far as a `DataFrame` with the columns `line_number`,
+    /// `raw` and `error`, ordered as they were rejected.
+    ///
+    /// The records are removed from the sink. If the sink writes to a file this returns an
+    /// empty `DataFrame`.
+    pub fn take(&self) -> PolarsResult<DataFrame> {
+        let mut inner = self.inner.lock().unwrap();
+        let line_numbers = std::mem::take(&mut inner.line_numbers);
+        let raw = std::mem::take(&mut inner.raw);
+        let errors = std::mem::take(&mut inner.errors);
+        DataFrame::new(vec![
+            Series::new("line_number", line_numbers),
+            Series::new("raw", raw),
+            Series::new("error", errors),
+        ])
+    }
+
+    /// Hand the records rejected by a single read to the sink.
+    ///
+    /// `line_number` maps the byte offset of a record to its (1-based) line number. It is
+    /// called with increasing offsets.
+    pub(crate) fn push<F>(
+        &self,
+        mut records: Vec<RejectedRecord>,
+        mut line_number: F,
+    ) -> PolarsResult<()>
+    where
+        F: FnMut(usize) -> usize,
+    {
+        if records.is_empty() {
+            return Ok(());
+        }
+        records.sort_unstable_by_key(|record| record.offset);
+
+        let mut inner = self.inner.lock().unwrap();
+        inner.n_rejected += records.len();
+        let inner = &mut *inner;
+        match &mut inner.writer {
+            Some(writer) => {
+                for record in records {
+                    write!(writer, "{},", line_number(record.offset))?;
+                    write_quoted(writer, &record.raw)?;
+                    writer.write_all(b",")?;
+                    write_quoted(writer, &record.error)?;
+                    writer.write_all(b"\n")?;
+                }
+                writer.flush()?;
+            }
+            None => {
+                for record in records {
+                    inner.line_numbers.push(line_number(record.offset) as u64);
+                    inner.raw.push(record.raw);
+                    inner.errors.push(record.error);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn write_quoted<W: Write>(writer: &mut W, value: &str) -> std::io::Result<()> {
+    writer.write_all(b"\"")?;
+    writer.write_all(value.replace('"', "\"\"").as_bytes())?;
+    writer.write_all(b"\"")
+}
+
+impl fmt::Debug for BadRecords {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BadRecords")
+            .field("n_rejected", &self.len())
+            .finish()
+    }
+}
+
+/// Sinks are equal if they share their records.
+impl PartialEq for BadRecords {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for BadRecords {}
+
+/// A record rejected by a parser.
+pub(crate) struct RejectedRecord {
+    /// Byte offset of the record in the input.
+    pub(crate) offset: usize,
+    pub(crate) raw: String,
+    pub(crate) error: String,
+}
+
+/// Keeps track of the rows a parser rejected while it fills its buffers.
+#[derive(Default)]
+pub(crate) struct RejectedRows {
+    /// One entry per parsed row, `false` if the row was rejected.
+    keep: Vec<bool>,
+    records: Vec<RejectedRecord>,
+}
+
+impl RejectedRows {
+    #[inline]
+    pub(crate) fn accept(&mut self) {
+        self.keep.push(true)
+    }
+
+    pub(crate) fn reject(&mut self, offset: usize, raw: &[u8], error: String) {
+        self.keep.push(false);
+        self.records.push(RejectedRecord {
+            offset,
+            raw: String::from_utf8_lossy(raw).into_owned(),
+            error,
+        })
+    }
+
+    /// Remove the rejected rows from `df`, which holds the rows in the order they were parsed.
+    pub(crate) fn filter(&self, df: DataFrame) -> PolarsResult<DataFrame> {
+        if self.records.is_empty() {
+            return Ok(df);
+        }
+        debug_assert_eq!(df.height(), self.keep.len());
+        let mask = BooleanChunked::from_slice("", &self.keep);
+        df.filter(&mask)
+    }
+
+    pub(crate) fn into_records(self) -> Vec<RejectedRecord> {
+        self.records
+    }
+}
+
+/// Resolves byte offsets to line numbers while walking the input front to back.
+pub(crate) struct LineCounter {
+    line_number: usize,
+    pos: usize,
+    eol_char: u8,
+}
+
+impl LineCounter {
+    /// `line_number` is the (1-based) line number at the start of the input.
+    pub(crate) fn new(line_number: usize, eol_char: u8) -> Self {
+        Self {
+            line_number,
+            pos: 0,
+            eol_char,
+        }
+    }
+
+    /// The line number at `offset` in `bytes`. Must be called with increasing offsets.
+    pub(crate) fn line_number_at(&mut self, bytes: &[u8], offset: usize) -> usize {
+        debug_assert!(offset >= self.pos);
+        self.line_number += memchr::memchr_iter(self.eol_char, &bytes[self.pos..offset]).count();
+        self.pos = offset;
+        self.line_number
+    }
+}
@@ -1,6 +1,10 @@
 //! Interface with the object_store crate and define AsyncSeek, AsyncRead.
 //! This is used, for example, by the parquet2 crate.
-use std::io::{self};
+//!
+//! [`CloudFile`] is a blocking counterpart for the readers that work on `Read + Seek` and
+//! [`CloudWriter`] for the writers that work on `Write`.
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::task::Poll;
@@ -8,9 +12,15 @@ use std::task::Poll;
 use futures::executor::block_on;
 use futures::future::BoxFuture;
 use futures::lock::Mutex;
-use futures::{AsyncRead, AsyncSeek, Future, TryFutureExt};
+use futures::{stream, AsyncRead, AsyncSeek, Future, StreamExt, TryFutureExt, TryStreamExt};
 use object_store::path::Path;
-use object_store::ObjectStore;
+use object_store::{MultipartId, ObjectStore};
+use polars_core::cloud::CloudOptions;
+use polars_core::error::to_compute_err;
+use polars_core::prelude::{PolarsError, PolarsResult};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use super::{build, CloudLocation};
 
 type OptionalFuture = Arc<Mutex<Option<BoxFuture<'static, std::io::Result<Vec<u8>>>>>>;
 
@@ -129,3 +139,335 @@ impl AsyncSeek for CloudReader {
         std::task::Poll::Ready(Ok(self.pos))
     }
 }
+
+/// Size of the range requests issued by [`CloudFile`] when it reads to the end, and of the
+/// blocks [`CloudWriter`] hands to the upload.
+const BLOCK_SIZE: usize = 8 * 1024 * 1024;
+/// Minimum size of the range request [`CloudFile`] issues for a single read.
+const READ_AHEAD: usize = 64 * 1024;
+/// Number of range requests [`CloudFile`] keeps in flight when it reads to the end.
+const CONCURRENCY: usize = 8;
+
+/// Blocking `Read` and `Seek` over an object in an object store.
+///
+/// Only the requested bytes are fetched, the object is never downloaded as a whole up front.
+/// A read that is not served by the block fetched last issues a range request for the bytes
+/// it asks for, extended to at least [`READ_AHEAD`] bytes, so readers that walk a file in
+/// small steps (e.g. the ipc reader) don't pay a round trip per step. Reading to the end
+/// fetches the remaining bytes in [`BLOCK_SIZE`] ranges, concurrently.
+pub struct CloudFile {
+    store: Arc<dyn ObjectStore>,
+    path: Path,
+    length: u64,
+    pos: u64,
+    // The block that was fetched last and its offset in the object.
+    block: Vec<u8>,
+    block_start: u64,
+}
+
+impl CloudFile {
+    /// Open the object at `uri`, this fetches its size.
+    pub fn open(uri: &str, options: Option<&CloudOptions>) -> PolarsResult<Self> {
+        let (CloudLocation { prefix, .. }, store) = build(uri, options)?;
+        let store: Arc<dyn ObjectStore> = Arc::from(store);
+        let path = Path::from(prefix);
+        let length = fetch_length(store.as_ref(), &path)? as u64;
+        Ok(Self {
+            store,
+            path,
+            length,
+            pos: 0,
+            block: vec![],
+            block_start: 0,
+        })
+    }
+
+    /// Size of the object in bytes.
+    pub fn len(&self) -> u64 {
+        self.length
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+fn fetch_length(store: &dyn ObjectStore, path: &Path) -> PolarsResult<usize> {
+    let meta = super::block_on(store.head(path)).map_err(to_compute_err)?;
+    Ok(meta.size)
+}
+
+/// Fetch the ranges of an object, at most [`CONCURRENCY`] at a time.
+fn fetch_ranges(
+    store: &dyn ObjectStore,
+    path: &Path,
+    ranges: Vec<Range<usize>>,
+) -> PolarsResult<Vec<Vec<u8>>> {
+    super::block_on(
+        stream::iter(ranges)
+            .map(|range| store.get_range(path, range).map_ok(|bytes| bytes.to_vec()))
+            .buffered(CONCURRENCY)
+            .try_collect(),
+    )
+    .map_err(to_compute_err)
+}
+
+fn to_io_err(e: PolarsError) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, e.to_string())
+}
+
+impl Read for CloudFile {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if buf.is_empty() || self.pos >= self.length {
+            return Ok(0);
+        }
+        let block_end = self.block_start + self.block.len() as u64;
+        if self.pos < self.block_start || self.pos >= block_end {
+            let start = self.pos as usize;
+            let end = std::cmp::min(
+                self.length as usize,
+                start + std::cmp::max(buf.len(), READ_AHEAD),
+            );
+            let mut blocks = fetch_ranges(self.store.as_ref(), &self.path, vec![start..end])
+                .map_err(to_io_err)?;
+            self.block = blocks.pop().unwrap_or_default();
+            self.block_start = self.pos;
+        }
+        let offset = (self.pos - self.block_start) as usize;
+        let n = std::cmp::min(buf.len(), self.block.len() - offset);
+        buf[..n].copy_from_slice(&self.block[offset..offset + n]);
+        self.pos += n as u64;
+        Ok(n)
+    }
+
+    fn read_to_end(&mut self, buf: &mut Vec<u8>) -> io::Result<usize> {
+        let start = self.pos as usize;
+        let length = self.length as usize;
+        if start >= length {
+            return Ok(0);
+        }
+        let ranges = (start..length)
+            .step_by(BLOCK_SIZE)
+            .map(|offset| offset..std::cmp::min(offset + BLOCK_SIZE, length))
+            .collect();
+        let blocks = fetch_ranges(self.store.as_ref(), &self.path, ranges).map_err(to_io_err)?;
+
+        let initial_len = buf.len();
+        buf.reserve(length - start);
+        for block in blocks {
+            buf.extend_from_slice(&block);
+        }
+        self.pos = self.length;
+        Ok(buf.len() - initial_len)
+    }
+}
+
+impl Seek for CloudFile {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => pos as i64,
+            SeekFrom::End(offset) => self.length as i64 + offset,
+            SeekFrom::Current(offset) => self.pos as i64 + offset,
+        };
+        if pos < 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            ));
+        }
+        self.pos = pos as u64;
+        Ok(self.pos)
+    }
+}
+
+#[cfg(any(
+    feature = "csv",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "json"
+))]
+impl crate::mmap::MmapBytesReader for CloudFile {}
+
+/// Blocking `Write` that uploads to an object store with a multipart upload.
+///
+/// The upload is only completed by [`CloudWriter::close`]. A writer that is dropped without
+/// being closed aborts the upload, so a failed write never leaves a partial object behind.
+///
+/// # Example
+///
+/// ```no_run
+/// use polars_core::prelude::*;
+/// use polars_io::prelude::*;
+/// use polars_io::CloudWriter;
+///
+/// fn example(df: &mut DataFrame) -> PolarsResult<()> {
+///     let mut writer = CloudWriter::new("s3://bucket/file.parquet", None)?;
+///     ParquetWriter::new(&mut writer).finish(df)?;
+///     writer.close()
+/// }
+/// ```
+pub struct CloudWriter {
+    store: Arc<dyn ObjectStore>,
+    path: Path,
+    multipart_id: MultipartId,
+    // Only accessed through `&mut self`, the mutex makes the writer `Sync`.
+    writer: std::sync::Mutex<Box<dyn AsyncWrite + Send + Unpin>>,
+    // Data is handed to the upload in blocks, not in the small writes of the file writers.
+    buffer: Vec<u8>,
+    closed: bool,
+}
+
+impl CloudWriter {
+    /// Start a multipart upload to `uri`. An existing object is replaced once the writer is
+    /// closed.
+    pub fn new(uri: &str, options: Option<&CloudOptions>) -> PolarsResult<Self> {
+        let (CloudLocation { prefix, .. }, store) = build(uri, options)?;
+        let store: Arc<dyn ObjectStore> = Arc::from(store);
+        let path = Path::from(prefix);
+        let (multipart_id, writer) =
+            super::block_on(store.put_multipart(&path)).map_err(to_compute_err)?;
+        Ok(Self {
+            store,
+            path,
+            multipart_id,
+            writer: std::sync::Mutex::new(writer),
+            buffer: Vec::with_capacity(BLOCK_SIZE),
+            closed: false,
+        })
+    }
+
+    /// Hand the buffered data to the upload.
+    fn write_buffer(&mut self) -> io::Result<()> {
+        if !self.buffer.is_empty() {
+            let writer = self.writer.get_mut().unwrap();
+            super::block_on(writer.write_all(&self.buffer))?;
+            self.buffer.clear();
+        }
+        Ok(())
+    }
+
+    /// Upload the remaining data and complete the upload.
+    pub fn close(&mut self) -> PolarsResult<()> {
+        if self.closed {
+            return Ok(());
+        }
+        self.write_buffer()?;
+        let writer = self.writer.get_mut().unwrap();
+        super::block_on(writer.shutdown())?;
+        self.closed = true;
+        Ok(())
+    }
+}
+
+impl Write for CloudWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.closed {
+            return Err(io::Error::new(
+                io::ErrorKind::Other,
+                "cannot write to a closed cloud writer",
+            ));
+        }
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= BLOCK_SIZE {
+            self.write_buffer()?;
+        }
+        Ok(buf.len())
+    }
+
+    /// Hand the buffered data to the upload, this does not complete the upload.
+    fn flush(&mut self) -> io::Result<()> {
+        self.write_buffer()
+    }
+}
+
+impl Drop for CloudWriter {
+    fn drop(&mut self) {
+        if !self.closed {
+            let _ = super::block_on(self.store.abort_multipart(&self.path, &self.multipart_id));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn test_cloud_file() {
+        let path = std::env::temp_dir().join("polars_test_cloud_file.txt");
+        let data = (0..100u8).collect::<Vec<_>>();
+        std::fs::File::create(&path)
+            .unwrap()
+            .write_all(&data)
+            .unwrap();
+
+        let uri = format!("file://{}", path.display());
+        let mut file = CloudFile::open(&uri, None).unwrap();
+        assert_eq!(file.len(), 100);
+
+        let mut buf = [0u8; 4];
+        file.seek(SeekFrom::End(-4)).unwrap();
+        file.read_exact(&mut buf).unwrap();
+        assert_eq!(buf, [96, 97, 98, 99]);
+
+        file.seek(SeekFrom::Start(10)).unwrap();
+        let mut out = vec![];
+        file.read_to_end(&mut out).unwrap();
+        assert_eq!(out, &data[10..]);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_cloud_file_in_async_context() {
+        let path = std::env::temp_dir().join("polars_test_cloud_file_async.txt");
+        let data = (0..100u8).collect::<Vec<_>>();
+        std::fs::write(&path, &data).unwrap();
+        let uri = format!("file://{}", path.display());
+
+        // the blocking adaptors must not start a runtime inside of a running one
+        let current_thread = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let multi_thread = tokio::runtime::Builder::new_multi_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        for runtime in [current_thread, multi_thread] {
+            let out = runtime.block_on(async {
+                let mut file = CloudFile::open(&uri, None).unwrap();
+                let mut out = vec![];
+                file.read_to_end(&mut out).unwrap();
+                out
+            });
+            assert_eq!(out, data);
+        }
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_cloud_writer() {
+        let path = std::env::temp_dir().join("polars_test_cloud_writer.txt");
+        let uri = format!("file://{}", path.display());
+        let data = (0..100u8).collect::<Vec<_>>();
+
+        let mut writer = CloudWriter::new(&uri, None).unwrap();
+        writer.write_all(&data[..50]).unwrap();
+        writer.flush().unwrap();
+        writer.write_all(&data[50..]).unwrap();
+        writer.close().unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), data);
+
+        // an upload that is not closed leaves the existing object untouched
+        let mut writer = CloudWriter::new(&uri, None).unwrap();
+        writer.write_all(b"partial").unwrap();
+        drop(writer);
+        assert_eq!(std::fs::read(&path).unwrap(), data);
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
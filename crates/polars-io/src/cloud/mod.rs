@@ -1,11 +1,14 @@
 //! Interface with cloud storage through the object_store crate.
 
+use std::future::Future;
 use std::str::FromStr;
 
 use object_store::local::LocalFileSystem;
 use object_store::ObjectStore;
+use once_cell::sync::Lazy;
 use polars_core::cloud::{CloudOptions, CloudType};
 use polars_core::prelude::{polars_bail, PolarsError, PolarsResult};
+use tokio::runtime::{Handle, Runtime, RuntimeFlavor};
 
 mod adaptors;
 mod glob;
@@ -14,6 +17,37 @@ pub use glob::*;
 
 type BuildResult = PolarsResult<(CloudLocation, Box<dyn ObjectStore>)>;
 
+/// The runtime that drives all blocking cloud operations, it is created on first use.
+static RUNTIME: Lazy<Runtime> = Lazy::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .expect("could not create the tokio runtime for cloud operations")
+});
+
+/// Run `future` to completion on the shared cloud runtime and block until it is done.
+///
+/// This may be called from within another tokio runtime, e.g. by a caller that is async
+/// itself: a multi threaded runtime is told that the current worker blocks, and a current
+/// thread runtime, which can't be blocked, has the future run from a separate thread.
+pub(crate) fn block_on<F>(future: F) -> F::Output
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    match Handle::try_current() {
+        Ok(handle) if handle.runtime_flavor() == RuntimeFlavor::MultiThread => {
+            tokio::task::block_in_place(|| RUNTIME.block_on(future))
+        }
+        Ok(_) => std::thread::scope(|s| {
+            s.spawn(|| RUNTIME.block_on(future))
+                .join()
+                .expect("cloud operation panicked")
+        }),
+        Err(_) => RUNTIME.block_on(future),
+    }
+}
+
 #[allow(dead_code)]
 fn err_missing_feature(feature: &str, scheme: &str) -> BuildResult {
     polars_bail!(
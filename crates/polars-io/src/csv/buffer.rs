@@ -127,6 +127,7 @@ pub(crate) struct Utf8Field {
     offsets: Vec<i64>,
     validity: MutableBitmap,
     quote_char: u8,
+    escape_char: Option<u8>,
     encoding: CsvEncoding,
     ignore_errors: bool,
 }
@@ -137,6 +138,7 @@ impl Utf8Field {
         capacity: usize,
         str_capacity: usize,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
         encoding: CsvEncoding,
         ignore_errors: bool,
     ) -> Self {
@@ -148,6 +150,7 @@ impl Utf8Field {
             offsets,
             validity: MutableBitmap::with_capacity(capacity),
             quote_char: quote_char.unwrap_or(b'"'),
+            escape_char,
             encoding,
             ignore_errors,
         }
@@ -184,7 +187,8 @@ impl ParsedBuffer for Utf8Field {
         }
 
         // Only for lossy utf8 we check utf8 now. Otherwise we check all utf8 at the end.
-        let parse_result = if delay_utf8_validation(self.encoding, ignore_errors) {
+        // This must agree with the check in `into_series`, so we use the flag of the buffer.
+        let parse_result = if delay_utf8_validation(self.encoding, self.ignore_errors) {
             true
         } else {
             validate_utf8(bytes)
@@ -203,7 +207,14 @@ impl ParsedBuffer for Utf8Field {
         let n_written = if needs_escaping {
             // Safety:
             // we just allocated enough capacity and data_len is correct.
-            unsafe { escape_field(bytes, self.quote_char, self.data.spare_capacity_mut()) }
+            unsafe {
+                escape_field(
+                    bytes,
+                    self.quote_char,
+                    self.escape_char,
+                    self.data.spare_capacity_mut(),
+                )
+            }
         } else {
             self.data.extend_from_slice(bytes);
             bytes.len()
@@ -259,18 +270,20 @@ pub(crate) struct CategoricalField<'a> {
 pub(crate) struct CategoricalField<'a> {
     escape_scratch: Vec<u8>,
     quote_char: u8,
+    escape_char: Option<u8>,
     builder: CategoricalChunkedBuilder<'a>,
     owned_strings: Vec<String>,
 }
 
 #[cfg(feature = "dtype-categorical")]
 impl<'a> CategoricalField<'a> {
-    fn new(name: &str, capacity: usize, quote_char: Option<u8>) -> Self {
+    fn new(name: &str, capacity: usize, quote_char: Option<u8>, escape_char: Option<u8>) -> Self {
         let builder = CategoricalChunkedBuilder::new(name, capacity);
 
         Self {
             escape_scratch: vec![],
             quote_char: quote_char.unwrap_or(b'"'),
+            escape_char,
             builder,
             owned_strings: vec![],
         }
@@ -300,6 +313,7 @@ impl<'a> CategoricalField<'a> {
                     let n_written = escape_field(
                         bytes,
                         self.quote_char,
+                        self.escape_char,
                         self.escape_scratch.spare_capacity_mut(),
                     );
                     self.escape_scratch.set_len(n_written);
@@ -500,6 +514,7 @@ pub(crate) fn init_buffers<'a>(
     // The running statistic of the amount of bytes we must allocate per str column
     str_capacities: &[RunningSize],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     encoding: CsvEncoding,
     ignore_errors: bool,
 ) -> PolarsResult<Vec<Buffer<'a>>> {
@@ -530,6 +545,7 @@ pub(crate) fn init_buffers<'a>(
                     capacity,
                     str_capacity,
                     quote_char,
+                    escape_char,
                     encoding,
                     ignore_errors,
                 )),
@@ -542,9 +558,12 @@ pub(crate) fn init_buffers<'a>(
                 #[cfg(feature = "dtype-date")]
                 &DataType::Date => Buffer::Date(DatetimeField::new(name, capacity)),
                 #[cfg(feature = "dtype-categorical")]
-                &DataType::Categorical(_) => {
-                    Buffer::Categorical(CategoricalField::new(name, capacity, quote_char))
-                }
+                &DataType::Categorical(_) => Buffer::Categorical(CategoricalField::new(
+                    name,
+                    capacity,
+                    quote_char,
+                    escape_char,
+                )),
                 dt => polars_bail!(
                     ComputeError: "unsupported data type when reading CSV: {} when reading CSV", dt,
                 ),
@@ -1,8 +1,9 @@
-use memchr::memchr2_iter;
+use memchr::{memchr2_iter, memchr_iter, memmem};
 use num_traits::Pow;
 use polars_core::prelude::*;
 
 use super::buffer::*;
+use crate::bad_records::RejectedRows;
 use crate::csv::read::NullValuesCompiled;
 use crate::csv::splitfields::SplitFields;
 
@@ -26,24 +27,35 @@ pub(crate) fn next_line_position_naive(input: &[u8], eol_char: u8) -> Option<usi
     Some(pos)
 }
 
+/// Count the delimiters and eol chars in `field`. A multi-byte delimiter only counts where it
+/// matches in full.
+fn count_delimiters_eol(field: &[u8], delimiter: &[u8], eol_char: u8) -> usize {
+    match delimiter {
+        [delimiter] => memchr2_iter(*delimiter, eol_char, field).count(),
+        _ => memmem::find_iter(field, delimiter).count() + memchr_iter(eol_char, field).count(),
+    }
+}
+
 /// Find the nearest next line position that is not embedded in a String field.
 pub(crate) fn next_line_position(
     mut input: &[u8],
     mut expected_fields: Option<usize>,
-    delimiter: u8,
+    delimiter: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) -> Option<usize> {
     fn accept_line(
         line: &[u8],
         expected_fields: usize,
-        delimiter: u8,
+        delimiter: &[u8],
         eol_char: u8,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
     ) -> bool {
         let mut count = 0usize;
-        for (field, _) in SplitFields::new(line, delimiter, quote_char, eol_char) {
-            if memchr2_iter(delimiter, eol_char, field).count() >= expected_fields {
+        for (field, _) in SplitFields::new(line, delimiter, quote_char, escape_char, eol_char) {
+            if count_delimiters_eol(field, delimiter, eol_char) >= expected_fields {
                 return false;
             }
             count += 1;
@@ -89,16 +101,31 @@ pub(crate) fn next_line_position(
         }
         debug_assert!(pos <= input.len());
         let new_input = unsafe { input.get_unchecked(pos..) };
-        let mut lines = SplitLines::new(new_input, quote_char.unwrap_or(b'"'), eol_char);
+        let mut lines =
+            SplitLines::new(new_input, quote_char.unwrap_or(b'"'), escape_char, eol_char);
         let line = lines.next();
 
         match (line, expected_fields) {
             // count the fields, and determine if they are equal to what we expect from the schema
             (Some(line), Some(expected_fields)) => {
-                if accept_line(line, expected_fields, delimiter, eol_char, quote_char) {
+                if accept_line(
+                    line,
+                    expected_fields,
+                    delimiter,
+                    eol_char,
+                    quote_char,
+                    escape_char,
+                ) {
                     let mut valid = true;
                     for line in lines.take(2) {
-                        if !accept_line(line, expected_fields, delimiter, eol_char, quote_char) {
+                        if !accept_line(
+                            line,
+                            expected_fields,
+                            delimiter,
+                            eol_char,
+                            quote_char,
+                            escape_char,
+                        ) {
                             valid = false;
                             break;
                         }
@@ -149,8 +176,13 @@ where
 ///     'field_1,field_2'
 /// and not with
 ///     '\nfield_1,field_1'
-pub(crate) fn skip_header(input: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
-    skip_this_line(input, quote, eol_char)
+pub(crate) fn skip_header(
+    input: &[u8],
+    quote: Option<u8>,
+    escape: Option<u8>,
+    eol_char: u8,
+) -> &[u8] {
+    skip_this_line(input, quote, escape, eol_char)
 }
 
 /// Remove whitespace from the start of buffer.
@@ -159,21 +191,33 @@ pub(crate) fn skip_whitespace(input: &[u8]) -> &[u8] {
     skip_condition(input, is_whitespace)
 }
 
+/// Skip the bytes that satisfy `f`, but stop where the (multi-byte) delimiter `exclude` starts.
+#[inline]
+fn skip_condition_exclude<F>(input: &[u8], exclude: &[u8], f: F) -> &[u8]
+where
+    F: Fn(u8) -> bool,
+{
+    let read = (0..input.len())
+        .position(|i| !f(input[i]) || input[i..].starts_with(exclude))
+        .unwrap_or(input.len());
+    &input[read..]
+}
+
 #[inline]
 /// Can be used to skip whitespace, but exclude the delimiter
-pub(crate) fn skip_whitespace_exclude(input: &[u8], exclude: u8) -> &[u8] {
-    skip_condition(input, |b| b != exclude && (is_whitespace(b)))
+pub(crate) fn skip_whitespace_exclude<'a>(input: &'a [u8], exclude: &[u8]) -> &'a [u8] {
+    skip_condition_exclude(input, exclude, is_whitespace)
 }
 
 #[inline]
 /// Can be used to skip whitespace, but exclude the delimiter
-pub(crate) fn skip_whitespace_line_ending_exclude(
-    input: &[u8],
-    exclude: u8,
+pub(crate) fn skip_whitespace_line_ending_exclude<'a>(
+    input: &'a [u8],
+    exclude: &[u8],
     eol_char: u8,
-) -> &[u8] {
-    skip_condition(input, |b| {
-        b != exclude && (is_whitespace(b) || is_line_ending(b, eol_char))
+) -> &'a [u8] {
+    skip_condition_exclude(input, exclude, |b| {
+        is_whitespace(b) || is_line_ending(b, eol_char)
     })
 }
 
@@ -188,8 +232,9 @@ pub(crate) fn get_line_stats(
     n_lines: usize,
     eol_char: u8,
     expected_fields: usize,
-    delimiter: u8,
+    delimiter: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
 ) -> Option<(f32, f32)> {
     let mut lengths = Vec::with_capacity(n_lines);
 
@@ -206,6 +251,7 @@ pub(crate) fn get_line_stats(
             Some(expected_fields),
             delimiter,
             quote_char,
+            escape_char,
             eol_char,
         )?;
         bytes_trunc = &bytes_trunc[pos + 1..];
@@ -241,14 +287,21 @@ pub(crate) fn get_line_stats(
 pub(crate) struct SplitLines<'a> {
     v: &'a [u8],
     quote_char: u8,
+    escape_char: Option<u8>,
     end_line_char: u8,
 }
 
 impl<'a> SplitLines<'a> {
-    pub(crate) fn new(slice: &'a [u8], quote_char: u8, end_line_char: u8) -> Self {
+    pub(crate) fn new(
+        slice: &'a [u8],
+        quote_char: u8,
+        escape_char: Option<u8>,
+        end_line_char: u8,
+    ) -> Self {
         Self {
             v: slice,
             quote_char,
+            escape_char: escape_char.filter(|&e| e != quote_char),
             end_line_char,
         }
     }
@@ -272,7 +325,13 @@ impl<'a> Iterator for SplitLines<'a> {
                 Some(&c) => {
                     pos += 1;
 
-                    if c == self.quote_char {
+                    // the escaped byte is part of the string field
+                    if in_field && Some(c) == self.escape_char {
+                        if iter.next().is_none() {
+                            return None;
+                        }
+                        pos += 1;
+                    } else if c == self.quote_char {
                         // toggle between string field enclosure
                         //      if we encounter a starting '"' -> in_field = true;
                         //      if we encounter a closing '"' -> in_field = false;
@@ -303,14 +362,23 @@ impl<'a> Iterator for SplitLines<'a> {
 }
 
 #[inline]
-fn find_quoted(bytes: &[u8], quote_char: u8, needle: u8) -> Option<usize> {
+fn find_quoted(bytes: &[u8], quote_char: u8, escape_char: Option<u8>, needle: u8) -> Option<usize> {
+    let escape_char = escape_char.filter(|&e| e != quote_char);
     let mut in_field = false;
+    let mut escaped = false;
 
     let mut idx = 0u32;
     // micro optimizations
     #[allow(clippy::explicit_counter_loop)]
     for &c in bytes.iter() {
-        if c == quote_char {
+        if escaped {
+            escaped = false;
+            idx += 1;
+            continue;
+        }
+        if in_field && Some(c) == escape_char {
+            escaped = true;
+        } else if c == quote_char {
             // toggle between string field enclosure
             //      if we encounter a starting '"' -> in_field = true;
             //      if we encounter a closing '"' -> in_field = false;
@@ -326,9 +394,9 @@ fn find_quoted(bytes: &[u8], quote_char: u8, needle: u8) -> Option<usize> {
 }
 
 #[inline]
-fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
+fn skip_this_line(bytes: &[u8], quote: Option<u8>, escape: Option<u8>, eol_char: u8) -> &[u8] {
     let pos = match quote {
-        Some(quote) => find_quoted(bytes, quote, eol_char),
+        Some(quote) => find_quoted(bytes, quote, escape, eol_char),
         None => bytes.iter().position(|x| *x == eol_char),
     };
     match pos {
@@ -346,13 +414,16 @@ fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
 /// * `projection` - Indices of the columns to project.
 /// * `buffers` - Parsed output will be written to these buffers. Except for UTF8 data. The offsets of the
 ///               fields are written to the buffers. The UTF8 data will be parsed later.
+/// * `rejected` - If set, rows with fields that cannot be parsed are recorded here instead of raising.
+///                Their unparsable fields are written as null to keep the buffers aligned.
 #[allow(clippy::too_many_arguments)]
 pub(super) fn parse_lines<'a>(
     mut bytes: &'a [u8],
     offset: usize,
-    delimiter: u8,
+    delimiter: &'a [u8],
     comment_char: Option<u8>,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
     null_values: Option<&NullValuesCompiled>,
     missing_is_null: bool,
@@ -363,6 +434,7 @@ pub(super) fn parse_lines<'a>(
     // length of original schema
     schema_len: usize,
     schema: &Schema,
+    mut rejected: Option<&mut RejectedRows>,
 ) -> PolarsResult<usize> {
     assert!(
         !projection.is_empty(),
@@ -373,6 +445,8 @@ pub(super) fn parse_lines<'a>(
     let start = bytes.as_ptr() as usize;
     let original_bytes_len = bytes.len();
     let n_lines = n_lines as u32;
+    // quarantined rows are never ignored
+    let ignore_errors = ignore_errors && rejected.is_none();
 
     let mut line_count = 0u32;
     loop {
@@ -396,7 +470,7 @@ pub(super) fn parse_lines<'a>(
         if let Some(c) = comment_char {
             // line is a comment -> skip
             if bytes[0] == c {
-                let bytes_rem = skip_this_line(bytes, quote_char, eol_char);
+                let bytes_rem = skip_this_line(bytes, quote_char, escape_char, eol_char);
                 bytes = bytes_rem;
                 continue;
             }
@@ -408,8 +482,10 @@ pub(super) fn parse_lines<'a>(
         let mut projection_iter = projection.iter().copied();
         let mut next_projected = unsafe { projection_iter.next().unwrap_unchecked() };
         let mut processed_fields = 0;
+        let line = bytes;
+        let mut line_error = None;
 
-        let mut iter = SplitFields::new(bytes, delimiter, quote_char, eol_char);
+        let mut iter = SplitFields::new(bytes, delimiter, quote_char, escape_char, eol_char);
         let mut idx = 0u32;
         let mut read_sol = 0;
         loop {
@@ -422,8 +498,9 @@ pub(super) fn parse_lines<'a>(
                 Some((mut field, needs_escaping)) => {
                     let field_len = field.len();
 
-                    // +1 is the split character that is consumed by the iterator.
-                    read_sol += field_len + 1;
+                    // the split characters that are consumed by the iterator.
+                    // A finished iterator ended at the single eol char.
+                    read_sol += field_len + if iter.finished { 1 } else { delimiter.len() };
 
                     if idx == next_projected as u32 {
                         // the iterator is finished when it encounters a `\n`
@@ -453,30 +530,43 @@ pub(super) fn parse_lines<'a>(
                         }
                         if add_null {
                             buf.add_null(!missing_is_null && field.is_empty())
-                        } else {
-                            buf.add(field, ignore_errors, needs_escaping, missing_is_null)
-                                .map_err(|_| {
-                                    let bytes_offset = offset + field.as_ptr() as usize - start;
-                                    let unparsable = String::from_utf8_lossy(field);
-                                    let column_name = schema.get_at_index(idx as usize).unwrap().0;
-                                    polars_err!(
-                                        ComputeError:
-                                        "Could not parse `{}` as dtype `{}` at column '{}' (column number {}).\n\
-                                        The current offset in the file is {} bytes.\n\
-                                        \n\
-                                        You might want to try:\n\
-                                        - increasing `infer_schema_length` (e.g. `infer_schema_length=10000`),\n\
-                                        - specifying correct dtype with the `dtypes` argument\n\
-                                        - setting `ignore_errors` to `True`,\n\
-                                        - adding `{}` to the `null_values` list.",
+                        } else if buf
+                            .add(field, ignore_errors, needs_escaping, missing_is_null)
+                            .is_err()
+                        {
+                            let unparsable = String::from_utf8_lossy(field);
+                            let column_name = schema.get_at_index(idx as usize).unwrap().0;
+                            if rejected.is_some() {
+                                if line_error.is_none() {
+                                    line_error = Some(format!(
+                                        "could not parse `{}` as dtype `{}` at column '{}' (column number {})",
                                         &unparsable,
                                         buf.dtype(),
                                         column_name,
                                         idx + 1,
-                                        bytes_offset,
-                                        &unparsable,
-                                    )
-                                })?;
+                                    ));
+                                }
+                                buf.add_null(false);
+                            } else {
+                                let bytes_offset = offset + field.as_ptr() as usize - start;
+                                polars_bail!(
+                                    ComputeError:
+                                    "Could not parse `{}` as dtype `{}` at column '{}' (column number {}).\n\
+                                    The current offset in the file is {} bytes.\n\
+                                    \n\
+                                    You might want to try:\n\
+                                    - increasing `infer_schema_length` (e.g. `infer_schema_length=10000`),\n\
+                                    - specifying correct dtype with the `dtypes` argument\n\
+                                    - setting `ignore_errors` to `True`,\n\
+                                    - adding `{}` to the `null_values` list.",
+                                    &unparsable,
+                                    buf.dtype(),
+                                    column_name,
+                                    idx + 1,
+                                    bytes_offset,
+                                    &unparsable,
+                                );
+                            }
                         }
                         processed_fields += 1;
 
@@ -490,6 +580,7 @@ pub(super) fn parse_lines<'a>(
                                     let bytes_rem = skip_this_line(
                                         &bytes[read_sol - 1..],
                                         quote_char,
+                                        escape_char,
                                         eol_char,
                                     );
                                     bytes = bytes_rem;
@@ -515,10 +606,161 @@ pub(super) fn parse_lines<'a>(
             buf.add_null(!missing_is_null);
             processed_fields += 1;
         }
+        if let Some(rejected) = rejected.as_deref_mut() {
+            match line_error.take() {
+                None => rejected.accept(),
+                Some(error) => {
+                    let line_len = bytes.as_ptr() as usize - line.as_ptr() as usize;
+                    let mut raw = &line[..line_len];
+                    while let [rest @ .., last] = raw {
+                        if *last == eol_char || *last == b'\r' {
+                            raw = rest;
+                        } else {
+                            break;
+                        }
+                    }
+                    let line_offset = offset + line.as_ptr() as usize - start;
+                    rejected.reject(line_offset, raw, error)
+                }
+            }
+        }
         line_count += 1;
     }
 }
 
+/// Validate that `bytes` only contains records that conform to RFC 4180.
+///
+/// Contrary to [`parse_lines`], which is lenient and tries to make sense of every line,
+/// this raises on the first malformed record and reports its line number and byte offset.
+///
+/// # Arguments
+/// * `offset` - offset in bytes of `bytes` in the total input.
+/// * `line_number` - line number (1-based) of the first line in `bytes`.
+///
+/// Returns the line number of the line following `bytes`.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn validate_records(
+    bytes: &[u8],
+    offset: usize,
+    mut line_number: usize,
+    delimiter: &[u8],
+    comment_char: Option<u8>,
+    quote_char: Option<u8>,
+    escape_char: Option<u8>,
+    eol_char: u8,
+    expected_fields: usize,
+) -> PolarsResult<usize> {
+    let escape_char = escape_char.filter(|&e| Some(e) != quote_char);
+    let is_delimiter_at = |pos: usize| bytes[pos..].starts_with(delimiter);
+    let is_eol_at = |pos: usize| {
+        bytes[pos] == eol_char || (bytes[pos] == b'\r' && bytes.get(pos + 1) == Some(&eol_char))
+    };
+    macro_rules! malformed {
+        ($line:expr, $pos:expr, $($arg:tt)*) => {
+            polars_bail!(
+                ComputeError: "malformed CSV record at line {} (byte offset {}): {}",
+                $line, offset + $pos, format!($($arg)*)
+            )
+        };
+    }
+
+    let len = bytes.len();
+    let mut pos = 0;
+    while pos < len {
+        // empty lines and comments are not records
+        if is_eol_at(pos) {
+            pos += if bytes[pos] == b'\r' { 2 } else { 1 };
+            line_number += 1;
+            continue;
+        }
+        if comment_char == Some(bytes[pos]) {
+            pos = memchr::memchr(eol_char, &bytes[pos..]).map_or(len, |i| pos + i + 1);
+            line_number += 1;
+            continue;
+        }
+
+        let record_start = pos;
+        let record_line = line_number;
+        let mut n_fields = 0;
+        loop {
+            n_fields += 1;
+            match quote_char {
+                Some(quote) if pos < len && bytes[pos] == quote => {
+                    let field_start = pos;
+                    let field_line = line_number;
+                    pos += 1;
+                    loop {
+                        if pos >= len {
+                            malformed!(field_line, field_start, "unterminated quoted field")
+                        }
+                        let c = bytes[pos];
+                        if Some(c) == escape_char {
+                            if bytes.get(pos + 1) == Some(&eol_char) {
+                                line_number += 1;
+                            }
+                            pos += 2;
+                            continue;
+                        }
+                        if c == eol_char {
+                            line_number += 1;
+                        } else if c == quote {
+                            // a doubled quote is an escaped quote
+                            if bytes.get(pos + 1) == Some(&quote) {
+                                pos += 2;
+                                continue;
+                            }
+                            pos += 1;
+                            break;
+                        }
+                        pos += 1;
+                    }
+                    if pos < len && !is_delimiter_at(pos) && !is_eol_at(pos) {
+                        malformed!(
+                            line_number,
+                            pos,
+                            "unexpected character '{}' after closing quote",
+                            bytes[pos] as char
+                        )
+                    }
+                }
+                _ => {
+                    while pos < len && bytes[pos] != eol_char && !is_delimiter_at(pos) {
+                        if Some(bytes[pos]) == quote_char {
+                            malformed!(line_number, pos, "quote character in unquoted field")
+                        }
+                        pos += 1;
+                    }
+                }
+            }
+
+            if pos < len && is_delimiter_at(pos) {
+                pos += delimiter.len();
+                continue;
+            }
+            // end of record
+            if pos < len && bytes[pos] == b'\r' {
+                pos += 1;
+            }
+            if pos < len && bytes[pos] == eol_char {
+                pos += 1;
+                line_number += 1;
+            }
+            break;
+        }
+
+        if n_fields != expected_fields {
+            malformed!(
+                record_line,
+                record_start,
+                "expected {} fields, found {}",
+                expected_fields,
+                n_fields
+            )
+        }
+    }
+    Ok(line_number)
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -526,14 +768,14 @@ mod test {
     #[test]
     fn test_splitfields() {
         let input = "\"foo\",\"bar\"";
-        let mut fields = SplitFields::new(input.as_bytes(), b',', Some(b'"'), b'\n');
+        let mut fields = SplitFields::new(input.as_bytes(), b",", Some(b'"'), None, b'\n');
 
         assert_eq!(fields.next(), Some(("\"foo\"".as_bytes(), true)));
         assert_eq!(fields.next(), Some(("\"bar\"".as_bytes(), true)));
         assert_eq!(fields.next(), None);
 
         let input2 = "\"foo\n bar\";\"baz\";12345";
-        let mut fields2 = SplitFields::new(input2.as_bytes(), b';', Some(b'"'), b'\n');
+        let mut fields2 = SplitFields::new(input2.as_bytes(), b";", Some(b'"'), None, b'\n');
 
         assert_eq!(fields2.next(), Some(("\"foo\n bar\"".as_bytes(), true)));
         assert_eq!(fields2.next(), Some(("\"baz\"".as_bytes(), true)));
@@ -544,15 +786,70 @@ mod test {
     #[test]
     fn test_splitlines() {
         let input = "1,\"foo\n\"\n2,\"foo\n\"\n";
-        let mut lines = SplitLines::new(input.as_bytes(), b'"', b'\n');
+        let mut lines = SplitLines::new(input.as_bytes(), b'"', None, b'\n');
         assert_eq!(lines.next(), Some("1,\"foo\n\"".as_bytes()));
         assert_eq!(lines.next(), Some("2,\"foo\n\"".as_bytes()));
         assert_eq!(lines.next(), None);
 
         let input2 = "1,'foo\n'\n2,'foo\n'\n";
-        let mut lines2 = SplitLines::new(input2.as_bytes(), b'\'', b'\n');
+        let mut lines2 = SplitLines::new(input2.as_bytes(), b'\'', None, b'\n');
         assert_eq!(lines2.next(), Some("1,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), Some("2,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), None);
     }
+
+    #[test]
+    fn test_splitfields_multi_byte_delimiter() {
+        let input = "a||\"b||c\"||d|e";
+        let mut fields = SplitFields::new(input.as_bytes(), b"||", Some(b'"'), None, b'\n');
+        assert_eq!(fields.next(), Some(("a".as_bytes(), false)));
+        assert_eq!(fields.next(), Some(("\"b||c\"".as_bytes(), true)));
+        assert_eq!(fields.next(), Some(("d|e".as_bytes(), false)));
+        assert_eq!(fields.next(), None);
+    }
+
+    #[test]
+    fn test_multi_byte_delimiter_matches_in_full() {
+        assert_eq!(count_delimiters_eol(b"x|y||z\n", b"||", b'\n'), 2);
+        assert_eq!(count_delimiters_eol(b"x|y,z", b",", b'\n'), 1);
+        // whitespace is only kept where the whole delimiter starts
+        assert_eq!(skip_whitespace_exclude(b"  \t;1", b"\t;"), b"\t;1");
+        assert_eq!(skip_whitespace_exclude(b" \t 1", b"\t;"), b"1");
+    }
+
+    #[test]
+    fn test_splitfields_escape_char() {
+        let input = r#""a \",b",c"#;
+        let mut fields = SplitFields::new(input.as_bytes(), b",", Some(b'"'), Some(b'\\'), b'\n');
+        assert_eq!(fields.next(), Some((r#""a \",b""#.as_bytes(), true)));
+        assert_eq!(fields.next(), Some(("c".as_bytes(), false)));
+        assert_eq!(fields.next(), None);
+    }
+
+    #[test]
+    fn test_validate_records() {
+        let validate = |input: &str| {
+            validate_records(
+                input.as_bytes(),
+                10,
+                2,
+                b",",
+                None,
+                Some(b'"'),
+                None,
+                b'\n',
+                2,
+            )
+        };
+        assert_eq!(validate("1,\"a\"\"b\"\n2,\"c\nd\"\n").unwrap(), 5);
+
+        let err = validate("1,a\n2,\"b\"c\n").unwrap_err().to_string();
+        assert!(err.contains("line 3 (byte offset 19)"), "{}", err);
+        let err = validate("1,a\n2,b,c\n").unwrap_err().to_string();
+        assert!(err.contains("line 3 (byte offset 14)"), "{}", err);
+        let err = validate("1,a\"b\n").unwrap_err().to_string();
+        assert!(err.contains("quote character in unquoted field"), "{}", err);
+        let err = validate("1,\"a\n").unwrap_err().to_string();
+        assert!(err.contains("unterminated quoted field"), "{}", err);
+    }
 }
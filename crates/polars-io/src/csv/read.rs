@@ -1,4 +1,5 @@
 use super::*;
+use crate::bad_records::BadRecords;
 use crate::csv::read_impl::{
     to_batched_owned_mmap, to_batched_owned_read, BatchedCsvReaderMmap, BatchedCsvReaderRead,
     OwnedBatchedCsvReader, OwnedBatchedCsvReaderMmap,
@@ -111,7 +112,7 @@ where
     projection: Option<Vec<usize>>,
     /// Optional column names to project/ select.
     columns: Option<Vec<String>>,
-    delimiter: Option<u8>,
+    delimiter: Option<Vec<u8>>,
     has_header: bool,
     ignore_errors: bool,
     pub(crate) schema: Option<SchemaRef>,
@@ -129,9 +130,12 @@ where
     missing_is_null: bool,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     skip_rows_after_header: usize,
     try_parse_dates: bool,
     row_count: Option<RowCount>,
+    strict: bool,
+    bad_records: Option<BadRecords>,
 }
 
 impl<'a, R> CsvReader<'a, R>
@@ -204,7 +208,13 @@ where
 
     /// Set the CSV file's column delimiter as a byte character
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
-        self.delimiter = Some(delimiter);
+        self.delimiter = Some(vec![delimiter]);
+        self
+    }
+
+    /// Set the CSV file's column delimiter as a sequence of bytes, e.g. `b"||"`.
+    pub fn with_multi_byte_delimiter(mut self, delimiter: &[u8]) -> Self {
+        self.delimiter = Some(delimiter.to_vec());
         self
     }
 
@@ -307,6 +317,32 @@ where
         self
     }
 
+    /// Set the `char` used to escape the next character in a quoted field, e.g. `b'\\'` for
+    /// `"a \"quoted\" word"`. The default is `[None]`, in which case quotes are escaped by doubling
+    /// them.
+    pub fn with_escape_char(mut self, escape: Option<u8>) -> Self {
+        self.escape_char = escape;
+        self
+    }
+
+    /// Raise on records that don't conform to RFC 4180, instead of trying to make sense of them.
+    /// The error reports the line number and byte offset of the malformed record.
+    ///
+    /// Malformed records are records with an unexpected number of fields, unterminated quoted
+    /// fields, quotes in unquoted fields and characters between a closing quote and the delimiter.
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Remove the rows with fields that cannot be parsed from the output and hand them to
+    /// `bad_records`, together with their line number and the parse error. This takes
+    /// precedence over [`CsvReader::with_ignore_errors`].
+    pub fn with_bad_records(mut self, bad_records: Option<BadRecords>) -> Self {
+        self.bad_records = bad_records;
+        self
+    }
+
     /// Automatically try to parse dates/ datetimes and time. If parsing fails, columns remain of dtype `[DataType::Utf8]`.
     pub fn with_try_parse_dates(mut self, toggle: bool) -> Self {
         self.try_parse_dates = toggle;
@@ -344,7 +380,7 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             self.skip_rows_before_header,
             std::mem::take(&mut self.projection),
             self.max_records,
-            self.delimiter,
+            self.delimiter.clone(),
             self.has_header,
             self.ignore_errors,
             self.schema.clone(),
@@ -358,6 +394,7 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             self.low_memory,
             self.comment_char,
             self.quote_char,
+            self.escape_char,
             self.eol_char,
             std::mem::take(&mut self.null_values),
             self.missing_is_null,
@@ -366,6 +403,8 @@ impl<'a, R: MmapBytesReader + 'a> CsvReader<'a, R> {
             self.skip_rows_after_header,
             std::mem::take(&mut self.row_count),
             self.try_parse_dates,
+            self.strict,
+            self.bad_records.clone(),
         )
     }
 
@@ -465,7 +504,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
 
                 let (inferred_schema, _, _) = infer_file_schema(
                     &reader_bytes,
-                    self.delimiter.unwrap_or(b','),
+                    self.delimiter.as_deref().unwrap_or(b","),
                     self.max_records,
                     self.has_header,
                     None,
@@ -473,6 +512,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
                     self.skip_rows_after_header,
                     self.comment_char,
                     self.quote_char,
+                    self.escape_char,
                     self.eol_char,
                     self.null_values.as_ref(),
                     self.try_parse_dates,
@@ -493,7 +533,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
 
                 let (inferred_schema, _, _) = infer_file_schema(
                     &reader_bytes,
-                    self.delimiter.unwrap_or(b','),
+                    self.delimiter.as_deref().unwrap_or(b","),
                     self.max_records,
                     self.has_header,
                     None,
@@ -501,6 +541,7 @@ impl<'a> CsvReader<'a, Box<dyn MmapBytesReader>> {
                     self.skip_rows_after_header,
                     self.comment_char,
                     self.quote_char,
+                    self.escape_char,
                     self.eol_char,
                     self.null_values.as_ref(),
                     self.try_parse_dates,
@@ -544,9 +585,12 @@ where
             missing_is_null: true,
             predicate: None,
             quote_char: Some(b'"'),
+            escape_char: None,
             skip_rows_after_header: 0,
             try_parse_dates: false,
             row_count: None,
+            strict: false,
+            bad_records: None,
         }
     }
 
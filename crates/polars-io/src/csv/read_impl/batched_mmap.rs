@@ -13,8 +13,9 @@ pub(crate) fn get_file_chunks_iterator(
     chunk_size: usize,
     bytes: &[u8],
     expected_fields: usize,
-    delimiter: u8,
+    delimiter: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) {
     for _ in 0..n_chunks {
@@ -29,6 +30,7 @@ pub(crate) fn get_file_chunks_iterator(
             Some(expected_fields),
             delimiter,
             quote_char,
+            escape_char,
             eol_char,
         ) {
             Some(pos) => search_pos + pos,
@@ -49,8 +51,9 @@ struct ChunkOffsetIter<'a> {
     // not a promise, but something we want
     rows_per_batch: usize,
     expected_fields: usize,
-    delimiter: u8,
+    delimiter: Vec<u8>,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 }
 
@@ -68,8 +71,9 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
                     let bytes_first_row = next_line_position(
                         &self.bytes[self.last_offset + 2..],
                         Some(self.expected_fields),
-                        self.delimiter,
+                        &self.delimiter,
                         self.quote_char,
+                        self.escape_char,
                         self.eol_char,
                     )
                     .unwrap_or(1);
@@ -84,8 +88,9 @@ impl<'a> Iterator for ChunkOffsetIter<'a> {
                     self.rows_per_batch * bytes_first_row,
                     self.bytes,
                     self.expected_fields,
-                    self.delimiter,
+                    &self.delimiter,
                     self.quote_char,
+                    self.escape_char,
                     self.eol_char,
                 );
                 match self.offsets.pop_front() {
@@ -109,6 +114,9 @@ impl<'a> CoreReader<'a> {
         let bytes = reader_bytes.as_ref();
         let (bytes, starting_point_offset) =
             self.find_starting_point(bytes, self.quote_char, self.eol_char)?;
+        let line_number = starting_point_offset
+            .map(|offset| first_line_number(reader_bytes.as_ref(), offset, self.eol_char))
+            .unwrap_or(1);
 
         // this is arbitrarily chosen.
         // we don't want this to depend on the thread pool size
@@ -124,8 +132,9 @@ impl<'a> CoreReader<'a> {
             n_chunks: offset_batch_size,
             rows_per_batch: self.chunk_size,
             expected_fields: self.schema.len(),
-            delimiter: self.delimiter,
+            delimiter: self.delimiter.clone(),
             quote_char: self.quote_char,
+            escape_char: self.escape_char,
             eol_char: self.eol_char,
         };
 
@@ -156,6 +165,7 @@ impl<'a> CoreReader<'a> {
             row_count: self.row_count,
             comment_char: self.comment_char,
             quote_char: self.quote_char,
+            escape_char: self.escape_char,
             eol_char: self.eol_char,
             null_values: self.null_values,
             missing_is_null: self.missing_is_null,
@@ -166,6 +176,10 @@ impl<'a> CoreReader<'a> {
             delimiter: self.delimiter,
             schema: self.schema,
             rows_read: 0,
+            strict: self.strict,
+            line_number,
+            bad_record_lines: LineCounter::new(1, self.eol_char),
+            bad_records: self.bad_records,
             _cat_lock,
         })
     }
@@ -183,6 +197,7 @@ pub struct BatchedCsvReaderMmap<'a> {
     row_count: Option<RowCount>,
     comment_char: Option<u8>,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
     null_values: Option<NullValuesCompiled>,
     missing_is_null: bool,
@@ -190,9 +205,15 @@ pub struct BatchedCsvReaderMmap<'a> {
     ignore_errors: bool,
     n_rows: Option<usize>,
     encoding: CsvEncoding,
-    delimiter: u8,
+    delimiter: Vec<u8>,
     schema: SchemaRef,
     rows_read: IdxSize,
+    strict: bool,
+    // line number of the next record to validate in strict mode
+    line_number: usize,
+    // resolves the line numbers of rejected records in `reader_bytes`
+    bad_record_lines: LineCounter,
+    bad_records: Option<BadRecords>,
     #[cfg(feature = "dtype-categorical")]
     _cat_lock: Option<polars_core::IUseStringCache>,
     #[cfg(not(feature = "dtype-categorical"))]
@@ -224,19 +245,37 @@ impl<'a> BatchedCsvReaderMmap<'a> {
             bytes = &bytes[pos..];
         }
 
-        let mut chunks = POOL.install(|| {
+        if self.strict {
+            for &(start, stop) in chunks {
+                self.line_number = validate_records(
+                    &bytes[start..stop],
+                    self.starting_point_offset.unwrap_or(0) + start,
+                    self.line_number,
+                    &self.delimiter,
+                    self.comment_char,
+                    self.quote_char,
+                    self.escape_char,
+                    self.eol_char,
+                    self.schema.len(),
+                )?;
+            }
+        }
+
+        let chunks = POOL.install(|| {
             chunks
                 .into_par_iter()
                 .copied()
                 .map(|(bytes_offset_thread, stop_at_nbytes)| {
+                    let mut rejected_records = vec![];
                     let mut df = read_chunk(
                         bytes,
-                        self.delimiter,
+                        &self.delimiter,
                         self.schema.as_ref(),
                         self.ignore_errors,
                         &self.projection,
                         bytes_offset_thread,
                         self.quote_char,
+                        self.escape_char,
                         self.eol_char,
                         self.comment_char,
                         self.chunk_size,
@@ -247,6 +286,7 @@ impl<'a> BatchedCsvReaderMmap<'a> {
                         self.chunk_size,
                         stop_at_nbytes,
                         self.starting_point_offset,
+                        self.bad_records.as_ref().map(|_| &mut rejected_records),
                     )?;
 
                     cast_columns(&mut df, &self.to_cast, false)?;
@@ -255,12 +295,21 @@ impl<'a> BatchedCsvReaderMmap<'a> {
                     if let Some(rc) = &self.row_count {
                         df.with_row_count_mut(&rc.name, Some(rc.offset));
                     }
-                    Ok(df)
+                    Ok((df, rejected_records))
                 })
                 .collect::<PolarsResult<Vec<_>>>()
         })?;
         self.file_chunks.clear();
 
+        let (mut chunks, rejected_records): (Vec<_>, Vec<_>) = chunks.into_iter().unzip();
+        if let Some(bad_records) = &self.bad_records {
+            let reader_bytes = self.reader_bytes.deref();
+            let lines = &mut self.bad_record_lines;
+            bad_records.push(rejected_records.into_iter().flatten().collect(), |offset| {
+                lines.line_number_at(reader_bytes, offset)
+            })?;
+        }
+
         if self.row_count.is_some() {
             update_row_counts2(&mut chunks, self.rows_read)
         }
@@ -14,8 +14,9 @@ pub(crate) fn get_offsets(
     chunk_size: usize,
     bytes: &[u8],
     expected_fields: usize,
-    delimiter: u8,
+    delimiter: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) {
     let mut start = 0;
@@ -31,6 +32,7 @@ pub(crate) fn get_offsets(
             Some(expected_fields),
             delimiter,
             quote_char,
+            escape_char,
             eol_char,
         ) {
             Some(pos) => search_pos + pos,
@@ -57,8 +59,9 @@ struct ChunkReader<'a> {
     // not a promise, but something we want
     rows_per_batch: usize,
     expected_fields: usize,
-    delimiter: u8,
+    delimiter: Vec<u8>,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 }
 
@@ -67,8 +70,9 @@ impl<'a> ChunkReader<'a> {
         file: &'a File,
         rows_per_batch: usize,
         expected_fields: usize,
-        delimiter: u8,
+        delimiter: Vec<u8>,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
         eol_char: u8,
         page_size: u64,
     ) -> Self {
@@ -87,6 +91,7 @@ impl<'a> ChunkReader<'a> {
             expected_fields,
             delimiter,
             quote_char,
+            escape_char,
             eol_char,
         }
     }
@@ -132,8 +137,9 @@ impl<'a> ChunkReader<'a> {
                 bytes_first_row = next_line_position(
                     &self.buf[2..],
                     Some(self.expected_fields),
-                    self.delimiter,
+                    &self.delimiter,
                     self.quote_char,
+                    self.escape_char,
                     self.eol_char,
                 );
 
@@ -179,8 +185,9 @@ impl<'a> ChunkReader<'a> {
             self.rows_per_batch * bytes_first_row,
             &self.buf,
             self.expected_fields,
-            self.delimiter,
+            &self.delimiter,
             self.quote_char,
+            self.escape_char,
             self.eol_char,
         );
         !self.offsets.is_empty()
@@ -197,6 +204,9 @@ impl<'a> CoreReader<'a> {
         };
         let (_, starting_point_offset) =
             self.find_starting_point(bytes, self.quote_char, self.eol_char)?;
+        let line_number = starting_point_offset
+            .map(|offset| first_line_number(bytes, offset, self.eol_char))
+            .unwrap_or(1);
         if let Some(starting_point_offset) = starting_point_offset {
             file.seek(SeekFrom::Current(starting_point_offset as i64))
                 .unwrap();
@@ -206,8 +216,9 @@ impl<'a> CoreReader<'a> {
             file,
             self.chunk_size,
             self.schema.len(),
-            self.delimiter,
+            self.delimiter.clone(),
             self.quote_char,
+            self.escape_char,
             self.eol_char,
             4096,
         );
@@ -239,6 +250,7 @@ impl<'a> CoreReader<'a> {
             row_count: self.row_count,
             comment_char: self.comment_char,
             quote_char: self.quote_char,
+            escape_char: self.escape_char,
             eol_char: self.eol_char,
             null_values: self.null_values,
             missing_is_null: self.missing_is_null,
@@ -249,6 +261,11 @@ impl<'a> CoreReader<'a> {
             delimiter: self.delimiter,
             schema: self.schema,
             rows_read: 0,
+            strict: self.strict,
+            bytes_validated: 0,
+            line_number,
+            bad_record_line_number: line_number,
+            bad_records: self.bad_records,
             _cat_lock,
         })
     }
@@ -266,6 +283,7 @@ pub struct BatchedCsvReaderRead<'a> {
     row_count: Option<RowCount>,
     comment_char: Option<u8>,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
     null_values: Option<NullValuesCompiled>,
     missing_is_null: bool,
@@ -273,9 +291,16 @@ pub struct BatchedCsvReaderRead<'a> {
     ignore_errors: bool,
     n_rows: Option<usize>,
     encoding: CsvEncoding,
-    delimiter: u8,
+    delimiter: Vec<u8>,
     schema: SchemaRef,
     rows_read: IdxSize,
+    strict: bool,
+    // byte offset and line number of the next record to validate in strict mode
+    bytes_validated: usize,
+    line_number: usize,
+    // line number at the start of the next chunk, used to locate rejected records
+    bad_record_line_number: usize,
+    bad_records: Option<BadRecords>,
     #[cfg(feature = "dtype-categorical")]
     _cat_lock: Option<polars_core::IUseStringCache>,
     #[cfg(not(feature = "dtype-categorical"))]
@@ -320,20 +345,40 @@ impl<'a> BatchedCsvReaderRead<'a> {
             return Ok(None);
         }
 
-        let mut chunks = POOL.install(|| {
+        if self.strict {
+            for &(ptr, len) in &self.file_chunks {
+                let chunk = unsafe { std::slice::from_raw_parts(ptr as *const u8, len) };
+                self.line_number = validate_records(
+                    chunk,
+                    self.starting_point_offset.unwrap_or(0) + self.bytes_validated,
+                    self.line_number,
+                    &self.delimiter,
+                    self.comment_char,
+                    self.quote_char,
+                    self.escape_char,
+                    self.eol_char,
+                    self.schema.len(),
+                )?;
+                self.bytes_validated += len;
+            }
+        }
+
+        let chunks = POOL.install(|| {
             self.file_chunks
                 .par_iter()
                 .map(|(ptr, len)| {
                     let chunk = unsafe { std::slice::from_raw_parts(*ptr as *const u8, *len) };
                     let stop_at_n_bytes = chunk.len();
+                    let mut rejected_records = vec![];
                     let mut df = read_chunk(
                         chunk,
-                        self.delimiter,
+                        &self.delimiter,
                         self.schema.as_ref(),
                         self.ignore_errors,
                         &self.projection,
                         0,
                         self.quote_char,
+                        self.escape_char,
                         self.eol_char,
                         self.comment_char,
                         self.chunk_size,
@@ -344,6 +389,7 @@ impl<'a> BatchedCsvReaderRead<'a> {
                         self.chunk_size,
                         stop_at_n_bytes,
                         self.starting_point_offset,
+                        self.bad_records.as_ref().map(|_| &mut rejected_records),
                     )?;
 
                     cast_columns(&mut df, &self.to_cast, false)?;
@@ -352,10 +398,24 @@ impl<'a> BatchedCsvReaderRead<'a> {
                     if let Some(rc) = &self.row_count {
                         df.with_row_count_mut(&rc.name, Some(rc.offset));
                     }
-                    Ok(df)
+                    Ok((df, rejected_records))
                 })
                 .collect::<PolarsResult<Vec<_>>>()
         })?;
+
+        let (mut chunks, rejected_records): (Vec<_>, Vec<_>) = chunks.into_iter().unzip();
+        if let Some(bad_records) = &self.bad_records {
+            // the offsets of the records are relative to their chunk
+            let starting_point_offset = self.starting_point_offset.unwrap_or(0);
+            for (&(ptr, len), rejected_records) in self.file_chunks.iter().zip(rejected_records) {
+                let chunk = unsafe { std::slice::from_raw_parts(ptr as *const u8, len) };
+                let mut lines = LineCounter::new(self.bad_record_line_number, self.eol_char);
+                bad_records.push(rejected_records, |offset| {
+                    lines.line_number_at(chunk, offset - starting_point_offset)
+                })?;
+                self.bad_record_line_number = lines.line_number_at(chunk, len);
+            }
+        }
         self.file_chunks.clear();
 
         if self.row_count.is_some() {
@@ -18,6 +18,7 @@ use polars_time::prelude::*;
 use polars_utils::flatten;
 use rayon::prelude::*;
 
+use crate::bad_records::{BadRecords, LineCounter, RejectedRecord, RejectedRows};
 use crate::csv::buffer::*;
 use crate::csv::parser::*;
 use crate::csv::read::NullValuesCompiled;
@@ -91,18 +92,23 @@ pub(crate) struct CoreReader<'a> {
     encoding: CsvEncoding,
     n_threads: Option<usize>,
     has_header: bool,
-    delimiter: u8,
+    delimiter: Vec<u8>,
     sample_size: usize,
     chunk_size: usize,
     low_memory: bool,
     comment_char: Option<u8>,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
     null_values: Option<NullValuesCompiled>,
     missing_is_null: bool,
     predicate: Option<Arc<dyn PhysicalIoExpr>>,
     to_cast: Vec<Field>,
     row_count: Option<RowCount>,
+    /// Raise on records that don't conform to RFC 4180
+    strict: bool,
+    /// Quarantine rows that cannot be parsed
+    bad_records: Option<BadRecords>,
 }
 
 impl<'a> fmt::Debug for CoreReader<'a> {
@@ -171,7 +177,7 @@ impl<'a> CoreReader<'a> {
         mut skip_rows: usize,
         mut projection: Option<Vec<usize>>,
         max_records: Option<usize>,
-        delimiter: Option<u8>,
+        delimiter: Option<Vec<u8>>,
         has_header: bool,
         ignore_errors: bool,
         schema: Option<SchemaRef>,
@@ -185,6 +191,7 @@ impl<'a> CoreReader<'a> {
         low_memory: bool,
         comment_char: Option<u8>,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
         eol_char: u8,
         null_values: Option<NullValues>,
         missing_is_null: bool,
@@ -193,6 +200,8 @@ impl<'a> CoreReader<'a> {
         skip_rows_after_header: usize,
         row_count: Option<RowCount>,
         try_parse_dates: bool,
+        strict: bool,
+        bad_records: Option<BadRecords>,
     ) -> PolarsResult<CoreReader<'a>> {
         #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
         let mut reader_bytes = reader_bytes;
@@ -205,8 +214,10 @@ impl<'a> CoreReader<'a> {
             );
         }
 
+        let delimiter = delimiter.unwrap_or_else(|| vec![b',']);
+        polars_ensure!(!delimiter.is_empty(), InvalidOperation: "CSV delimiter must not be empty");
+
         // check if schema should be inferred
-        let delimiter = delimiter.unwrap_or(b',');
 
         let mut schema = match schema {
             Some(schema) => schema,
@@ -216,15 +227,20 @@ impl<'a> CoreReader<'a> {
                     // In case the file is compressed this schema inference is wrong and has to be done
                     // again after decompression.
                     #[cfg(any(feature = "decompress", feature = "decompress-fast"))]
-                    if let Some(b) =
-                        decompress(&reader_bytes, n_rows, delimiter, quote_char, eol_char)
-                    {
+                    if let Some(b) = decompress(
+                        &reader_bytes,
+                        n_rows,
+                        &delimiter,
+                        quote_char,
+                        escape_char,
+                        eol_char,
+                    ) {
                         reader_bytes = ReaderBytes::Owned(b);
                     }
 
                     let (inferred_schema, _, _) = infer_file_schema(
                         &reader_bytes,
-                        delimiter,
+                        &delimiter,
                         max_records,
                         has_header,
                         schema_overwrite.as_deref(),
@@ -232,6 +248,7 @@ impl<'a> CoreReader<'a> {
                         skip_rows_after_header,
                         comment_char,
                         quote_char,
+                        escape_char,
                         eol_char,
                         null_values.as_ref(),
                         try_parse_dates,
@@ -283,12 +300,15 @@ impl<'a> CoreReader<'a> {
             low_memory,
             comment_char,
             quote_char,
+            escape_char,
             eol_char,
             null_values,
             missing_is_null,
             predicate,
             to_cast,
             row_count,
+            strict,
+            bad_records,
         })
     }
 
@@ -301,7 +321,7 @@ impl<'a> CoreReader<'a> {
         let starting_point_offset = bytes.as_ptr() as usize;
 
         // Skip all leading white space and the occasional utf8-bom
-        bytes = skip_whitespace_exclude(skip_bom(bytes), self.delimiter);
+        bytes = skip_whitespace_exclude(skip_bom(bytes), &self.delimiter);
         // \n\n can be a empty string row of a single column
         // in other cases we skip it.
         if self.schema.len() > 1 {
@@ -310,7 +330,7 @@ impl<'a> CoreReader<'a> {
 
         // If there is a header we skip it.
         if self.has_header {
-            bytes = skip_header(bytes, quote_char, eol_char);
+            bytes = skip_header(bytes, quote_char, self.escape_char, eol_char);
         }
 
         if self.skip_rows_before_header > 0 {
@@ -330,7 +350,14 @@ impl<'a> CoreReader<'a> {
                     // we don't pass expected fields
                     // as we want to skip all rows
                     // no matter the no. of fields
-                    _ => next_line_position(bytes, None, self.delimiter, self.quote_char, eol_char),
+                    _ => next_line_position(
+                        bytes,
+                        None,
+                        &self.delimiter,
+                        self.quote_char,
+                        self.escape_char,
+                        eol_char,
+                    ),
                 }
                 .ok_or_else(|| polars_err!(NoData: "not enough lines to skip"))?;
 
@@ -367,8 +394,9 @@ impl<'a> CoreReader<'a> {
             self.sample_size,
             self.eol_char,
             self.schema.len(),
-            self.delimiter,
+            &self.delimiter,
             self.quote_char,
+            self.escape_char,
         ) {
             if logging {
                 eprintln!("avg line length: {mean}\nstd. dev. line length: {std}");
@@ -391,8 +419,9 @@ impl<'a> CoreReader<'a> {
                     if let Some(pos) = next_line_position(
                         &bytes[n_bytes..],
                         Some(self.schema.len()),
-                        self.delimiter,
+                        &self.delimiter,
                         self.quote_char,
+                        self.escape_char,
                         self.eol_char,
                     ) {
                         if set_upper_bound {
@@ -447,8 +476,9 @@ impl<'a> CoreReader<'a> {
             bytes,
             n_file_chunks,
             self.schema.len(),
-            self.delimiter,
+            &self.delimiter,
             self.quote_char,
+            self.escape_char,
             self.eol_char,
         );
 
@@ -526,8 +556,18 @@ impl<'a> CoreReader<'a> {
         predicate: Option<&Arc<dyn PhysicalIoExpr>>,
     ) -> PolarsResult<DataFrame> {
         let logging = verbose();
+        let file_bytes = bytes;
         let (file_chunks, chunk_size, total_rows, starting_point_offset, bytes, remaining_bytes) =
             self.determine_file_chunks_and_statistics(&mut n_threads, bytes, logging)?;
+        if self.strict {
+            if let Some(offset) = starting_point_offset {
+                self.validate_records(
+                    bytes,
+                    offset,
+                    first_line_number(file_bytes, offset, self.eol_char),
+                )?;
+            }
+        }
         let projection = self.get_projection();
         let str_columns = self.get_string_columns(&projection)?;
 
@@ -540,6 +580,7 @@ impl<'a> CoreReader<'a> {
                 &self.schema,
                 &self.init_string_size_stats(&str_columns, 0),
                 self.quote_char,
+                self.escape_char,
                 self.encoding,
                 self.ignore_errors,
             )?;
@@ -561,13 +602,14 @@ impl<'a> CoreReader<'a> {
                 file_chunks
                     .into_par_iter()
                     .map(|(bytes_offset_thread, stop_at_nbytes)| {
-                        let delimiter = self.delimiter;
+                        let delimiter = &self.delimiter;
                         let schema = self.schema.as_ref();
                         let ignore_errors = self.ignore_errors;
                         let projection = &projection;
 
                         let mut read = bytes_offset_thread;
                         let mut dfs = Vec::with_capacity(256);
+                        let mut rejected_records = vec![];
                         let mut last_read = usize::MAX;
                         loop {
                             if read >= stop_at_nbytes || read == last_read {
@@ -580,9 +622,12 @@ impl<'a> CoreReader<'a> {
                                 schema,
                                 &str_capacities,
                                 self.quote_char,
+                                self.escape_char,
                                 self.encoding,
-                                self.ignore_errors,
+                                self.ignore_errors || self.bad_records.is_some(),
                             )?;
+                            let mut rejected =
+                                self.bad_records.as_ref().map(|_| RejectedRows::default());
 
                             let local_bytes = &bytes[read..stop_at_nbytes];
 
@@ -594,6 +639,7 @@ impl<'a> CoreReader<'a> {
                                 delimiter,
                                 self.comment_char,
                                 self.quote_char,
+                                self.escape_char,
                                 self.eol_char,
                                 self.null_values.as_ref(),
                                 self.missing_is_null,
@@ -603,6 +649,7 @@ impl<'a> CoreReader<'a> {
                                 chunk_size,
                                 self.schema.len(),
                                 &self.schema,
+                                rejected.as_mut(),
                             )?;
 
                             let mut local_df = DataFrame::new_no_checks(
@@ -611,6 +658,10 @@ impl<'a> CoreReader<'a> {
                                     .map(|buf| buf.into_series())
                                     .collect::<PolarsResult<_>>()?,
                             );
+                            if let Some(rejected) = rejected {
+                                local_df = rejected.filter(local_df)?;
+                                rejected_records.extend(rejected.into_records());
+                            }
                             let current_row_count = local_df.height() as IdxSize;
                             if let Some(rc) = &self.row_count {
                                 local_df.with_row_count_mut(&rc.name, Some(rc.offset));
@@ -627,10 +678,12 @@ impl<'a> CoreReader<'a> {
                             }
                             dfs.push((local_df, current_row_count));
                         }
-                        Ok(dfs)
+                        Ok((dfs, rejected_records))
                     })
                     .collect::<PolarsResult<Vec<_>>>()
             })?;
+            let (dfs, rejected_records): (Vec<_>, Vec<_>) = dfs.into_iter().unzip();
+            self.push_bad_records(file_bytes, rejected_records.into_iter().flatten().collect())?;
             let mut dfs = flatten(&dfs, None);
             if self.row_count.is_some() {
                 update_row_counts(&mut dfs, 0)
@@ -650,18 +703,20 @@ impl<'a> CoreReader<'a> {
 
             let str_capacities = self.init_string_size_stats(&str_columns, capacity);
 
-            let mut dfs = POOL.install(|| {
+            let dfs = POOL.install(|| {
                 file_chunks
                     .into_par_iter()
                     .map(|(bytes_offset_thread, stop_at_nbytes)| {
+                        let mut rejected_records = vec![];
                         let mut df = read_chunk(
                             bytes,
-                            self.delimiter,
+                            &self.delimiter,
                             self.schema.as_ref(),
                             self.ignore_errors,
                             &projection,
                             bytes_offset_thread,
                             self.quote_char,
+                            self.escape_char,
                             self.eol_char,
                             self.comment_char,
                             capacity,
@@ -672,6 +727,7 @@ impl<'a> CoreReader<'a> {
                             usize::MAX,
                             stop_at_nbytes,
                             starting_point_offset,
+                            self.bad_records.as_ref().map(|_| &mut rejected_records),
                         )?;
 
                         // update the running str bytes statistics
@@ -684,10 +740,12 @@ impl<'a> CoreReader<'a> {
                             df.with_row_count_mut(&rc.name, Some(rc.offset));
                         }
                         let n_read = df.height() as IdxSize;
-                        Ok((df, n_read))
+                        Ok(((df, n_read), rejected_records))
                     })
                     .collect::<PolarsResult<Vec<_>>>()
             })?;
+            let (mut dfs, rejected_records): (Vec<_>, Vec<_>) = dfs.into_iter().unzip();
+            let mut rejected_records: Vec<_> = rejected_records.into_iter().flatten().collect();
             if let (Some(n_rows), Some(remaining_bytes)) = (self.n_rows, remaining_bytes) {
                 let rows_already_read: usize = dfs.iter().map(|x| x.1 as usize).sum();
                 if rows_already_read < n_rows {
@@ -700,16 +758,20 @@ impl<'a> CoreReader<'a> {
                                 self.schema.as_ref(),
                                 &str_capacities,
                                 self.quote_char,
+                                self.escape_char,
                                 self.encoding,
-                                self.ignore_errors,
+                                self.ignore_errors || self.bad_records.is_some(),
                             )?;
+                            let mut rejected =
+                                self.bad_records.as_ref().map(|_| RejectedRows::default());
 
                             parse_lines(
                                 remaining_bytes,
-                                0,
-                                self.delimiter,
+                                remaining_bytes.as_ptr() as usize - file_bytes.as_ptr() as usize,
+                                &self.delimiter,
                                 self.comment_char,
                                 self.quote_char,
+                                self.escape_char,
                                 self.eol_char,
                                 self.null_values.as_ref(),
                                 self.missing_is_null,
@@ -719,14 +781,23 @@ impl<'a> CoreReader<'a> {
                                 remaining_rows - 1,
                                 self.schema.len(),
                                 self.schema.as_ref(),
+                                rejected.as_mut(),
                             )?;
 
-                            DataFrame::new_no_checks(
+                            let df = DataFrame::new_no_checks(
                                 buffers
                                     .into_iter()
                                     .map(|buf| buf.into_series())
                                     .collect::<PolarsResult<_>>()?,
-                            )
+                            );
+                            match rejected {
+                                Some(rejected) => {
+                                    let df = rejected.filter(df)?;
+                                    rejected_records.extend(rejected.into_records());
+                                    df
+                                }
+                                None => df,
+                            }
                         };
 
                         cast_columns(&mut df, &self.to_cast, false)?;
@@ -738,6 +809,7 @@ impl<'a> CoreReader<'a> {
                     });
                 }
             }
+            self.push_bad_records(file_bytes, rejected_records)?;
             if self.row_count.is_some() {
                 update_row_counts(&mut dfs, 0)
             }
@@ -745,6 +817,43 @@ impl<'a> CoreReader<'a> {
         }
     }
 
+    /// Hand the records rejected while parsing `file_bytes` to the bad records sink.
+    fn push_bad_records(
+        &self,
+        file_bytes: &[u8],
+        rejected_records: Vec<RejectedRecord>,
+    ) -> PolarsResult<()> {
+        if let Some(bad_records) = &self.bad_records {
+            let mut lines = LineCounter::new(1, self.eol_char);
+            bad_records.push(rejected_records, |offset| {
+                lines.line_number_at(file_bytes, offset)
+            })?;
+        }
+        Ok(())
+    }
+
+    /// Check that `bytes` conforms to RFC 4180, see [`validate_records`].
+    ///
+    /// Returns the line number of the line following `bytes`.
+    fn validate_records(
+        &self,
+        bytes: &[u8],
+        offset: usize,
+        line_number: usize,
+    ) -> PolarsResult<usize> {
+        validate_records(
+            bytes,
+            offset,
+            line_number,
+            &self.delimiter,
+            self.comment_char,
+            self.quote_char,
+            self.escape_char,
+            self.eol_char,
+            self.schema.len(),
+        )
+    }
+
     /// Read the csv into a DataFrame. The predicate can come from a lazy physical plan.
     pub fn as_df(&mut self) -> PolarsResult<DataFrame> {
         let predicate = self.predicate.take();
@@ -765,6 +874,11 @@ impl<'a> CoreReader<'a> {
     }
 }
 
+/// The line number (1-based) at `offset` in the file.
+fn first_line_number(file_bytes: &[u8], offset: usize, eol_char: u8) -> usize {
+    memchr::memchr_iter(eol_char, &file_bytes[..offset]).count() + 1
+}
+
 fn update_string_stats(
     str_capacities: &[RunningSize],
     str_columns: &StringColumns,
@@ -784,12 +898,13 @@ fn update_string_stats(
 #[allow(clippy::too_many_arguments)]
 fn read_chunk(
     bytes: &[u8],
-    delimiter: u8,
+    delimiter: &[u8],
     schema: &Schema,
     ignore_errors: bool,
     projection: &[usize],
     bytes_offset_thread: usize,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
     comment_char: Option<u8>,
     capacity: usize,
@@ -800,16 +915,20 @@ fn read_chunk(
     chunk_size: usize,
     stop_at_nbytes: usize,
     starting_point_offset: Option<usize>,
+    // if set, rows that cannot be parsed are removed from the output and quarantined here
+    rejected_records: Option<&mut Vec<RejectedRecord>>,
 ) -> PolarsResult<DataFrame> {
     let mut read = bytes_offset_thread;
+    let mut rejected = rejected_records.as_ref().map(|_| RejectedRows::default());
     let mut buffers = init_buffers(
         projection,
         capacity,
         schema,
         str_capacities,
         quote_char,
+        escape_char,
         encoding,
-        ignore_errors,
+        ignore_errors || rejected.is_some(),
     )?;
 
     let mut last_read = usize::MAX;
@@ -827,6 +946,7 @@ fn read_chunk(
             delimiter,
             comment_char,
             quote_char,
+            escape_char,
             eol_char,
             null_values,
             missing_is_null,
@@ -836,15 +956,24 @@ fn read_chunk(
             chunk_size,
             schema.len(),
             schema,
+            rejected.as_mut(),
         )?;
     }
 
-    Ok(DataFrame::new_no_checks(
+    let df = DataFrame::new_no_checks(
         buffers
             .into_iter()
             .map(|buf| buf.into_series())
             .collect::<PolarsResult<_>>()?,
-    ))
+    );
+    match (rejected, rejected_records) {
+        (Some(rejected), Some(rejected_records)) => {
+            let df = rejected.filter(df)?;
+            rejected_records.extend(rejected.into_records());
+            Ok(df)
+        }
+        _ => Ok(df),
+    }
 }
 
 /// List of strings, which are stored inside of a [Schema].
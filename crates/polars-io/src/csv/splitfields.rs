@@ -5,25 +5,34 @@ mod inner {
     pub(crate) struct SplitFields<'a> {
         v: &'a [u8],
         delimiter: u8,
-        finished: bool,
+        // the remaining bytes of a multi-byte delimiter
+        delimiter_tail: &'a [u8],
+        pub finished: bool,
         quote_char: u8,
         quoting: bool,
+        escape_char: Option<u8>,
         eol_char: u8,
     }
 
     impl<'a> SplitFields<'a> {
         pub(crate) fn new(
             slice: &'a [u8],
-            delimiter: u8,
+            delimiter: &'a [u8],
             quote_char: Option<u8>,
+            escape_char: Option<u8>,
             eol_char: u8,
         ) -> Self {
+            debug_assert!(!delimiter.is_empty());
+            let quote_char_ = quote_char.unwrap_or(b'"');
             Self {
                 v: slice,
-                delimiter,
+                delimiter: delimiter[0],
+                delimiter_tail: &delimiter[1..],
                 finished: false,
-                quote_char: quote_char.unwrap_or(b'"'),
+                quote_char: quote_char_,
                 quoting: quote_char.is_some(),
+                // an escape char equal to the quote char is the default `""` escaping
+                escape_char: escape_char.filter(|&e| e != quote_char_),
                 eol_char,
             }
         }
@@ -46,6 +55,19 @@ mod inner {
         fn eof_oel(&self, current_ch: u8) -> bool {
             current_ch == self.delimiter || current_ch == self.eol_char
         }
+
+        #[inline]
+        fn delimiter_len(&self) -> usize {
+            self.delimiter_tail.len() + 1
+        }
+
+        /// Check if a (multi-byte) delimiter starts at `idx`.
+        #[inline]
+        fn is_delimiter_at(&self, idx: usize) -> bool {
+            self.v.get(idx) == Some(&self.delimiter)
+                && (self.delimiter_tail.is_empty()
+                    || self.v[idx + 1..].starts_with(self.delimiter_tail))
+        }
     }
 
     impl<'a> Iterator for SplitFields<'a> {
@@ -74,11 +96,17 @@ mod inner {
                 // denotes if we are in a string field, started with a quote
                 let mut in_field = false;
 
-                let mut idx = 0u32;
-                let mut current_idx = 0u32;
-                // micro optimizations
-                #[allow(clippy::explicit_counter_loop)]
-                for &c in self.v.iter() {
+                let mut idx = 0usize;
+                let mut current_idx = 0usize;
+                while current_idx < self.v.len() {
+                    // safety
+                    // we are in bounds
+                    let c = unsafe { *self.v.get_unchecked(current_idx) };
+                    // an escaped byte can never close the field, nor end it
+                    if in_field && Some(c) == self.escape_char {
+                        current_idx += 2;
+                        continue;
+                    }
                     if c == self.quote_char {
                         // toggle between string field enclosure
                         //      if we encounter a starting '"' -> in_field = true;
@@ -90,12 +118,12 @@ mod inner {
                         if c == self.eol_char {
                             // safety
                             // we are in bounds
-                            return unsafe {
-                                self.finish_eol(needs_escaping, current_idx as usize)
-                            };
+                            return unsafe { self.finish_eol(needs_escaping, current_idx) };
+                        }
+                        if self.is_delimiter_at(current_idx) {
+                            idx = current_idx;
+                            break;
                         }
-                        idx = current_idx;
-                        break;
                     }
                     current_idx += 1;
                 }
@@ -104,19 +132,26 @@ mod inner {
                     return self.finish(needs_escaping);
                 }
 
-                idx as usize
+                idx
             } else {
-                match self.v.iter().position(|&c| self.eof_oel(c)) {
-                    None => return self.finish(needs_escaping),
-                    Some(idx) => unsafe {
-                        // Safety:
-                        // idx was just found
-                        if *self.v.get_unchecked(idx) == self.eol_char {
-                            return self.finish_eol(needs_escaping, idx);
-                        } else {
-                            idx
-                        }
-                    },
+                let mut start = 0;
+                loop {
+                    match self.v[start..].iter().position(|&c| self.eof_oel(c)) {
+                        None => return self.finish(needs_escaping),
+                        Some(idx) => unsafe {
+                            let idx = start + idx;
+                            // Safety:
+                            // idx was just found
+                            if *self.v.get_unchecked(idx) == self.eol_char {
+                                return self.finish_eol(needs_escaping, idx);
+                            } else if self.is_delimiter_at(idx) {
+                                break idx;
+                            } else {
+                                // only the first byte of a multi-byte delimiter matched
+                                start = idx + 1;
+                            }
+                        },
+                    }
                 }
             };
 
@@ -125,7 +160,7 @@ mod inner {
                 // safety
                 // we are in bounds
                 let ret = Some((self.v.get_unchecked(..pos), needs_escaping));
-                self.v = self.v.get_unchecked(pos + 1..);
+                self.v = self.v.get_unchecked(pos + self.delimiter_len()..);
                 ret
             }
         }
@@ -158,9 +193,12 @@ mod inner {
     pub(crate) struct SplitFields<'a> {
         pub v: &'a [u8],
         delimiter: u8,
+        // the remaining bytes of a multi-byte delimiter
+        delimiter_tail: &'a [u8],
         pub finished: bool,
         quote_char: u8,
         quoting: bool,
+        escape_char: Option<u8>,
         eol_char: u8,
         simd_delimiter: SimdVec,
         simd_eol_char: SimdVec,
@@ -169,19 +207,25 @@ mod inner {
     impl<'a> SplitFields<'a> {
         pub(crate) fn new(
             slice: &'a [u8],
-            delimiter: u8,
+            delimiter: &'a [u8],
             quote_char: Option<u8>,
+            escape_char: Option<u8>,
             eol_char: u8,
         ) -> Self {
-            let simd_delimiter = SimdVec::splat(delimiter);
+            debug_assert!(!delimiter.is_empty());
+            let simd_delimiter = SimdVec::splat(delimiter[0]);
             let simd_eol_char = SimdVec::splat(eol_char);
+            let quote_char_ = quote_char.unwrap_or(b'"');
 
             Self {
                 v: slice,
-                delimiter,
+                delimiter: delimiter[0],
+                delimiter_tail: &delimiter[1..],
                 finished: false,
-                quote_char: quote_char.unwrap_or(b'"'),
+                quote_char: quote_char_,
                 quoting: quote_char.is_some(),
+                // an escape char equal to the quote char is the default `""` escaping
+                escape_char: escape_char.filter(|&e| e != quote_char_),
                 eol_char,
                 simd_delimiter,
                 simd_eol_char,
@@ -206,6 +250,19 @@ mod inner {
         fn eof_oel(&self, current_ch: u8) -> bool {
             current_ch == self.delimiter || current_ch == self.eol_char
         }
+
+        #[inline]
+        fn delimiter_len(&self) -> usize {
+            self.delimiter_tail.len() + 1
+        }
+
+        /// Check if a (multi-byte) delimiter starts at `idx`.
+        #[inline]
+        fn is_delimiter_at(&self, idx: usize) -> bool {
+            self.v.get(idx) == Some(&self.delimiter)
+                && (self.delimiter_tail.is_empty()
+                    || self.v[idx + 1..].starts_with(self.delimiter_tail))
+        }
     }
 
     impl<'a> Iterator for SplitFields<'a> {
@@ -234,11 +291,17 @@ mod inner {
                 // denotes if we are in a string field, started with a quote
                 let mut in_field = false;
 
-                let mut idx = 0u32;
-                let mut current_idx = 0u32;
-                // micro optimizations
-                #[allow(clippy::explicit_counter_loop)]
-                for &c in self.v.iter() {
+                let mut idx = 0usize;
+                let mut current_idx = 0usize;
+                while current_idx < self.v.len() {
+                    // safety
+                    // we are in bounds
+                    let c = unsafe { *self.v.get_unchecked(current_idx) };
+                    // an escaped byte can never close the field, nor end it
+                    if in_field && Some(c) == self.escape_char {
+                        current_idx += 2;
+                        continue;
+                    }
                     if c == self.quote_char {
                         // toggle between string field enclosure
                         //      if we encounter a starting '"' -> in_field = true;
@@ -250,12 +313,12 @@ mod inner {
                         if c == self.eol_char {
                             // safety
                             // we are in bounds
-                            return unsafe {
-                                self.finish_eol(needs_escaping, current_idx as usize)
-                            };
+                            return unsafe { self.finish_eol(needs_escaping, current_idx) };
+                        }
+                        if self.is_delimiter_at(current_idx) {
+                            idx = current_idx;
+                            break;
                         }
-                        idx = current_idx;
-                        break;
                     }
                     current_idx += 1;
                 }
@@ -264,51 +327,57 @@ mod inner {
                     return self.finish(needs_escaping);
                 }
 
-                idx as usize
+                idx
             } else {
                 let mut total_idx = 0;
 
                 loop {
-                    let bytes = unsafe { self.v.get_unchecked_release(total_idx..) };
-
-                    if bytes.len() > SIMD_SIZE {
-                        unsafe {
-                            let lane: [u8; SIMD_SIZE] = bytes
-                                .get_unchecked(0..SIMD_SIZE)
-                                .try_into()
-                                .unwrap_unchecked_release();
-                            let simd_bytes = SimdVec::from(lane);
-                            let has_eol_char = simd_bytes.simd_eq(self.simd_eol_char);
-                            let has_delimiter = simd_bytes.simd_eq(self.simd_delimiter);
-                            let has_any = has_delimiter.bitor(has_eol_char);
-                            if has_any.any() {
-                                // soundness we can transmute because we have the same alignment
-                                let has_any = std::mem::transmute::<
-                                    Mask<_, SIMD_SIZE>,
-                                    [bool; SIMD_SIZE],
-                                >(has_any);
-                                total_idx += simple_argmax(&has_any);
-                                break;
-                            } else {
-                                total_idx += SIMD_SIZE;
+                    // find the next eol char or first byte of the delimiter
+                    loop {
+                        let bytes = unsafe { self.v.get_unchecked_release(total_idx..) };
+
+                        if bytes.len() > SIMD_SIZE {
+                            unsafe {
+                                let lane: [u8; SIMD_SIZE] = bytes
+                                    .get_unchecked(0..SIMD_SIZE)
+                                    .try_into()
+                                    .unwrap_unchecked_release();
+                                let simd_bytes = SimdVec::from(lane);
+                                let has_eol_char = simd_bytes.simd_eq(self.simd_eol_char);
+                                let has_delimiter = simd_bytes.simd_eq(self.simd_delimiter);
+                                let has_any = has_delimiter.bitor(has_eol_char);
+                                if has_any.any() {
+                                    // soundness we can transmute because we have the same alignment
+                                    let has_any = std::mem::transmute::<
+                                        Mask<_, SIMD_SIZE>,
+                                        [bool; SIMD_SIZE],
+                                    >(has_any);
+                                    total_idx += simple_argmax(&has_any);
+                                    break;
+                                } else {
+                                    total_idx += SIMD_SIZE;
+                                }
                             }
-                        }
-                    } else {
-                        match bytes.iter().position(|&c| self.eof_oel(c)) {
-                            None => return self.finish(needs_escaping),
-                            Some(idx) => {
-                                total_idx += idx;
-                                break;
+                        } else {
+                            match bytes.iter().position(|&c| self.eof_oel(c)) {
+                                None => return self.finish(needs_escaping),
+                                Some(idx) => {
+                                    total_idx += idx;
+                                    break;
+                                }
                             }
                         }
                     }
-                }
-                unsafe {
-                    if *self.v.get_unchecked_release(total_idx) == self.eol_char {
-                        return self.finish_eol(needs_escaping, total_idx);
-                    } else {
-                        total_idx
+                    unsafe {
+                        if *self.v.get_unchecked_release(total_idx) == self.eol_char {
+                            return self.finish_eol(needs_escaping, total_idx);
+                        }
+                    }
+                    if self.is_delimiter_at(total_idx) {
+                        break total_idx;
                     }
+                    // only the first byte of a multi-byte delimiter matched
+                    total_idx += 1;
                 }
             };
 
@@ -317,7 +386,7 @@ mod inner {
                 // safety
                 // we are in bounds
                 let ret = Some((self.v.get_unchecked(..pos), needs_escaping));
-                self.v = self.v.get_unchecked(pos + 1..);
+                self.v = self.v.get_unchecked(pos + self.delimiter_len()..);
                 ret
             }
         }
@@ -23,8 +23,9 @@ pub(crate) fn get_file_chunks(
     bytes: &[u8],
     n_chunks: usize,
     expected_fields: usize,
-    delimiter: u8,
+    delimiter: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) -> Vec<(usize, usize)> {
     let mut last_pos = 0;
@@ -43,6 +44,7 @@ pub(crate) fn get_file_chunks(
             Some(expected_fields),
             delimiter,
             quote_char,
+            escape_char,
             eol_char,
         ) {
             Some(pos) => search_pos + pos,
@@ -178,7 +180,7 @@ pub(crate) fn parse_bytes_with_encoding(
 #[allow(clippy::too_many_arguments)]
 pub fn infer_file_schema_inner(
     reader_bytes: &ReaderBytes,
-    delimiter: u8,
+    delimiter: &[u8],
     max_read_rows: Option<usize>,
     has_header: bool,
     schema_overwrite: Option<&Schema>,
@@ -188,6 +190,7 @@ pub fn infer_file_schema_inner(
     skip_rows_after_header: usize,
     comment_char: Option<u8>,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
     null_values: Option<&NullValues>,
     try_parse_dates: bool,
@@ -202,7 +205,8 @@ pub fn infer_file_schema_inner(
 
     let bytes = skip_line_ending(skip_bom(reader_bytes), eol_char);
     polars_ensure!(!bytes.is_empty(), NoData: "empty CSV");
-    let mut lines = SplitLines::new(bytes, quote_char.unwrap_or(b'"'), eol_char).skip(*skip_rows);
+    let mut lines =
+        SplitLines::new(bytes, quote_char.unwrap_or(b'"'), escape_char, eol_char).skip(*skip_rows);
     // it can be that we have a single line without eol char
     let has_eol = bytes.contains(&eol_char);
 
@@ -240,7 +244,8 @@ pub fn infer_file_schema_inner(
             }
         }
 
-        let byterecord = SplitFields::new(header_line, delimiter, quote_char, eol_char);
+        let byterecord =
+            SplitFields::new(header_line, delimiter, quote_char, escape_char, eol_char);
         if has_header {
             let headers = byterecord
                 .map(|(slice, needs_escaping)| {
@@ -275,7 +280,7 @@ pub fn infer_file_schema_inner(
                 .collect();
             // needed because SplitLines does not return the \n char, so SplitFields does not catch
             // the latest value if ending with a delimiter.
-            if header_line.ends_with(&[delimiter]) {
+            if header_line.ends_with(delimiter) {
                 column_names.push(format!("column_{}", column_names.len() + 1))
             }
             column_names
@@ -297,6 +302,7 @@ pub fn infer_file_schema_inner(
             skip_rows_after_header,
             comment_char,
             quote_char,
+            escape_char,
             eol_char,
             null_values,
             try_parse_dates,
@@ -307,7 +313,8 @@ pub fn infer_file_schema_inner(
     };
     if !has_header {
         // re-init lines so that the header is included in type inference.
-        lines = SplitLines::new(bytes, quote_char.unwrap_or(b'"'), eol_char).skip(*skip_rows);
+        lines = SplitLines::new(bytes, quote_char.unwrap_or(b'"'), escape_char, eol_char)
+            .skip(*skip_rows);
     }
 
     let header_length = headers.len();
@@ -360,7 +367,7 @@ pub fn infer_file_schema_inner(
             }
         }
 
-        let mut record = SplitFields::new(line, delimiter, quote_char, eol_char);
+        let mut record = SplitFields::new(line, delimiter, quote_char, escape_char, eol_char);
 
         for i in 0..header_length {
             if let Some((slice, needs_escaping)) = record.next() {
@@ -480,6 +487,7 @@ pub fn infer_file_schema_inner(
             skip_rows_after_header,
             comment_char,
             quote_char,
+            escape_char,
             eol_char,
             null_values,
             try_parse_dates,
@@ -502,7 +510,7 @@ pub fn infer_file_schema_inner(
 #[allow(clippy::too_many_arguments)]
 pub fn infer_file_schema(
     reader_bytes: &ReaderBytes,
-    delimiter: u8,
+    delimiter: &[u8],
     max_read_rows: Option<usize>,
     has_header: bool,
     schema_overwrite: Option<&Schema>,
@@ -512,6 +520,7 @@ pub fn infer_file_schema(
     skip_rows_after_header: usize,
     comment_char: Option<u8>,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
     null_values: Option<&NullValues>,
     try_parse_dates: bool,
@@ -526,6 +535,7 @@ pub fn infer_file_schema(
         skip_rows_after_header,
         comment_char,
         quote_char,
+        escape_char,
         eol_char,
         null_values,
         try_parse_dates,
@@ -551,8 +561,9 @@ pub fn is_compressed(bytes: &[u8]) -> bool {
 fn decompress_impl<R: Read>(
     decoder: &mut R,
     n_rows: Option<usize>,
-    delimiter: u8,
+    delimiter: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
     let chunk_size = 4096;
@@ -583,7 +594,8 @@ fn decompress_impl<R: Read>(
                     }
                     // now that we have enough, we compute the number of fields (also takes embedding into account)
                     expected_fields =
-                        SplitFields::new(&out, delimiter, quote_char, eol_char).count();
+                        SplitFields::new(&out, delimiter, quote_char, escape_char, eol_char)
+                            .count();
                     break;
                 }
             }
@@ -598,6 +610,7 @@ fn decompress_impl<R: Read>(
                     Some(expected_fields),
                     delimiter,
                     quote_char,
+                    escape_char,
                     eol_char,
                 ) {
                     Some(pos) => {
@@ -624,22 +637,37 @@ fn decompress_impl<R: Read>(
 pub(crate) fn decompress(
     bytes: &[u8],
     n_rows: Option<usize>,
-    delimiter: u8,
+    delimiter: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
 ) -> Option<Vec<u8>> {
     if bytes.starts_with(&GZIP) {
         let mut decoder = flate2::read::MultiGzDecoder::new(bytes);
-        decompress_impl(&mut decoder, n_rows, delimiter, quote_char, eol_char)
+        decompress_impl(
+            &mut decoder,
+            n_rows,
+            delimiter,
+            quote_char,
+            escape_char,
+            eol_char,
+        )
     } else if bytes.starts_with(&ZLIB0) || bytes.starts_with(&ZLIB1) || bytes.starts_with(&ZLIB2) {
         let mut decoder = flate2::read::ZlibDecoder::new(bytes);
-        decompress_impl(&mut decoder, n_rows, delimiter, quote_char, eol_char)
+        decompress_impl(
+            &mut decoder,
+            n_rows,
+            delimiter,
+            quote_char,
+            escape_char,
+            eol_char,
+        )
     } else {
         None
     }
 }
 
-/// replace double quotes by single ones
+/// replace double quotes by single ones and drop the escape characters
 ///
 /// This function assumes that bytes is wrapped in the quoting character.
 ///
@@ -648,12 +676,26 @@ pub(crate) fn decompress(
 /// The caller must ensure that:
 ///     - Output buffer must have enough capacity to hold `bytes.len()`
 ///     - bytes ends with the quote character e.g.: `"`
-pub(super) unsafe fn escape_field(bytes: &[u8], quote: u8, buf: &mut [MaybeUninit<u8>]) -> usize {
+pub(super) unsafe fn escape_field(
+    bytes: &[u8],
+    quote: u8,
+    escape: Option<u8>,
+    buf: &mut [MaybeUninit<u8>],
+) -> usize {
+    let escape = escape.filter(|&e| e != quote);
     let mut prev_quote = false;
+    let mut prev_escape = false;
 
     let mut count = 0;
     for c in bytes.get_unchecked(1..bytes.len() - 1) {
-        if *c == quote {
+        if prev_escape {
+            prev_escape = false;
+            buf.get_unchecked_mut(count).write(*c);
+            count += 1;
+        } else if Some(*c) == escape {
+            prev_quote = false;
+            prev_escape = true;
+        } else if *c == quote {
             if prev_quote {
                 prev_quote = false;
                 buf.get_unchecked_mut(count).write(*c);
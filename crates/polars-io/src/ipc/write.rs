@@ -109,6 +109,11 @@ impl<W: Write> BatchedWriter<W> {
         self.writer.finish()?;
         Ok(())
     }
+
+    /// Return the underlying writer, call [`BatchedWriter::finish`] first.
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner()
+    }
 }
 
 /// Compression codec
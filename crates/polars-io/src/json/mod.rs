@@ -170,6 +170,7 @@ where
     schema: Option<SchemaRef>,
     schema_overwrite: Option<&'a Schema>,
     json_format: JsonFormat,
+    bad_records: Option<BadRecords>,
 }
 
 impl<'a, R> SerReader<R> for JsonReader<'a, R>
@@ -187,6 +188,7 @@ where
             schema: None,
             schema_overwrite: None,
             json_format: JsonFormat::Json,
+            bad_records: None,
         }
     }
 
@@ -206,6 +208,7 @@ where
         let out = match self.json_format {
             JsonFormat::Json => {
                 polars_ensure!(!self.ignore_errors, InvalidOperation: "'ignore_errors' only supported in ndjson");
+                polars_ensure!(self.bad_records.is_none(), InvalidOperation: "'bad_records' only supported in ndjson");
                 let mut bytes = rb.deref().to_vec();
                 let json_value =
                     simd_json::to_borrowed_value(&mut bytes).map_err(to_compute_err)?;
@@ -280,6 +283,7 @@ where
                     false,
                     self.infer_schema_len,
                     self.ignore_errors,
+                    self.bad_records,
                 )?;
                 let mut df: DataFrame = json_reader.as_df()?;
                 if self.rechunk {
@@ -355,4 +359,12 @@ where
         self.ignore_errors = ignore;
         self
     }
+
+    /// Remove the lines that cannot be parsed from the output and hand them to `bad_records`,
+    /// together with their line number and the parse error. Only supported for
+    /// [`JsonFormat::JsonLines`].
+    pub fn with_bad_records(mut self, bad_records: Option<BadRecords>) -> Self {
+        self.bad_records = bad_records;
+        self
+    }
 }
@@ -4,6 +4,8 @@
 
 #[cfg(feature = "avro")]
 pub mod avro;
+#[cfg(any(feature = "csv", feature = "json"))]
+pub mod bad_records;
 #[cfg(feature = "cloud")]
 mod cloud;
 #[cfg(any(feature = "csv", feature = "json"))]
@@ -18,6 +20,8 @@ pub mod json;
 pub mod ndjson;
 #[cfg(feature = "cloud")]
 pub use crate::cloud::glob as async_glob;
+#[cfg(feature = "cloud")]
+pub use crate::cloud::{CloudFile, CloudWriter};
 
 #[cfg(any(
     feature = "csv",
@@ -171,7 +175,38 @@ pub(crate) fn finish_reader<R: ArrowReader>(
 
 /// Check if the path is a cloud url.
 pub fn is_cloud_url<P: AsRef<Path>>(p: P) -> bool {
-    p.as_ref().starts_with("s3://")
-        || p.as_ref().starts_with("file://")
-        || p.as_ref().starts_with("gcs://")
+    [
+        "s3://", "file://", "gs://", "gcs://", "gcp://", "az://", "adl://", "abfs://",
+    ]
+    .iter()
+    .any(|scheme| p.as_ref().starts_with(scheme))
+}
+
+/// Open a local file, or an object in cloud storage if `path` is a cloud url.
+///
+/// Cloud objects are read with range requests, see [`CloudFile`].
+#[cfg(any(feature = "csv", feature = "ipc", feature = "json"))]
+pub fn open_file_or_cloud(
+    path: &Path,
+    cloud_options: Option<&polars_core::cloud::CloudOptions>,
+) -> PolarsResult<Box<dyn mmap::MmapBytesReader>> {
+    if is_cloud_url(path) {
+        #[cfg(feature = "cloud")]
+        {
+            let uri = path.to_string_lossy();
+            Ok(Box::new(CloudFile::open(&uri, cloud_options)?))
+        }
+        #[cfg(not(feature = "cloud"))]
+        {
+            let _ = cloud_options;
+            polars_bail!(
+                ComputeError:
+                "one or more of the cloud storage features ('aws', 'gcp', ...) must be enabled \
+                in order to read cloud urls"
+            )
+        }
+    } else {
+        let path = utils::resolve_homedir(path);
+        Ok(Box::new(polars_utils::open_file(&path)?))
+    }
 }
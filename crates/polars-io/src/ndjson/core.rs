@@ -10,6 +10,7 @@ use polars_core::utils::accumulate_dataframes_vertical;
 use polars_core::POOL;
 use rayon::prelude::*;
 
+use crate::bad_records::{BadRecords, LineCounter, RejectedRows};
 use crate::csv::utils::*;
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::ndjson::buffer::*;
@@ -34,6 +35,7 @@ where
     path: Option<PathBuf>,
     low_memory: bool,
     ignore_errors: bool,
+    bad_records: Option<BadRecords>,
 }
 
 impl<'a, R> JsonLineReader<'a, R>
@@ -86,6 +88,13 @@ where
         self.low_memory = toggle;
         self
     }
+
+    /// Remove the lines that cannot be parsed from the output and hand them to `bad_records`,
+    /// together with their line number and the parse error.
+    pub fn with_bad_records(mut self, bad_records: Option<BadRecords>) -> Self {
+        self.bad_records = bad_records;
+        self
+    }
 }
 
 impl<'a> JsonLineReader<'a, File> {
@@ -114,6 +123,7 @@ where
             chunk_size: 1 << 18,
             low_memory: false,
             ignore_errors: false,
+            bad_records: None,
         }
     }
     fn finish(mut self) -> PolarsResult<DataFrame> {
@@ -130,6 +140,7 @@ where
             self.low_memory,
             self.infer_schema_len,
             self.ignore_errors,
+            self.bad_records,
         )?;
 
         let mut df: DataFrame = json_reader.as_df()?;
@@ -149,6 +160,7 @@ pub(crate) struct CoreJsonReader<'a> {
     chunk_size: usize,
     low_memory: bool,
     ignore_errors: bool,
+    bad_records: Option<BadRecords>,
 }
 impl<'a> CoreJsonReader<'a> {
     #[allow(clippy::too_many_arguments)]
@@ -163,6 +175,7 @@ impl<'a> CoreJsonReader<'a> {
         low_memory: bool,
         infer_schema_len: Option<usize>,
         ignore_errors: bool,
+        bad_records: Option<BadRecords>,
     ) -> PolarsResult<CoreJsonReader<'a>> {
         let reader_bytes = reader_bytes;
 
@@ -192,6 +205,7 @@ impl<'a> CoreJsonReader<'a> {
             chunk_size,
             low_memory,
             ignore_errors,
+            bad_records,
         })
     }
     fn parse_json(&mut self, mut n_threads: usize, bytes: &[u8]) -> PolarsResult<DataFrame> {
@@ -232,17 +246,49 @@ impl<'a> CoreJsonReader<'a> {
             file_chunks
                 .into_par_iter()
                 .map(|(start_pos, stop_at_nbytes)| {
-                    let mut buffers = init_buffers(&self.schema, capacity, self.ignore_errors)?;
-                    parse_lines(&bytes[start_pos..stop_at_nbytes], &mut buffers)?;
-                    DataFrame::new(
-                        buffers
-                            .into_values()
-                            .map(|buf| buf.into_series())
-                            .collect::<_>(),
-                    )
+                    let local_bytes = &bytes[start_pos..stop_at_nbytes];
+                    match &self.bad_records {
+                        None => {
+                            let mut buffers =
+                                init_buffers(&self.schema, capacity, self.ignore_errors)?;
+                            parse_lines(local_bytes, &mut buffers)?;
+                            let df = DataFrame::new(
+                                buffers
+                                    .into_values()
+                                    .map(|buf| buf.into_series())
+                                    .collect::<_>(),
+                            )?;
+                            Ok((df, vec![]))
+                        }
+                        Some(_) => {
+                            // quarantined lines are never ignored
+                            let mut buffers = init_buffers(&self.schema, capacity, false)?;
+                            let mut rejected = RejectedRows::default();
+                            parse_lines_quarantined(
+                                local_bytes,
+                                start_pos,
+                                &mut buffers,
+                                &mut rejected,
+                            );
+                            let df = DataFrame::new(
+                                buffers
+                                    .into_values()
+                                    .map(|buf| buf.into_series())
+                                    .collect::<_>(),
+                            )?;
+                            Ok((rejected.filter(df)?, rejected.into_records()))
+                        }
+                    }
                 })
                 .collect::<PolarsResult<Vec<_>>>()
         })?;
+        let (dfs, rejected_records): (Vec<_>, Vec<_>) = dfs.into_iter().unzip();
+        if let Some(bad_records) = &self.bad_records {
+            let mut lines = LineCounter::new(1, NEWLINE);
+            bad_records.push(rejected_records.into_iter().flatten().collect(), |offset| {
+                lines.line_number_at(bytes, offset)
+            })?;
+        }
         accumulate_dataframes_vertical(dfs)
     }
     pub fn as_df(&mut self) -> PolarsResult<DataFrame> {
@@ -321,6 +367,80 @@ fn parse_lines(bytes: &[u8], buffers: &mut PlIndexMap<BufferKey, Buffer>) -> Pol
     Ok(())
 }
 
+/// Parse the lines in `bytes` one by one. A line that cannot be parsed is recorded in `rejected`
+/// instead of raising, its row in the buffers is padded with nulls.
+///
+/// `offset` is the offset of `bytes` in the total input.
+fn parse_lines_quarantined(
+    bytes: &[u8],
+    offset: usize,
+    buffers: &mut PlIndexMap<BufferKey, Buffer>,
+    rejected: &mut RejectedRows,
+) {
+    let mut scratch = vec![];
+    let mut line_offset = offset;
+    for line in bytes.split(|&b| b == NEWLINE) {
+        let current_offset = line_offset;
+        line_offset += line.len() + 1;
+        let line = match line {
+            [rest @ .., RETURN] => rest,
+            _ => line,
+        };
+        if line.iter().all(|b| b.is_ascii_whitespace()) {
+            continue;
+        }
+
+        scratch.clear();
+        scratch.extend_from_slice(line);
+        let mut error = None;
+        match simd_json::to_borrowed_value(&mut scratch) {
+            Ok(simd_json::BorrowedValue::Object(value)) => {
+                for (s, inner) in buffers.iter_mut() {
+                    let result = match s.0.map_lookup(&value) {
+                        Some(v) => inner.add(v),
+                        None => {
+                            inner.add_null();
+                            Ok(())
+                        }
+                    };
+                    if let Err(e) = result {
+                        // keep the buffers aligned, the row is filtered out later
+                        inner.add_null();
+                        error.get_or_insert_with(|| e.to_string());
+                    }
+                }
+            }
+            Ok(value) => {
+                // a line that is valid JSON, but not an object, is not a record
+                buffers.iter_mut().for_each(|(_, inner)| inner.add_null());
+                error = Some(format!(
+                    "expected a JSON object, got a {}",
+                    json_value_kind(&value)
+                ));
+            }
+            Err(e) => {
+                buffers.iter_mut().for_each(|(_, inner)| inner.add_null());
+                error = Some(format!("error parsing line: {}", e));
+            }
+        }
+        match error {
+            None => rejected.accept(),
+            Some(error) => rejected.reject(current_offset, line, error),
+        }
+    }
+}
+
+fn json_value_kind(value: &simd_json::BorrowedValue) -> &'static str {
+    match value {
+        simd_json::BorrowedValue::Static(simd_json::StaticNode::Null) => "null",
+        simd_json::BorrowedValue::Static(simd_json::StaticNode::Bool(_)) => "boolean",
+        simd_json::BorrowedValue::Static(_) => "number",
+        simd_json::BorrowedValue::String(_) => "string",
+        simd_json::BorrowedValue::Array(_) => "array",
+        simd_json::BorrowedValue::Object(_) => "object",
+    }
+}
+
 /// Find the nearest next line position.
 /// Does not check for new line characters embedded in String fields.
 /// This just looks for `}\n`
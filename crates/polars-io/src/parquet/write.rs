@@ -271,6 +271,11 @@ impl<W: Write> BatchedWriter<W> {
         let size = self.writer.end(None)?;
         Ok(size)
     }
+
+    /// Return the underlying writer, call [`BatchedWriter::finish`] first.
+    pub fn into_inner(self) -> W {
+        self.writer.into_inner()
+    }
 }
 
 fn create_serializer<'a>(
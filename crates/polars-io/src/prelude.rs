@@ -1,6 +1,8 @@
 #[cfg(test)]
 use polars_core::prelude::*;
 
+#[cfg(any(feature = "csv", feature = "json"))]
+pub use crate::bad_records::BadRecords;
 #[cfg(feature = "csv")]
 pub use crate::csv::*;
 #[cfg(any(feature = "ipc", feature = "ipc_streaming"))]
@@ -36,6 +36,8 @@ pub mod functions;
 mod into;
 #[cfg(feature = "list_eval")]
 mod list;
+#[cfg(feature = "dtype-struct")]
+mod struct_;
 
 #[cfg(any(feature = "cumulative_eval", feature = "list_eval"))]
 pub use eval::*;
@@ -46,3 +48,5 @@ use into::IntoExpr;
 pub use list::*;
 pub use polars_plan::dsl::*;
 pub use polars_plan::logical_plan::UdfSchema;
+#[cfg(feature = "dtype-struct")]
+pub use struct_::*;
@@ -0,0 +1,40 @@
+use polars_core::prelude::*;
+use polars_plan::dsl::*;
+
+use crate::prelude::*;
+
+pub trait StructNameSpaceExtension: Sized {
+    /// Add or replace fields of the struct. The expressions are evaluated with the fields of the
+    /// struct as columns, e.g. `col("a")` refers to the field `a`.
+    fn with_fields(self, exprs: Vec<Expr>) -> Expr;
+}
+
+impl StructNameSpaceExtension for StructNameSpace {
+    fn with_fields(self, exprs: Vec<Expr>) -> Expr {
+        let exprs2 = exprs.clone();
+        let func = move |s: Series| {
+            let ca = s.struct_()?;
+            let df = DataFrame::new_no_checks(ca.fields().to_vec());
+            let out = df.lazy().with_columns(exprs.clone()).collect()?;
+            StructChunked::new(s.name(), out.get_columns()).map(|ca| Some(ca.into_series()))
+        };
+
+        self.0
+            .map(
+                func,
+                GetOutput::try_map_dtype(move |dt| match dt {
+                    DataType::Struct(fields) => {
+                        let input_schema = Schema::from_iter(fields.iter().cloned());
+                        let mut schema = input_schema.clone();
+                        for e in &exprs2 {
+                            let fld = e.to_field(&input_schema, Context::Default)?;
+                            schema.with_column(fld.name, fld.dtype);
+                        }
+                        Ok(DataType::Struct(schema.iter_fields().collect()))
+                    }
+                    dt => Ok(dt.clone()),
+                }),
+            )
+            .with_fmt("struct.with_fields")
+    }
+}
@@ -0,0 +1,81 @@
+use std::path::{Path, PathBuf};
+
+use polars_core::prelude::*;
+use polars_io::RowCount;
+
+use crate::prelude::*;
+
+#[derive(Clone)]
+pub struct ScanArgsAvro {
+    pub n_rows: Option<usize>,
+    pub rechunk: bool,
+    pub row_count: Option<RowCount>,
+}
+
+impl Default for ScanArgsAvro {
+    fn default() -> Self {
+        Self {
+            n_rows: None,
+            rechunk: true,
+            row_count: None,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct LazyAvroReader {
+    pub(crate) args: ScanArgsAvro,
+    pub(crate) path: PathBuf,
+}
+
+impl LazyAvroReader {
+    fn new(path: PathBuf, args: ScanArgsAvro) -> Self {
+        Self { args, path }
+    }
+}
+
+impl LazyFileListReader for LazyAvroReader {
+    fn finish_no_glob(self) -> PolarsResult<LazyFrame> {
+        let options = ScanArgsAnonymous {
+            name: "AVRO SCAN",
+            n_rows: self.args.n_rows,
+            row_count: self.args.row_count.clone(),
+            ..ScanArgsAnonymous::default()
+        };
+
+        LazyFrame::anonymous_scan(Arc::new(self), options)
+    }
+
+    fn path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    fn with_path(mut self, path: PathBuf) -> Self {
+        self.path = path;
+        self
+    }
+
+    fn rechunk(&self) -> bool {
+        self.args.rechunk
+    }
+
+    fn with_rechunk(mut self, toggle: bool) -> Self {
+        self.args.rechunk = toggle;
+        self
+    }
+
+    fn n_rows(&self) -> Option<usize> {
+        self.args.n_rows
+    }
+
+    fn row_count(&self) -> Option<&RowCount> {
+        self.args.row_count.as_ref()
+    }
+}
+
+impl LazyFrame {
+    /// Create a LazyFrame directly from an avro scan.
+    pub fn scan_avro(path: impl AsRef<Path>, args: ScanArgsAvro) -> PolarsResult<Self> {
+        LazyAvroReader::new(path.as_ref().to_owned(), args).finish()
+    }
+}
@@ -1,6 +1,8 @@
 use std::path::{Path, PathBuf};
 
+use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
+use polars_io::bad_records::BadRecords;
 use polars_io::csv::utils::{get_reader_bytes, infer_file_schema};
 use polars_io::csv::{CsvEncoding, NullValues};
 use polars_io::RowCount;
@@ -12,7 +14,7 @@ use crate::prelude::*;
 #[cfg(feature = "csv")]
 pub struct LazyCsvReader<'a> {
     path: PathBuf,
-    delimiter: u8,
+    delimiter: Vec<u8>,
     has_header: bool,
     ignore_errors: bool,
     skip_rows: usize,
@@ -23,6 +25,7 @@ pub struct LazyCsvReader<'a> {
     low_memory: bool,
     comment_char: Option<u8>,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     eol_char: u8,
     null_values: Option<NullValues>,
     missing_is_null: bool,
@@ -32,6 +35,9 @@ pub struct LazyCsvReader<'a> {
     encoding: CsvEncoding,
     row_count: Option<RowCount>,
     try_parse_dates: bool,
+    strict: bool,
+    bad_records: Option<BadRecords>,
+    cloud_options: Option<CloudOptions>,
 }
 
 #[cfg(feature = "csv")]
@@ -39,7 +45,7 @@ impl<'a> LazyCsvReader<'a> {
     pub fn new(path: impl AsRef<Path>) -> Self {
         LazyCsvReader {
             path: path.as_ref().to_owned(),
-            delimiter: b',',
+            delimiter: vec![b','],
             has_header: true,
             ignore_errors: false,
             skip_rows: 0,
@@ -50,6 +56,7 @@ impl<'a> LazyCsvReader<'a> {
             low_memory: false,
             comment_char: None,
             quote_char: Some(b'"'),
+            escape_char: None,
             eol_char: b'\n',
             null_values: None,
             missing_is_null: true,
@@ -59,6 +66,9 @@ impl<'a> LazyCsvReader<'a> {
             encoding: CsvEncoding::Utf8,
             row_count: None,
             try_parse_dates: false,
+            strict: false,
+            bad_records: None,
+            cloud_options: None,
         }
     }
 
@@ -132,7 +142,14 @@ impl<'a> LazyCsvReader<'a> {
     /// Set the CSV file's column delimiter as a byte character
     #[must_use]
     pub fn with_delimiter(mut self, delimiter: u8) -> Self {
-        self.delimiter = delimiter;
+        self.delimiter = vec![delimiter];
+        self
+    }
+
+    /// Set the CSV file's column delimiter as a sequence of bytes, e.g. `b"||"`.
+    #[must_use]
+    pub fn with_multi_byte_delimiter(mut self, delimiter: &[u8]) -> Self {
+        self.delimiter = delimiter.to_vec();
         self
     }
 
@@ -150,6 +167,37 @@ impl<'a> LazyCsvReader<'a> {
         self
     }
 
+    /// Set the `char` used to escape the next character in a quoted field. The default is `[None]`,
+    /// in which case quotes are escaped by doubling them.
+    #[must_use]
+    pub fn with_escape_char(mut self, escape: Option<u8>) -> Self {
+        self.escape_char = escape;
+        self
+    }
+
+    /// Raise on records that don't conform to RFC 4180, reporting their line number and byte offset.
+    #[must_use]
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Remove the rows with fields that cannot be parsed from the output and hand them to
+    /// `bad_records`, together with their line number and the parse error. The records are
+    /// available once the query has been collected.
+    #[must_use]
+    pub fn with_bad_records(mut self, bad_records: Option<BadRecords>) -> Self {
+        self.bad_records = bad_records;
+        self
+    }
+
+    /// Set the [`CloudOptions`] used to list and read files if the path is a cloud url.
+    #[must_use]
+    pub fn with_cloud_options(mut self, cloud_options: Option<CloudOptions>) -> Self {
+        self.cloud_options = cloud_options;
+        self
+    }
+
     /// Set the `char` used as end of line. The default is `b'\n'`.
     #[must_use]
     pub fn with_end_of_line_char(mut self, eol_char: u8) -> Self {
@@ -205,21 +253,22 @@ impl<'a> LazyCsvReader<'a> {
     where
         F: Fn(Schema) -> PolarsResult<Schema>,
     {
+        let cloud_options = self.cloud_options.as_ref();
         let mut file = if let Some(mut paths) = self.glob()? {
             let path = match paths.next() {
                 Some(globresult) => globresult?,
                 None => polars_bail!(ComputeError: "globbing pattern did not match any files"),
             };
-            polars_utils::open_file(&path)
+            polars_io::open_file_or_cloud(&path, cloud_options)
         } else {
-            polars_utils::open_file(&self.path)
+            polars_io::open_file_or_cloud(&self.path, cloud_options)
         }?;
-        let reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
+        let reader_bytes = get_reader_bytes(&mut file)?;
         let mut skip_rows = self.skip_rows;
 
         let (schema, _, _) = infer_file_schema(
             &reader_bytes,
-            self.delimiter,
+            &self.delimiter,
             self.infer_schema_length,
             self.has_header,
             // we set it to None and modify them after the schema is updated
@@ -228,6 +277,7 @@ impl<'a> LazyCsvReader<'a> {
             self.skip_rows_after_header,
             self.comment_char,
             self.quote_char,
+            self.escape_char,
             self.eol_char,
             None,
             self.try_parse_dates,
@@ -260,6 +310,7 @@ impl LazyFileListReader for LazyCsvReader<'_> {
             self.low_memory,
             self.comment_char,
             self.quote_char,
+            self.escape_char,
             self.eol_char,
             self.null_values,
             self.infer_schema_length,
@@ -268,6 +319,9 @@ impl LazyFileListReader for LazyCsvReader<'_> {
             self.encoding,
             self.row_count,
             self.try_parse_dates,
+            self.strict,
+            self.bad_records,
+            self.cloud_options,
         )?
         .build()
         .into();
@@ -306,6 +360,10 @@ impl LazyFileListReader for LazyCsvReader<'_> {
         self.row_count.as_ref()
     }
 
+    fn cloud_options(&self) -> Option<&CloudOptions> {
+        self.cloud_options.as_ref()
+    }
+
     fn concat_impl(&self, lfs: Vec<LazyFrame>) -> PolarsResult<LazyFrame> {
         // set to false, as the csv parser has full thread utilization
         concat_impl(&lfs, self.rechunk(), false, true, false)
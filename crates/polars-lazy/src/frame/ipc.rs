@@ -1,5 +1,6 @@
 use std::path::{Path, PathBuf};
 
+use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
 use polars_io::RowCount;
 
@@ -12,6 +13,7 @@ pub struct ScanArgsIpc {
     pub rechunk: bool,
     pub row_count: Option<RowCount>,
     pub memmap: bool,
+    pub cloud_options: Option<CloudOptions>,
 }
 
 impl Default for ScanArgsIpc {
@@ -22,6 +24,7 @@ impl Default for ScanArgsIpc {
             rechunk: true,
             row_count: None,
             memmap: true,
+            cloud_options: None,
         }
     }
 }
@@ -53,6 +56,7 @@ impl LazyFileListReader for LazyIpcReader {
             args.cache,
             args.row_count.clone(),
             args.rechunk,
+            args.cloud_options,
         )?
         .build()
         .into();
@@ -91,6 +95,10 @@ impl LazyFileListReader for LazyIpcReader {
     fn row_count(&self) -> Option<&RowCount> {
         self.args.row_count.as_ref()
     }
+
+    fn cloud_options(&self) -> Option<&CloudOptions> {
+        self.args.cloud_options.as_ref()
+    }
 }
 
 impl LazyFrame {
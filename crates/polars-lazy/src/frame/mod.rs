@@ -1,4 +1,6 @@
 //! Lazy variant of a [DataFrame](polars_core::frame::DataFrame).
+#[cfg(feature = "avro")]
+mod avro;
 #[cfg(feature = "csv")]
 mod csv;
 #[cfg(feature = "ipc")]
@@ -17,11 +19,13 @@ mod file_list_reader;
 pub mod pivot;
 
 use std::borrow::Cow;
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
 use std::path::PathBuf;
 use std::sync::Arc;
 
 pub use anonymous_scan::*;
+#[cfg(feature = "avro")]
+pub use avro::*;
 #[cfg(feature = "csv")]
 pub use csv::*;
 pub use file_list_reader::*;
@@ -32,6 +36,8 @@ pub use ndjson::*;
 #[cfg(feature = "parquet")]
 pub use parquet::*;
 use polars_arrow::prelude::QuantileInterpolOptions;
+#[cfg(feature = "async")]
+use polars_core::cloud::CloudOptions;
 use polars_core::frame::explode::MeltArgs;
 use polars_core::frame::hash_join::{JoinType, JoinValidation};
 use polars_core::prelude::*;
@@ -67,6 +73,18 @@ impl IntoLazy for DataFrame {
     }
 }
 
+/// How nearness of vectors is measured by [`LazyFrame::top_k_nearest`].
+#[cfg(feature = "dtype-array")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum VectorMetric {
+    /// Smallest euclidean distance.
+    Euclidean,
+    /// Largest cosine similarity.
+    Cosine,
+    /// Largest dot product.
+    Dot,
+}
+
 /// Lazy abstraction over an eager `DataFrame`.
 /// It really is an abstraction over a logical plan. The methods of this struct will incrementally
 /// modify a logical plan until output is requested (via [collect](crate::frame::LazyFrame::collect))
@@ -316,6 +334,35 @@ impl LazyFrame {
             .slice(0, k)
     }
 
+    /// Get the `k` rows of which the vectors in the `Array` column `column` are nearest to
+    /// `query`, nearest first. Rows with null vectors are ranked last.
+    #[cfg(feature = "dtype-array")]
+    pub fn top_k_nearest(
+        self,
+        column: &str,
+        query: &[f64],
+        k: IdxSize,
+        metric: VectorMetric,
+    ) -> PolarsResult<Self> {
+        let query = Series::new("", &[Series::new("", query)])
+            .cast(&DataType::Array(Box::new(DataType::Float64), query.len()))?;
+        let query = lit(query);
+        let out = match metric {
+            VectorMetric::Euclidean => {
+                let distance = (col(column) - query).arr().l2_norm();
+                self.bottom_k(k, [distance], [false], true, false)
+            }
+            VectorMetric::Cosine => {
+                let similarity = col(column).arr().cosine_similarity(query);
+                self.top_k(k, [similarity], [false], true, false)
+            }
+            VectorMetric::Dot => {
+                self.top_k(k, [col(column).arr().dot(query)], [false], true, false)
+            }
+        };
+        Ok(out)
+    }
+
     /// Reverse the DataFrame
     ///
     /// # Example
@@ -613,43 +660,97 @@ impl LazyFrame {
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
     #[cfg(feature = "parquet")]
-    pub fn sink_parquet(mut self, path: PathBuf, options: ParquetWriteOptions) -> PolarsResult<()> {
-        self.opt_state.streaming = true;
-        self.logical_plan = LogicalPlan::FileSink {
-            input: Box::new(self.logical_plan),
-            payload: FileSinkOptions {
+    pub fn sink_parquet(self, path: PathBuf, options: ParquetWriteOptions) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
                 path: Arc::new(path),
                 file_type: FileType::Parquet(options),
+                cloud_options: None,
             },
-        };
-        let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true)?;
-        polars_ensure!(
-            is_streaming,
-            ComputeError: "cannot run the whole query in a streaming order; \
-            use `collect().write_parquet()` instead"
-        );
-        let _ = physical_plan.execute(&mut state)?;
-        Ok(())
+            "collect().write_parquet()",
+        )
+    }
+
+    /// Stream a query result into a parquet file in cloud storage with a multipart upload,
+    /// see [`LazyFrame::sink_parquet`].
+    #[cfg(all(feature = "parquet", feature = "async"))]
+    pub fn sink_parquet_cloud(
+        self,
+        uri: String,
+        cloud_options: Option<CloudOptions>,
+        options: ParquetWriteOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
+                path: Arc::new(PathBuf::from(uri)),
+                file_type: FileType::Parquet(options),
+                cloud_options,
+            },
+            "collect().write_parquet()",
+        )
     }
 
     /// Stream a query result into an ipc/arrow file. This is useful if the final result doesn't fit
     /// into memory. This methods will return an error if the query cannot be completely done in a
     /// streaming fashion.
     #[cfg(feature = "ipc")]
-    pub fn sink_ipc(mut self, path: PathBuf, options: IpcWriterOptions) -> PolarsResult<()> {
-        self.opt_state.streaming = true;
-        self.logical_plan = LogicalPlan::FileSink {
-            input: Box::new(self.logical_plan),
-            payload: FileSinkOptions {
+    pub fn sink_ipc(self, path: PathBuf, options: IpcWriterOptions) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
                 path: Arc::new(path),
                 file_type: FileType::Ipc(options),
+                cloud_options: None,
             },
+            "collect().write_ipc()",
+        )
+    }
+
+    /// Stream a query result into an ipc/arrow file in cloud storage with a multipart upload,
+    /// see [`LazyFrame::sink_ipc`].
+    #[cfg(all(feature = "ipc", feature = "async"))]
+    pub fn sink_ipc_cloud(
+        self,
+        uri: String,
+        cloud_options: Option<CloudOptions>,
+        options: IpcWriterOptions,
+    ) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
+                path: Arc::new(PathBuf::from(uri)),
+                file_type: FileType::Ipc(options),
+                cloud_options,
+            },
+            "collect().write_ipc()",
+        )
+    }
+
+    /// Stream a query result into an avro file. This is useful if the final result doesn't fit
+    /// into memory. This methods will return an error if the query cannot be completely done in a
+    /// streaming fashion.
+    #[cfg(feature = "avro")]
+    pub fn sink_avro(self, path: PathBuf, options: AvroWriterOptions) -> PolarsResult<()> {
+        self.sink(
+            FileSinkOptions {
+                path: Arc::new(path),
+                file_type: FileType::Avro(options),
+                cloud_options: None,
+            },
+            "collect().write_avro()",
+        )
+    }
+
+    #[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
+    fn sink(mut self, payload: FileSinkOptions, msg_alternative: &str) -> PolarsResult<()> {
+        self.opt_state.streaming = true;
+        self.logical_plan = LogicalPlan::FileSink {
+            input: Box::new(self.logical_plan),
+            payload,
         };
         let (mut state, mut physical_plan, is_streaming) = self.prepare_collect(true)?;
         polars_ensure!(
             is_streaming,
             ComputeError: "cannot run the whole query in a streaming order; \
-            use `collect().write_ipc()` instead"
+            use `{}` instead", msg_alternative
         );
         let _ = physical_plan.execute(&mut state)?;
         Ok(())
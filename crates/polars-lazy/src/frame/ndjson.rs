@@ -1,6 +1,8 @@
 use std::path::{Path, PathBuf};
 
+use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
+use polars_io::bad_records::BadRecords;
 use polars_io::RowCount;
 
 use super::{LazyFileListReader, LazyFrame, ScanArgsAnonymous};
@@ -15,6 +17,8 @@ pub struct LazyJsonLineReader {
     pub(crate) row_count: Option<RowCount>,
     pub(crate) infer_schema_length: Option<usize>,
     pub(crate) n_rows: Option<usize>,
+    pub(crate) bad_records: Option<BadRecords>,
+    pub(crate) cloud_options: Option<CloudOptions>,
 }
 
 impl LazyJsonLineReader {
@@ -28,6 +32,8 @@ impl LazyJsonLineReader {
             row_count: None,
             infer_schema_length: Some(100),
             n_rows: None,
+            bad_records: None,
+            cloud_options: None,
         }
     }
     /// Add a `row_count` column.
@@ -70,6 +76,22 @@ impl LazyJsonLineReader {
         self.batch_size = batch_size;
         self
     }
+
+    /// Remove the lines that cannot be parsed from the output and hand them to `bad_records`,
+    /// together with their line number and the parse error. The records are available once
+    /// the query has been collected.
+    #[must_use]
+    pub fn with_bad_records(mut self, bad_records: Option<BadRecords>) -> Self {
+        self.bad_records = bad_records;
+        self
+    }
+
+    /// Set the [`CloudOptions`] used to list and read files if the path is a cloud url.
+    #[must_use]
+    pub fn with_cloud_options(mut self, cloud_options: Option<CloudOptions>) -> Self {
+        self.cloud_options = cloud_options;
+        self
+    }
 }
 
 impl LazyFileListReader for LazyJsonLineReader {
@@ -116,4 +138,8 @@ impl LazyFileListReader for LazyJsonLineReader {
     fn row_count(&self) -> Option<&RowCount> {
         self.row_count.as_ref()
     }
+
+    fn cloud_options(&self) -> Option<&CloudOptions> {
+        self.cloud_options.as_ref()
+    }
 }
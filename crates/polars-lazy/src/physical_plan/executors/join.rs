@@ -107,41 +107,9 @@ impl Executor for JoinExec {
             // prepare the tolerance
             // we must ensure that we use the right units
             #[cfg(feature = "asof_join")]
-            {
-                if let JoinType::AsOf(options) = &mut self.args.how {
-                    use polars_core::utils::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
-                    if let Some(tol) = &options.tolerance_str {
-                        let duration = polars_time::Duration::parse(tol);
-                        polars_ensure!(
-                            duration.months() == 0,
-                            ComputeError: "cannot use month offset in timedelta of an asof join; \
-                            consider using 4 weeks"
-                        );
-                        let left_asof = df_left.column(left_on_series[0].name())?;
-                        use DataType::*;
-                        match left_asof.dtype() {
-                            Datetime(tu, _) | Duration(tu) => {
-                                let tolerance = match tu {
-                                    TimeUnit::Nanoseconds => duration.duration_ns(),
-                                    TimeUnit::Microseconds => duration.duration_us(),
-                                    TimeUnit::Milliseconds => duration.duration_ms(),
-                                };
-                                options.tolerance = Some(AnyValue::from(tolerance))
-                            }
-                            Date => {
-                                let days = (duration.duration_ms() / MILLISECONDS_IN_DAY) as i32;
-                                options.tolerance = Some(AnyValue::from(days))
-                            }
-                            Time => {
-                                let tolerance = duration.duration_ns();
-                                options.tolerance = Some(AnyValue::from(tolerance))
-                            }
-                            _ => {
-                                panic!("can only use timedelta string language with Date/Datetime/Duration/Time dtypes")
-                            }
-                        }
-                    }
-                }
+            if let JoinType::AsOf(options) = &mut self.args.how {
+                let left_asof = df_left.column(left_on_series[0].name())?;
+                resolve_asof_tolerance(options, left_asof.dtype())?;
             }
 
             let df = df_left._join_impl(
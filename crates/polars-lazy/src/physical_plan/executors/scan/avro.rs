@@ -0,0 +1,42 @@
+use polars_io::avro::AvroReader;
+
+use super::*;
+use crate::prelude::{AnonymousScan, AnonymousScanOptions, LazyAvroReader};
+
+impl AnonymousScan for LazyAvroReader {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+    fn scan(&self, scan_opts: AnonymousScanOptions) -> PolarsResult<DataFrame> {
+        // the projection is given in file order, so it can be passed to the reader as is
+        let projection = scan_opts
+            .with_columns
+            .map(|columns| {
+                columns
+                    .iter()
+                    .map(|name| scan_opts.schema.try_index_of(name))
+                    .collect::<PolarsResult<Vec<_>>>()
+            })
+            .transpose()?;
+
+        let file = polars_utils::open_file(&self.path)?;
+        AvroReader::new(file)
+            .with_projection(projection)
+            .with_n_rows(scan_opts.n_rows)
+            .set_rechunk(self.args.rechunk)
+            .finish()
+    }
+
+    fn schema(&self, _infer_schema_length: Option<usize>) -> PolarsResult<Schema> {
+        let file = polars_utils::open_file(&self.path)?;
+        AvroReader::new(file).schema()
+    }
+
+    fn allows_projection_pushdown(&self) -> bool {
+        true
+    }
+
+    fn allows_slice_pushdown(&self) -> bool {
+        true
+    }
+}
@@ -1,5 +1,7 @@
 use std::path::PathBuf;
 
+use polars_core::cloud::CloudOptions;
+
 use super::*;
 
 pub struct CsvExec {
@@ -8,6 +10,7 @@ pub struct CsvExec {
     pub options: CsvParserOptions,
     pub file_options: FileScanOptions,
     pub predicate: Option<Arc<dyn PhysicalExpr>>,
+    pub cloud_options: Option<CloudOptions>,
 }
 
 impl CsvExec {
@@ -25,11 +28,11 @@ impl CsvExec {
         let n_rows = _set_n_rows_for_scan(self.file_options.n_rows);
         let predicate = self.predicate.clone().map(phys_expr_to_io_expr);
 
-        CsvReader::from_path(&self.path)
-            .unwrap()
+        let file = polars_io::open_file_or_cloud(&self.path, self.cloud_options.as_ref())?;
+        CsvReader::new(file)
             .has_header(self.options.has_header)
             .with_dtypes(Some(self.schema.clone()))
-            .with_delimiter(self.options.delimiter)
+            .with_multi_byte_delimiter(&self.options.delimiter)
             .with_ignore_errors(self.options.ignore_errors)
             .with_skip_rows(self.options.skip_rows)
             .with_n_rows(n_rows)
@@ -40,11 +43,14 @@ impl CsvExec {
             .with_encoding(CsvEncoding::LossyUtf8)
             .with_comment_char(self.options.comment_char)
             .with_quote_char(self.options.quote_char)
+            .with_escape_char(self.options.escape_char)
             .with_end_of_line_char(self.options.eol_char)
             .with_encoding(self.options.encoding)
             .with_rechunk(self.file_options.rechunk)
             .with_row_count(std::mem::take(&mut self.file_options.row_count))
             .with_try_parse_dates(self.options.try_parse_dates)
+            .with_strict(self.options.strict)
+            .with_bad_records(self.options.bad_records.clone())
             .finish()
     }
 }
@@ -1,5 +1,7 @@
 use std::path::PathBuf;
 
+use polars_core::cloud::CloudOptions;
+
 use super::*;
 
 pub struct IpcExec {
@@ -8,18 +10,19 @@ pub struct IpcExec {
     pub(crate) predicate: Option<Arc<dyn PhysicalExpr>>,
     pub(crate) options: IpcScanOptions,
     pub(crate) file_options: FileScanOptions,
+    pub(crate) cloud_options: Option<CloudOptions>,
 }
 
 impl IpcExec {
     fn read(&mut self, verbose: bool) -> PolarsResult<DataFrame> {
-        let (file, projection, n_rows, predicate) = prepare_scan_args(
-            &self.path,
+        let (projection, n_rows, predicate) = prepare_scan_args(
             &self.predicate,
             &mut self.file_options.with_columns,
             &mut self.schema,
             self.file_options.n_rows,
             self.file_options.row_count.is_some(),
         );
+        let file = polars_io::open_file_or_cloud(&self.path, self.cloud_options.as_ref())?;
         IpcReader::new(file)
             .with_n_rows(n_rows)
             .with_row_count(std::mem::take(&mut self.file_options.row_count))
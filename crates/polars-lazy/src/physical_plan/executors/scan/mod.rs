@@ -1,3 +1,5 @@
+#[cfg(feature = "avro")]
+mod avro;
 #[cfg(feature = "csv")]
 mod csv;
 #[cfg(feature = "ipc")]
@@ -35,15 +37,12 @@ type Predicate = Option<Arc<dyn PhysicalIoExpr>>;
 
 #[cfg(any(feature = "ipc", feature = "parquet"))]
 fn prepare_scan_args(
-    path: &std::path::Path,
     predicate: &Option<Arc<dyn PhysicalExpr>>,
     with_columns: &mut Option<Arc<Vec<String>>>,
     schema: &mut SchemaRef,
     n_rows: Option<usize>,
     has_row_count: bool,
-) -> (std::fs::File, Projection, StopNRows, Predicate) {
-    let file = std::fs::File::open(path).unwrap();
-
+) -> (Projection, StopNRows, Predicate) {
     let with_columns = mem::take(with_columns);
     let schema = mem::take(schema);
 
@@ -57,7 +56,7 @@ fn prepare_scan_args(
     let n_rows = _set_n_rows_for_scan(n_rows);
     let predicate = predicate.clone().map(phys_expr_to_io_expr);
 
-    (file, projection, n_rows, predicate)
+    (projection, n_rows, predicate)
 }
 
 /// Producer of an in memory DataFrame
@@ -9,18 +9,20 @@ impl AnonymousScan for LazyJsonLineReader {
     }
     fn scan(&self, scan_opts: AnonymousScanOptions) -> PolarsResult<DataFrame> {
         let schema = scan_opts.output_schema.unwrap_or(scan_opts.schema);
-        JsonLineReader::from_path(&self.path)?
+        let file = polars_io::open_file_or_cloud(&self.path, self.cloud_options.as_ref())?;
+        JsonLineReader::new(file)
             .with_schema(schema)
             .with_rechunk(self.rechunk)
             .with_chunk_size(self.batch_size)
             .low_memory(self.low_memory)
             .with_n_rows(scan_opts.n_rows)
             .with_chunk_size(self.batch_size)
+            .with_bad_records(self.bad_records.clone())
             .finish()
     }
 
     fn schema(&self, infer_schema_length: Option<usize>) -> PolarsResult<Schema> {
-        let f = polars_utils::open_file(&self.path)?;
+        let f = polars_io::open_file_or_cloud(&self.path, self.cloud_options.as_ref())?;
         let mut reader = std::io::BufReader::new(f);
 
         let data_type =
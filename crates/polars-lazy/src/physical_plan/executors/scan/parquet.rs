@@ -34,8 +34,7 @@ impl ParquetExec {
     }
 
     fn read(&mut self) -> PolarsResult<DataFrame> {
-        let (file, projection, n_rows, predicate) = prepare_scan_args(
-            &self.path,
+        let (projection, n_rows, predicate) = prepare_scan_args(
             &self.predicate,
             &mut self.file_options.with_columns,
             &mut self.schema,
@@ -43,6 +42,7 @@ impl ParquetExec {
             self.file_options.row_count.is_some(),
         );
 
+        let file = std::fs::File::open(&self.path).unwrap();
         ParquetReader::new(file)
             .with_n_rows(n_rows)
             .read_parallel(self.options.parallel)
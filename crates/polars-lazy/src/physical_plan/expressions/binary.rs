@@ -32,6 +32,12 @@ impl BinaryExpr {
 
 /// Can partially do operations in place.
 fn apply_operator_owned(left: Series, right: Series, op: Operator) -> PolarsResult<Series> {
+    #[cfg(feature = "dtype-array")]
+    if matches!(left.dtype(), DataType::Array(_, _))
+        || matches!(right.dtype(), DataType::Array(_, _))
+    {
+        return apply_operator(&left, &right, op);
+    }
     match op {
         Operator::Plus => Ok(left + right),
         Operator::Minus => Ok(left - right),
@@ -51,10 +57,10 @@ pub fn apply_operator(left: &Series, right: &Series, op: Operator) -> PolarsResu
         Operator::NotEq => {
             ChunkCompare::<&Series>::not_equal(left, right).map(|ca| ca.into_series())
         }
-        Operator::Plus => Ok(left + right),
-        Operator::Minus => Ok(left - right),
-        Operator::Multiply => Ok(left * right),
-        Operator::Divide => Ok(left / right),
+        Operator::Plus => left.try_add(right),
+        Operator::Minus => left.try_sub(right),
+        Operator::Multiply => left.try_mul(right),
+        Operator::Divide => left.try_div(right),
         Operator::TrueDivide => match left.dtype() {
             #[cfg(feature = "dtype-decimal")]
             Decimal(_, _) => Ok(left / right),
@@ -74,7 +80,7 @@ pub fn apply_operator(left: &Series, right: &Series, op: Operator) -> PolarsResu
         Operator::And => left.bitand(right),
         Operator::Or => left.bitor(right),
         Operator::Xor => left.bitxor(right),
-        Operator::Modulus => Ok(left % right),
+        Operator::Modulus => left.try_rem(right),
         Operator::EqValidity => left.equal_missing(right).map(|ca| ca.into_series()),
         Operator::NotEqValidity => left.not_equal_missing(right).map(|ca| ca.into_series()),
     }
@@ -406,9 +412,9 @@ mod stats {
             {
                 match (fld_l.data_type(), fld_r.data_type()) {
                     #[cfg(feature = "dtype-categorical")]
-                    (DataType::Utf8, DataType::Categorical(_)) => {}
+                    (DataType::Utf8, DataType::Categorical(_) | DataType::Enum(_)) => {}
                     #[cfg(feature = "dtype-categorical")]
-                    (DataType::Categorical(_), DataType::Utf8) => {}
+                    (DataType::Categorical(_) | DataType::Enum(_), DataType::Utf8) => {}
                     (l, r) if l != r => panic!("implementation error: {l:?}, {r:?}"),
                     _ => {}
                 }
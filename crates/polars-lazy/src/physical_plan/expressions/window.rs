@@ -29,7 +29,7 @@ pub struct WindowExpr {
     /// A function Expr. i.e. Mean, Median, Max, etc.
     pub(crate) function: Expr,
     pub(crate) phys_function: Arc<dyn PhysicalExpr>,
-    pub(crate) options: WindowOptions,
+    pub(crate) options: WindowType,
     pub(crate) expr: Expr,
 }
 
@@ -315,13 +315,43 @@ impl WindowExpr {
         has_arity && agg_col
     }
 
+    fn mapping(&self) -> WindowMapping {
+        match &self.options {
+            WindowType::Over(options) => options.mapping,
+            // every row has its own window, so the groups map one-to-one to the rows
+            #[cfg(feature = "dynamic_groupby")]
+            WindowType::Rolling(_) => WindowMapping::GroupsToRows,
+        }
+    }
+
+    #[cfg(feature = "dynamic_groupby")]
+    fn evaluate_rolling(
+        &self,
+        df: &DataFrame,
+        state: &ExecutionState,
+        options: &RollingGroupOptions,
+    ) -> PolarsResult<Series> {
+        let (_time_key, _keys, groups) = df.groupby_rolling(vec![], options)?;
+        let mut ac = self.phys_function.evaluate_on_groups(df, &groups, state)?;
+        let mut out = ac.finalize();
+        polars_ensure!(
+            out.len() == groups.len(),
+            ComputeError: "a rolling expression should produce a single value per window, \
+            got {} values for {} windows", out.len(), groups.len()
+        );
+        if let Some(name) = &self.out_name {
+            out.rename(name.as_ref());
+        }
+        Ok(out)
+    }
+
     fn determine_map_strategy(
         &self,
         agg_state: &AggState,
         sorted_keys: bool,
         gb: &GroupBy,
     ) -> PolarsResult<MapStrategy> {
-        match (self.options.mapping, agg_state) {
+        match (self.mapping(), agg_state) {
             // Explode
             // `(col("x").sum() * col("y")).list().over("groups").flatten()`
             (WindowMapping::Explode, _) => Ok(MapStrategy::Explode),
@@ -407,6 +437,11 @@ impl PhysicalExpr for WindowExpr {
             return Ok(Series::full_null(field.name(), 0, field.data_type()));
         }
 
+        #[cfg(feature = "dynamic_groupby")]
+        if let WindowType::Rolling(options) = &self.options {
+            return self.evaluate_rolling(df, state, options);
+        }
+
         let groupby_columns = self
             .group_by
             .iter()
@@ -423,7 +458,7 @@ impl PhysicalExpr for WindowExpr {
         let explicit_list_agg = self.is_explicit_list_agg();
 
         // if we flatten this column we need to make sure the groups are sorted.
-        let mut sort_groups = matches!(self.options.mapping, WindowMapping::Explode) ||
+        let mut sort_groups = matches!(self.mapping(), WindowMapping::Explode) ||
             // if not
             //      `col().over()`
             // and not
@@ -557,18 +592,20 @@ impl PhysicalExpr for WindowExpr {
                         let keys = gb.keys();
                         cache_gb(gb, state, &cache_key);
 
-                        let get_join_tuples = || {
+                        let get_join_tuples = || -> PolarsResult<_> {
                             if groupby_columns.len() == 1 {
                                 // group key from right column
                                 let right = &keys[0];
-                                groupby_columns[0]
-                                    .hash_join_left(right, JoinValidation::ManyToMany)
-                                    .unwrap()
-                                    .1
+                                Ok(groupby_columns[0]
+                                    .hash_join_left(right, JoinValidation::ManyToMany)?
+                                    .1)
                             } else {
                                 let df_right = DataFrame::new_no_checks(keys);
                                 let df_left = DataFrame::new_no_checks(groupby_columns);
-                                private_left_join_multiple_keys(&df_left, &df_right, None, None).1
+                                Ok(private_left_join_multiple_keys(
+                                    &df_left, &df_right, None, None,
+                                )?
+                                .1)
                             }
                         };
 
@@ -581,10 +618,10 @@ impl PhysicalExpr for WindowExpr {
                             if let Some(opt_join_tuples) = jt_map.get_mut(&cache_key) {
                                 std::mem::replace(opt_join_tuples, default_join_ids())
                             } else {
-                                get_join_tuples()
+                                get_join_tuples()?
                             }
                         } else {
-                            get_join_tuples()
+                            get_join_tuples()?
                         };
 
                         let mut out = materialize_column(&join_opt_ids, &out_column);
@@ -199,20 +199,26 @@ pub fn create_physical_plan(
                 #[cfg(feature = "csv")]
                 FileScan::Csv {
                     options: csv_options,
+                    cloud_options,
                 } => Ok(Box::new(executors::CsvExec {
                     path,
                     schema: file_info.schema,
                     options: csv_options,
                     predicate,
                     file_options,
+                    cloud_options,
                 })),
                 #[cfg(feature = "ipc")]
-                FileScan::Ipc { options } => Ok(Box::new(executors::IpcExec {
+                FileScan::Ipc {
+                    options,
+                    cloud_options,
+                } => Ok(Box::new(executors::IpcExec {
                     path,
                     schema: file_info.schema,
                     predicate,
                     options,
                     file_options,
+                    cloud_options,
                 })),
                 #[cfg(feature = "parquet")]
                 FileScan::Parquet {
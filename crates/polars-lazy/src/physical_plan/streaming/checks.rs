@@ -80,6 +80,8 @@ pub(super) fn streamable_join(args: &JoinArgs) -> bool {
         #[cfg(feature = "cross_join")]
         JoinType::Cross => true,
         JoinType::Inner | JoinType::Left => true,
+        #[cfg(feature = "asof_join")]
+        JoinType::AsOf(_) => true,
         _ => false,
     };
     supported && !args.validation.needs_checks()
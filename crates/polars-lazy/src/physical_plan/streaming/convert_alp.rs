@@ -43,6 +43,7 @@ fn insert_file_sink(mut root: Node, lp_arena: &mut Arena<ALogicalPlan>) -> Node
             payload: FileSinkOptions {
                 path: Default::default(),
                 file_type: FileType::Memory,
+                cloud_options: None,
             },
         })
     }
@@ -387,8 +388,9 @@ pub(crate) fn insert_streaming_nodes(
                         expr_arena
                             .get(*node)
                             .get_type(schema, Context::Default, expr_arena)
-                            // ensure we don't groupby list
-                            .map(|dt| !matches!(dt, DataType::List(_)))
+                            // ensure we don't groupby list or array
+                            // those keys cannot be decoded from the row format
+                            .map(|dt| dt.inner_dtype().is_none())
                             .unwrap_or(false)
                     })
                 };
@@ -2,6 +2,8 @@ pub(crate) use polars_ops::prelude::*;
 pub use polars_plan::logical_plan::{
     AnonymousScan, AnonymousScanOptions, Literal, LiteralValue, LogicalPlan, Null, NULL,
 };
+#[cfg(feature = "avro")]
+pub use polars_plan::prelude::AvroWriterOptions;
 #[cfg(feature = "ipc")]
 pub use polars_plan::prelude::IpcWriterOptions;
 #[cfg(feature = "parquet")]
@@ -9,6 +11,10 @@ pub use polars_plan::prelude::ParquetWriteOptions;
 pub(crate) use polars_plan::prelude::*;
 #[cfg(feature = "rolling_window")]
 pub use polars_time::{prelude::RollingOptions, Duration};
+#[cfg(feature = "temporal")]
+pub use polars_time::{Ambiguous, NonExistent};
+#[cfg(feature = "date_diff")]
+pub use polars_time::DateDiffUnit;
 #[cfg(feature = "dynamic_groupby")]
 pub use polars_time::{DynamicGroupOptions, PolarsTemporalGroupby, RollingGroupOptions};
 pub(crate) use polars_utils::arena::{Arena, Node};
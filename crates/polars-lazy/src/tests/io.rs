@@ -181,6 +181,7 @@ fn test_ipc_globbing() -> PolarsResult<()> {
             rechunk: false,
             row_count: None,
             memmap: true,
+            cloud_options: None,
         },
     )?
     .collect()?;
@@ -253,6 +254,38 @@ fn test_ndjson_globbing() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(not(target_os = "windows"))]
+#[cfg(all(feature = "async", feature = "json"))]
+fn test_cloud_url_scans() -> PolarsResult<()> {
+    // for side effects
+    init_files();
+    let datasets = std::fs::canonicalize("../../examples/datasets")?;
+    let url = |pattern: &str| format!("file://{}/{}", datasets.display(), pattern);
+
+    let df = LazyCsvReader::new(url("*.csv")).finish()?.collect()?;
+    assert_eq!(df.shape(), (135, 4));
+    let df = LazyCsvReader::new(url("foods1.csv"))
+        .finish()?
+        .select([col("calories")])
+        .slice(0, 3)
+        .collect()?;
+    assert_eq!(df.shape(), (3, 1));
+
+    let df = LazyFrame::scan_ipc(url("*.ipc"), Default::default())?.collect()?;
+    assert_eq!(df.shape(), (54, 4));
+    let cal = df.column("calories")?;
+    assert_eq!(cal.get(53)?, AnyValue::Int64(194));
+
+    let df = LazyJsonLineReader::new(url("*.ndjson"))
+        .finish()?
+        .select([col("category"), col("calories")])
+        .collect()?;
+    assert_eq!(df.shape(), (54, 2));
+
+    Ok(())
+}
+
 #[test]
 pub fn test_simple_slice() -> PolarsResult<()> {
     let _guard = SINGLE_LOCK.lock().unwrap();
@@ -411,6 +444,57 @@ fn scan_predicate_on_set_null_values() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "avro", feature = "streaming"))]
+fn test_sink_and_scan_avro() -> PolarsResult<()> {
+    let path = std::env::temp_dir().join("polars_test_sink_and_scan_avro.avro");
+    scan_foods_csv().sink_avro(path.clone(), Default::default())?;
+
+    let expected = scan_foods_csv()
+        .select([col("sugars_g"), col("category")])
+        .slice(0, 5)
+        .collect()?;
+    let df = LazyFrame::scan_avro(&path, Default::default())?
+        .select([col("sugars_g"), col("category")])
+        .slice(0, 5)
+        .collect()?;
+    assert!(df.frame_equal(&expected));
+
+    let df = LazyFrame::scan_avro(&path, Default::default())?.collect()?;
+    assert_eq!(df.shape(), (27, 4));
+
+    std::fs::remove_file(path)?;
+    Ok(())
+}
+
+#[test]
+#[cfg(not(target_os = "windows"))]
+#[cfg(all(
+    feature = "async",
+    feature = "parquet",
+    feature = "ipc",
+    feature = "streaming"
+))]
+fn test_sink_cloud_url() -> PolarsResult<()> {
+    let dir = std::env::temp_dir();
+    let parquet = dir.join("polars_test_sink_cloud_url.parquet");
+    let ipc = dir.join("polars_test_sink_cloud_url.ipc");
+    let url = |path: &std::path::Path| format!("file://{}", path.display());
+
+    scan_foods_csv().sink_parquet_cloud(url(&parquet), None, Default::default())?;
+    scan_foods_csv().sink_ipc_cloud(url(&ipc), None, Default::default())?;
+
+    let expected = scan_foods_csv().collect()?;
+    let df = LazyFrame::scan_parquet(&parquet, Default::default())?.collect()?;
+    assert!(df.frame_equal_missing(&expected));
+    let df = LazyFrame::scan_ipc(&ipc, Default::default())?.collect()?;
+    assert!(df.frame_equal_missing(&expected));
+
+    std::fs::remove_file(parquet)?;
+    std::fs::remove_file(ipc)?;
+    Ok(())
+}
+
 #[test]
 fn scan_anonymous_fn() -> PolarsResult<()> {
     let function = Arc::new(|_scan_opts: AnonymousScanOptions| Ok(fruits_cars()));
@@ -64,6 +64,71 @@ fn test_cross_join_pd() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "interval_join")]
+fn test_interval_join_pd() -> PolarsResult<()> {
+    let events = df![
+        "time" => [1i64, 6, 12, 20],
+        "value" => ["a", "b", "c", "d"],
+        "unused" => [0, 0, 0, 0]
+    ]?;
+    let outages = df![
+        "start" => [0i64, 5, 10],
+        "end" => [5i64, 10, 15],
+        "id" => [1, 2, 3],
+        "unused" => [0, 0, 0]
+    ]?;
+
+    let q = events
+        .lazy()
+        .join_builder()
+        .with(outages.lazy())
+        .left_on([col("time")])
+        .right_on([col("start"), col("end")])
+        .how(JoinType::Interval)
+        .finish()
+        .select([col("value"), col("id"), col("start")]);
+
+    // the join keys of both tables are projected, the unused columns are not
+    let (mut expr_arena, mut lp_arena) = get_arenas();
+    let lp = q.clone().optimize(&mut lp_arena, &mut expr_arena).unwrap();
+    let mut projections = (&lp_arena)
+        .iter(lp)
+        .filter_map(|(_, lp)| match lp {
+            ALogicalPlan::DataFrameScan { projection, .. } => {
+                let mut projection = projection.as_deref().unwrap().clone();
+                projection.sort();
+                Some(projection)
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    projections.sort();
+    assert_eq!(
+        projections,
+        &[vec!["end", "id", "start"], vec!["time", "value"]]
+    );
+
+    assert_eq!(
+        q.schema()?.as_ref(),
+        &Schema::from_iter([
+            Field::new("value", DataType::Utf8),
+            Field::new("id", DataType::Int32),
+            Field::new("start", DataType::Int64),
+        ])
+    );
+
+    let out = q.collect()?;
+    let expected = df![
+        "value" => ["a", "b", "c"],
+        "id" => [1, 2, 3],
+        "start" => [0i64, 5, 10]
+    ]?;
+    assert!(out.frame_equal(&expected));
+
+    Ok(())
+}
+
 #[test]
 fn test_row_count_pd() -> PolarsResult<()> {
     let df = df![
@@ -269,6 +269,70 @@ fn test_streaming_slice() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "asof_join")]
+fn test_streaming_asof_join() -> PolarsResult<()> {
+    let trades = df![
+        "time" => [23i64, 38, 48, 48, 48],
+        "ticker" => ["MSFT", "MSFT", "GOOG", "GOOG", "AAPL"],
+        "venue" => [1, 1, 2, 1, 3],
+    ]?
+    .lazy();
+    let quotes = df![
+        "time" => [23i64, 23, 30, 41, 48, 49, 72, 75],
+        "ticker" => ["GOOG", "MSFT", "MSFT", "MSFT", "GOOG", "AAPL", "GOOG", "MSFT"],
+        "venue" => [2, 1, 1, 1, 2, 3, 2, 1],
+        "bid" => [720.5, 51.95, 51.97, 51.99, 720.5, 97.99, 720.5, 52.01],
+    ]?
+    .lazy();
+
+    let by = vec!["ticker".into(), "venue".into()];
+    let q = trades
+        .join_builder()
+        .with(quotes)
+        .left_on([col("time")])
+        .right_on([col("time")])
+        .how(JoinType::AsOf(AsOfOptions {
+            strategy: AsofStrategy::Nearest,
+            left_by: Some(by.clone()),
+            right_by: Some(by),
+            allow_exact_matches: false,
+            ..Default::default()
+        }))
+        .finish();
+
+    assert_streaming_with_default(q, false, false);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "asof_join")]
+fn test_streaming_asof_join_unsorted() -> PolarsResult<()> {
+    let left = df![
+        "time" => [1i64, 5, 10],
+    ]?
+    .lazy();
+    let right = df![
+        "time" => [4i64, 1, 9],
+        "value" => [1, 2, 3],
+    ]?
+    .lazy();
+
+    let q = left
+        .join_builder()
+        .with(right)
+        .left_on([col("time")])
+        .right_on([col("time")])
+        .how(JoinType::AsOf(Default::default()))
+        .finish();
+
+    for streaming in [true, false] {
+        let err = q.clone().with_streaming(streaming).collect().unwrap_err();
+        assert!(err.to_string().contains("not explicitly sorted"));
+    }
+    Ok(())
+}
+
 #[test]
 fn test_streaming_partial() -> PolarsResult<()> {
     let lf_left = df![
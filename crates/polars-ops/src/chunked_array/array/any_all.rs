@@ -0,0 +1,52 @@
+use arrow::array::{BooleanArray, FixedSizeListArray};
+use arrow::bitmap::utils::count_zeros;
+
+use super::*;
+
+fn array_all_any(arr: &FixedSizeListArray, is_all: bool) -> PolarsResult<ArrayRef> {
+    let values = arr.values();
+
+    polars_ensure!(values.data_type() == &ArrowDataType::Boolean, ComputeError: "expected boolean elements in array");
+
+    let values = values.as_any().downcast_ref::<BooleanArray>().unwrap();
+    let validity = arr.validity().cloned();
+    let width = arr.size();
+
+    // nulls don't count as set, so fold them into the values
+    let values = match values.validity() {
+        Some(validity) => values.values() & validity,
+        None => values.values().clone(),
+    };
+    let (bytes, offset, _) = values.as_slice();
+
+    let iter = (0..arr.len()).map(|row| {
+        let unset = count_zeros(bytes, offset + row * width, width);
+        if is_all {
+            unset == 0
+        } else {
+            unset < width
+        }
+    });
+
+    Ok(Box::new(
+        BooleanArray::from_trusted_len_values_iter(iter).with_validity(validity),
+    ))
+}
+
+pub(super) fn array_all(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| array_all_any(arr, true))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    unsafe { Ok(BooleanChunked::from_chunks(ca.name(), chunks).into_series()) }
+}
+
+pub(super) fn array_any(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let chunks = ca
+        .downcast_iter()
+        .map(|arr| array_all_any(arr, false))
+        .collect::<PolarsResult<Vec<_>>>()?;
+
+    unsafe { Ok(BooleanChunked::from_chunks(ca.name(), chunks).into_series()) }
+}
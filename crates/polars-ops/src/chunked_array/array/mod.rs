@@ -1,6 +1,9 @@
+mod any_all;
 mod min_max;
 mod namespace;
 mod sum_mean;
+mod take;
+mod vector;
 
 pub use namespace::ArrayNameSpace;
 use polars_core::prelude::*;
@@ -1,3 +1,7 @@
+use std::fmt::Write;
+
+use polars_arrow::utils::CustomIterTools;
+
 use super::min_max::AggType;
 use super::*;
 use crate::chunked_array::array::sum_mean::sum_with_nulls;
@@ -51,6 +55,175 @@ pub trait ArrayNameSpace: AsArray {
         let ca = self.as_array();
         ca.try_apply_amortized(|s| s.as_ref().unique_stable())
     }
+
+    fn array_mean(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        sum_mean::mean(ca).map(|ca| ca.into_series())
+    }
+
+    fn array_std(&self, ddof: u8) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        sum_mean::std(ca, ddof).map(|ca| ca.into_series())
+    }
+
+    fn array_all(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        any_all::array_all(ca)
+    }
+
+    fn array_any(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        any_all::array_any(ca)
+    }
+
+    /// Get the value at `index` of every array. The index is either broadcast or given per
+    /// array. Negative indices count from the back and out of bounds indices produce null.
+    fn array_get(&self, index: &Int64Chunked) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        take::get(ca, index)
+    }
+
+    /// Check if the arrays contain `item`. The item is either broadcast or given per array.
+    fn array_contains(&self, item: &Series) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_array().rechunk();
+        let width = ca.width();
+        let values = ca.get_inner().cast(&ca.inner_dtype())?;
+        let item = item.cast(&ca.inner_dtype())?;
+
+        let mask = match item.len() {
+            1 => values.equal(&item)?,
+            len => {
+                polars_ensure!(
+                    len == ca.len(),
+                    ComputeError: "item length: {} does not match that of the array: {}",
+                    len, ca.len(),
+                );
+                // repeat every item for all the values of its array
+                let idx: NoNull<IdxCa> = (0..len as IdxSize)
+                    .flat_map(|row| std::iter::repeat(row).take(width))
+                    .collect();
+                values.equal(&item.take(&idx.into_inner())?)?
+            }
+        };
+        let mask = mask.rechunk();
+        let mask = mask.downcast_iter().next().unwrap();
+        let arr = ca.downcast_iter().next().unwrap();
+        let mut out: BooleanChunked = (0..ca.len())
+            .map(|row| {
+                arr.is_valid(row).then(|| {
+                    (row * width..(row + 1) * width).any(|i| mask.is_valid(i) && mask.value(i))
+                })
+            })
+            .collect_trusted();
+        out.rename(ca.name());
+        Ok(out)
+    }
+
+    /// In case the inner dtype [`DataType::Utf8`], the individual items will be joined into a
+    /// single string separated by `separator`.
+    fn array_join(&self, separator: &str) -> PolarsResult<Utf8Chunked> {
+        let ca = self.as_array();
+        match ca.inner_dtype() {
+            DataType::Utf8 => {
+                // used to amortize heap allocs
+                let mut buf = String::with_capacity(128);
+
+                let mut builder = Utf8ChunkedBuilder::new(
+                    ca.name(),
+                    ca.len(),
+                    ca.get_inner().utf8()?.get_values_size() + separator.len() * ca.len(),
+                );
+
+                ca.amortized_iter().for_each(|opt_s| {
+                    let opt_val = opt_s.map(|s| {
+                        // make sure that we don't write values of previous iteration
+                        buf.clear();
+                        let ca = s.as_ref().utf8().unwrap();
+                        let iter = ca.into_iter().map(|opt_v| opt_v.unwrap_or("null"));
+
+                        for val in iter {
+                            buf.write_str(val).unwrap();
+                            buf.write_str(separator).unwrap();
+                        }
+                        // last value should not have a separator, so slice that off
+                        &buf[..buf.len().saturating_sub(separator.len())]
+                    });
+                    builder.append_option(opt_val)
+                });
+                Ok(builder.finish())
+            }
+            dt => polars_bail!(op = "`arr.join`", got = dt, expected = "Utf8"),
+        }
+    }
+
+    fn array_sort(&self, options: SortOptions) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        take::sort(ca, options)
+    }
+
+    fn array_reverse(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        take::reverse(ca)
+    }
+
+    fn array_arg_min(&self) -> IdxCa {
+        let ca = self.as_array();
+        let mut out: IdxCa = ca
+            .amortized_iter()
+            .map(|opt_s| opt_s.and_then(|s| s.as_ref().arg_min().map(|idx| idx as IdxSize)))
+            .collect_trusted();
+        out.rename(ca.name());
+        out
+    }
+
+    fn array_arg_max(&self) -> IdxCa {
+        let ca = self.as_array();
+        let mut out: IdxCa = ca
+            .amortized_iter()
+            .map(|opt_s| opt_s.and_then(|s| s.as_ref().arg_max().map(|idx| idx as IdxSize)))
+            .collect_trusted();
+        out.rename(ca.name());
+        out
+    }
+
+    fn array_to_list(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        ca.cast(&DataType::List(Box::new(ca.inner_dtype())))
+    }
+
+    /// Convert the arrays to a struct with a field `field_{i}` for every position `i`.
+    #[cfg(feature = "dtype-struct")]
+    fn array_to_struct(&self) -> PolarsResult<StructChunked> {
+        let ca = self.as_array();
+        take::to_struct(ca)
+    }
+
+    /// Explode the arrays to their values. Null arrays explode to `width` null values.
+    fn array_explode(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        take::explode(ca)
+    }
+
+    /// Compute the dot product of every array with the array at the same row in `other`, or
+    /// with `other` if it is a single array. `Float32` arrays produce `Float32`, other numeric
+    /// arrays `Float64`. Rows that contain null values produce null.
+    fn array_dot(&self, other: &Series) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        vector::dot(ca, other)
+    }
+
+    /// Compute the cosine similarity of every array with the array at the same row in `other`,
+    /// or with `other` if it is a single array.
+    fn array_cosine_similarity(&self, other: &Series) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        vector::cosine_similarity(ca, other)
+    }
+
+    /// Compute the euclidean norm of every array.
+    fn array_l2_norm(&self) -> PolarsResult<Series> {
+        let ca = self.as_array();
+        vector::l2_norm(ca)
+    }
 }
 
 impl ArrayNameSpace for ArrayChunked {}
@@ -116,3 +116,58 @@ pub(super) fn sum_with_nulls(ca: &ArrayChunked, inner_dtype: &DataType) -> Polar
     out.rename(ca.name());
     Ok(out)
 }
+
+/// Apply `f` to the non-null values of every row, cast to `f64`.
+fn apply_float_rows<F>(ca: &ArrayChunked, mut f: F) -> PolarsResult<Float64Chunked>
+where
+    F: FnMut(&[f64]) -> Option<f64>,
+{
+    let inner_dtype = ca.inner_dtype();
+    polars_ensure!(
+        inner_dtype.is_numeric(),
+        InvalidOperation: "expected a numeric inner dtype, got {}", inner_dtype
+    );
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let width = ca.width();
+    let values = ca.get_inner().cast(&DataType::Float64)?;
+    let values = values.f64().unwrap();
+    let values = values.downcast_iter().next().unwrap();
+
+    // amortize the allocation
+    let mut buf = Vec::with_capacity(width);
+    let mut out: Float64Chunked = (0..ca.len())
+        .map(|row| {
+            if !arr.is_valid(row) {
+                return None;
+            }
+            buf.clear();
+            buf.extend(
+                (row * width..(row + 1) * width)
+                    .filter(|&i| values.is_valid(i))
+                    .map(|i| values.value(i)),
+            );
+            f(&buf)
+        })
+        .collect_trusted();
+    out.rename(ca.name());
+    Ok(out)
+}
+
+pub(super) fn mean(ca: &ArrayChunked) -> PolarsResult<Float64Chunked> {
+    apply_float_rows(ca, |values| {
+        (!values.is_empty()).then(|| values.iter().sum::<f64>() / values.len() as f64)
+    })
+}
+
+pub(super) fn std(ca: &ArrayChunked, ddof: u8) -> PolarsResult<Float64Chunked> {
+    apply_float_rows(ca, |values| {
+        let n = values.len();
+        if n <= ddof as usize {
+            return None;
+        }
+        let mean = values.iter().sum::<f64>() / n as f64;
+        let squared_diffs = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>();
+        Some((squared_diffs / (n - ddof as usize) as f64).sqrt())
+    })
+}
@@ -0,0 +1,119 @@
+//! Operations that reorder or select the values of a fixed size list. These compute the
+//! indices into the inner values directly from the fixed width and take them in one go.
+use arrow::array::{Array, FixedSizeListArray};
+use polars_arrow::utils::CustomIterTools;
+use polars_core::prelude::*;
+
+/// Wrap `values`, the inner values of `ca` after a reordering within the rows, in a fixed size
+/// list with the same width and row validity as `ca`.
+fn rewrap(ca: &ArrayChunked, values: Series) -> Series {
+    let arr = ca.downcast_iter().next().unwrap();
+    let values = values.rechunk();
+    let values = values.array_ref(0).clone();
+    let data_type = FixedSizeListArray::default_datatype(values.data_type().clone(), ca.width());
+    let arr = FixedSizeListArray::new(data_type, values, arr.validity().cloned());
+    // Safety: the inner values have the inner dtype of `ca`.
+    unsafe { Series::from_chunks_and_dtype_unchecked(ca.name(), vec![Box::new(arr)], ca.dtype()) }
+}
+
+/// The inner values with their logical dtype.
+fn logical_inner(ca: &ArrayChunked) -> PolarsResult<Series> {
+    ca.get_inner().cast(&ca.inner_dtype())
+}
+
+pub(super) fn reverse(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let ca = ca.rechunk();
+    let width = ca.width() as IdxSize;
+    let idx: NoNull<IdxCa> = (0..ca.len() as IdxSize)
+        .flat_map(|row| (0..width).rev().map(move |i| row * width + i))
+        .collect();
+    let values = ca.get_inner().take(&idx.into_inner())?;
+    Ok(rewrap(&ca, values))
+}
+
+pub(super) fn sort(ca: &ArrayChunked, options: SortOptions) -> PolarsResult<Series> {
+    let ca = ca.rechunk();
+    let width = ca.width();
+    let values = ca.get_inner();
+
+    let mut idx = Vec::with_capacity(values.len());
+    for row in 0..ca.len() {
+        let offset = (row * width) as IdxSize;
+        let sorted = values.slice(offset as i64, width).arg_sort(options);
+        idx.extend(sorted.into_no_null_iter().map(|i| i + offset));
+    }
+    let idx = IdxCa::from_vec("", idx);
+    let values = values.take(&idx)?;
+    Ok(rewrap(&ca, values))
+}
+
+pub(super) fn get(ca: &ArrayChunked, index: &Int64Chunked) -> PolarsResult<Series> {
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let width = ca.width() as i64;
+
+    // negative indices count from the back, out of bounds indices give null
+    let take_idx = |row: usize, index: Option<i64>| {
+        let index = index?;
+        let index = if index < 0 { index + width } else { index };
+        (arr.is_valid(row) && (0..width).contains(&index))
+            .then(|| (row as i64 * width + index) as IdxSize)
+    };
+    let idx: IdxCa = match index.len() {
+        1 => {
+            let index = index.get(0);
+            (0..ca.len())
+                .map(|row| take_idx(row, index))
+                .collect_trusted()
+        }
+        len => {
+            polars_ensure!(
+                len == ca.len(),
+                ComputeError: "index length: {} does not match that of the array: {}",
+                len, ca.len(),
+            );
+            index
+                .into_iter()
+                .enumerate()
+                .map(|(row, index)| take_idx(row, index))
+                .collect()
+        }
+    };
+    logical_inner(&ca)?.take(&idx)
+}
+
+#[cfg(feature = "dtype-struct")]
+pub(super) fn to_struct(ca: &ArrayChunked) -> PolarsResult<StructChunked> {
+    let ca = ca.rechunk();
+    let arr = ca.downcast_iter().next().unwrap();
+    let width = ca.width();
+    let values = logical_inner(&ca)?;
+    let fields = (0..width)
+        .map(|i| {
+            // the `i`th value of every row, or null for null rows
+            let idx: IdxCa = (0..ca.len())
+                .map(|row| arr.is_valid(row).then(|| (row * width + i) as IdxSize))
+                .collect_trusted();
+            let mut field = values.take(&idx)?;
+            field.rename(&format!("field_{i}"));
+            Ok(field)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    StructChunked::new(ca.name(), &fields)
+}
+
+pub(super) fn explode(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let ca = ca.rechunk();
+    let values = logical_inner(&ca)?;
+    if ca.null_count() == 0 {
+        return Ok(values);
+    }
+
+    // null rows explode to `width` nulls, so that every row keeps its size
+    let arr = ca.downcast_iter().next().unwrap();
+    let width = ca.width();
+    let idx: IdxCa = (0..values.len())
+        .map(|i| arr.is_valid(i / width).then_some(i as IdxSize))
+        .collect_trusted();
+    values.take(&idx)
+}
@@ -0,0 +1,180 @@
+//! Row-wise vector operations on arrays of floats. The values of the rows are contiguous, so
+//! the kernels work directly on the values buffer.
+use arrow::array::PrimitiveArray;
+use arrow::bitmap::utils::count_zeros;
+use arrow::bitmap::Bitmap;
+use polars_arrow::kernels::vector;
+use polars_arrow::prelude::FromData;
+use polars_arrow::utils::combine_validities_and;
+use polars_core::datatypes::PolarsFloatType;
+use polars_core::export::num::Float;
+
+use super::*;
+
+/// The float type the values are computed in. `Float32` values stay `Float32`, all other numeric
+/// values are computed as `Float64`.
+fn vector_float_dtype(inner_dtype: &DataType) -> DataType {
+    match inner_dtype {
+        DataType::Float32 => DataType::Float32,
+        _ => DataType::Float64,
+    }
+}
+
+fn cast_values(ca: &ArrayChunked) -> PolarsResult<ArrayChunked> {
+    let inner_dtype = ca.inner_dtype();
+    polars_ensure!(
+        inner_dtype.is_numeric(),
+        InvalidOperation: "expected an array of numeric values, got {}", inner_dtype
+    );
+    polars_ensure!(ca.width() > 0, ComputeError: "expected arrays with a positive width");
+    let dtype = DataType::Array(Box::new(vector_float_dtype(&inner_dtype)), ca.width());
+    Ok(ca.cast(&dtype)?.array().unwrap().rechunk())
+}
+
+/// Cast `other` to arrays of the width and float type of `ca`. A list literal is accepted as
+/// the vector to compare with.
+fn cast_other(ca: &ArrayChunked, other: &Series) -> PolarsResult<ArrayChunked> {
+    match other.dtype() {
+        DataType::Array(_, _) | DataType::List(_) => {
+            let other = other.cast(ca.dtype())?;
+            Ok(other.array()?.rechunk())
+        }
+        dt => polars_bail!(
+            InvalidOperation: "expected the other vector to be an array or list, got {}", dt
+        ),
+    }
+}
+
+/// The validity of the rows, where rows that contain null values are null as well.
+fn row_validity(ca: &ArrayChunked) -> Option<Bitmap> {
+    let arr = ca.downcast_iter().next().unwrap();
+    let width = ca.width();
+    let inner = arr
+        .values()
+        .validity()
+        .filter(|validity| validity.unset_bits() > 0)
+        .map(|validity| {
+            let (bytes, offset, _) = validity.as_slice();
+            (0..arr.len())
+                .map(|row| count_zeros(bytes, offset + row * width, width) == 0)
+                .collect::<Bitmap>()
+        });
+    combine_validities_and(arr.validity(), inner.as_ref())
+}
+
+fn values<T: PolarsFloatType>(ca: &ArrayChunked) -> ChunkedArray<T> {
+    ca.get_inner().unpack::<T>().unwrap().clone()
+}
+
+fn finish<T: PolarsFloatType>(
+    name: &str,
+    values: Vec<T::Native>,
+    validity: Option<Bitmap>,
+) -> Series {
+    let arr = PrimitiveArray::from_data_default(values.into(), validity);
+    unsafe { ChunkedArray::<T>::from_chunks(name, vec![Box::new(arr)]).into_series() }
+}
+
+fn binary<T, F>(ca: &ArrayChunked, other: &ArrayChunked, kernel: F) -> PolarsResult<Series>
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+    F: Fn(&[T::Native], &[T::Native], usize) -> Vec<T::Native>,
+{
+    // the operations are symmetric, so a single row on the left can be swapped to the right
+    let (ca, other) = if ca.len() == 1 && other.len() != 1 {
+        (other, ca)
+    } else {
+        (ca, other)
+    };
+    polars_ensure!(
+        other.len() == 1 || other.len() == ca.len(),
+        ComputeError: "cannot compare vectors of series with lengths {} and {}",
+        ca.len(), other.len(),
+    );
+    let lhs = values::<T>(ca);
+    let rhs = values::<T>(other);
+    let lhs = lhs.downcast_iter().next().unwrap().values().as_slice();
+    let rhs = rhs.downcast_iter().next().unwrap().values().as_slice();
+    let out = kernel(lhs, rhs, ca.width());
+
+    let other_validity = match row_validity(other) {
+        Some(validity) if other.len() == 1 && ca.len() != 1 => {
+            (!validity.get_bit(0)).then(|| Bitmap::new_zeroed(ca.len()))
+        }
+        validity => validity,
+    };
+    let validity = combine_validities_and(row_validity(ca).as_ref(), other_validity.as_ref());
+    Ok(finish::<T>(ca.name(), out, validity))
+}
+
+pub(super) fn dot(ca: &ArrayChunked, other: &Series) -> PolarsResult<Series> {
+    let ca = cast_values(ca)?;
+    let other = cast_other(&ca, other)?;
+    match ca.inner_dtype() {
+        DataType::Float32 => binary::<Float32Type, _>(&ca, &other, vector::dot_rows),
+        _ => binary::<Float64Type, _>(&ca, &other, vector::dot_rows),
+    }
+}
+
+pub(super) fn cosine_similarity(ca: &ArrayChunked, other: &Series) -> PolarsResult<Series> {
+    let ca = cast_values(ca)?;
+    let other = cast_other(&ca, other)?;
+    match ca.inner_dtype() {
+        DataType::Float32 => binary::<Float32Type, _>(&ca, &other, vector::cosine_similarity_rows),
+        _ => binary::<Float64Type, _>(&ca, &other, vector::cosine_similarity_rows),
+    }
+}
+
+fn l2_norm_impl<T>(ca: &ArrayChunked) -> Series
+where
+    T: PolarsFloatType,
+    T::Native: Float,
+{
+    let values = values::<T>(ca);
+    let values = values.downcast_iter().next().unwrap().values().as_slice();
+    let out = vector::l2_norm_rows(values, ca.width());
+    finish::<T>(ca.name(), out, row_validity(ca))
+}
+
+pub(super) fn l2_norm(ca: &ArrayChunked) -> PolarsResult<Series> {
+    let ca = cast_values(ca)?;
+    match ca.inner_dtype() {
+        DataType::Float32 => Ok(l2_norm_impl::<Float32Type>(&ca)),
+        _ => Ok(l2_norm_impl::<Float64Type>(&ca)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_vector_ops() -> PolarsResult<()> {
+        let dtype = DataType::Array(Box::new(DataType::Float32), 2);
+        let s = Series::new(
+            "a",
+            &[
+                Some(Series::new("", &[3.0f32, 4.0])),
+                None,
+                Some(Series::new("", &[Some(1.0f32), None])),
+            ],
+        )
+        .cast(&dtype)?;
+        let ca = s.array()?;
+
+        let out = l2_norm(ca)?;
+        assert_eq!(out.dtype(), &DataType::Float32);
+        assert_eq!(Vec::from(out.f32()?), &[Some(5.0), None, None]);
+
+        // a single vector is broadcast
+        let query = Series::new("", &[Series::new("", &[1.0f64, 0.0])]);
+        let out = dot(ca, &query)?;
+        assert_eq!(Vec::from(out.f32()?), &[Some(3.0), None, None]);
+        let out = cosine_similarity(ca, &query)?;
+        assert_eq!(Vec::from(out.f32()?), &[Some(0.6), None, None]);
+
+        assert!(dot(ca, &Series::new("", &[1.0, 2.0])).is_err());
+        Ok(())
+    }
+}
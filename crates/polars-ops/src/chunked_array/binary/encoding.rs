@@ -0,0 +1,130 @@
+//! Base32 ([RFC 4648](https://www.rfc-editor.org/rfc/rfc4648#section-6)) and
+//! base85 ([RFC 1924](https://www.rfc-editor.org/rfc/rfc1924)) codecs.
+//! The latter uses the same alphabet as Python's `base64.b85encode`.
+
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE85_ALPHABET: &[u8; 85] =
+    b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~";
+
+pub(super) fn base32_encode(input: &[u8]) -> String {
+    let mut out = String::with_capacity((input.len() + 4) / 5 * 8);
+    for chunk in input.chunks(5) {
+        let mut buf = [0u8; 5];
+        buf[..chunk.len()].copy_from_slice(chunk);
+        let bits = buf.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64);
+        // number of output characters that carry data
+        let n_chars = (chunk.len() * 8 + 4) / 5;
+        for i in 0..8 {
+            if i < n_chars {
+                let idx = (bits >> (35 - i * 5)) & 0x1f;
+                out.push(BASE32_ALPHABET[idx as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+pub(super) fn base32_decode(input: &[u8]) -> Option<Vec<u8>> {
+    let end = input
+        .iter()
+        .rposition(|b| *b != b'=')
+        .map(|i| i + 1)
+        .unwrap_or(0);
+    let input = &input[..end];
+    let mut out = Vec::with_capacity(input.len() * 5 / 8);
+    for chunk in input.chunks(8) {
+        let n_bytes = match chunk.len() {
+            8 => 5,
+            7 => 4,
+            5 => 3,
+            4 => 2,
+            2 => 1,
+            _ => return None,
+        };
+        let mut bits = 0u64;
+        for i in 0..8 {
+            let v = match chunk.get(i) {
+                Some(b) => BASE32_ALPHABET.iter().position(|a| a == b)? as u64,
+                None => 0,
+            };
+            bits = (bits << 5) | v;
+        }
+        out.extend_from_slice(&bits.to_be_bytes()[3..3 + n_bytes]);
+    }
+    Some(out)
+}
+
+pub(super) fn base85_encode(input: &[u8]) -> String {
+    let mut out = String::with_capacity((input.len() + 3) / 4 * 5);
+    for chunk in input.chunks(4) {
+        let mut buf = [0u8; 4];
+        buf[..chunk.len()].copy_from_slice(chunk);
+        let mut value = u32::from_be_bytes(buf);
+        let mut encoded = [0u8; 5];
+        for c in encoded.iter_mut().rev() {
+            *c = BASE85_ALPHABET[(value % 85) as usize];
+            value /= 85;
+        }
+        // a partial chunk of n bytes is written as n + 1 characters
+        for c in &encoded[..chunk.len() + 1] {
+            out.push(*c as char);
+        }
+    }
+    out
+}
+
+pub(super) fn base85_decode(input: &[u8]) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(input.len() / 5 * 4 + 4);
+    for chunk in input.chunks(5) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut value = 0u64;
+        for i in 0..5 {
+            let v = match chunk.get(i) {
+                Some(b) => BASE85_ALPHABET.iter().position(|a| a == b)? as u64,
+                // pad with the highest digit so truncation rounds correctly
+                None => 84,
+            };
+            value = value * 85 + v;
+        }
+        let value = u32::try_from(value).ok()?;
+        out.extend_from_slice(&value.to_be_bytes()[..chunk.len() - 1]);
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_base32_roundtrip() {
+        let cases: [(&[u8], &str); 6] = [
+            (b"", ""),
+            (b"f", "MY======"),
+            (b"fo", "MZXQ===="),
+            (b"foo", "MZXW6==="),
+            (b"foob", "MZXW6YQ="),
+            (b"fooba", "MZXW6YTB"),
+        ];
+        for (raw, encoded) in cases {
+            assert_eq!(base32_encode(raw), encoded);
+            assert_eq!(base32_decode(encoded.as_bytes()).unwrap(), raw);
+        }
+        assert!(base32_decode(b"MZXW6YT1").is_none());
+    }
+
+    #[test]
+    fn test_base85_roundtrip() {
+        assert_eq!(base85_encode(b"hello"), "Xk~0{Zv");
+        assert_eq!(base85_decode(b"Xk~0{Zv").unwrap(), b"hello");
+        for n in 0..9 {
+            let raw = (0..n).map(|i| (i * 37) as u8).collect::<Vec<_>>();
+            assert_eq!(base85_decode(base85_encode(&raw).as_bytes()).unwrap(), raw);
+        }
+        assert!(base85_decode(b"X").is_none());
+    }
+}
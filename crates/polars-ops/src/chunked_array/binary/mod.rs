@@ -1,7 +1,11 @@
+#[cfg(feature = "binary_encoding")]
+mod encoding;
 mod namespace;
+mod reinterpret;
 
 pub use namespace::*;
 use polars_core::prelude::*;
+pub use reinterpret::Endianness;
 
 pub trait AsBinary {
     fn as_binary(&self) -> &BinaryChunked;
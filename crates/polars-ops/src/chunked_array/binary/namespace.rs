@@ -6,7 +6,12 @@ use base64::engine::general_purpose;
 #[cfg(feature = "binary_encoding")]
 use base64::Engine as _;
 use memchr::memmem::find;
+use polars_arrow::export::arrow::compute::substring::substring;
+use polars_arrow::export::arrow::{self};
+use polars_arrow::kernels::string::binary_lengths;
 
+#[cfg(feature = "binary_encoding")]
+use super::encoding;
 use super::*;
 
 pub trait BinaryNameSpaceImpl: AsBinary {
@@ -46,6 +51,60 @@ pub trait BinaryNameSpaceImpl: AsBinary {
         out
     }
 
+    /// Get the length of the binary values in bytes.
+    fn bin_lengths(&self) -> UInt32Chunked {
+        let ca = self.as_binary();
+        ca.apply_kernel_cast(&binary_lengths)
+    }
+
+    /// Slice the binary values.
+    /// Takes `length` bytes starting at `offset`, or all remaining bytes if `length` is `None`.
+    /// `offset` can be negative, in which case the start counts from the end of the value.
+    fn bin_slice(&self, offset: i64, length: Option<u64>) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        let chunks = ca
+            .downcast_iter()
+            .map(|c| substring(c, offset, &length))
+            .collect::<arrow::error::Result<_>>()?;
+
+        unsafe { Ok(BinaryChunked::from_chunks(ca.name(), chunks)) }
+    }
+
+    /// Decode the binary values as fixed-width numbers of type `dtype`.
+    /// Values whose length does not match the width of `dtype` become null.
+    fn bin_reinterpret(&self, dtype: &DataType, endianness: Endianness) -> PolarsResult<Series> {
+        let ca = self.as_binary();
+        super::reinterpret::reinterpret(ca, dtype, endianness)
+    }
+
+    /// Decode the binary values as UTF-8.
+    /// If `strict` is `false`, invalid values become null instead of raising an error.
+    fn decode_utf8(&self, strict: bool) -> PolarsResult<Utf8Chunked> {
+        let ca = self.as_binary();
+        let mut out: Utf8Chunked = if strict {
+            ca.into_iter()
+                .map(|opt_b| {
+                    opt_b
+                        .map(|b| {
+                            std::str::from_utf8(b).map_err(|_| {
+                                polars_err!(
+                                    ComputeError:
+                                    "invalid `utf8` sequence found; try setting `strict=false` to ignore"
+                                )
+                            })
+                        })
+                        .transpose()
+                })
+                .collect::<PolarsResult<_>>()?
+        } else {
+            ca.into_iter()
+                .map(|opt_b| opt_b.and_then(|b| std::str::from_utf8(b).ok()))
+                .collect()
+        };
+        out.rename(ca.name());
+        Ok(out)
+    }
+
     #[cfg(feature = "binary_encoding")]
     fn hex_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
         let ca = self.as_binary();
@@ -103,6 +162,66 @@ pub trait BinaryNameSpaceImpl: AsBinary {
                 .unwrap()
         }
     }
+
+    #[cfg(feature = "binary_encoding")]
+    fn base32_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        if strict {
+            ca.try_apply(|s| {
+                let bytes = encoding::base32_decode(s).ok_or_else(|| {
+                    polars_err!(
+                        ComputeError:
+                        "invalid `base32` encoding found; try setting `strict=false` to ignore"
+                    )
+                })?;
+                Ok(bytes.into())
+            })
+        } else {
+            Ok(ca.apply_on_opt(|opt_s| {
+                opt_s.and_then(|s| encoding::base32_decode(s).map(Cow::Owned))
+            }))
+        }
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn base32_encode(&self) -> Series {
+        let ca = self.as_binary();
+        unsafe {
+            ca.apply(|s| encoding::base32_encode(s).into_bytes().into())
+                .cast_unchecked(&DataType::Utf8)
+                .unwrap()
+        }
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn base85_decode(&self, strict: bool) -> PolarsResult<BinaryChunked> {
+        let ca = self.as_binary();
+        if strict {
+            ca.try_apply(|s| {
+                let bytes = encoding::base85_decode(s).ok_or_else(|| {
+                    polars_err!(
+                        ComputeError:
+                        "invalid `base85` encoding found; try setting `strict=false` to ignore"
+                    )
+                })?;
+                Ok(bytes.into())
+            })
+        } else {
+            Ok(ca.apply_on_opt(|opt_s| {
+                opt_s.and_then(|s| encoding::base85_decode(s).map(Cow::Owned))
+            }))
+        }
+    }
+
+    #[cfg(feature = "binary_encoding")]
+    fn base85_encode(&self) -> Series {
+        let ca = self.as_binary();
+        unsafe {
+            ca.apply(|s| encoding::base85_encode(s).into_bytes().into())
+                .cast_unchecked(&DataType::Utf8)
+                .unwrap()
+        }
+    }
 }
 
 impl BinaryNameSpaceImpl for BinaryChunked {}
@@ -0,0 +1,58 @@
+use arrow::types::NativeType;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use super::*;
+
+/// Byte order used to decode fixed-width values from binary data.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Endianness {
+    #[default]
+    Little,
+    Big,
+}
+
+fn reinterpret_numeric<T>(ca: &BinaryChunked, endianness: Endianness) -> ChunkedArray<T>
+where
+    T: PolarsNumericType,
+{
+    let f = |b: &[u8]| -> Option<T::Native> {
+        let bytes = <T::Native as NativeType>::Bytes::try_from(b).ok()?;
+        Some(match endianness {
+            Endianness::Little => <T::Native as NativeType>::from_le_bytes(bytes),
+            Endianness::Big => <T::Native as NativeType>::from_be_bytes(bytes),
+        })
+    };
+    let mut out: ChunkedArray<T> = ca.into_iter().map(|opt_b| opt_b.and_then(f)).collect();
+    out.rename(ca.name());
+    out
+}
+
+pub(super) fn reinterpret(
+    ca: &BinaryChunked,
+    dtype: &DataType,
+    endianness: Endianness,
+) -> PolarsResult<Series> {
+    use DataType::*;
+    let out = match dtype {
+        #[cfg(feature = "dtype-i8")]
+        Int8 => reinterpret_numeric::<Int8Type>(ca, endianness).into_series(),
+        #[cfg(feature = "dtype-i16")]
+        Int16 => reinterpret_numeric::<Int16Type>(ca, endianness).into_series(),
+        Int32 => reinterpret_numeric::<Int32Type>(ca, endianness).into_series(),
+        Int64 => reinterpret_numeric::<Int64Type>(ca, endianness).into_series(),
+        #[cfg(feature = "dtype-u8")]
+        UInt8 => reinterpret_numeric::<UInt8Type>(ca, endianness).into_series(),
+        #[cfg(feature = "dtype-u16")]
+        UInt16 => reinterpret_numeric::<UInt16Type>(ca, endianness).into_series(),
+        UInt32 => reinterpret_numeric::<UInt32Type>(ca, endianness).into_series(),
+        UInt64 => reinterpret_numeric::<UInt64Type>(ca, endianness).into_series(),
+        Float32 => reinterpret_numeric::<Float32Type>(ca, endianness).into_series(),
+        Float64 => reinterpret_numeric::<Float64Type>(ca, endianness).into_series(),
+        dt => polars_bail!(
+            InvalidOperation: "cannot reinterpret binary data as {}; expected a fixed-width numeric type", dt
+        ),
+    };
+    Ok(out)
+}
@@ -1,10 +1,17 @@
 use polars_arrow::kernels::replace_time_zone as replace_time_zone_kernel;
+use polars_arrow::time_zone::{Ambiguous, NonExistent};
 use polars_core::prelude::*;
 
+/// Replace the time zone of `ca` without converting the time, i.e. the wall clock
+/// time of the datetimes stays the same.
+///
+/// `ambiguous` and `non_existent` tell how to deal with local datetimes that occur twice,
+/// or not at all, in the new time zone around daylight saving time transitions.
 pub fn replace_time_zone(
     ca: &DatetimeChunked,
     time_zone: Option<&str>,
-    use_earliest: Option<bool>,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
 ) -> PolarsResult<DatetimeChunked> {
     let out: PolarsResult<_> = {
         let from = ca.time_zone().as_deref().unwrap_or("UTC");
@@ -12,13 +19,23 @@ pub fn replace_time_zone(
         let chunks = ca
             .downcast_iter()
             .map(|arr| {
-                replace_time_zone_kernel(arr, ca.time_unit().to_arrow(), from, to, use_earliest)
+                replace_time_zone_kernel(
+                    arr,
+                    ca.time_unit().to_arrow(),
+                    from,
+                    to,
+                    ambiguous,
+                    non_existent,
+                )
             })
             .collect::<PolarsResult<_>>()?;
         let out = unsafe { ChunkedArray::from_chunks(ca.name(), chunks) };
         Ok(out.into_datetime(ca.time_unit(), time_zone.map(|x| x.to_string())))
     };
     let mut out = out?;
-    out.set_sorted_flag(ca.is_sorted_flag());
+    // datetimes that were set to null break the order
+    if out.null_count() == ca.null_count() {
+        out.set_sorted_flag(ca.is_sorted_flag());
+    }
     Ok(out)
 }
@@ -83,6 +83,20 @@ where
     }
 }
 
+#[inline]
+fn previous_interp<T: Copy>(low: T, _high: T, steps: IdxSize, _steps_n: T, av: &mut Vec<T>) {
+    for _ in 1..steps {
+        av.push(low)
+    }
+}
+
+#[inline]
+fn next_interp<T: Copy>(_low: T, high: T, steps: IdxSize, _steps_n: T, av: &mut Vec<T>) {
+    for _ in 1..steps {
+        av.push(high)
+    }
+}
+
 fn interpolate_impl<T, I>(chunked_arr: &ChunkedArray<T>, interpolation_branch: I) -> ChunkedArray<T>
 where
     T: PolarsNumericType,
@@ -168,7 +182,7 @@ where
 fn interpolate_nearest(s: &Series) -> Series {
     match s.dtype() {
         #[cfg(feature = "dtype-categorical")]
-        DataType::Categorical(_) => s.clone(),
+        DataType::Categorical(_) | DataType::Enum(_) => s.clone(),
         DataType::Binary => s.clone(),
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(_) => s.clone(),
@@ -188,10 +202,279 @@ fn interpolate_nearest(s: &Series) -> Series {
     }
 }
 
+fn interpolate_step(s: &Series, next: bool) -> Series {
+    match s.dtype() {
+        #[cfg(feature = "dtype-categorical")]
+        DataType::Categorical(_) | DataType::Enum(_) => s.clone(),
+        DataType::Binary => s.clone(),
+        #[cfg(feature = "dtype-struct")]
+        DataType::Struct(_) => s.clone(),
+        DataType::List(_) => s.clone(),
+        _ => {
+            let logical = s.dtype();
+            let s = s.to_physical_repr();
+
+            macro_rules! dispatch {
+                ($ca:expr) => {{
+                    if next {
+                        interpolate_impl($ca, next_interp).into_series()
+                    } else {
+                        interpolate_impl($ca, previous_interp).into_series()
+                    }
+                }};
+            }
+            let out = downcast_as_macro_arg_physical!(s, dispatch);
+            out.cast(logical).unwrap()
+        }
+    }
+}
+
+/// Evaluate `f(k, x)` for every (sorted) `query` position, where `k` is the index
+/// of the known position directly left of `x`.
+fn eval_segments<F>(xs: &[f64], query: &[f64], f: F) -> Vec<f64>
+where
+    F: Fn(usize, f64) -> f64,
+{
+    let mut k = 0;
+    query
+        .iter()
+        .map(|&x| {
+            while k + 2 < xs.len() && xs[k + 1] < x {
+                k += 1;
+            }
+            f(k, x)
+        })
+        .collect()
+}
+
+fn linear_at(xs: &[f64], ys: &[f64], query: &[f64]) -> Vec<f64> {
+    eval_segments(xs, query, |k, x| {
+        ys[k] + (ys[k + 1] - ys[k]) * (x - xs[k]) / (xs[k + 1] - xs[k])
+    })
+}
+
+/// Natural cubic spline, i.e. with a zero second derivative at the end points.
+fn cubic_spline_at(xs: &[f64], ys: &[f64], query: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+    if n < 3 {
+        return linear_at(xs, ys, query);
+    }
+    let h = xs.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+
+    // solve the tridiagonal system of the second derivatives with the Thomas algorithm
+    let mut c_prime = vec![0.0; n];
+    let mut d_prime = vec![0.0; n];
+    for i in 1..n - 1 {
+        let (a, b, c) = (h[i - 1], 2.0 * (h[i - 1] + h[i]), h[i]);
+        let d = 6.0 * ((ys[i + 1] - ys[i]) / h[i] - (ys[i] - ys[i - 1]) / h[i - 1]);
+        let denom = b - a * c_prime[i - 1];
+        c_prime[i] = c / denom;
+        d_prime[i] = (d - a * d_prime[i - 1]) / denom;
+    }
+    let mut m = vec![0.0; n];
+    for i in (1..n - 1).rev() {
+        m[i] = d_prime[i] - c_prime[i] * m[i + 1];
+    }
+
+    eval_segments(xs, query, |k, x| {
+        let (t0, t1, h) = (x - xs[k], xs[k + 1] - x, h[k]);
+        m[k] * t1.powi(3) / (6.0 * h)
+            + m[k + 1] * t0.powi(3) / (6.0 * h)
+            + (ys[k] / h - m[k] * h / 6.0) * t1
+            + (ys[k + 1] / h - m[k + 1] * h / 6.0) * t0
+    })
+}
+
+/// Cubic Hermite spline with the derivatives `d` at the known positions.
+fn hermite_at(xs: &[f64], ys: &[f64], d: &[f64], query: &[f64]) -> Vec<f64> {
+    eval_segments(xs, query, |k, x| {
+        let h = xs[k + 1] - xs[k];
+        let t = (x - xs[k]) / h;
+        let (t2, t3) = (t * t, t * t * t);
+        (2.0 * t3 - 3.0 * t2 + 1.0) * ys[k]
+            + (t3 - 2.0 * t2 + t) * h * d[k]
+            + (-2.0 * t3 + 3.0 * t2) * ys[k + 1]
+            + (t3 - t2) * h * d[k + 1]
+    })
+}
+
+/// Shape preserving three point estimate of the derivative at an end point.
+fn pchip_edge(h0: f64, h1: f64, delta0: f64, delta1: f64) -> f64 {
+    // unlike `f64::signum`, zero has no sign here
+    let sign = |v: f64| if v == 0.0 { 0.0 } else { v.signum() };
+    let d = ((2.0 * h0 + h1) * delta0 - h0 * delta1) / (h0 + h1);
+    if sign(d) != sign(delta0) {
+        0.0
+    } else if sign(delta0) != sign(delta1) && d.abs() > 3.0 * delta0.abs() {
+        3.0 * delta0
+    } else {
+        d
+    }
+}
+
+/// Piecewise cubic Hermite interpolation with the monotone derivatives of Fritsch and Carlson.
+fn pchip_at(xs: &[f64], ys: &[f64], query: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+    if n < 3 {
+        return linear_at(xs, ys, query);
+    }
+    let h = xs.windows(2).map(|w| w[1] - w[0]).collect::<Vec<_>>();
+    let delta = (0..n - 1)
+        .map(|k| (ys[k + 1] - ys[k]) / h[k])
+        .collect::<Vec<_>>();
+
+    let mut d = vec![0.0; n];
+    for k in 1..n - 1 {
+        if delta[k - 1] * delta[k] > 0.0 {
+            let w1 = 2.0 * h[k] + h[k - 1];
+            let w2 = h[k] + 2.0 * h[k - 1];
+            d[k] = (w1 + w2) / (w1 / delta[k - 1] + w2 / delta[k]);
+        }
+    }
+    d[0] = pchip_edge(h[0], h[1], delta[0], delta[1]);
+    d[n - 1] = pchip_edge(h[n - 2], h[n - 3], delta[n - 2], delta[n - 3]);
+    hermite_at(xs, ys, &d, query)
+}
+
+/// Akima spline, which limits overshoots by weighting the slopes of the neighbouring segments.
+fn akima_at(xs: &[f64], ys: &[f64], query: &[f64]) -> Vec<f64> {
+    let n = xs.len();
+    if n < 3 {
+        return linear_at(xs, ys, query);
+    }
+    // the slopes are extended with two extrapolated slopes on both sides,
+    // slope `k` of the segments is at `m[k + 2]`
+    let mut m = vec![0.0; n + 3];
+    for k in 0..n - 1 {
+        m[k + 2] = (ys[k + 1] - ys[k]) / (xs[k + 1] - xs[k]);
+    }
+    m[1] = 2.0 * m[2] - m[3];
+    m[0] = 2.0 * m[1] - m[2];
+    m[n + 1] = 2.0 * m[n] - m[n - 1];
+    m[n + 2] = 2.0 * m[n + 1] - m[n];
+
+    let d = (0..n)
+        .map(|i| {
+            let w1 = (m[i + 3] - m[i + 2]).abs();
+            let w2 = (m[i + 1] - m[i]).abs();
+            if w1 + w2 == 0.0 {
+                (m[i + 1] + m[i + 2]) / 2.0
+            } else {
+                (w1 * m[i + 1] + w2 * m[i + 2]) / (w1 + w2)
+            }
+        })
+        .collect::<Vec<_>>();
+    hermite_at(xs, ys, &d, query)
+}
+
+/// Lagrange polynomial of degree `order` through the `order + 1` known positions
+/// around every gap.
+fn polynomial_at(xs: &[f64], ys: &[f64], query: &[f64], order: usize) -> Vec<f64> {
+    let n_points = std::cmp::min(order + 1, xs.len());
+    eval_segments(xs, query, |k, x| {
+        let start = std::cmp::min(
+            (k + 1).saturating_sub((n_points + 1) / 2),
+            xs.len() - n_points,
+        );
+        let points = start..start + n_points;
+        points
+            .clone()
+            .map(|i| {
+                let basis = points
+                    .clone()
+                    .filter(|&j| j != i)
+                    .map(|j| (x - xs[j]) / (xs[i] - xs[j]))
+                    .product::<f64>();
+                ys[i] * basis
+            })
+            .sum()
+    })
+}
+
+/// Interpolate the nulls between the first and last non-null value with `interpolant`,
+/// which receives the known positions and values and the positions of the nulls.
+fn interpolate_f64<F>(s: &Series, interpolant: F) -> Series
+where
+    F: Fn(&[f64], &[f64], &[f64]) -> Vec<f64>,
+{
+    let logical = s.dtype();
+    let physical = logical.to_physical();
+    if !physical.is_numeric() || s.null_count() == 0 || s.null_count() == s.len() {
+        return s.clone();
+    }
+    let ca = s.to_physical_repr().cast(&DataType::Float64).unwrap();
+    let ca = ca.f64().unwrap();
+    let mut out = ca.into_iter().collect::<Vec<_>>();
+
+    let (xs, ys): (Vec<_>, Vec<_>) = out
+        .iter()
+        .enumerate()
+        .filter_map(|(i, v)| v.map(|v| (i as f64, v)))
+        .unzip();
+    let first = xs[0] as usize;
+    let last = xs[xs.len() - 1] as usize;
+    let query = (first..last)
+        .filter(|&i| out[i].is_none())
+        .map(|i| i as f64)
+        .collect::<Vec<_>>();
+
+    let values = interpolant(&xs, &ys, &query);
+    for (x, v) in query.into_iter().zip(values) {
+        out[x as usize] = Some(v)
+    }
+    Float64Chunked::new(ca.name(), &out)
+        .into_series()
+        .cast(&physical)
+        .unwrap()
+        .cast(logical)
+        .unwrap()
+}
+
+/// Set the interpolated values that are further than `limit` steps away from
+/// a non-null value in the `direction` back to null.
+fn apply_limit(
+    original: &Series,
+    filled: Series,
+    limit: IdxSize,
+    direction: LimitDirection,
+) -> Series {
+    let is_valid = original.is_not_null();
+    let is_valid = is_valid.into_no_null_iter().collect::<Vec<_>>();
+    let mut keep = vec![true; is_valid.len()];
+    let limit = limit as usize;
+
+    let mut i = 0;
+    while i < is_valid.len() {
+        if is_valid[i] {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < is_valid.len() && !is_valid[i] {
+            i += 1;
+        }
+        let len = i - start;
+        for (k, keep) in keep[start..i].iter_mut().enumerate() {
+            *keep = match direction {
+                LimitDirection::Forward => k < limit,
+                LimitDirection::Backward => len - 1 - k < limit,
+                LimitDirection::Both => k < limit || len - 1 - k < limit,
+            };
+        }
+    }
+    // a null index takes a null
+    let idx = keep
+        .iter()
+        .enumerate()
+        .map(|(i, keep)| keep.then_some(i as IdxSize))
+        .collect::<IdxCa>();
+    filled.take(&idx).unwrap()
+}
+
 fn interpolate_linear(s: &Series) -> Series {
     match s.dtype() {
         #[cfg(feature = "dtype-categorical")]
-        DataType::Categorical(_) => s.clone(),
+        DataType::Categorical(_) | DataType::Enum(_) => s.clone(),
         DataType::Binary => s.clone(),
         #[cfg(feature = "dtype-struct")]
         DataType::Struct(_) => s.clone(),
@@ -235,18 +518,194 @@ where
     interpolate_impl(ca, signed_interp::<T::Native>).into_series()
 }
 
+/// Fill the null values between the anchors `(x0, y0)` and `(x1, y1)` with the
+/// values of the anchors, weighted by the distance of the x value of a null to them.
+fn interpolate_by_impl<F>(
+    y: &Float64Chunked,
+    x: &Float64Chunked,
+    interpolation_branch: F,
+) -> Float64Chunked
+where
+    F: Fn(f64, f64, f64, f64, f64) -> f64,
+{
+    let x = x.into_iter().collect::<Vec<_>>();
+    let mut out = y.into_iter().collect::<Vec<_>>();
+
+    // only rows with a value and a position can be interpolated from
+    let mut low = None;
+    for i in 0..out.len() {
+        let (Some(y1), Some(x1)) = (out[i], x[i]) else {
+            continue;
+        };
+        if let Some(low) = low {
+            let (y0, x0) = (out[low].unwrap(), x[low].unwrap());
+            for j in low + 1..i {
+                if let (None, Some(xj)) = (out[j], x[j]) {
+                    out[j] = Some(interpolation_branch(y0, y1, x0, x1, xj))
+                }
+            }
+        }
+        low = Some(i);
+    }
+    Float64Chunked::new(y.name(), &out)
+}
+
+fn time_itp(y0: f64, y1: f64, x0: f64, x1: f64, x: f64) -> f64 {
+    if x1 == x0 {
+        y0
+    } else {
+        y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+    }
+}
+
+fn nearest_by_itp(y0: f64, y1: f64, x0: f64, x1: f64, x: f64) -> f64 {
+    // ties are resolved to the upper value, like `nearest_itp`
+    if (x1 - x) > (x - x0) {
+        y0
+    } else {
+        y1
+    }
+}
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum InterpolationMethod {
     Linear,
     Nearest,
+    /// Linear interpolation weighted by the distances of an x column, e.g. a time column.
+    /// This needs the x values, so it is only supported by [`interpolate_by`].
+    Time,
+    /// Fill with the previous non-null value.
+    Previous,
+    /// Fill with the next non-null value.
+    Next,
+    /// Natural cubic spline through all non-null values.
+    Cubic,
+    /// Monotone piecewise cubic Hermite interpolation, which doesn't overshoot the data.
+    Pchip,
+    /// Akima spline, which is less sensitive to outliers than a cubic spline.
+    Akima,
+    /// Polynomial of the given order through the `order + 1` non-null values around a gap.
+    Polynomial(usize),
+}
+
+/// Which nulls of a gap are filled when the interpolation has a `limit`.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LimitDirection {
+    /// Fill the first `limit` nulls after a non-null value.
+    #[default]
+    Forward,
+    /// Fill the last `limit` nulls before a non-null value.
+    Backward,
+    /// Fill the `limit` nulls at both sides of a gap.
+    Both,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct InterpolationOptions {
+    pub method: InterpolationMethod,
+    /// Maximum number of consecutive nulls to fill.
+    pub limit: Option<IdxSize>,
+    pub limit_direction: LimitDirection,
 }
 
-pub fn interpolate(s: &Series, method: InterpolationMethod) -> Series {
-    match method {
+impl Default for InterpolationOptions {
+    fn default() -> Self {
+        Self {
+            method: InterpolationMethod::Linear,
+            limit: None,
+            limit_direction: LimitDirection::default(),
+        }
+    }
+}
+
+impl From<InterpolationMethod> for InterpolationOptions {
+    fn from(method: InterpolationMethod) -> Self {
+        Self {
+            method,
+            ..Default::default()
+        }
+    }
+}
+
+/// Interpolate the null values of `s`.
+///
+/// Only the nulls between the first and the last non-null value are filled.
+/// The result has the dtype of `s`.
+pub fn interpolate(s: &Series, method: InterpolationMethod) -> PolarsResult<Series> {
+    let out = match method {
         InterpolationMethod::Linear => interpolate_linear(s),
+        InterpolationMethod::Time => polars_bail!(
+            InvalidOperation: "interpolation method 'time' needs the time of every value, use 'interpolate_by'"
+        ),
         InterpolationMethod::Nearest => interpolate_nearest(s),
+        InterpolationMethod::Previous => interpolate_step(s, false),
+        InterpolationMethod::Next => interpolate_step(s, true),
+        InterpolationMethod::Cubic => interpolate_f64(s, cubic_spline_at),
+        InterpolationMethod::Pchip => interpolate_f64(s, pchip_at),
+        InterpolationMethod::Akima => interpolate_f64(s, akima_at),
+        InterpolationMethod::Polynomial(order) => {
+            interpolate_f64(s, |xs, ys, query| polynomial_at(xs, ys, query, order))
+        }
+    };
+    Ok(out)
+}
+
+/// Interpolate the null values of `s`, filling at most `limit` consecutive nulls.
+pub fn interpolate_with_options(
+    s: &Series,
+    options: &InterpolationOptions,
+) -> PolarsResult<Series> {
+    let out = interpolate(s, options.method)?;
+    Ok(match options.limit {
+        Some(limit) if out.null_count() != s.null_count() => {
+            apply_limit(s, out, limit, options.limit_direction)
+        }
+        _ => out,
+    })
+}
+
+/// Interpolate the null values of `s` at the positions given by the `by` column.
+///
+/// Unlike [`interpolate`], the values are not assumed to be equally spaced: a null
+/// is filled by the line (or the nearest value) between its neighbours, measured
+/// in the units of `by`. [`InterpolationMethod::Linear`] and [`InterpolationMethod::Time`]
+/// are the same here, the other methods are not supported. Rows where `by` is null are not
+/// filled.
+pub fn interpolate_by(
+    s: &Series,
+    by: &Series,
+    method: InterpolationMethod,
+) -> PolarsResult<Series> {
+    let itp = match method {
+        InterpolationMethod::Linear | InterpolationMethod::Time => time_itp,
+        InterpolationMethod::Nearest => nearest_by_itp,
+        method => polars_bail!(
+            InvalidOperation: "interpolation method {:?} is not supported by 'interpolate_by'", method
+        ),
+    };
+    polars_ensure!(
+        s.len() == by.len(),
+        ShapeMismatch: "the interpolated column has length {}, but the 'by' column has length {}",
+        s.len(), by.len()
+    );
+    polars_ensure!(
+        by.dtype().to_physical().is_numeric(),
+        InvalidOperation: "cannot interpolate by a column of dtype {}", by.dtype()
+    );
+    let logical = s.dtype();
+    let physical = logical.to_physical();
+    if !physical.is_numeric() || s.null_count() == 0 || s.null_count() == s.len() {
+        return Ok(s.clone());
     }
+
+    let y = s.to_physical_repr().cast(&DataType::Float64)?;
+    let x = by.to_physical_repr().cast(&DataType::Float64)?;
+    let (y, x) = (y.f64().unwrap(), x.f64().unwrap());
+    let out = interpolate_by_impl(y, x, itp);
+    out.into_series().cast(&physical)?.cast(logical)
 }
 
 #[cfg(test)]
@@ -256,7 +715,7 @@ mod test {
     #[test]
     fn test_interpolate() {
         let ca = UInt32Chunked::new("", &[Some(1), None, None, Some(4), Some(5)]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.u32().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -264,7 +723,7 @@ mod test {
         );
 
         let ca = UInt32Chunked::new("", &[None, Some(1), None, None, Some(4), Some(5)]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.u32().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -272,14 +731,14 @@ mod test {
         );
 
         let ca = UInt32Chunked::new("", &[None, Some(1), None, None, Some(4), Some(5), None]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.u32().unwrap();
         assert_eq!(
             Vec::from(out),
             &[None, Some(1), Some(2), Some(3), Some(4), Some(5), None]
         );
         let ca = UInt32Chunked::new("", &[None, Some(1), None, None, Some(4), Some(5), None]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Nearest);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Nearest).unwrap();
         let out = out.u32().unwrap();
         assert_eq!(
             Vec::from(out),
@@ -287,10 +746,110 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_interpolate_methods() {
+        let s =
+            Float64Chunked::new("", &[None, Some(1.0), None, None, Some(4.0), None]).into_series();
+        let out = interpolate(&s, InterpolationMethod::Previous).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[None, Some(1.0), Some(1.0), Some(1.0), Some(4.0), None]
+        );
+        let out = interpolate(&s, InterpolationMethod::Next).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[None, Some(1.0), Some(4.0), Some(4.0), Some(4.0), None]
+        );
+        // the time of the values is needed to interpolate by time
+        assert!(interpolate(&s, InterpolationMethod::Time).is_err());
+
+        // a natural spline through points on a line is that line
+        let s = Float64Chunked::new("", &[Some(0.0), None, Some(2.0), None, None, Some(5.0)])
+            .into_series();
+        let out = interpolate(&s, InterpolationMethod::Cubic).unwrap();
+        let out = out.f64().unwrap();
+        for (i, v) in out.into_no_null_iter().enumerate() {
+            assert!((v - i as f64).abs() < 1e-12);
+        }
+
+        // monotone data stays monotone
+        let s =
+            Float64Chunked::new("", &[Some(0.0), None, Some(1.0), None, Some(1.0)]).into_series();
+        let out = interpolate(&s, InterpolationMethod::Pchip).unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(0.0), Some(0.6875), Some(1.0), Some(1.0), Some(1.0)]
+        );
+
+        let s =
+            Float64Chunked::new("", &[Some(0.0), None, Some(4.0), None, Some(16.0)]).into_series();
+        let out = interpolate(&s, InterpolationMethod::Polynomial(2)).unwrap();
+        let out = out.f64().unwrap();
+        assert!((out.get(1).unwrap() - 1.0).abs() < 1e-12);
+        assert!((out.get(3).unwrap() - 9.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_interpolate_limit() {
+        let s = Int32Chunked::new("", &[Some(1), None, None, None, Some(5)]).into_series();
+        let mut options = InterpolationOptions {
+            limit: Some(1),
+            ..Default::default()
+        };
+        let out = interpolate_with_options(&s, &options).unwrap();
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[Some(1), Some(2), None, None, Some(5)]
+        );
+        options.limit_direction = LimitDirection::Backward;
+        let out = interpolate_with_options(&s, &options).unwrap();
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[Some(1), None, None, Some(4), Some(5)]
+        );
+        options.limit_direction = LimitDirection::Both;
+        let out = interpolate_with_options(&s, &options).unwrap();
+        assert_eq!(
+            Vec::from(out.i32().unwrap()),
+            &[Some(1), Some(2), None, Some(4), Some(5)]
+        );
+    }
+
+    #[test]
+    fn test_interpolate_by() {
+        let ca = Float64Chunked::new("", &[Some(1.0), None, None, Some(7.0), None]);
+        let by = Int64Chunked::new("", &[0, 1, 4, 6, 7]);
+        let out = interpolate_by(
+            &ca.clone().into_series(),
+            &by.clone().into_series(),
+            InterpolationMethod::Time,
+        )
+        .unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(1.0), Some(2.0), Some(5.0), Some(7.0), None]
+        );
+
+        let out = interpolate_by(
+            &ca.into_series(),
+            &by.into_series(),
+            InterpolationMethod::Nearest,
+        )
+        .unwrap();
+        assert_eq!(
+            Vec::from(out.f64().unwrap()),
+            &[Some(1.0), Some(1.0), Some(7.0), Some(7.0), None]
+        );
+
+        let s = Float64Chunked::new("", &[Some(1.0), None]).into_series();
+        let by = Int64Chunked::new("", &[0, 1]).into_series();
+        assert!(interpolate_by(&s, &by, InterpolationMethod::Cubic).is_err());
+    }
+
     #[test]
     fn test_interpolate_decreasing_unsigned() {
         let ca = UInt32Chunked::new("", &[Some(4), None, None, Some(1)]);
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.u32().unwrap();
         assert_eq!(Vec::from(out), &[Some(4), Some(3), Some(2), Some(1)])
     }
@@ -315,7 +874,7 @@ mod test {
                 Some(4660f32),
             ],
         );
-        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear);
+        let out = interpolate(&ca.into_series(), InterpolationMethod::Linear).unwrap();
         let out = out.f32().unwrap();
 
         assert_eq!(
@@ -1,7 +1,36 @@
 #[cfg(feature = "nightly")]
 use core::unicode::conversions;
+#[cfg(feature = "string_unicode")]
+use std::borrow::Cow;
 
+#[cfg(feature = "string_unicode")]
+use polars_arrow::utils::CustomIterTools;
+#[cfg(feature = "string_unicode")]
+use polars_core::prelude::UInt32Chunked;
 use polars_core::prelude::Utf8Chunked;
+#[cfg(all(feature = "serde", feature = "string_unicode"))]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "string_unicode")]
+use unicode_normalization::{
+    is_nfc_quick, is_nfd_quick, is_nfkc_quick, is_nfkd_quick, IsNormalized, UnicodeNormalization,
+};
+#[cfg(feature = "string_unicode")]
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Unicode normalization form, see <https://unicode.org/reports/tr15/>.
+#[cfg(feature = "string_unicode")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UnicodeForm {
+    /// Canonical decomposition followed by canonical composition.
+    NFC,
+    /// Compatibility decomposition followed by canonical composition.
+    NFKC,
+    /// Canonical decomposition.
+    NFD,
+    /// Compatibility decomposition.
+    NFKD,
+}
 
 // inlined from std
 fn convert_while_ascii(b: &[u8], convert: fn(&u8) -> u8, out: &mut Vec<u8>) {
@@ -259,3 +288,111 @@ pub(super) fn to_titlecase<'a>(ca: &'a Utf8Chunked) -> Utf8Chunked {
     };
     ca.apply_mut(f)
 }
+
+#[cfg(feature = "string_unicode")]
+pub(super) fn normalize(ca: &Utf8Chunked, form: UnicodeForm) -> Utf8Chunked {
+    ca.apply(|s| {
+        // most strings are already normalized, the quick check avoids the allocation
+        let quick_check = match form {
+            UnicodeForm::NFC => is_nfc_quick(s.chars()),
+            UnicodeForm::NFKC => is_nfkc_quick(s.chars()),
+            UnicodeForm::NFD => is_nfd_quick(s.chars()),
+            UnicodeForm::NFKD => is_nfkd_quick(s.chars()),
+        };
+        if quick_check == IsNormalized::Yes {
+            return Cow::Borrowed(s);
+        }
+        let normalized = match form {
+            UnicodeForm::NFC => s.nfc().collect(),
+            UnicodeForm::NFKC => s.nfkc().collect(),
+            UnicodeForm::NFD => s.nfd().collect(),
+            UnicodeForm::NFKD => s.nfkd().collect(),
+        };
+        Cow::Owned(normalized)
+    })
+}
+
+/// Locale-independent case folding for caseless comparison. Every char is mapped to its
+/// uppercase and then to its lowercase equivalent without context, so that e.g. 'ß' folds to
+/// "ss" and both 'ς' and 'Σ' fold to 'σ'.
+#[cfg(feature = "string_unicode")]
+pub(super) fn casefold(ca: &Utf8Chunked) -> Utf8Chunked {
+    ca.apply(|s| {
+        if s.is_ascii() {
+            Cow::Owned(s.to_ascii_lowercase())
+        } else {
+            Cow::Owned(
+                s.chars()
+                    .flat_map(char::to_uppercase)
+                    .flat_map(char::to_lowercase)
+                    .collect(),
+            )
+        }
+    })
+}
+
+/// Every byte is a grapheme cluster; only "\r\n" clusters multiple ascii chars.
+#[cfg(feature = "string_unicode")]
+fn is_single_byte_graphemes(s: &str) -> bool {
+    s.is_ascii() && !s.contains('\r')
+}
+
+#[cfg(feature = "string_unicode")]
+pub(super) fn n_graphemes(ca: &Utf8Chunked) -> UInt32Chunked {
+    let mut out: UInt32Chunked = ca
+        .into_iter()
+        .map(|opt_s| {
+            opt_s.map(|s| {
+                if is_single_byte_graphemes(s) {
+                    s.len() as u32
+                } else {
+                    s.graphemes(true).count() as u32
+                }
+            })
+        })
+        .collect_trusted();
+    out.rename(ca.name());
+    out
+}
+
+/// Reverse the strings by extended grapheme cluster, so that combining marks stay attached to
+/// their base char.
+#[cfg(feature = "string_unicode")]
+pub(super) fn reverse(ca: &Utf8Chunked) -> Utf8Chunked {
+    ca.apply(|s| {
+        if is_single_byte_graphemes(s) {
+            Cow::Owned(s.chars().rev().collect())
+        } else {
+            Cow::Owned(s.graphemes(true).rev().collect())
+        }
+    })
+}
+
+#[cfg(all(test, feature = "string_unicode"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_unicode_ops() {
+        // "é" as 'e' followed by a combining acute accent
+        let ca = Utf8Chunked::new("a", &[Some("cafe\u{301}"), None, Some("ﬁ")]);
+
+        let out = normalize(&ca, UnicodeForm::NFC);
+        assert_eq!(Vec::from(&out), &[Some("caf\u{e9}"), None, Some("ﬁ")]);
+        let out = normalize(&ca, UnicodeForm::NFKC);
+        assert_eq!(Vec::from(&out), &[Some("caf\u{e9}"), None, Some("fi")]);
+
+        let out = n_graphemes(&ca);
+        assert_eq!(Vec::from(&out), &[Some(4), None, Some(1)]);
+
+        let out = reverse(&ca);
+        assert_eq!(Vec::from(&out), &[Some("e\u{301}fac"), None, Some("ﬁ")]);
+
+        let ca = Utf8Chunked::new("a", &["Straße", "ΣΊΣΥΦΟΣ", "σίσυφος"]);
+        let out = casefold(&ca);
+        assert_eq!(
+            Vec::from(&out),
+            &[Some("strasse"), Some("σίσυφοσ"), Some("σίσυφοσ")]
+        );
+    }
+}
@@ -0,0 +1,216 @@
+use std::iter::once;
+
+use polars_arrow::utils::CustomIterTools;
+use polars_core::prelude::*;
+
+/// Apply `f` to the string pairs of `ca` and `other`, broadcasting a side of length 1.
+fn apply_binary<T, F>(ca: &Utf8Chunked, other: &Utf8Chunked, f: F) -> PolarsResult<ChunkedArray<T>>
+where
+    T: PolarsNumericType,
+    F: Fn(&str, &str) -> Option<T::Native>,
+{
+    let f_opt = |a: Option<&str>, b: Option<&str>| match (a, b) {
+        (Some(a), Some(b)) => f(a, b),
+        _ => None,
+    };
+    let mut out: ChunkedArray<T> = match (ca.len(), other.len()) {
+        (_, 1) => {
+            let b = other.get(0);
+            ca.into_iter().map(|a| f_opt(a, b)).collect_trusted()
+        }
+        (1, _) => {
+            let a = ca.get(0);
+            other.into_iter().map(|b| f_opt(a, b)).collect_trusted()
+        }
+        (len_a, len_b) => {
+            polars_ensure!(
+                len_a == len_b,
+                ComputeError: "cannot compare strings of series with lengths {} and {}",
+                len_a, len_b,
+            );
+            ca.into_iter()
+                .zip(other)
+                .map(|(a, b)| f_opt(a, b))
+                .collect_trusted()
+        }
+    };
+    out.rename(ca.name());
+    Ok(out)
+}
+
+fn levenshtein(a: &str, b: &str) -> u32 {
+    let b = b.chars().collect::<Vec<_>>();
+    // previous row of the edit matrix
+    let mut prev = (0..=b.len() as u32).collect::<Vec<_>>();
+    let mut cur = vec![0u32; b.len() + 1];
+
+    for (i, ca) in a.chars().enumerate() {
+        cur[0] = i as u32 + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + (ca != *cb) as u32;
+            cur[j + 1] = substitution.min(prev[j + 1] + 1).min(cur[j] + 1);
+        }
+        std::mem::swap(&mut prev, &mut cur);
+    }
+    prev[b.len()]
+}
+
+fn hamming(a: &str, b: &str) -> Option<u32> {
+    let mut a = a.chars();
+    let mut b = b.chars();
+    let mut distance = 0;
+    loop {
+        match (a.next(), b.next()) {
+            (Some(ca), Some(cb)) => distance += (ca != cb) as u32,
+            (None, None) => return Some(distance),
+            // only defined for strings with an equal number of chars
+            _ => return None,
+        }
+    }
+}
+
+fn jaro(a: &[char], b: &[char]) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    let match_distance = (a.len().max(b.len()) / 2).saturating_sub(1);
+    let mut a_matched = vec![false; a.len()];
+    let mut b_matched = vec![false; b.len()];
+
+    let mut matches = 0;
+    for (i, ca) in a.iter().enumerate() {
+        let start = i.saturating_sub(match_distance);
+        let end = (i + match_distance + 1).min(b.len());
+        for j in start..end {
+            if !b_matched[j] && b[j] == *ca {
+                a_matched[i] = true;
+                b_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    // count the matched chars that are out of order
+    let b_matches = b.iter().zip(&b_matched).filter(|(_, m)| **m);
+    let a_matches = a.iter().zip(&a_matched).filter(|(_, m)| **m);
+    let transpositions = a_matches
+        .zip(b_matches)
+        .filter(|((ca, _), (cb, _))| ca != cb)
+        .count();
+
+    let m = matches as f64;
+    (m / a.len() as f64 + m / b.len() as f64 + (m - transpositions as f64 / 2.0) / m) / 3.0
+}
+
+fn jaro_winkler(a: &str, b: &str) -> f64 {
+    let a = a.chars().collect::<Vec<_>>();
+    let b = b.chars().collect::<Vec<_>>();
+    let sim = jaro(&a, &b);
+    let prefix = a
+        .iter()
+        .zip(&b)
+        .take(4)
+        .take_while(|(ca, cb)| ca == cb)
+        .count();
+    sim + prefix as f64 * 0.1 * (1.0 - sim)
+}
+
+/// The set of char n-grams of `s`. A non-empty string shorter than `n` is its only n-gram.
+fn ngrams(s: &str, n: usize) -> PlHashSet<&str> {
+    let bounds = s
+        .char_indices()
+        .map(|(i, _)| i)
+        .chain(once(s.len()))
+        .collect::<Vec<_>>();
+    let n_chars = bounds.len() - 1;
+    if n_chars < n {
+        once(s).filter(|s| !s.is_empty()).collect()
+    } else {
+        (0..=n_chars - n)
+            .map(|i| &s[bounds[i]..bounds[i + n]])
+            .collect()
+    }
+}
+
+fn jaccard_ngram(a: &str, b: &str, n: usize) -> f64 {
+    let a = ngrams(a, n);
+    let b = ngrams(b, n);
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let intersection = a.intersection(&b).count();
+    intersection as f64 / (a.len() + b.len() - intersection) as f64
+}
+
+pub(super) fn levenshtein_distance(
+    ca: &Utf8Chunked,
+    other: &Utf8Chunked,
+) -> PolarsResult<UInt32Chunked> {
+    apply_binary(ca, other, |a, b| Some(levenshtein(a, b)))
+}
+
+pub(super) fn hamming_distance(
+    ca: &Utf8Chunked,
+    other: &Utf8Chunked,
+) -> PolarsResult<UInt32Chunked> {
+    apply_binary(ca, other, hamming)
+}
+
+pub(super) fn jaro_winkler_similarity(
+    ca: &Utf8Chunked,
+    other: &Utf8Chunked,
+) -> PolarsResult<Float64Chunked> {
+    apply_binary(ca, other, |a, b| Some(jaro_winkler(a, b)))
+}
+
+pub(super) fn jaccard_ngram_similarity(
+    ca: &Utf8Chunked,
+    other: &Utf8Chunked,
+    n: usize,
+) -> PolarsResult<Float64Chunked> {
+    polars_ensure!(n > 0, ComputeError: "n-gram size must be positive");
+    apply_binary(ca, other, |a, b| Some(jaccard_ngram(a, b, n)))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_distances() {
+        assert_eq!(levenshtein("kitten", "sitting"), 3);
+        assert_eq!(levenshtein("", "abc"), 3);
+        assert_eq!(levenshtein("héllo", "hello"), 1);
+
+        assert_eq!(hamming("karolin", "kathrin"), Some(3));
+        assert_eq!(hamming("abc", "ab"), None);
+
+        assert!((jaro_winkler("martha", "marhta") - 0.9611).abs() < 1e-4);
+        assert!((jaro_winkler("dixon", "dicksonx") - 0.8133).abs() < 1e-4);
+        assert_eq!(jaro_winkler("abc", "xyz"), 0.0);
+
+        assert_eq!(jaccard_ngram("abcd", "abce", 2), 0.5);
+        assert_eq!(jaccard_ngram("a", "a", 3), 1.0);
+        assert_eq!(jaccard_ngram("ab", "cd", 3), 0.0);
+    }
+
+    #[test]
+    fn test_distance_broadcast() -> PolarsResult<()> {
+        let ca = Utf8Chunked::new("a", &[Some("foo"), None, Some("bar")]);
+        let other = Utf8Chunked::new("b", &["fob"]);
+        let out = levenshtein_distance(&ca, &other)?;
+        assert_eq!(Vec::from(&out), &[Some(1), None, Some(3)]);
+        assert_eq!(out.name(), "a");
+
+        let other = Utf8Chunked::new("b", &["a", "b"]);
+        assert!(hamming_distance(&ca, &other).is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,298 @@
+use std::borrow::Cow;
+
+use aho_corasick::{AhoCorasick, AhoCorasickBuilder, MatchKind};
+use polars_arrow::utils::CustomIterTools;
+use polars_core::prelude::*;
+
+fn build_ac(
+    patterns: &[&str],
+    ascii_case_insensitive: bool,
+    kind: MatchKind,
+) -> PolarsResult<AhoCorasick> {
+    AhoCorasickBuilder::new()
+        .ascii_case_insensitive(ascii_case_insensitive)
+        .match_kind(kind)
+        .build(patterns)
+        .map_err(|e| polars_err!(ComputeError: "could not build Aho-Corasick automaton: {}", e))
+}
+
+/// The patterns to search for. Either one set shared by all strings, or a set per string.
+enum Patterns<'a> {
+    /// `None` if the shared set is a null list.
+    Shared(Option<Utf8Chunked>),
+    PerRow(&'a ListChunked),
+}
+
+/// Interpret a `Utf8` series as one set of values and a `List(Utf8)` series as one set of
+/// values per row. A list of length 1 is broadcast to all rows.
+fn get_patterns<'a>(s: &'a Series, len: usize, name: &str) -> PolarsResult<Patterns<'a>> {
+    match s.dtype() {
+        DataType::Utf8 => Ok(Patterns::Shared(Some(s.utf8()?.clone()))),
+        DataType::List(inner) if matches!(**inner, DataType::Utf8) => {
+            let ca = s.list()?;
+            if ca.len() == 1 {
+                let values = ca.get(0).map(|s| s.utf8().cloned()).transpose()?;
+                Ok(Patterns::Shared(values))
+            } else {
+                polars_ensure!(
+                    ca.len() == len,
+                    ComputeError: "{} length: {} does not match that of the string column: {}",
+                    name, ca.len(), len,
+                );
+                Ok(Patterns::PerRow(ca))
+            }
+        }
+        dt => polars_bail!(
+            ComputeError: "expected {} of type 'str' or 'list[str]', got '{}'", name, dt
+        ),
+    }
+}
+
+fn non_null_values(ca: &Utf8Chunked) -> Vec<&str> {
+    ca.into_iter().flatten().collect()
+}
+
+/// Check if the strings contain any of the `patterns`.
+pub(super) fn contains_any(
+    ca: &Utf8Chunked,
+    patterns: &Series,
+    ascii_case_insensitive: bool,
+) -> PolarsResult<BooleanChunked> {
+    let mut out: BooleanChunked = match get_patterns(patterns, ca.len(), "patterns")? {
+        Patterns::Shared(None) => BooleanChunked::full_null(ca.name(), ca.len()),
+        Patterns::Shared(Some(pat)) => {
+            let ac = build_ac(
+                &non_null_values(&pat),
+                ascii_case_insensitive,
+                MatchKind::LeftmostFirst,
+            )?;
+            ca.into_iter()
+                .map(|opt_s| opt_s.map(|s| ac.is_match(s)))
+                .collect_trusted()
+        }
+        Patterns::PerRow(pat) => ca
+            .into_iter()
+            .zip(pat.amortized_iter())
+            .map(|(opt_s, opt_pat)| match (opt_s, opt_pat) {
+                (Some(s), Some(pat)) => {
+                    let pat = non_null_values(pat.as_ref().utf8()?);
+                    let ac = build_ac(&pat, ascii_case_insensitive, MatchKind::LeftmostFirst)?;
+                    Ok(Some(ac.is_match(s)))
+                }
+                _ => Ok(None),
+            })
+            .collect::<PolarsResult<_>>()?,
+    };
+    out.rename(ca.name());
+    Ok(out)
+}
+
+/// Pair the patterns with the replacements at the same position, a single replacement is
+/// broadcast to all patterns. Pairs with a null pattern or replacement are skipped, so that
+/// a null doesn't shift the pairs that follow it.
+fn pair_replacements<'a>(
+    patterns: &'a Utf8Chunked,
+    replace_with: &'a Utf8Chunked,
+) -> PolarsResult<(Vec<&'a str>, Vec<&'a str>)> {
+    let pairs = match replace_with.len() {
+        1 => {
+            let replacement = replace_with.get(0);
+            patterns
+                .into_iter()
+                .filter_map(|opt_pat| opt_pat.zip(replacement))
+                .unzip()
+        }
+        n => {
+            polars_ensure!(
+                n == patterns.len(),
+                ComputeError:
+                "number of replacements: {} does not match the number of patterns: {}",
+                n, patterns.len(),
+            );
+            patterns
+                .into_iter()
+                .zip(replace_with)
+                .filter_map(|(opt_pat, opt_rep)| opt_pat.zip(opt_rep))
+                .unzip()
+        }
+    };
+    Ok(pairs)
+}
+
+/// Replace all non-overlapping occurrences of the `patterns` with the value at the same
+/// position in `replace_with`. The replacements are shared by all strings and a single
+/// replacement is used for every pattern.
+pub(super) fn replace_many(
+    ca: &Utf8Chunked,
+    patterns: &Series,
+    replace_with: &Series,
+    ascii_case_insensitive: bool,
+) -> PolarsResult<Utf8Chunked> {
+    let replace_with = match get_patterns(replace_with, 1, "replacements")? {
+        Patterns::Shared(Some(values)) => values,
+        _ => polars_bail!(
+            ComputeError: "expected a single, non-null list of replacements"
+        ),
+    };
+
+    let mut out: Utf8Chunked = match get_patterns(patterns, ca.len(), "patterns")? {
+        Patterns::Shared(None) => Utf8Chunked::full_null(ca.name(), ca.len()),
+        Patterns::Shared(Some(pat)) => {
+            let (pat, replace_with) = pair_replacements(&pat, &replace_with)?;
+            let ac = build_ac(&pat, ascii_case_insensitive, MatchKind::LeftmostFirst)?;
+            ca.apply(|s| Cow::Owned(ac.replace_all(s, &replace_with)))
+        }
+        Patterns::PerRow(pat) => ca
+            .into_iter()
+            .zip(pat.amortized_iter())
+            .map(|(opt_s, opt_pat)| match (opt_s, opt_pat) {
+                (Some(s), Some(pat)) => {
+                    let (pat, replace_with) =
+                        pair_replacements(pat.as_ref().utf8()?, &replace_with)?;
+                    let ac = build_ac(&pat, ascii_case_insensitive, MatchKind::LeftmostFirst)?;
+                    Ok(Some(ac.replace_all(s, &replace_with)))
+                }
+                _ => Ok(None),
+            })
+            .collect::<PolarsResult<_>>()?,
+    };
+    out.rename(ca.name());
+    Ok(out)
+}
+
+fn push_matches(
+    builder: &mut ListUtf8ChunkedBuilder,
+    ac: &AhoCorasick,
+    s: &str,
+    overlapping: bool,
+) {
+    let as_str = |m: aho_corasick::Match| &s[m.start()..m.end()];
+    if overlapping {
+        builder.append_values_iter(ac.find_overlapping_iter(s).map(as_str))
+    } else {
+        builder.append_values_iter(ac.find_iter(s).map(as_str))
+    }
+}
+
+/// Extract all occurrences of the `patterns` from the strings, in the order in which they
+/// appear. Strings without a match produce an empty list.
+pub(super) fn extract_many(
+    ca: &Utf8Chunked,
+    patterns: &Series,
+    ascii_case_insensitive: bool,
+    overlapping: bool,
+) -> PolarsResult<ListChunked> {
+    // overlapping matches are only reported by the standard semantics
+    let kind = if overlapping {
+        MatchKind::Standard
+    } else {
+        MatchKind::LeftmostFirst
+    };
+    let mut builder = ListUtf8ChunkedBuilder::new(ca.name(), ca.len(), ca.get_values_size());
+
+    match get_patterns(patterns, ca.len(), "patterns")? {
+        Patterns::Shared(None) => {
+            for _ in 0..ca.len() {
+                builder.append_null()
+            }
+        }
+        Patterns::Shared(Some(pat)) => {
+            let ac = build_ac(&non_null_values(&pat), ascii_case_insensitive, kind)?;
+            for opt_s in ca.into_iter() {
+                match opt_s {
+                    Some(s) => push_matches(&mut builder, &ac, s, overlapping),
+                    None => builder.append_null(),
+                }
+            }
+        }
+        Patterns::PerRow(pat) => {
+            for (opt_s, opt_pat) in ca.into_iter().zip(pat.amortized_iter()) {
+                match (opt_s, opt_pat) {
+                    (Some(s), Some(pat)) => {
+                        let pat = non_null_values(pat.as_ref().utf8()?);
+                        let ac = build_ac(&pat, ascii_case_insensitive, kind)?;
+                        push_matches(&mut builder, &ac, s, overlapping)
+                    }
+                    _ => builder.append_null(),
+                }
+            }
+        }
+    }
+    Ok(builder.finish())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_find_many() -> PolarsResult<()> {
+        let ca = Utf8Chunked::new("a", &[Some("Tom likes cats"), None, Some("dogs and CATS")]);
+        let patterns = Series::new("", &["cat", "dog"]);
+
+        let out = contains_any(&ca, &patterns, false)?;
+        assert_eq!(Vec::from(&out), &[Some(true), None, Some(true)]);
+        let out = contains_any(&ca, &Series::new("", &["CAT"]), true)?;
+        assert_eq!(Vec::from(&out), &[Some(true), None, Some(true)]);
+
+        let replace_with = Series::new("", &["mouse", "bird"]);
+        let out = replace_many(&ca, &patterns, &replace_with, false)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some("Tom likes mouses"), None, Some("birds and CATS")]
+        );
+
+        let out = extract_many(&ca, &patterns, true, false)?;
+        let out = out.explode()?;
+        let out = out.utf8()?;
+        assert_eq!(
+            Vec::from(out),
+            &[Some("cat"), None, Some("dog"), Some("CAT")]
+        );
+
+        // patterns per row
+        let patterns = Series::new(
+            "",
+            &[
+                Series::new("", &["likes"]),
+                Series::new("", &["x"]),
+                Series::new("", &["and", "dogs"]),
+            ],
+        );
+        let out = contains_any(&ca, &patterns, false)?;
+        assert_eq!(Vec::from(&out), &[Some(true), None, Some(true)]);
+        let out = replace_many(&ca, &patterns, &Series::new("", &["-"]), false)?;
+        assert_eq!(
+            Vec::from(&out),
+            &[Some("Tom - cats"), None, Some("- - CATS")]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_many_nulls() -> PolarsResult<()> {
+        let ca = Utf8Chunked::new("a", &["a b c"]);
+        // nulls skip their pair instead of shifting the pairs that follow
+        let patterns = Series::new("", &[Some("a"), None, Some("b"), Some("c")]);
+        let replace_with = Series::new("", &[Some("1"), Some("2"), None, Some("3")]);
+        let out = replace_many(&ca, &patterns, &replace_with, false)?;
+        assert_eq!(Vec::from(&out), &[Some("1 b 3")]);
+
+        let replace_with = Series::new("", &["1", "2"]);
+        assert!(replace_many(&ca, &patterns, &replace_with, false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_extract_many_overlapping() -> PolarsResult<()> {
+        let ca = Utf8Chunked::new("a", &["abcd"]);
+        let patterns = Series::new("", &["abc", "bcd"]);
+
+        let out = extract_many(&ca, &patterns, false, false)?;
+        assert_eq!(out.get(0).unwrap().len(), 1);
+        let out = extract_many(&ca, &patterns, false, true)?;
+        let out = out.explode()?;
+        assert_eq!(Vec::from(out.utf8()?), &[Some("abc"), Some("bcd")]);
+        Ok(())
+    }
+}
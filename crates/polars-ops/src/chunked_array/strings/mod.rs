@@ -1,5 +1,9 @@
 #[cfg(feature = "strings")]
 mod case;
+#[cfg(feature = "string_distance")]
+mod distance;
+#[cfg(feature = "find_many")]
+mod find_many;
 #[cfg(feature = "extract_jsonpath")]
 mod json_path;
 #[cfg(feature = "string_justify")]
@@ -9,6 +13,8 @@ mod namespace;
 #[cfg(feature = "strings")]
 mod replace;
 
+#[cfg(feature = "string_unicode")]
+pub use case::UnicodeForm;
 #[cfg(feature = "extract_jsonpath")]
 pub use json_path::*;
 #[cfg(feature = "strings")]
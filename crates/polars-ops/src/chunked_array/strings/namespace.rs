@@ -20,6 +20,32 @@ fn f_regex_extract<'a>(reg: &Regex, input: &'a str, group_index: usize) -> Optio
         .and_then(|cap| cap.get(group_index).map(|m| Cow::Borrowed(m.as_str())))
 }
 
+/// Name of the struct field for every capture group of `reg`, group 0 excluded.
+/// Named groups use their name, unnamed groups their index.
+#[cfg(feature = "extract_groups")]
+fn capture_group_names(reg: &Regex) -> Vec<String> {
+    reg.capture_names()
+        .enumerate()
+        .skip(1)
+        .map(|(idx, name)| name.map_or_else(|| idx.to_string(), |name| name.to_string()))
+        .collect()
+}
+
+/// The `Struct` data type produced by [`Utf8NameSpaceImpl::extract_groups`] for `pat`.
+#[cfg(feature = "extract_groups")]
+pub fn extract_groups_dtype(pat: &str) -> PolarsResult<DataType> {
+    let reg = Regex::new(pat)?;
+    let fields = capture_group_names(&reg)
+        .into_iter()
+        .map(|name| Field::new(&name, DataType::Utf8))
+        .collect::<Vec<_>>();
+    polars_ensure!(
+        !fields.is_empty(),
+        ComputeError: "regex pattern '{}' has no capture groups to extract", pat
+    );
+    Ok(DataType::Struct(fields))
+}
+
 pub trait Utf8NameSpaceImpl: AsUtf8 {
     #[cfg(not(feature = "binary_encoding"))]
     fn hex_decode(&self) -> PolarsResult<Utf8Chunked> {
@@ -353,6 +379,46 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         Ok(builder.finish())
     }
 
+    /// Extract all capture groups of a regex pattern into a `Struct` with one `Utf8` field per
+    /// group, named after the named groups or else the group index. The regex is run once per
+    /// string; if it doesn't match, all fields are null.
+    #[cfg(feature = "extract_groups")]
+    fn extract_groups(&self, pat: &str) -> PolarsResult<Series> {
+        let ca = self.as_utf8();
+        let reg = Regex::new(pat)?;
+        let names = capture_group_names(&reg);
+        polars_ensure!(
+            !names.is_empty(),
+            ComputeError: "regex pattern '{}' has no capture groups to extract", pat
+        );
+
+        let bytes_capacity = ca.get_values_size() / names.len();
+        let mut builders = names
+            .iter()
+            .map(|name| Utf8ChunkedBuilder::new(name, ca.len(), bytes_capacity))
+            .collect::<Vec<_>>();
+        let mut locs = reg.capture_locations();
+
+        for opt_s in ca.into_iter() {
+            match opt_s.filter(|s| reg.captures_read(&mut locs, s).is_some()) {
+                Some(s) => {
+                    for (i, builder) in builders.iter_mut().enumerate() {
+                        builder.append_option(locs.get(i + 1).map(|(start, end)| &s[start..end]))
+                    }
+                }
+                None => builders
+                    .iter_mut()
+                    .for_each(|builder| builder.append_null()),
+            }
+        }
+
+        let fields = builders
+            .into_iter()
+            .map(|builder| builder.finish().into_series())
+            .collect::<Vec<_>>();
+        StructChunked::new(ca.name(), &fields).map(|ca| ca.into_series())
+    }
+
     /// Count all successive non-overlapping regex matches.
     fn count_match(&self, pat: &str) -> PolarsResult<UInt32Chunked> {
         let ca = self.as_utf8();
@@ -366,6 +432,73 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         Ok(out)
     }
 
+    /// Check if the strings contain any of the literal `patterns`, using an Aho-Corasick
+    /// automaton. `patterns` is either a `Utf8` series holding one set of patterns, or a
+    /// `List(Utf8)` series holding a set of patterns per string.
+    #[cfg(feature = "find_many")]
+    fn contains_any(
+        &self,
+        patterns: &Series,
+        ascii_case_insensitive: bool,
+    ) -> PolarsResult<BooleanChunked> {
+        let ca = self.as_utf8();
+        find_many::contains_any(ca, patterns, ascii_case_insensitive)
+    }
+
+    /// Replace all non-overlapping occurrences of the literal `patterns` with the value at the
+    /// same position in `replace_with`. A single replacement is used for every pattern.
+    #[cfg(feature = "find_many")]
+    fn replace_many(
+        &self,
+        patterns: &Series,
+        replace_with: &Series,
+        ascii_case_insensitive: bool,
+    ) -> PolarsResult<Utf8Chunked> {
+        let ca = self.as_utf8();
+        find_many::replace_many(ca, patterns, replace_with, ascii_case_insensitive)
+    }
+
+    /// Extract all occurrences of the literal `patterns` as a list, in the order in which they
+    /// occur in the string.
+    #[cfg(feature = "find_many")]
+    fn extract_many(
+        &self,
+        patterns: &Series,
+        ascii_case_insensitive: bool,
+        overlapping: bool,
+    ) -> PolarsResult<ListChunked> {
+        let ca = self.as_utf8();
+        find_many::extract_many(ca, patterns, ascii_case_insensitive, overlapping)
+    }
+
+    /// Levenshtein edit distance, in chars, to the strings of `other`.
+    #[cfg(feature = "string_distance")]
+    fn levenshtein(&self, other: &Utf8Chunked) -> PolarsResult<UInt32Chunked> {
+        let ca = self.as_utf8();
+        distance::levenshtein_distance(ca, other)
+    }
+
+    /// Hamming distance, in chars, to the strings of `other`. Null if the number of chars differs.
+    #[cfg(feature = "string_distance")]
+    fn hamming(&self, other: &Utf8Chunked) -> PolarsResult<UInt32Chunked> {
+        let ca = self.as_utf8();
+        distance::hamming_distance(ca, other)
+    }
+
+    /// Jaro-Winkler similarity to the strings of `other`, between 0 and 1.
+    #[cfg(feature = "string_distance")]
+    fn jaro_winkler(&self, other: &Utf8Chunked) -> PolarsResult<Float64Chunked> {
+        let ca = self.as_utf8();
+        distance::jaro_winkler_similarity(ca, other)
+    }
+
+    /// Jaccard similarity of the sets of char `n`-grams of these strings and those of `other`.
+    #[cfg(feature = "string_distance")]
+    fn jaccard_ngram(&self, other: &Utf8Chunked, n: usize) -> PolarsResult<Float64Chunked> {
+        let ca = self.as_utf8();
+        distance::jaccard_ngram_similarity(ca, other, n)
+    }
+
     /// Modify the strings to their lowercase equivalent
     #[must_use]
     fn to_lowercase(&self) -> Utf8Chunked {
@@ -388,6 +521,37 @@ pub trait Utf8NameSpaceImpl: AsUtf8 {
         case::to_titlecase(ca)
     }
 
+    /// Bring the strings into the given Unicode normalization form.
+    #[must_use]
+    #[cfg(feature = "string_unicode")]
+    fn str_normalize(&self, form: UnicodeForm) -> Utf8Chunked {
+        let ca = self.as_utf8();
+        case::normalize(ca, form)
+    }
+
+    /// Case fold the strings for caseless comparison.
+    #[must_use]
+    #[cfg(feature = "string_unicode")]
+    fn casefold(&self) -> Utf8Chunked {
+        let ca = self.as_utf8();
+        case::casefold(ca)
+    }
+
+    /// Get the length of the string values as number of extended grapheme clusters.
+    #[cfg(feature = "string_unicode")]
+    fn str_n_graphemes(&self) -> UInt32Chunked {
+        let ca = self.as_utf8();
+        case::n_graphemes(ca)
+    }
+
+    /// Reverse the strings, keeping grapheme clusters intact.
+    #[must_use]
+    #[cfg(feature = "string_unicode")]
+    fn str_reverse(&self) -> Utf8Chunked {
+        let ca = self.as_utf8();
+        case::reverse(ca)
+    }
+
     /// Concat with the values from a second Utf8Chunked
     #[must_use]
     fn concat(&self, other: &Utf8Chunked) -> Utf8Chunked {
@@ -0,0 +1,248 @@
+use polars_core::prelude::*;
+
+use crate::frame::join::{collect_intervals, interval_key_to_physical, sort_by_start, Interval};
+
+/// The physical `start` and `end` columns of an interval frame.
+fn physical_bounds(df: &DataFrame, start: &str, end: &str) -> PolarsResult<(Series, Series)> {
+    let start = df.column(start)?;
+    let end = df.column(end)?;
+    polars_ensure!(
+        start.dtype() == end.dtype(),
+        ComputeError: "interval bounds '{}' and '{}' should have the same data type",
+        start.name(), end.name()
+    );
+    Ok((
+        interval_key_to_physical(start)?,
+        interval_key_to_physical(end)?,
+    ))
+}
+
+/// Collect the valid intervals of every group of `by`, in order of appearance of the groups.
+fn grouped_intervals<T>(
+    df: &DataFrame,
+    start: &Series,
+    end: &Series,
+    by: &[&str],
+) -> PolarsResult<Vec<Vec<Interval<T::Native>>>>
+where
+    T: PolarsNumericType,
+{
+    let intervals = collect_intervals(start.unpack::<T>()?, Some(end.unpack::<T>()?));
+    if by.is_empty() {
+        return Ok(vec![intervals]);
+    }
+    let groups = df.groupby_stable(by)?.take_groups().into_idx();
+    let mut row_group = vec![0; df.height()];
+    for (group, (_, idx)) in groups.into_iter().enumerate() {
+        for i in idx {
+            row_group[i as usize] = group;
+        }
+    }
+    let n_groups = row_group.iter().max().map_or(0, |g| g + 1);
+    let mut grouped = vec![vec![]; n_groups];
+    for interval in intervals {
+        grouped[row_group[interval.idx as usize]].push(interval);
+    }
+    Ok(grouped)
+}
+
+fn merge_intervals_impl<T>(
+    df: &DataFrame,
+    start: &Series,
+    end: &Series,
+    by: &[&str],
+) -> PolarsResult<(Vec<IdxSize>, Vec<T::Native>, Vec<T::Native>)>
+where
+    T: PolarsNumericType,
+{
+    let mut first_idx = vec![];
+    let mut starts = vec![];
+    let mut ends = vec![];
+    for mut intervals in grouped_intervals::<T>(df, start, end, by)? {
+        if intervals.is_empty() {
+            continue;
+        }
+        // the `by` values are taken from the first row of the group
+        let group_idx = intervals[0].idx;
+        sort_by_start(&mut intervals);
+
+        let mut current = intervals[0];
+        for interval in &intervals[1..] {
+            // intervals that overlap or touch are merged
+            if interval.start <= current.end {
+                if interval.end > current.end {
+                    current.end = interval.end
+                }
+            } else {
+                first_idx.push(group_idx);
+                starts.push(current.start);
+                ends.push(current.end);
+                current = *interval;
+            }
+        }
+        first_idx.push(group_idx);
+        starts.push(current.start);
+        ends.push(current.end);
+    }
+    Ok((first_idx, starts, ends))
+}
+
+#[allow(clippy::type_complexity)]
+fn split_intervals_impl<T>(
+    df: &DataFrame,
+    start: &Series,
+    end: &Series,
+    by: &[&str],
+) -> PolarsResult<(Vec<IdxSize>, Vec<Option<T::Native>>, Vec<Option<T::Native>>)>
+where
+    T: PolarsNumericType,
+{
+    // every row is cut at the bounds of all intervals in its group
+    let mut pieces: Vec<Vec<(T::Native, T::Native)>> = vec![vec![]; df.height()];
+    for intervals in grouped_intervals::<T>(df, start, end, by)? {
+        let mut bounds = Vec::with_capacity(intervals.len() * 2);
+        for interval in &intervals {
+            bounds.push(interval.start);
+            bounds.push(interval.end);
+        }
+        // intervals don't contain NaN
+        bounds.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        bounds.dedup();
+
+        for interval in intervals {
+            let first = bounds.partition_point(|b| *b < interval.start);
+            let row_pieces = &mut pieces[interval.idx as usize];
+            for window in bounds[first..].windows(2) {
+                if window[0] >= interval.end {
+                    break;
+                }
+                row_pieces.push((window[0], window[1]));
+            }
+        }
+    }
+
+    let start = start.unpack::<T>()?;
+    let end = end.unpack::<T>()?;
+    let mut idx = Vec::with_capacity(df.height());
+    let mut starts = Vec::with_capacity(df.height());
+    let mut ends = Vec::with_capacity(df.height());
+    for (row, (row_pieces, (start, end))) in pieces
+        .into_iter()
+        .zip(start.into_iter().zip(end))
+        .enumerate()
+    {
+        if row_pieces.is_empty() {
+            // null and empty intervals are kept as is
+            idx.push(row as IdxSize);
+            starts.push(start);
+            ends.push(end);
+        } else {
+            for (start, end) in row_pieces {
+                idx.push(row as IdxSize);
+                starts.push(Some(start));
+                ends.push(Some(end));
+            }
+        }
+    }
+    Ok((idx, starts, ends))
+}
+
+/// Merge the overlapping or adjacent `[start, end)` intervals within every group of `by`.
+///
+/// Returns the `by` columns and the `start` and `end` of the merged intervals, ordered by
+/// group and start. Null and empty intervals are dropped.
+pub fn merge_intervals(
+    df: &DataFrame,
+    start: &str,
+    end: &str,
+    by: &[&str],
+) -> PolarsResult<DataFrame> {
+    let dtype = df.column(start)?.dtype().clone();
+    let (start_phys, end_phys) = physical_bounds(df, start, end)?;
+
+    let (first_idx, starts, ends) = match start_phys.dtype() {
+        DataType::Float64 => {
+            let (idx, starts, ends) =
+                merge_intervals_impl::<Float64Type>(df, &start_phys, &end_phys, by)?;
+            (idx, Series::new(start, starts), Series::new(end, ends))
+        }
+        _ => {
+            let (idx, starts, ends) =
+                merge_intervals_impl::<Int64Type>(df, &start_phys, &end_phys, by)?;
+            (idx, Series::new(start, starts), Series::new(end, ends))
+        }
+    };
+
+    let mut columns = df
+        .select(by)?
+        .take(&IdxCa::from_vec("", first_idx))?
+        .get_columns()
+        .to_vec();
+    columns.push(starts.cast(&dtype)?);
+    columns.push(ends.cast(&dtype)?);
+    DataFrame::new(columns)
+}
+
+/// Split the `[start, end)` intervals within every group of `by` at the bounds of all
+/// intervals of that group, so that any two intervals in a group either coincide or don't
+/// overlap.
+///
+/// Every row is repeated for each of its pieces. Null and empty intervals are kept as is.
+pub fn split_intervals(
+    df: &DataFrame,
+    start: &str,
+    end: &str,
+    by: &[&str],
+) -> PolarsResult<DataFrame> {
+    let dtype = df.column(start)?.dtype().clone();
+    let (start_phys, end_phys) = physical_bounds(df, start, end)?;
+
+    let (idx, starts, ends) = match start_phys.dtype() {
+        DataType::Float64 => {
+            let (idx, starts, ends) =
+                split_intervals_impl::<Float64Type>(df, &start_phys, &end_phys, by)?;
+            (idx, Series::new(start, starts), Series::new(end, ends))
+        }
+        _ => {
+            let (idx, starts, ends) =
+                split_intervals_impl::<Int64Type>(df, &start_phys, &end_phys, by)?;
+            (idx, Series::new(start, starts), Series::new(end, ends))
+        }
+    };
+
+    let mut out = df.take(&IdxCa::from_vec("", idx))?;
+    out.with_column(starts.cast(&dtype)?)?;
+    out.with_column(ends.cast(&dtype)?)?;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_merge_split_intervals() -> PolarsResult<()> {
+        let df = df![
+            "id" => [1, 1, 1, 2, 2],
+            "start" => [0i64, 3, 8, 0, 4],
+            "end" => [5i64, 6, 9, 4, 6],
+        ]?;
+
+        let out = merge_intervals(&df, "start", "end", &["id"])?;
+        let expected = df![
+            "id" => [1, 1, 2],
+            "start" => [0i64, 8, 0],
+            "end" => [6i64, 9, 6],
+        ]?;
+        assert!(out.frame_equal(&expected));
+
+        let out = split_intervals(&df, "start", "end", &["id"])?;
+        let expected = df![
+            "id" => [1, 1, 1, 1, 1, 2, 2],
+            "start" => [0i64, 3, 3, 5, 8, 0, 4],
+            "end" => [3i64, 5, 5, 6, 9, 4, 6],
+        ]?;
+        assert!(out.frame_equal(&expected));
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+use std::cmp::Ordering;
+
+use polars_arrow::data_types::IsFloat;
+use polars_core::utils::slice_slice;
+
+use super::*;
+
+#[derive(Clone, Copy)]
+pub(crate) struct Interval<T> {
+    pub(crate) start: T,
+    pub(crate) end: T,
+    pub(crate) idx: IdxSize,
+}
+
+/// Cast the interval keys to the physical type the interval algorithms run on.
+pub(crate) fn interval_key_to_physical(s: &Series) -> PolarsResult<Series> {
+    let dtype = s.dtype();
+    polars_ensure!(
+        dtype.is_numeric() || dtype.is_temporal(),
+        InvalidOperation: "interval bounds should be numeric or temporal, got {}", dtype
+    );
+    if dtype.is_float() {
+        s.cast(&DataType::Float64)
+    } else {
+        s.to_physical_repr().cast(&DataType::Int64)
+    }
+}
+
+/// Collect the valid intervals `[start, end)`, skipping nulls, NaNs and empty intervals.
+/// If there is no `end`, every `start` is a point.
+pub(crate) fn collect_intervals<T>(
+    start: &ChunkedArray<T>,
+    end: Option<&ChunkedArray<T>>,
+) -> Vec<Interval<T::Native>>
+where
+    T: PolarsNumericType,
+{
+    match end {
+        Some(end) => start
+            .into_iter()
+            .zip(end)
+            .enumerate()
+            .filter_map(|(idx, bounds)| match bounds {
+                (Some(start), Some(end)) if start < end => Some(Interval {
+                    start,
+                    end,
+                    idx: idx as IdxSize,
+                }),
+                _ => None,
+            })
+            .collect(),
+        None => start
+            .into_iter()
+            .enumerate()
+            .filter_map(|(idx, point)| match point {
+                Some(point) if !point.is_nan() => Some(Interval {
+                    start: point,
+                    end: point,
+                    idx: idx as IdxSize,
+                }),
+                _ => None,
+            })
+            .collect(),
+    }
+}
+
+pub(crate) fn sort_by_start<T: PartialOrd>(intervals: &mut [Interval<T>]) {
+    // NaNs are filtered out, so the order is total
+    intervals.sort_by(|a, b| a.start.partial_cmp(&b.start).unwrap_or(Ordering::Equal));
+}
+
+/// Find all pairs of left and right rows that overlap with a sweep over the interval starts.
+///
+/// Every interval stays in an active set until an interval of the other side starts at or after
+/// its end. Everything left in the other side's active set when an interval starts overlaps it.
+/// Left points are matched by the right intervals that contain them.
+fn overlapping_pairs<T: PartialOrd + Copy>(
+    mut left: Vec<Interval<T>>,
+    left_is_point: bool,
+    mut right: Vec<Interval<T>>,
+) -> Vec<(IdxSize, IdxSize)> {
+    sort_by_start(&mut left);
+    sort_by_start(&mut right);
+
+    let mut active_left: Vec<Interval<T>> = vec![];
+    let mut active_right: Vec<Interval<T>> = vec![];
+    let mut out = vec![];
+    let mut left_iter = left.into_iter().peekable();
+    let mut right_iter = right.into_iter().peekable();
+
+    loop {
+        // on ties the right interval goes first, so that it contains a point at its start
+        let take_right = match (left_iter.peek(), right_iter.peek()) {
+            (Some(l), Some(r)) => r.start <= l.start,
+            (Some(_), None) => false,
+            (None, Some(_)) if !active_left.is_empty() => true,
+            _ => break,
+        };
+        if take_right {
+            let r = right_iter.next().unwrap();
+            active_left.retain(|l| l.end > r.start);
+            out.extend(active_left.iter().map(|l| (l.idx, r.idx)));
+            active_right.push(r);
+        } else {
+            let l = left_iter.next().unwrap();
+            active_right.retain(|r| r.end > l.start);
+            out.extend(active_right.iter().map(|r| (l.idx, r.idx)));
+            if !left_is_point {
+                active_left.push(l);
+            }
+        }
+    }
+    out.sort_unstable();
+    out
+}
+
+fn interval_join_ids_impl<T>(
+    left_start: &Series,
+    left_end: Option<&Series>,
+    right_start: &Series,
+    right_end: &Series,
+) -> PolarsResult<Vec<(IdxSize, IdxSize)>>
+where
+    T: PolarsNumericType,
+{
+    let left_end = left_end.map(|s| s.unpack::<T>()).transpose()?;
+    let left = collect_intervals(left_start.unpack::<T>()?, left_end);
+    let right = collect_intervals(right_start.unpack::<T>()?, Some(right_end.unpack::<T>()?));
+    Ok(overlapping_pairs(left, left_end.is_none(), right))
+}
+
+/// Get the join indices of an interval join.
+///
+/// The left side holds either points (`left_end` is `None`) or intervals, the right side
+/// holds intervals. All intervals are half-open: `[start, end)`.
+pub fn _interval_join_ids(
+    left_start: &Series,
+    left_end: Option<&Series>,
+    right_start: &Series,
+    right_end: &Series,
+) -> PolarsResult<(Vec<IdxSize>, Vec<IdxSize>)> {
+    let dtype = right_start.dtype();
+    polars_ensure!(
+        left_start.dtype() == dtype
+            && right_end.dtype() == dtype
+            && left_end.map_or(true, |s| s.dtype() == dtype),
+        ComputeError: "interval bounds of an interval join should have the same data type"
+    );
+    let left_start = interval_key_to_physical(left_start)?;
+    let left_end = left_end.map(interval_key_to_physical).transpose()?;
+    let right_start = interval_key_to_physical(right_start)?;
+    let right_end = interval_key_to_physical(right_end)?;
+
+    let pairs = match left_start.dtype() {
+        DataType::Float64 => interval_join_ids_impl::<Float64Type>(
+            &left_start,
+            left_end.as_ref(),
+            &right_start,
+            &right_end,
+        )?,
+        _ => interval_join_ids_impl::<Int64Type>(
+            &left_start,
+            left_end.as_ref(),
+            &right_start,
+            &right_end,
+        )?,
+    };
+    Ok(pairs.into_iter().unzip())
+}
+
+pub(super) trait IntervalJoin: IntoDf {
+    fn _interval_join(
+        &self,
+        other: &DataFrame,
+        selected_left: &[Series],
+        selected_right: &[Series],
+        suffix: Option<&str>,
+        slice: Option<(i64, usize)>,
+    ) -> PolarsResult<DataFrame> {
+        let left_df = self.to_df();
+        polars_ensure!(
+            matches!(selected_left.len(), 1 | 2) && selected_right.len() == 2,
+            ComputeError: "an interval join expects 'left_on' to be a point or a [start, end) pair \
+            and 'right_on' to be a [start, end) pair, got {} and {} columns",
+            selected_left.len(), selected_right.len()
+        );
+        let (left_idx, right_idx) = _interval_join_ids(
+            &selected_left[0],
+            selected_left.get(1),
+            &selected_right[0],
+            &selected_right[1],
+        )?;
+        let mut left_idx = &*left_idx;
+        let mut right_idx = &*right_idx;
+        if let Some((offset, len)) = slice {
+            left_idx = slice_slice(left_idx, offset, len);
+            right_idx = slice_slice(right_idx, offset, len);
+        }
+
+        // the bounds of both sides differ, so all columns are kept
+        let (df_left, df_right) = POOL.join(
+            // safety: join indices are known to be in bounds
+            || unsafe { left_df._create_left_df_from_slice(left_idx, false, true) },
+            || unsafe { other._take_unchecked_slice(right_idx, true) },
+        );
+        _finish_join(df_left, df_right, suffix)
+    }
+}
+
+impl IntervalJoin for DataFrame {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_interval_join_ids() -> PolarsResult<()> {
+        let right_start = Series::new("start", &[0i64, 5, 10, 2]);
+        let right_end = Series::new("end", &[5i64, 10, 15, 4]);
+
+        // points are contained in [start, end)
+        let points = Series::new("t", &[Some(0i64), Some(3), Some(5), None, Some(15)]);
+        let (left, right) = _interval_join_ids(&points, None, &right_start, &right_end)?;
+        assert_eq!(left, &[0, 1, 1, 2]);
+        assert_eq!(right, &[0, 0, 3, 1]);
+
+        // intervals that only touch don't overlap
+        let left_start = Series::new("a", &[4i64, 10, 20]);
+        let left_end = Series::new("b", &[6i64, 11, 30]);
+        let (left, right) =
+            _interval_join_ids(&left_start, Some(&left_end), &right_start, &right_end)?;
+        assert_eq!(left, &[0, 0, 1]);
+        assert_eq!(right, &[0, 1, 2]);
+        Ok(())
+    }
+}
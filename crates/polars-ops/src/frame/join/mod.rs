@@ -1,8 +1,18 @@
+#[cfg(feature = "interval_join")]
+mod interval_join;
 #[cfg(feature = "merge_sorted")]
 mod merge_sorted;
 #[cfg(feature = "chunked_ids")]
 use std::borrow::Cow;
 
+#[cfg(feature = "interval_join")]
+use interval_join::IntervalJoin;
+#[cfg(feature = "interval_join")]
+pub use interval_join::_interval_join_ids;
+#[cfg(feature = "interval_join")]
+pub(crate) use interval_join::{
+    collect_intervals, interval_key_to_physical, sort_by_start, Interval,
+};
 #[cfg(feature = "merge_sorted")]
 pub use merge_sorted::_merge_sorted_dfs;
 use polars_core::frame::hash_join::*;
@@ -144,6 +154,18 @@ pub trait DataFrameJoinOps: IntoDf {
             }
         }
 
+        // the keys of an interval join are the bounds of the intervals
+        #[cfg(feature = "interval_join")]
+        if let JoinType::Interval = args.how {
+            return left_df._interval_join(
+                other,
+                &selected_left,
+                &selected_right,
+                args.suffix.as_deref(),
+                args.slice,
+            );
+        }
+
         polars_ensure!(
             selected_left.len() == selected_right.len(),
             ComputeError:
@@ -207,6 +229,8 @@ pub trait DataFrameJoinOps: IntoDf {
                             right_by,
                             options.strategy,
                             options.tolerance,
+                            options.tolerance_column.as_deref(),
+                            options.allow_exact_matches,
                             args.suffix.as_deref(),
                             args.slice,
                         ),
@@ -216,6 +240,8 @@ pub trait DataFrameJoinOps: IntoDf {
                             right_on,
                             options.strategy,
                             options.tolerance,
+                            options.tolerance_column.as_deref(),
+                            options.allow_exact_matches,
                             args.suffix,
                             args.slice,
                         ),
@@ -227,6 +253,10 @@ pub trait DataFrameJoinOps: IntoDf {
                 JoinType::Cross => {
                     unreachable!()
                 }
+                #[cfg(feature = "interval_join")]
+                JoinType::Interval => {
+                    unreachable!()
+                }
             };
         }
 
@@ -242,8 +272,8 @@ pub trait DataFrameJoinOps: IntoDf {
         }
         // make sure that we don't have logical types.
         // we don't overwrite the original selected as that might be used to create a column in the new df
-        let selected_left_physical = _to_physical_and_bit_repr(&selected_left);
-        let selected_right_physical = _to_physical_and_bit_repr(&selected_right);
+        let selected_left_physical = _to_physical_and_bit_repr(&selected_left)?;
+        let selected_right_physical = _to_physical_and_bit_repr(&selected_right)?;
 
         // multiple keys
         match args.how {
@@ -341,6 +371,10 @@ pub trait DataFrameJoinOps: IntoDf {
             JoinType::Cross => {
                 unreachable!()
             }
+            #[cfg(feature = "interval_join")]
+            JoinType::Interval => {
+                unreachable!()
+            }
         }
     }
 
@@ -430,6 +464,34 @@ pub trait DataFrameJoinOps: IntoDf {
     {
         self.join(other, left_on, right_on, JoinArgs::new(JoinType::Outer))
     }
+
+    /// Join every row of `self` to all rows of `other` whose interval overlaps it.
+    ///
+    /// `left_on` is either a point or a `[start, end)` pair of columns and `right_on`
+    /// is a `[start, end)` pair. All columns of both frames are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use polars_core::prelude::*;
+    /// # use polars_ops::prelude::*;
+    /// fn join_dfs(events: &DataFrame, outages: &DataFrame) -> PolarsResult<DataFrame> {
+    ///     events.interval_join(outages, ["time"], ["start", "end"])
+    /// }
+    /// ```
+    #[cfg(feature = "interval_join")]
+    fn interval_join<I, S>(
+        &self,
+        other: &DataFrame,
+        left_on: I,
+        right_on: I,
+    ) -> PolarsResult<DataFrame>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.join(other, left_on, right_on, JoinArgs::new(JoinType::Interval))
+    }
 }
 
 trait DataFrameJoinOpsPrivate: IntoDf {
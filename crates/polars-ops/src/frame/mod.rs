@@ -1,7 +1,11 @@
+#[cfg(feature = "interval_join")]
+mod intervals;
 mod join;
 #[cfg(feature = "pivot")]
 pub mod pivot;
 
+#[cfg(feature = "interval_join")]
+pub use intervals::{merge_intervals, split_intervals};
 pub use join::*;
 #[cfg(feature = "to_dummies")]
 use polars_core::export::rayon::prelude::*;
@@ -36,6 +36,16 @@ fn restore_logical_type(s: &Series, logical_type: &DataType) -> Series {
                     .into_series()
             }
         }
+        #[cfg(feature = "dtype-categorical")]
+        (DataType::Enum(Some(rev_map)), _) => {
+            let cats = s.u32().unwrap().clone();
+            // safety:
+            // the rev-map comes from these enum values
+            unsafe {
+                CategoricalChunked::from_enum_cats_and_rev_map_unchecked(cats, rev_map.clone())
+                    .into_series()
+            }
+        }
         (DataType::Float32, DataType::UInt32) => {
             let ca = s.u32().unwrap();
             ca._reinterpret_float().into_series()
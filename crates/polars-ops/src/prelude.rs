@@ -4,5 +4,7 @@ pub(crate) use {crate::series::*, polars_core::export::rayon::prelude::*};
 pub use crate::chunked_array::*;
 #[cfg(feature = "merge_sorted")]
 pub use crate::frame::_merge_sorted_dfs;
+#[cfg(feature = "interval_join")]
+pub use crate::frame::{_interval_join_ids, merge_intervals, split_intervals};
 pub use crate::frame::{DataFrameJoinOps, DataFrameOps};
 pub use crate::series::*;
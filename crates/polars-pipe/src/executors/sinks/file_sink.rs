@@ -1,47 +1,101 @@
 use std::any::Any;
+use std::io::Write;
 use std::path::Path;
 use std::thread::JoinHandle;
 
 use crossbeam_channel::{bounded, Receiver, Sender};
+use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroWriter;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::ParquetWriter;
 #[cfg(feature = "ipc")]
 use polars_io::prelude::IpcWriter;
-#[cfg(feature = "ipc")]
+#[cfg(any(feature = "ipc", feature = "avro"))]
 use polars_io::SerWriter;
+#[cfg(feature = "cloud")]
+use polars_io::{is_cloud_url, CloudWriter};
 use polars_plan::prelude::*;
 
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
 use crate::pipeline::morsels_per_sink;
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
 trait SinkWriter {
     fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()>;
-    fn _finish(&mut self) -> PolarsResult<()>;
+    fn _finish(self: Box<Self>) -> PolarsResult<()>;
+}
+
+/// The destination of a file sink.
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
+trait SinkOutput: Write + Send + Sync {
+    /// Called once the writer is finished.
+    fn close(self: Box<Self>) -> PolarsResult<()>;
+}
+
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
+impl SinkOutput for std::fs::File {
+    fn close(self: Box<Self>) -> PolarsResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "cloud")]
+impl SinkOutput for CloudWriter {
+    fn close(mut self: Box<Self>) -> PolarsResult<()> {
+        CloudWriter::close(&mut self)
+    }
+}
+
+/// Create a local file, or start a multipart upload if `path` is a cloud url.
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
+#[allow(unused_variables)]
+fn open_output(
+    path: &Path,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<Box<dyn SinkOutput>> {
+    #[cfg(feature = "cloud")]
+    if is_cloud_url(path) {
+        let uri = path.to_string_lossy();
+        return Ok(Box::new(CloudWriter::new(&uri, cloud_options)?));
+    }
+    Ok(Box::new(std::fs::File::create(path)?))
 }
 
 #[cfg(feature = "parquet")]
-impl SinkWriter for polars_io::parquet::BatchedWriter<std::fs::File> {
+impl SinkWriter for polars_io::parquet::BatchedWriter<Box<dyn SinkOutput>> {
     fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         self.write_batch(df)
     }
 
-    fn _finish(&mut self) -> PolarsResult<()> {
+    fn _finish(mut self: Box<Self>) -> PolarsResult<()> {
         self.finish()?;
-        Ok(())
+        (*self).into_inner().close()
     }
 }
 
 #[cfg(feature = "ipc")]
-impl SinkWriter for polars_io::ipc::BatchedWriter<std::fs::File> {
+impl SinkWriter for polars_io::ipc::BatchedWriter<Box<dyn SinkOutput>> {
     fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
         self.write_batch(df)
     }
 
-    fn _finish(&mut self) -> PolarsResult<()> {
+    fn _finish(mut self: Box<Self>) -> PolarsResult<()> {
         self.finish()?;
-        Ok(())
+        (*self).into_inner().close()
+    }
+}
+
+#[cfg(feature = "avro")]
+impl SinkWriter for polars_io::avro::BatchedWriter<Box<dyn SinkOutput>> {
+    fn _write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        self.write_batch(df)
+    }
+
+    fn _finish(mut self: Box<Self>) -> PolarsResult<()> {
+        self.finish()?;
+        (*self).into_inner().close()
     }
 }
 
@@ -54,8 +108,9 @@ impl ParquetSink {
         path: &Path,
         options: ParquetWriteOptions,
         schema: &Schema,
+        cloud_options: Option<&CloudOptions>,
     ) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
+        let file = open_output(path, cloud_options)?;
         let writer = ParquetWriter::new(file)
             .with_compression(options.compression)
             .with_data_pagesize_limit(options.data_pagesize_limit)
@@ -91,8 +146,13 @@ pub struct IpcSink {}
 #[cfg(feature = "ipc")]
 impl IpcSink {
     #[allow(clippy::new_ret_no_self)]
-    pub fn new(path: &Path, options: IpcWriterOptions, schema: &Schema) -> PolarsResult<FilesSink> {
-        let file = std::fs::File::create(path)?;
+    pub fn new(
+        path: &Path,
+        options: IpcWriterOptions,
+        schema: &Schema,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<FilesSink> {
+        let file = open_output(path, cloud_options)?;
         let writer = IpcWriter::new(file)
             .with_compression(options.compression)
             .batched(schema)?;
@@ -117,7 +177,43 @@ impl IpcSink {
     }
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(feature = "avro")]
+pub struct AvroSink {}
+#[cfg(feature = "avro")]
+impl AvroSink {
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        path: &Path,
+        options: AvroWriterOptions,
+        schema: &Schema,
+        cloud_options: Option<&CloudOptions>,
+    ) -> PolarsResult<FilesSink> {
+        let file = open_output(path, cloud_options)?;
+        let writer = AvroWriter::new(file)
+            .with_compression(options.compression)
+            .batched(schema)?;
+
+        let writer = Box::new(writer) as Box<dyn SinkWriter + Send + Sync>;
+
+        let morsels_per_sink = morsels_per_sink();
+        let backpressure = morsels_per_sink * 2;
+        let (sender, receiver) = bounded(backpressure);
+
+        let io_thread_handle = Arc::new(Some(init_writer_thread(
+            receiver,
+            writer,
+            options.maintain_order,
+            morsels_per_sink,
+        )));
+
+        Ok(FilesSink {
+            sender,
+            io_thread_handle,
+        })
+    }
+}
+
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
 fn init_writer_thread(
     receiver: Receiver<Option<DataChunk>>,
     mut writer: Box<dyn SinkWriter + Send + Sync>,
@@ -164,13 +260,13 @@ fn init_writer_thread(
 
 // Ensure the data is return in the order it was streamed
 #[derive(Clone)]
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
 pub struct FilesSink {
     sender: Sender<Option<DataChunk>>,
     io_thread_handle: Arc<Option<JoinHandle<()>>>,
 }
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
 impl Sink for FilesSink {
     fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
         // don't add empty dataframes
@@ -45,6 +45,19 @@ pub(super) fn physical_agg_to_logical(cols: &mut [Series], output_schema: &Schem
                     }
                 }
             }
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(Some(rev_map)) => {
+                let cats = s.u32().unwrap().clone();
+                // safety:
+                // the rev-map comes from these categoricals
+                unsafe {
+                    *s = CategoricalChunked::from_enum_cats_and_rev_map_unchecked(
+                        cats,
+                        rev_map.clone(),
+                    )
+                    .into_series()
+                }
+            }
             _ => {
                 let dtype_left = s.dtype();
                 if dtype_left != dtype
@@ -0,0 +1,195 @@
+use std::any::Any;
+use std::sync::Arc;
+
+use polars_core::error::PolarsResult;
+use polars_core::frame::hash_join::JoinArgs;
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_ops::prelude::{DataFrameJoinOps, SeriesMethods};
+
+use crate::expressions::PhysicalPipedExpr;
+use crate::operators::{
+    DataChunk, FinalizedSink, Operator, OperatorResult, PExecutionContext, Sink, SinkResult,
+};
+
+/// Collects the right table of an asof join. The left table is streamed
+/// through the [`AsOfJoinProbe`], so that its order is maintained.
+pub struct AsOfJoinBuild {
+    chunks: Vec<DataChunk>,
+    args: JoinArgs,
+    join_columns_left: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+    join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+}
+
+impl AsOfJoinBuild {
+    pub(crate) fn new(
+        args: JoinArgs,
+        join_columns_left: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+        join_columns_right: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+    ) -> Self {
+        AsOfJoinBuild {
+            chunks: vec![],
+            args,
+            join_columns_left,
+            join_columns_right,
+        }
+    }
+}
+
+/// Evaluate the join columns and add them to the chunk, so that the join can find them by name.
+///
+/// The sorted flag of the asof key doesn't survive the expressions, so without `by` columns the
+/// key is flagged if its values are sorted. Unsorted keys then raise the same error as they do
+/// in the default engine.
+fn with_join_columns(
+    context: &PExecutionContext,
+    chunk: &DataChunk,
+    join_columns: &[Arc<dyn PhysicalPipedExpr>],
+    check_sorted: bool,
+) -> PolarsResult<(DataFrame, Vec<Series>)> {
+    let mut df = chunk.data.clone();
+    let mut selected = join_columns
+        .iter()
+        .map(|e| e.evaluate(chunk, context.execution_state.as_any()))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    if check_sorted
+        && matches!(selected[0].is_sorted_flag(), IsSorted::Not)
+        && selected[0].is_sorted(SortOptions::default())?
+    {
+        selected[0].set_sorted_flag(IsSorted::Ascending);
+    }
+    for s in &selected {
+        df.with_column(s.clone())?;
+    }
+    Ok((df, selected))
+}
+
+fn asof_options(args: &JoinArgs) -> &AsOfOptions {
+    match &args.how {
+        JoinType::AsOf(options) => options,
+        _ => unreachable!(),
+    }
+}
+
+impl Sink for AsOfJoinBuild {
+    fn sink(&mut self, _context: &PExecutionContext, chunk: DataChunk) -> PolarsResult<SinkResult> {
+        self.chunks.push(chunk);
+        Ok(SinkResult::CanHaveMoreInput)
+    }
+
+    fn combine(&mut self, other: &mut dyn Sink) {
+        let other = other.as_any().downcast_mut::<Self>().unwrap();
+        let other_chunks = std::mem::take(&mut other.chunks);
+        self.chunks.extend(other_chunks);
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Sink> {
+        Box::new(Self::new(
+            self.args.clone(),
+            self.join_columns_left.clone(),
+            self.join_columns_right.clone(),
+        ))
+    }
+
+    fn finalize(&mut self, context: &PExecutionContext) -> PolarsResult<FinalizedSink> {
+        // the threads may have received the chunks in any order
+        let mut chunks = std::mem::take(&mut self.chunks);
+        chunks.sort_unstable_by_key(|chunk| chunk.chunk_index);
+        let chunk = DataChunk {
+            chunk_index: 0,
+            data: accumulate_dataframes_vertical_unchecked(chunks.into_iter().map(|c| c.data)),
+        };
+        let options = asof_options(&self.args);
+        let (df_right, right_on) = with_join_columns(
+            context,
+            &chunk,
+            &self.join_columns_right,
+            options.right_by.is_none(),
+        )?;
+        // group the right table on the `by` columns once, instead of for every left chunk
+        let by_right = options
+            .right_by
+            .clone()
+            .map(|right_by| AsofJoinByRight::new(&df_right, right_on[0].name(), right_by))
+            .transpose()?;
+
+        Ok(FinalizedSink::Operator(Box::new(AsOfJoinProbe {
+            df_right: Arc::new(df_right),
+            right_on: Arc::new(right_on),
+            by_right: by_right.map(Arc::new),
+            join_columns_left: self.join_columns_left.clone(),
+            args: self.args.clone(),
+        })))
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn fmt(&self) -> &str {
+        "asof_join_build"
+    }
+}
+
+#[derive(Clone)]
+pub struct AsOfJoinProbe {
+    df_right: Arc<DataFrame>,
+    // the evaluated join columns of the right table
+    right_on: Arc<Vec<Series>>,
+    // the right table grouped on the `by` columns
+    by_right: Option<Arc<AsofJoinByRight>>,
+    join_columns_left: Arc<Vec<Arc<dyn PhysicalPipedExpr>>>,
+    args: JoinArgs,
+}
+
+impl Operator for AsOfJoinProbe {
+    fn execute(
+        &mut self,
+        context: &PExecutionContext,
+        chunk: &DataChunk,
+    ) -> PolarsResult<OperatorResult> {
+        let options = asof_options(&self.args);
+        // the chunks are probed independently, so every chunk has to be sorted by itself.
+        // A slice is applied after the join, not to every chunk.
+        let (df_left, left_on) = with_join_columns(
+            context,
+            chunk,
+            &self.join_columns_left,
+            options.left_by.is_none(),
+        )?;
+        let out = match (&self.by_right, &options.left_by) {
+            (Some(by_right), Some(left_by)) => df_left._join_asof_by_right(
+                by_right,
+                left_on[0].name(),
+                left_by.clone(),
+                options.strategy,
+                options.tolerance.clone(),
+                options.tolerance_column.as_deref(),
+                options.allow_exact_matches,
+                self.args.suffix.as_deref(),
+                None,
+            )?,
+            _ => df_left._join_impl(
+                &self.df_right,
+                left_on,
+                self.right_on.as_ref().clone(),
+                JoinArgs {
+                    slice: None,
+                    ..self.args.clone()
+                },
+                false,
+                false,
+            )?,
+        };
+        Ok(OperatorResult::Finished(chunk.with_data(out)))
+    }
+
+    fn split(&self, _thread_no: usize) -> Box<dyn Operator> {
+        Box::new(self.clone())
+    }
+
+    fn fmt(&self) -> &str {
+        "asof_join_probe"
+    }
+}
@@ -1,8 +1,12 @@
+#[cfg(feature = "asof_join")]
+mod asof;
 #[cfg(feature = "cross_join")]
 mod cross;
 mod generic_build;
 mod inner_left;
 
+#[cfg(feature = "asof_join")]
+pub(crate) use asof::*;
 #[cfg(feature = "cross_join")]
 pub(crate) use cross::*;
 pub(crate) use generic_build::GenericBuild;
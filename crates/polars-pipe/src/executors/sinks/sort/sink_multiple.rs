@@ -27,15 +27,11 @@ fn get_sort_fields(sort_idx: &[usize], sort_args: &SortArguments) -> Vec<SortFie
         .collect()
 }
 
-#[cfg(feature = "dtype-categorical")]
 fn sort_column_can_be_decoded(schema: &Schema, sort_idx: &[usize]) -> bool {
-    !sort_idx
-        .iter()
-        .any(|i| matches!(schema.get_at_index(*i).unwrap().1, DataType::Categorical(_)))
-}
-#[cfg(not(feature = "dtype-categorical"))]
-fn sort_column_can_be_decoded(_schema: &Schema, _sort_idx: &[usize]) -> bool {
-    true
+    !sort_idx.iter().any(|i| {
+        let dtype = schema.get_at_index(*i).unwrap().1;
+        dtype.is_categorical() || dtype.is_nested()
+    })
 }
 
 fn sort_by_idx<V: Clone>(values: &[V], idx: &[usize]) -> Vec<V> {
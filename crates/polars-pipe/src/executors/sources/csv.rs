@@ -1,10 +1,12 @@
-use std::fs::File;
 use std::path::PathBuf;
 
+use polars_core::cloud::CloudOptions;
 use polars_core::export::arrow::Either;
 use polars_core::POOL;
 use polars_io::csv::read_impl::{BatchedCsvReaderMmap, BatchedCsvReaderRead};
 use polars_io::csv::{CsvEncoding, CsvReader};
+use polars_io::is_cloud_url;
+use polars_io::mmap::MmapBytesReader;
 use polars_plan::global::_set_n_rows_for_scan;
 use polars_plan::prelude::{CsvParserOptions, FileScanOptions};
 
@@ -15,7 +17,7 @@ pub(crate) struct CsvSource {
     #[allow(dead_code)]
     // this exist because we need to keep ownership
     schema: SchemaRef,
-    reader: Option<*mut CsvReader<'static, File>>,
+    reader: Option<*mut CsvReader<'static, Box<dyn MmapBytesReader>>>,
     batched_reader:
         Option<Either<*mut BatchedCsvReaderMmap<'static>, *mut BatchedCsvReaderRead<'static>>>,
     n_threads: usize,
@@ -23,6 +25,7 @@ pub(crate) struct CsvSource {
     path: Option<PathBuf>,
     options: Option<CsvParserOptions>,
     file_options: Option<FileScanOptions>,
+    cloud_options: Option<CloudOptions>,
     verbose: bool,
 }
 
@@ -59,11 +62,11 @@ impl CsvSource {
             eprintln!("STREAMING CHUNK SIZE: {chunk_size} rows")
         }
 
-        let reader = CsvReader::from_path(&path)
-            .unwrap()
+        let file = polars_io::open_file_or_cloud(&path, self.cloud_options.as_ref())?;
+        let reader = CsvReader::new(file)
             .has_header(options.has_header)
             .with_schema(self.schema.clone())
-            .with_delimiter(options.delimiter)
+            .with_multi_byte_delimiter(&options.delimiter)
             .with_ignore_errors(options.ignore_errors)
             .with_skip_rows(options.skip_rows)
             .with_n_rows(n_rows)
@@ -73,18 +76,22 @@ impl CsvSource {
             .with_encoding(CsvEncoding::LossyUtf8)
             .with_comment_char(options.comment_char)
             .with_quote_char(options.quote_char)
+            .with_escape_char(options.escape_char)
             .with_end_of_line_char(options.eol_char)
             .with_encoding(options.encoding)
             // never rechunk in streaming
             .with_rechunk(false)
             .with_chunk_size(chunk_size)
             .with_row_count(file_options.row_count)
-            .with_try_parse_dates(options.try_parse_dates);
+            .with_try_parse_dates(options.try_parse_dates)
+            .with_strict(options.strict)
+            .with_bad_records(options.bad_records);
 
         let reader = Box::new(reader);
-        let reader = Box::leak(reader) as *mut CsvReader<'static, File>;
+        let reader = Box::leak(reader) as *mut CsvReader<'static, Box<dyn MmapBytesReader>>;
 
-        let batched_reader = if options.low_memory {
+        // cloud objects are fetched in full, so they can't be read in batches from a file
+        let batched_reader = if options.low_memory && !is_cloud_url(&path) {
             let batched_reader = unsafe { Box::new((*reader).batched_borrowed_read()?) };
             let batched_reader = Box::leak(batched_reader) as *mut BatchedCsvReaderRead;
             Either::Right(batched_reader)
@@ -103,6 +110,7 @@ impl CsvSource {
         schema: SchemaRef,
         options: CsvParserOptions,
         file_options: FileScanOptions,
+        cloud_options: Option<CloudOptions>,
         verbose: bool,
     ) -> PolarsResult<Self> {
         Ok(CsvSource {
@@ -114,6 +122,7 @@ impl CsvSource {
             path: Some(path),
             options: Some(options),
             file_options: Some(file_options),
+            cloud_options,
             verbose,
         })
     }
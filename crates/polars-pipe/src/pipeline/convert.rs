@@ -85,12 +85,14 @@ where
                 #[cfg(feature = "csv")]
                 FileScan::Csv {
                     options: csv_options,
+                    cloud_options,
                 } => {
                     let src = sources::CsvSource::new(
                         path,
                         file_info.schema,
                         csv_options,
                         file_options,
+                        cloud_options,
                         verbose,
                     )?;
                     Ok(Box::new(src) as Box<dyn Source>)
@@ -130,17 +132,30 @@ where
     let out = match lp_arena.get(node) {
         FileSink { input, payload } => {
             let path = payload.path.as_ref().as_path();
+            let cloud_options = payload.cloud_options.as_ref();
             let input_schema = lp_arena.get(*input).schema(lp_arena);
             match &payload.file_type {
                 #[cfg(feature = "parquet")]
-                FileType::Parquet(options) => {
-                    Box::new(ParquetSink::new(path, *options, input_schema.as_ref())?)
-                        as Box<dyn Sink>
-                }
+                FileType::Parquet(options) => Box::new(ParquetSink::new(
+                    path,
+                    *options,
+                    input_schema.as_ref(),
+                    cloud_options,
+                )?) as Box<dyn Sink>,
                 #[cfg(feature = "ipc")]
-                FileType::Ipc(options) => {
-                    Box::new(IpcSink::new(path, *options, input_schema.as_ref())?) as Box<dyn Sink>
-                }
+                FileType::Ipc(options) => Box::new(IpcSink::new(
+                    path,
+                    *options,
+                    input_schema.as_ref(),
+                    cloud_options,
+                )?) as Box<dyn Sink>,
+                #[cfg(feature = "avro")]
+                FileType::Avro(options) => Box::new(AvroSink::new(
+                    path,
+                    *options,
+                    input_schema.as_ref(),
+                    cloud_options,
+                )?) as Box<dyn Sink>,
                 FileType::Memory => Box::new(OrderedSink::new()) as Box<dyn Sink>,
             }
         }
@@ -192,6 +207,38 @@ where
                         join_columns_right,
                     )) as Box<dyn Sink>
                 }
+                #[cfg(feature = "asof_join")]
+                JoinType::AsOf(asof_options) => {
+                    let input_schema_left = lp_arena.get(*input_left).schema(lp_arena);
+                    let input_schema_right = lp_arena.get(*input_right).schema(lp_arena);
+                    let left_asof = expr_arena.get(left_on[0]).to_field(
+                        input_schema_left.as_ref(),
+                        Context::Default,
+                        expr_arena,
+                    )?;
+                    let mut args = options.args.clone();
+                    let mut asof_options = asof_options.clone();
+                    resolve_asof_tolerance(&mut asof_options, left_asof.data_type())?;
+                    args.how = JoinType::AsOf(asof_options);
+
+                    let join_columns_left = Arc::new(exprs_to_physical(
+                        left_on,
+                        expr_arena,
+                        to_physical,
+                        Some(input_schema_left.as_ref()),
+                    )?);
+                    let join_columns_right = Arc::new(exprs_to_physical(
+                        right_on,
+                        expr_arena,
+                        to_physical,
+                        Some(input_schema_right.as_ref()),
+                    )?);
+                    Box::new(AsOfJoinBuild::new(
+                        args,
+                        join_columns_left,
+                        join_columns_right,
+                    )) as Box<dyn Sink>
+                }
                 _ => unimplemented!(),
             }
         }
@@ -613,7 +660,14 @@ where
 }
 
 pub fn swap_join_order(options: &JoinOptions) -> bool {
-    matches!(options.args.how, JoinType::Left)
+    // these joins stream the left table to maintain its order
+    let streams_left = match options.args.how {
+        JoinType::Left => true,
+        #[cfg(feature = "asof_join")]
+        JoinType::AsOf(_) => true,
+        _ => false,
+    };
+    streams_left
         || match (options.rows_left, options.rows_right) {
             ((Some(left), _), (Some(right), _)) => left > right,
             ((_, left), (_, right)) => left > right,
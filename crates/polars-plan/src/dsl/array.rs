@@ -1,3 +1,5 @@
+use polars_core::prelude::*;
+
 use crate::dsl::function_expr::{ArrayFunction, FunctionExpr};
 use crate::prelude::*;
 
@@ -37,4 +39,124 @@ impl ArrayNameSpace {
         self.0
             .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Unique(true)))
     }
+
+    /// Compute the mean of the items in every subarray.
+    pub fn mean(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Mean))
+    }
+
+    /// Compute the standard deviation of the items in every subarray.
+    pub fn std(self, ddof: u8) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Std(ddof)))
+    }
+
+    /// Evaluate whether any boolean value in every subarray is true.
+    pub fn any(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Any))
+    }
+
+    /// Evaluate whether all boolean values in every subarray are true.
+    pub fn all(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::All))
+    }
+
+    /// Get items in every subarray by index.
+    pub fn get(self, index: Expr) -> Expr {
+        self.0
+            .map_many_private(FunctionExpr::ArrayExpr(ArrayFunction::Get), &[index], false)
+    }
+
+    /// Check if every subarray contains an item.
+    pub fn contains<E: Into<Expr>>(self, item: E) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::Contains),
+            &[item.into()],
+            false,
+        )
+    }
+
+    /// Join all string items in a subarray and place a separator between them.
+    /// # Error
+    /// This errors if inner type of array `!= DataType::Utf8`.
+    pub fn join(self, separator: &str) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Join(
+                separator.to_string(),
+            )))
+    }
+
+    /// Sort every subarray.
+    pub fn sort(self, options: SortOptions) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Sort(options)))
+    }
+
+    /// Reverse every subarray.
+    pub fn reverse(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::Reverse))
+    }
+
+    /// Return the index of the minimal value of every subarray.
+    pub fn arg_min(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::ArgMin))
+    }
+
+    /// Return the index of the maximum value of every subarray.
+    pub fn arg_max(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::ArgMax))
+    }
+
+    /// Convert this `Array` to a `List`.
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_list(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::ToList))
+    }
+
+    /// Convert this `Array` to a `Struct` with a field `field_{i}` for every position `i`.
+    #[cfg(feature = "dtype-struct")]
+    #[allow(clippy::wrong_self_convention)]
+    pub fn to_struct(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::ToStruct))
+    }
+
+    /// Explode every subarray to its items. Null subarrays produce `width` nulls.
+    pub fn explode(self) -> Expr {
+        self.0
+            .apply_private(FunctionExpr::ArrayExpr(ArrayFunction::Explode))
+    }
+
+    /// Compute the dot product of every subarray with `other`. `other` is either an array
+    /// column of the same width or a single vector that is used for every row.
+    pub fn dot<E: Into<Expr>>(self, other: E) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::Dot),
+            &[other.into()],
+            false,
+        )
+    }
+
+    /// Compute the cosine similarity of every subarray with `other`. `other` is either an
+    /// array column of the same width or a single vector that is used for every row.
+    pub fn cosine_similarity<E: Into<Expr>>(self, other: E) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::ArrayExpr(ArrayFunction::CosineSimilarity),
+            &[other.into()],
+            false,
+        )
+    }
+
+    /// Compute the euclidean norm of every subarray.
+    pub fn l2_norm(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::ArrayExpr(ArrayFunction::L2Norm))
+    }
 }
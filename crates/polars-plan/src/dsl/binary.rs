@@ -22,4 +22,56 @@ impl BinaryNameSpace {
         let sub = sub.as_ref().into();
         self.0.map_private(BinaryFunction::StartsWith(sub).into())
     }
+
+    /// Get the length of the binary values in bytes.
+    pub fn lengths(self) -> Expr {
+        self.0.map_private(BinaryFunction::Lengths.into())
+    }
+
+    /// Take `length` bytes of every binary value, starting at `offset`.
+    /// A negative `offset` counts from the end of the value; a `length` of `None` takes
+    /// everything up to the end.
+    pub fn slice(self, offset: i64, length: Option<u64>) -> Expr {
+        self.0
+            .map_private(BinaryFunction::Slice(offset, length).into())
+    }
+
+    /// Decode every binary value as a single fixed-width number of type `dtype`,
+    /// using the given byte order. Values of the wrong length become null.
+    pub fn reinterpret(self, dtype: DataType, endianness: Endianness) -> Expr {
+        self.0
+            .map_private(BinaryFunction::Reinterpret(dtype, endianness).into())
+    }
+
+    /// Decode the binary values as UTF-8 strings.
+    pub fn decode_utf8(self, strict: bool) -> Expr {
+        self.0
+            .map_private(BinaryFunction::DecodeUtf8(strict).into())
+    }
+
+    /// Encode the binary values as base32 strings.
+    #[cfg(feature = "binary_encoding")]
+    pub fn base32_encode(self) -> Expr {
+        self.0.map_private(BinaryFunction::Base32Encode.into())
+    }
+
+    /// Decode base32 encoded binary values.
+    #[cfg(feature = "binary_encoding")]
+    pub fn base32_decode(self, strict: bool) -> Expr {
+        self.0
+            .map_private(BinaryFunction::Base32Decode(strict).into())
+    }
+
+    /// Encode the binary values as base85 strings.
+    #[cfg(feature = "binary_encoding")]
+    pub fn base85_encode(self) -> Expr {
+        self.0.map_private(BinaryFunction::Base85Encode.into())
+    }
+
+    /// Decode base85 encoded binary values.
+    #[cfg(feature = "binary_encoding")]
+    pub fn base85_decode(self, strict: bool) -> Expr {
+        self.0
+            .map_private(BinaryFunction::Base85Decode(strict).into())
+    }
 }
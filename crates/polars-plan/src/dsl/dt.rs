@@ -250,12 +250,25 @@ impl DateLikeNameSpace {
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::DSTOffset))
     }
 
-    pub fn round<S: AsRef<str>>(self, every: S, offset: S) -> Expr {
+    /// Round the datetimes to the boundaries of windows of size `every`, shifted by `offset`.
+    ///
+    /// `ambiguous` and `non_existent` control how rounded datetimes that fall on a DST
+    /// transition are resolved.
+    pub fn round<S: AsRef<str>>(
+        self,
+        every: S,
+        offset: S,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> Expr {
         let every = every.as_ref().into();
         let offset = offset.as_ref().into();
         self.0
             .map_private(FunctionExpr::TemporalExpr(TemporalFunction::Round(
-                every, offset,
+                every,
+                offset,
+                ambiguous,
+                non_existent,
             )))
     }
 
@@ -266,14 +279,19 @@ impl DateLikeNameSpace {
         self.0.map_private(FunctionExpr::DateOffset(by))
     }
 
+    /// Replace the time zone of the datetimes without changing their wall time.
+    ///
+    /// `ambiguous` and `non_existent` control how local datetimes that fall on a DST
+    /// transition of `time_zone` are resolved.
     #[cfg(feature = "timezones")]
     pub fn replace_time_zone(
         self,
         time_zone: Option<TimeZone>,
-        use_earliest: Option<bool>,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
     ) -> Expr {
         self.0.map_private(FunctionExpr::TemporalExpr(
-            TemporalFunction::ReplaceTimeZone(time_zone, use_earliest),
+            TemporalFunction::ReplaceTimeZone(time_zone, ambiguous, non_existent),
         ))
     }
 
@@ -284,4 +302,58 @@ impl DateLikeNameSpace {
             false,
         )
     }
+
+    /// Add `n` business days, skipping the days not in `week_mask` (starting at Monday)
+    /// and the `holidays`, given in days since the unix epoch.
+    #[cfg(feature = "business")]
+    pub fn add_business_days(
+        self,
+        n: Expr,
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        roll: Roll,
+    ) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::TemporalExpr(TemporalFunction::AddBusinessDays {
+                week_mask,
+                holidays,
+                roll,
+            }),
+            &[n],
+            false,
+        )
+    }
+
+    /// Check whether the dates fall on a business day.
+    #[cfg(feature = "business")]
+    pub fn is_business_day(self, week_mask: [bool; 7], holidays: Vec<i32>) -> Expr {
+        self.0.map_private(FunctionExpr::TemporalExpr(
+            TemporalFunction::IsBusinessDay {
+                week_mask,
+                holidays,
+            },
+        ))
+    }
+
+    /// The number of `unit`s from `other` to these dates. Months, quarters and years are
+    /// calendar aware: one month after January 31st is the last day of February.
+    /// The difference is truncated towards zero, unless `fractional` is set.
+    #[cfg(feature = "date_diff")]
+    pub fn diff_in(self, other: Expr, unit: DateDiffUnit, fractional: bool) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::TemporalExpr(TemporalFunction::DiffIn { unit, fractional }),
+            &[other],
+            false,
+        )
+    }
+
+    /// The age in whole years at `reference` of these dates of birth.
+    #[cfg(feature = "date_diff")]
+    pub fn age(self, reference: Expr) -> Expr {
+        self.0.map_many_private(
+            FunctionExpr::TemporalExpr(TemporalFunction::Age),
+            &[reference],
+            false,
+        )
+    }
 }
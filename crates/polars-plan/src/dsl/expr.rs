@@ -121,7 +121,7 @@ pub enum Expr {
         function: Box<Expr>,
         partition_by: Vec<Expr>,
         order_by: Option<Box<Expr>>,
-        options: WindowOptions,
+        options: WindowType,
     },
     Wildcard,
     Slice {
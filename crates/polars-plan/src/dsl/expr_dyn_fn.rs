@@ -221,6 +221,38 @@ where
 
 pub trait FunctionOutputField: Send + Sync {
     fn get_field(&self, input_schema: &Schema, cntxt: Context, fields: &[Field]) -> Field;
+
+    /// Like [`FunctionOutputField::get_field`], but an output field that cannot be
+    /// determined is returned as an error.
+    fn try_get_field(
+        &self,
+        input_schema: &Schema,
+        cntxt: Context,
+        fields: &[Field],
+    ) -> PolarsResult<Field> {
+        Ok(self.get_field(input_schema, cntxt, fields))
+    }
+}
+
+/// An output field that may fail to resolve, see [`GetOutput::try_map_dtype`].
+struct TryOutputField<F>(F);
+
+impl<F> FunctionOutputField for TryOutputField<F>
+where
+    F: Fn(&Schema, Context, &[Field]) -> PolarsResult<Field> + Send + Sync,
+{
+    fn get_field(&self, input_schema: &Schema, cntxt: Context, fields: &[Field]) -> Field {
+        self.try_get_field(input_schema, cntxt, fields).unwrap()
+    }
+
+    fn try_get_field(
+        &self,
+        input_schema: &Schema,
+        cntxt: Context,
+        fields: &[Field],
+    ) -> PolarsResult<Field> {
+        (self.0)(input_schema, cntxt, fields)
+    }
 }
 
 pub type GetOutput = SpecialEq<Arc<dyn FunctionOutputField>>;
@@ -265,6 +297,21 @@ impl GetOutput {
         }))
     }
 
+    /// Like [`GetOutput::map_dtype`], but an error of `f` is returned when the schema is resolved.
+    pub fn try_map_dtype<F>(f: F) -> Self
+    where
+        F: 'static + Fn(&DataType) -> PolarsResult<DataType> + Send + Sync,
+    {
+        SpecialEq::new(Arc::new(TryOutputField(
+            move |_: &Schema, _: Context, flds: &[Field]| {
+                let mut fld = flds[0].clone();
+                let new_type = f(fld.data_type())?;
+                fld.coerce(new_type);
+                Ok(fld)
+            },
+        )))
+    }
+
     pub fn float_type() -> Self {
         Self::map_dtype(|dt| match dt {
             DataType::Float32 => DataType::Float32,
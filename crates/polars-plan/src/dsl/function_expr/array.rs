@@ -2,13 +2,31 @@ use polars_ops::chunked_array::array::*;
 
 use super::*;
 
-#[derive(Clone, Copy, Eq, PartialEq, Hash, Debug)]
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum ArrayFunction {
     Min,
     Max,
     Sum,
     Unique(bool),
+    Mean,
+    Std(u8),
+    Any,
+    All,
+    Get,
+    Contains,
+    Join(String),
+    Sort(SortOptions),
+    Reverse,
+    ArgMin,
+    ArgMax,
+    ToList,
+    #[cfg(feature = "dtype-struct")]
+    ToStruct,
+    Explode,
+    Dot,
+    CosineSimilarity,
+    L2Norm,
 }
 
 impl Display for ArrayFunction {
@@ -19,6 +37,24 @@ impl Display for ArrayFunction {
             Max => "max",
             Sum => "sum",
             Unique(_) => "unique",
+            Mean => "mean",
+            Std(_) => "std",
+            Any => "any",
+            All => "all",
+            Get => "get",
+            Contains => "contains",
+            Join(_) => "join",
+            Sort(_) => "sort",
+            Reverse => "reverse",
+            ArgMin => "arg_min",
+            ArgMax => "arg_max",
+            ToList => "to_list",
+            #[cfg(feature = "dtype-struct")]
+            ToStruct => "to_struct",
+            Explode => "explode",
+            Dot => "dot",
+            CosineSimilarity => "cosine_similarity",
+            L2Norm => "l2_norm",
         };
 
         write!(f, "arr.{name}")
@@ -46,3 +82,75 @@ pub(super) fn unique(s: &Series, stable: bool) -> PolarsResult<Series> {
     };
     out.map(|ca| ca.into_series())
 }
+
+pub(super) fn mean(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_mean()
+}
+
+pub(super) fn std(s: &Series, ddof: u8) -> PolarsResult<Series> {
+    s.array()?.array_std(ddof)
+}
+
+pub(super) fn any(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_any()
+}
+
+pub(super) fn all(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_all()
+}
+
+pub(super) fn get(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    let index = s[1].cast(&DataType::Int64)?;
+    ca.array_get(index.i64().unwrap())
+}
+
+pub(super) fn contains(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].array()?;
+    ca.array_contains(&s[1]).map(|ca| ca.into_series())
+}
+
+pub(super) fn join(s: &Series, separator: &str) -> PolarsResult<Series> {
+    s.array()?.array_join(separator).map(|ca| ca.into_series())
+}
+
+pub(super) fn sort(s: &Series, options: SortOptions) -> PolarsResult<Series> {
+    s.array()?.array_sort(options)
+}
+
+pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_reverse()
+}
+
+pub(super) fn arg_min(s: &Series) -> PolarsResult<Series> {
+    Ok(s.array()?.array_arg_min().into_series())
+}
+
+pub(super) fn arg_max(s: &Series) -> PolarsResult<Series> {
+    Ok(s.array()?.array_arg_max().into_series())
+}
+
+pub(super) fn to_list(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_to_list()
+}
+
+#[cfg(feature = "dtype-struct")]
+pub(super) fn to_struct(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_to_struct().map(|ca| ca.into_series())
+}
+
+pub(super) fn explode(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_explode()
+}
+
+pub(super) fn dot(s: &[Series]) -> PolarsResult<Series> {
+    s[0].array()?.array_dot(&s[1])
+}
+
+pub(super) fn cosine_similarity(s: &[Series]) -> PolarsResult<Series> {
+    s[0].array()?.array_cosine_similarity(&s[1])
+}
+
+pub(super) fn l2_norm(s: &Series) -> PolarsResult<Series> {
+    s.array()?.array_l2_norm()
+}
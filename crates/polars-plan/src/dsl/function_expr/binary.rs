@@ -6,9 +6,24 @@ use super::*;
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum BinaryFunction {
-    Contains { pat: Vec<u8>, literal: bool },
+    Contains {
+        pat: Vec<u8>,
+        literal: bool,
+    },
     StartsWith(Vec<u8>),
     EndsWith(Vec<u8>),
+    Lengths,
+    Slice(i64, Option<u64>),
+    Reinterpret(DataType, Endianness),
+    DecodeUtf8(bool),
+    #[cfg(feature = "binary_encoding")]
+    Base32Encode,
+    #[cfg(feature = "binary_encoding")]
+    Base32Decode(bool),
+    #[cfg(feature = "binary_encoding")]
+    Base85Encode,
+    #[cfg(feature = "binary_encoding")]
+    Base85Decode(bool),
 }
 
 impl Display for BinaryFunction {
@@ -18,6 +33,18 @@ impl Display for BinaryFunction {
             Contains { .. } => "contains",
             StartsWith(_) => "starts_with",
             EndsWith(_) => "ends_with",
+            Lengths => "lengths",
+            Slice(_, _) => "slice",
+            Reinterpret(_, _) => "reinterpret",
+            DecodeUtf8(_) => "decode_utf8",
+            #[cfg(feature = "binary_encoding")]
+            Base32Encode => "base32_encode",
+            #[cfg(feature = "binary_encoding")]
+            Base32Decode(_) => "base32_decode",
+            #[cfg(feature = "binary_encoding")]
+            Base85Encode => "base85_encode",
+            #[cfg(feature = "binary_encoding")]
+            Base85Decode(_) => "base85_decode",
         };
         write!(f, "bin.{s}")
     }
@@ -41,6 +68,54 @@ pub(super) fn starts_with(s: &Series, sub: &[u8]) -> PolarsResult<Series> {
     Ok(ca.starts_with(sub).into_series())
 }
 
+pub(super) fn lengths(s: &Series) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    Ok(ca.bin_lengths().into_series())
+}
+
+pub(super) fn slice(s: &Series, offset: i64, length: Option<u64>) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    ca.bin_slice(offset, length).map(|ca| ca.into_series())
+}
+
+pub(super) fn reinterpret(
+    s: &Series,
+    dtype: &DataType,
+    endianness: Endianness,
+) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    ca.bin_reinterpret(dtype, endianness)
+}
+
+pub(super) fn decode_utf8(s: &Series, strict: bool) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    ca.decode_utf8(strict).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base32_encode(s: &Series) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    Ok(ca.base32_encode())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base32_decode(s: &Series, strict: bool) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    ca.base32_decode(strict).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base85_encode(s: &Series) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    Ok(ca.base85_encode())
+}
+
+#[cfg(feature = "binary_encoding")]
+pub(super) fn base85_decode(s: &Series, strict: bool) -> PolarsResult<Series> {
+    let ca = s.binary()?;
+    ca.base85_decode(strict).map(|ca| ca.into_series())
+}
+
 impl From<BinaryFunction> for FunctionExpr {
     fn from(b: BinaryFunction) -> Self {
         FunctionExpr::BinaryExpr(b)
@@ -40,20 +40,24 @@ pub enum TemporalFunction {
     BaseUtcOffset,
     #[cfg(feature = "timezones")]
     DSTOffset,
-    Round(String, String),
+    Round(String, String, Ambiguous, NonExistent),
     #[cfg(feature = "timezones")]
-    ReplaceTimeZone(Option<TimeZone>, Option<bool>),
+    ReplaceTimeZone(Option<TimeZone>, Ambiguous, NonExistent),
     DateRange {
         every: Duration,
         closed: ClosedWindow,
         time_unit: Option<TimeUnit>,
         time_zone: Option<TimeZone>,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
     },
     DateRanges {
         every: Duration,
         closed: ClosedWindow,
         time_unit: Option<TimeUnit>,
         time_zone: Option<TimeZone>,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
     },
     TimeRange {
         every: Duration,
@@ -64,6 +68,36 @@ pub enum TemporalFunction {
         closed: ClosedWindow,
     },
     Combine(TimeUnit),
+    #[cfg(feature = "business")]
+    AddBusinessDays {
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+        roll: Roll,
+    },
+    #[cfg(feature = "business")]
+    IsBusinessDay {
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+    },
+    #[cfg(feature = "business")]
+    BusinessDayCount {
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+    },
+    #[cfg(feature = "business")]
+    BusinessDateRange {
+        every: i64,
+        closed: ClosedWindow,
+        week_mask: [bool; 7],
+        holidays: Vec<i32>,
+    },
+    #[cfg(feature = "date_diff")]
+    DiffIn {
+        unit: DateDiffUnit,
+        fractional: bool,
+    },
+    #[cfg(feature = "date_diff")]
+    Age,
 }
 
 impl Display for TemporalFunction {
@@ -100,12 +134,24 @@ impl Display for TemporalFunction {
             DSTOffset => "dst_offset",
             Round(..) => "round",
             #[cfg(feature = "timezones")]
-            ReplaceTimeZone(_, _) => "replace_time_zone",
+            ReplaceTimeZone(..) => "replace_time_zone",
             DateRange { .. } => return write!(f, "date_range"),
             DateRanges { .. } => return write!(f, "date_ranges"),
             TimeRange { .. } => return write!(f, "time_range"),
             TimeRanges { .. } => return write!(f, "time_ranges"),
             Combine(_) => "combine",
+            #[cfg(feature = "business")]
+            AddBusinessDays { .. } => "add_business_days",
+            #[cfg(feature = "business")]
+            IsBusinessDay { .. } => "is_business_day",
+            #[cfg(feature = "business")]
+            BusinessDayCount { .. } => return write!(f, "business_day_count"),
+            #[cfg(feature = "business")]
+            BusinessDateRange { .. } => return write!(f, "business_date_range"),
+            #[cfg(feature = "date_diff")]
+            DiffIn { .. } => "diff_in",
+            #[cfg(feature = "date_diff")]
+            Age => "age",
         };
         write!(f, "dt.{s}")
     }
@@ -141,10 +187,13 @@ pub(super) fn ordinal_day(s: &Series) -> PolarsResult<Series> {
 pub(super) fn time(s: &Series) -> PolarsResult<Series> {
     match s.dtype() {
         #[cfg(feature = "timezones")]
-        DataType::Datetime(_, Some(_)) => {
-            polars_ops::prelude::replace_time_zone(s.datetime().unwrap(), None, None)?
-                .cast(&DataType::Time)
-        }
+        DataType::Datetime(_, Some(_)) => polars_ops::prelude::replace_time_zone(
+            s.datetime().unwrap(),
+            None,
+            Ambiguous::Raise,
+            NonExistent::Raise,
+        )?
+        .cast(&DataType::Time),
         DataType::Datetime(_, _) => s.datetime().unwrap().cast(&DataType::Time),
         DataType::Date => s.datetime().unwrap().cast(&DataType::Time),
         DataType::Time => Ok(s.clone()),
@@ -156,8 +205,13 @@ pub(super) fn date(s: &Series) -> PolarsResult<Series> {
         #[cfg(feature = "timezones")]
         DataType::Datetime(_, Some(tz)) => {
             let mut out = {
-                polars_ops::chunked_array::replace_time_zone(s.datetime().unwrap(), None, None)?
-                    .cast(&DataType::Date)?
+                polars_ops::chunked_array::replace_time_zone(
+                    s.datetime().unwrap(),
+                    None,
+                    Ambiguous::Raise,
+                    NonExistent::Raise,
+                )?
+                .cast(&DataType::Date)?
             };
             if tz != "UTC" {
                 // DST transitions may not preserve sortedness.
@@ -175,8 +229,13 @@ pub(super) fn datetime(s: &Series) -> PolarsResult<Series> {
         #[cfg(feature = "timezones")]
         DataType::Datetime(tu, Some(tz)) => {
             let mut out = {
-                polars_ops::chunked_array::replace_time_zone(s.datetime().unwrap(), None, None)?
-                    .cast(&DataType::Datetime(*tu, None))?
+                polars_ops::chunked_array::replace_time_zone(
+                    s.datetime().unwrap(),
+                    None,
+                    Ambiguous::Raise,
+                    NonExistent::Raise,
+                )?
+                .cast(&DataType::Datetime(*tu, None))?
             };
             if tz != "UTC" {
                 // DST transitions may not preserve sortedness.
@@ -262,6 +321,84 @@ pub(super) fn month_end(s: &Series) -> PolarsResult<Series> {
     })
 }
 
+#[cfg(feature = "business")]
+pub(super) fn add_business_days(
+    s: &[Series],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    roll: Roll,
+) -> PolarsResult<Series> {
+    polars_time::add_business_days(&s[0], &s[1], week_mask, holidays, roll)
+}
+
+#[cfg(feature = "business")]
+pub(super) fn is_business_day(
+    s: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    polars_time::is_business_day(s, week_mask, holidays).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "business")]
+pub(super) fn business_day_count(
+    s: &[Series],
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    polars_time::business_day_count(&s[0], &s[1], week_mask, holidays).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "date_diff")]
+pub(super) fn diff_in(s: &[Series], unit: DateDiffUnit, fractional: bool) -> PolarsResult<Series> {
+    polars_time::date_diff(&s[0], &s[1], unit, fractional)
+}
+
+#[cfg(feature = "date_diff")]
+pub(super) fn age(s: &[Series]) -> PolarsResult<Series> {
+    polars_time::age(&s[0], &s[1])
+}
+
+#[cfg(feature = "business")]
+pub(super) fn business_date_range(
+    s: &[Series],
+    every: i64,
+    closed: ClosedWindow,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Series> {
+    let start = s[0].cast(&DataType::Date)?;
+    let stop = s[1].cast(&DataType::Date)?;
+    polars_ensure!(
+        start.len() == stop.len(),
+        ComputeError: "'start' and 'stop' should have the same length",
+    );
+    let start = start.date().unwrap();
+    let stop = stop.date().unwrap();
+
+    let mut builder = ListPrimitiveChunkedBuilder::<Int32Type>::new(
+        "date",
+        start.len(),
+        start.len() * 5,
+        DataType::Int32,
+    );
+    for (start, stop) in start.into_iter().zip(stop.into_iter()) {
+        match (start, stop) {
+            (Some(start), Some(stop)) => {
+                let rng = polars_time::business_date_range(
+                    "", start, stop, every, closed, week_mask, holidays,
+                )?;
+                builder.append_slice(rng.cont_slice().unwrap())
+            }
+            _ => builder.append_null(),
+        }
+    }
+    builder
+        .finish()
+        .into_series()
+        .cast(&DataType::List(Box::new(DataType::Date)))
+}
+
 #[cfg(feature = "timezones")]
 pub(super) fn base_utc_offset(s: &Series) -> PolarsResult<Series> {
     match s.dtype() {
@@ -295,7 +432,13 @@ pub(super) fn dst_offset(s: &Series) -> PolarsResult<Series> {
     }
 }
 
-pub(super) fn round(s: &Series, every: &str, offset: &str) -> PolarsResult<Series> {
+pub(super) fn round(
+    s: &Series,
+    every: &str,
+    offset: &str,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
+) -> PolarsResult<Series> {
     let every = Duration::parse(every);
     let offset = Duration::parse(offset);
     Ok(match s.dtype() {
@@ -304,15 +447,25 @@ pub(super) fn round(s: &Series, every: &str, offset: &str) -> PolarsResult<Serie
             Some(tz) => s
                 .datetime()
                 .unwrap()
-                .round(every, offset, tz.parse::<Tz>().ok().as_ref())?
+                .round(
+                    every,
+                    offset,
+                    tz.parse::<Tz>().ok().as_ref(),
+                    ambiguous,
+                    non_existent,
+                )?
                 .into_series(),
             _ => s
                 .datetime()
                 .unwrap()
-                .round(every, offset, None)?
+                .round(every, offset, None, ambiguous, non_existent)?
                 .into_series(),
         },
-        DataType::Date => s.date().unwrap().round(every, offset, None)?.into_series(),
+        DataType::Date => s
+            .date()
+            .unwrap()
+            .round(every, offset, None, ambiguous, non_existent)?
+            .into_series(),
         dt => polars_bail!(opq = round, got = dt, expected = "date/datetime"),
     })
 }
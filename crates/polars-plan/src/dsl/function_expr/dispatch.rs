@@ -19,8 +19,13 @@ pub(super) fn diff(s: &Series, n: i64, null_behavior: NullBehavior) -> PolarsRes
 }
 
 #[cfg(feature = "interpolate")]
-pub(super) fn interpolate(s: &Series, method: InterpolationMethod) -> PolarsResult<Series> {
-    Ok(polars_ops::prelude::interpolate(s, method))
+pub(super) fn interpolate(s: &Series, options: &InterpolationOptions) -> PolarsResult<Series> {
+    polars_ops::prelude::interpolate_with_options(s, options)
+}
+
+#[cfg(feature = "interpolate")]
+pub(super) fn interpolate_by(s: &[Series], method: InterpolationMethod) -> PolarsResult<Series> {
+    polars_ops::prelude::interpolate_by(&s[0], &s[1], method)
 }
 
 pub(super) fn to_physical(s: &Series) -> PolarsResult<Series> {
@@ -37,8 +42,12 @@ pub(super) fn set_sorted_flag(s: &Series, sorted: IsSorted) -> PolarsResult<Seri
 pub(super) fn replace_time_zone(
     s: &Series,
     time_zone: Option<&str>,
-    use_earliest: Option<bool>,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
 ) -> PolarsResult<Series> {
     let ca = s.datetime().unwrap();
-    Ok(polars_ops::prelude::replace_time_zone(ca, time_zone, use_earliest)?.into_series())
+    Ok(
+        polars_ops::prelude::replace_time_zone(ca, time_zone, ambiguous, non_existent)?
+            .into_series(),
+    )
 }
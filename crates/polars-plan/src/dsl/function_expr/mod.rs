@@ -85,7 +85,7 @@ pub(super) use self::range::RangeFunction;
 #[cfg(feature = "strings")]
 pub(crate) use self::strings::StringFunction;
 #[cfg(feature = "dtype-struct")]
-pub(super) use self::struct_::StructFunction;
+pub(crate) use self::struct_::StructFunction;
 #[cfg(feature = "trigonometry")]
 pub(super) use self::trigonometry::TrigonometricFunction;
 use super::*;
@@ -173,7 +173,9 @@ pub enum FunctionExpr {
     #[cfg(feature = "diff")]
     Diff(i64, NullBehavior),
     #[cfg(feature = "interpolate")]
-    Interpolate(InterpolationMethod),
+    Interpolate(InterpolationOptions),
+    #[cfg(feature = "interpolate")]
+    InterpolateBy(InterpolationMethod),
     #[cfg(feature = "log")]
     Entropy {
         base: f64,
@@ -301,6 +303,8 @@ impl Display for FunctionExpr {
             Diff(_, _) => "diff",
             #[cfg(feature = "interpolate")]
             Interpolate(_) => "interpolate",
+            #[cfg(feature = "interpolate")]
+            InterpolateBy(_) => "interpolate_by",
             #[cfg(feature = "log")]
             Entropy { .. } => "entropy",
             #[cfg(feature = "log")]
@@ -521,6 +525,24 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                     Max => map!(array::max),
                     Sum => map!(array::sum),
                     Unique(stable) => map!(array::unique, stable),
+                    Mean => map!(array::mean),
+                    Std(ddof) => map!(array::std, ddof),
+                    Any => map!(array::any),
+                    All => map!(array::all),
+                    Get => map_as_slice!(array::get),
+                    Contains => map_as_slice!(array::contains),
+                    Join(separator) => map!(array::join, &separator),
+                    Sort(options) => map!(array::sort, options),
+                    Reverse => map!(array::reverse),
+                    ArgMin => map!(array::arg_min),
+                    ArgMax => map!(array::arg_max),
+                    ToList => map!(array::to_list),
+                    #[cfg(feature = "dtype-struct")]
+                    ToStruct => map!(array::to_struct),
+                    Explode => map!(array::explode),
+                    Dot => map_as_slice!(array::dot),
+                    CosineSimilarity => map_as_slice!(array::cosine_similarity),
+                    L2Norm => map!(array::l2_norm),
                 }
             }
             #[cfg(feature = "dtype-struct")]
@@ -529,6 +551,10 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
                 match sf {
                     FieldByIndex(index) => map!(struct_::get_by_index, index),
                     FieldByName(name) => map!(struct_::get_by_name, name.clone()),
+                    #[cfg(feature = "regex")]
+                    FieldByNames(pattern) => map!(struct_::get_by_names, pattern.clone()),
+                    #[cfg(feature = "json")]
+                    JsonEncode => map!(struct_::json_encode),
                 }
             }
             #[cfg(feature = "top_k")]
@@ -552,8 +578,12 @@ impl From<FunctionExpr> for SpecialEq<Arc<dyn SeriesUdf>> {
             #[cfg(feature = "diff")]
             Diff(n, null_behavior) => map!(dispatch::diff, n, null_behavior),
             #[cfg(feature = "interpolate")]
-            Interpolate(method) => {
-                map!(dispatch::interpolate, method)
+            Interpolate(options) => {
+                map!(dispatch::interpolate, &options)
+            }
+            #[cfg(feature = "interpolate")]
+            InterpolateBy(method) => {
+                map_as_slice!(dispatch::interpolate_by, method)
             }
             #[cfg(feature = "log")]
             Entropy { base, normalize } => map!(log::entropy, base, normalize),
@@ -634,6 +664,10 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
         match func {
             #[cfg(feature = "regex")]
             Contains { literal, strict } => map_as_slice!(strings::contains, literal, strict),
+            #[cfg(feature = "find_many")]
+            ContainsAny {
+                ascii_case_insensitive,
+            } => map_as_slice!(strings::contains_any, ascii_case_insensitive),
             CountMatch(pat) => {
                 map!(strings::count_match, &pat)
             }
@@ -645,7 +679,24 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             ExtractAll => {
                 map_as_slice!(strings::extract_all)
             }
+            #[cfg(feature = "extract_groups")]
+            ExtractGroups { pat, .. } => {
+                map!(strings::extract_groups, &pat)
+            }
+            #[cfg(feature = "find_many")]
+            ExtractMany {
+                ascii_case_insensitive,
+                overlapping,
+            } => map_as_slice!(strings::extract_many, ascii_case_insensitive, overlapping),
             NChars => map!(strings::n_chars),
+            #[cfg(feature = "string_unicode")]
+            NGraphemes => map!(strings::n_graphemes),
+            #[cfg(feature = "string_unicode")]
+            Normalize(form) => map!(strings::normalize, form),
+            #[cfg(feature = "string_unicode")]
+            Casefold => map!(strings::casefold),
+            #[cfg(feature = "string_unicode")]
+            Reverse => map!(strings::reverse),
             Length => map!(strings::lengths),
             #[cfg(feature = "string_justify")]
             Zfill(alignment) => {
@@ -669,6 +720,10 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             ConcatHorizontal(delimiter) => map_as_slice!(strings::concat_hor, &delimiter),
             #[cfg(feature = "regex")]
             Replace { n, literal } => map_as_slice!(strings::replace, literal, n),
+            #[cfg(feature = "find_many")]
+            ReplaceMany {
+                ascii_case_insensitive,
+            } => map_as_slice!(strings::replace_many, ascii_case_insensitive),
             Uppercase => map!(strings::uppercase),
             Lowercase => map!(strings::lowercase),
             #[cfg(feature = "nightly")]
@@ -678,6 +733,14 @@ impl From<StringFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             RStrip(matches) => map!(strings::rstrip, matches.as_deref()),
             #[cfg(feature = "string_from_radix")]
             FromRadix(radix, strict) => map!(strings::from_radix, radix, strict),
+            #[cfg(feature = "string_distance")]
+            Hamming => map_as_slice!(strings::hamming),
+            #[cfg(feature = "string_distance")]
+            JaccardNgram(n) => map_as_slice!(strings::jaccard_ngram, n),
+            #[cfg(feature = "string_distance")]
+            JaroWinkler => map_as_slice!(strings::jaro_winkler),
+            #[cfg(feature = "string_distance")]
+            Levenshtein => map_as_slice!(strings::levenshtein),
             Slice(start, length) => map!(strings::str_slice, start, length),
             Explode => map!(strings::explode),
             #[cfg(feature = "dtype-decimal")]
@@ -704,6 +767,18 @@ impl From<BinaryFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             StartsWith(sub) => {
                 map!(binary::starts_with, &sub)
             }
+            Lengths => map!(binary::lengths),
+            Slice(offset, length) => map!(binary::slice, offset, length),
+            Reinterpret(dtype, endianness) => map!(binary::reinterpret, &dtype, endianness),
+            DecodeUtf8(strict) => map!(binary::decode_utf8, strict),
+            #[cfg(feature = "binary_encoding")]
+            Base32Encode => map!(binary::base32_encode),
+            #[cfg(feature = "binary_encoding")]
+            Base32Decode(strict) => map!(binary::base32_decode, strict),
+            #[cfg(feature = "binary_encoding")]
+            Base85Encode => map!(binary::base85_encode),
+            #[cfg(feature = "binary_encoding")]
+            Base85Decode(strict) => map!(binary::base85_decode, strict),
         }
     }
 }
@@ -743,10 +818,17 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
             BaseUtcOffset => map!(datetime::base_utc_offset),
             #[cfg(feature = "timezones")]
             DSTOffset => map!(datetime::dst_offset),
-            Round(every, offset) => map!(datetime::round, &every, &offset),
+            Round(every, offset, ambiguous, non_existent) => {
+                map!(datetime::round, &every, &offset, ambiguous, non_existent)
+            }
             #[cfg(feature = "timezones")]
-            ReplaceTimeZone(tz, use_earliest) => {
-                map!(dispatch::replace_time_zone, tz.as_deref(), use_earliest)
+            ReplaceTimeZone(tz, ambiguous, non_existent) => {
+                map!(
+                    dispatch::replace_time_zone,
+                    tz.as_deref(),
+                    ambiguous,
+                    non_existent
+                )
             }
             Combine(tu) => map_as_slice!(temporal::combine, tu),
             DateRange {
@@ -754,6 +836,8 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
                 closed,
                 time_unit,
                 time_zone,
+                ambiguous,
+                non_existent,
             } => {
                 map_as_slice!(
                     temporal::temporal_range_dispatch,
@@ -761,7 +845,9 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
                     every,
                     closed,
                     time_unit,
-                    time_zone.clone()
+                    time_zone.clone(),
+                    ambiguous,
+                    non_existent
                 )
             }
             DateRanges {
@@ -769,6 +855,8 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
                 closed,
                 time_unit,
                 time_zone,
+                ambiguous,
+                non_existent,
             } => {
                 map_as_slice!(
                     temporal::temporal_range_dispatch,
@@ -776,7 +864,9 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
                     every,
                     closed,
                     time_unit,
-                    time_zone.clone()
+                    time_zone.clone(),
+                    ambiguous,
+                    non_existent
                 )
             }
             TimeRange { every, closed } => {
@@ -786,7 +876,9 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
                     every,
                     closed,
                     None,
-                    None
+                    None,
+                    Ambiguous::Raise,
+                    NonExistent::Raise
                 )
             }
             TimeRanges { every, closed } => {
@@ -796,9 +888,44 @@ impl From<TemporalFunction> for SpecialEq<Arc<dyn SeriesUdf>> {
                     every,
                     closed,
                     None,
-                    None
+                    None,
+                    Ambiguous::Raise,
+                    NonExistent::Raise
                 )
             }
+            #[cfg(feature = "business")]
+            AddBusinessDays {
+                week_mask,
+                holidays,
+                roll,
+            } => map_as_slice!(datetime::add_business_days, week_mask, &holidays, roll),
+            #[cfg(feature = "business")]
+            IsBusinessDay {
+                week_mask,
+                holidays,
+            } => map!(datetime::is_business_day, week_mask, &holidays),
+            #[cfg(feature = "business")]
+            BusinessDayCount {
+                week_mask,
+                holidays,
+            } => map_as_slice!(datetime::business_day_count, week_mask, &holidays),
+            #[cfg(feature = "business")]
+            BusinessDateRange {
+                every,
+                closed,
+                week_mask,
+                holidays,
+            } => map_as_slice!(
+                datetime::business_date_range,
+                every,
+                closed,
+                week_mask,
+                &holidays
+            ),
+            #[cfg(feature = "date_diff")]
+            DiffIn { unit, fractional } => map_as_slice!(datetime::diff_in, unit, fractional),
+            #[cfg(feature = "date_diff")]
+            Age => map_as_slice!(datetime::age),
         }
     }
 }
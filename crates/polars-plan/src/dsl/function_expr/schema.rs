@@ -30,6 +30,14 @@ impl FunctionExpr {
                     Contains { .. } | EndsWith(_) | StartsWith(_) => {
                         mapper.with_dtype(DataType::Boolean)
                     }
+                    Lengths => mapper.with_dtype(DataType::UInt32),
+                    Slice(_, _) => mapper.with_same_dtype(),
+                    Reinterpret(dtype, _) => mapper.with_dtype(dtype.clone()),
+                    DecodeUtf8(_) => mapper.with_dtype(DataType::Utf8),
+                    #[cfg(feature = "binary_encoding")]
+                    Base32Encode | Base85Encode => mapper.with_dtype(DataType::Utf8),
+                    #[cfg(feature = "binary_encoding")]
+                    Base32Decode(_) | Base85Decode(_) => mapper.with_same_dtype(),
                 }
             }
             #[cfg(feature = "temporal")]
@@ -58,7 +66,7 @@ impl FunctionExpr {
                     DSTOffset => DataType::Duration(TimeUnit::Milliseconds),
                     Round(..) => mapper.with_same_dtype().unwrap().dtype,
                     #[cfg(feature = "timezones")]
-                    ReplaceTimeZone(tz, _use_earliest) => {
+                    ReplaceTimeZone(tz, ..) => {
                         return mapper.map_datetime_dtype_timezone(tz.as_ref())
                     }
                     DateRange {
@@ -66,6 +74,7 @@ impl FunctionExpr {
                         closed: _,
                         time_unit,
                         time_zone,
+                        ..
                     } => {
                         // output dtype may change based on `every`, `time_unit`, and `time_zone`
                         let inner_dtype =
@@ -77,6 +86,7 @@ impl FunctionExpr {
                         closed: _,
                         time_unit,
                         time_zone,
+                        ..
                     } => {
                         // output dtype may change based on `every`, `time_unit`, and `time_zone`
                         let inner_dtype =
@@ -87,6 +97,26 @@ impl FunctionExpr {
                         ));
                     }
 
+                    #[cfg(feature = "business")]
+                    AddBusinessDays { .. } => mapper.with_same_dtype().unwrap().dtype,
+                    #[cfg(feature = "business")]
+                    IsBusinessDay { .. } => DataType::Boolean,
+                    #[cfg(feature = "business")]
+                    BusinessDayCount { .. } => DataType::Int32,
+                    #[cfg(feature = "business")]
+                    BusinessDateRange { .. } => {
+                        return Ok(Field::new("date", DataType::List(Box::new(DataType::Date))));
+                    }
+                    #[cfg(feature = "date_diff")]
+                    DiffIn { fractional, .. } => {
+                        if *fractional {
+                            DataType::Float64
+                        } else {
+                            DataType::Int64
+                        }
+                    }
+                    #[cfg(feature = "date_diff")]
+                    Age => DataType::Int64,
                     TimeRange { .. } => {
                         return Ok(Field::new("time", DataType::List(Box::new(DataType::Time))));
                     }
@@ -161,13 +191,37 @@ impl FunctionExpr {
                 match af {
                     Min | Max => mapper.with_same_dtype(),
                     Sum => mapper.nested_sum_type(),
-                    Unique(_) => mapper.try_map_dtype(|dt| {
+                    Unique(_) | ToList => mapper.try_map_dtype(|dt| {
                         if let DataType::Array(inner, _) = dt {
                             Ok(DataType::List(inner.clone()))
                         } else {
                             polars_bail!(ComputeError: "expected array dtype")
                         }
                     }),
+                    Mean | Std(_) => mapper.with_dtype(DataType::Float64),
+                    Any | All | Contains => mapper.with_dtype(DataType::Boolean),
+                    Get | Explode => mapper.map_to_list_inner_dtype(),
+                    Join(_) => mapper.with_dtype(DataType::Utf8),
+                    Sort(_) | Reverse => mapper.with_same_dtype(),
+                    // float32 vectors stay float32
+                    Dot | CosineSimilarity | L2Norm => mapper.map_dtype(|dt| match dt {
+                        DataType::Array(inner, _) if **inner == DataType::Float32 => {
+                            DataType::Float32
+                        }
+                        _ => DataType::Float64,
+                    }),
+                    ArgMin | ArgMax => mapper.with_dtype(IDX_DTYPE),
+                    #[cfg(feature = "dtype-struct")]
+                    ToStruct => mapper.try_map_dtype(|dt| {
+                        if let DataType::Array(inner, width) = dt {
+                            let fields = (0..*width)
+                                .map(|i| Field::new(&format!("field_{i}"), *inner.clone()))
+                                .collect();
+                            Ok(DataType::Struct(fields))
+                        } else {
+                            polars_bail!(ComputeError: "expected array dtype")
+                        }
+                    }),
                 }
             }
             #[cfg(feature = "dtype-struct")]
@@ -200,6 +254,13 @@ impl FunctionExpr {
                             polars_bail!(StructFieldNotFound: "{}", name.as_ref());
                         }
                     }
+                    #[cfg(feature = "regex")]
+                    FieldByNames(pattern) => polars_bail!(
+                        ComputeError: "`struct.field_by_names` with pattern {} was not expanded",
+                        pattern.as_ref()
+                    ),
+                    #[cfg(feature = "json")]
+                    JsonEncode => mapper.with_dtype(DataType::Utf8),
                 }
             }
             #[cfg(feature = "top_k")]
@@ -230,6 +291,8 @@ impl FunctionExpr {
             }),
             #[cfg(feature = "interpolate")]
             Interpolate(_) => mapper.with_same_dtype(),
+            #[cfg(feature = "interpolate")]
+            InterpolateBy(_) => mapper.with_same_dtype(),
             ShrinkType => {
                 // we return the smallest type this can return
                 // this might not be correct once the actual data
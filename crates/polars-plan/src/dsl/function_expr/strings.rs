@@ -17,6 +17,8 @@ use super::*;
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[derive(Clone, PartialEq, Debug, Eq, Hash)]
 pub enum StringFunction {
+    #[cfg(feature = "string_unicode")]
+    Casefold,
     #[cfg(feature = "concat_str")]
     ConcatHorizontal(String),
     #[cfg(feature = "concat_str")]
@@ -26,6 +28,10 @@ pub enum StringFunction {
         literal: bool,
         strict: bool,
     },
+    #[cfg(feature = "find_many")]
+    ContainsAny {
+        ascii_case_insensitive: bool,
+    },
     CountMatch(String),
     EndsWith,
     Explode,
@@ -34,9 +40,31 @@ pub enum StringFunction {
         group_index: usize,
     },
     ExtractAll,
+    #[cfg(feature = "extract_groups")]
+    ExtractGroups {
+        dtype: DataType,
+        pat: String,
+    },
+    #[cfg(feature = "find_many")]
+    ExtractMany {
+        ascii_case_insensitive: bool,
+        overlapping: bool,
+    },
     #[cfg(feature = "string_from_radix")]
     FromRadix(u32, bool),
+    #[cfg(feature = "string_distance")]
+    Hamming,
+    #[cfg(feature = "string_distance")]
+    JaccardNgram(usize),
+    #[cfg(feature = "string_distance")]
+    JaroWinkler,
+    #[cfg(feature = "string_distance")]
+    Levenshtein,
     NChars,
+    #[cfg(feature = "string_unicode")]
+    NGraphemes,
+    #[cfg(feature = "string_unicode")]
+    Normalize(UnicodeForm),
     Length,
     #[cfg(feature = "string_justify")]
     LJust {
@@ -57,6 +85,12 @@ pub enum StringFunction {
         n: i64,
         literal: bool,
     },
+    #[cfg(feature = "find_many")]
+    ReplaceMany {
+        ascii_case_insensitive: bool,
+    },
+    #[cfg(feature = "string_unicode")]
+    Reverse,
     #[cfg(feature = "string_justify")]
     RJust {
         width: usize,
@@ -81,23 +115,39 @@ impl StringFunction {
     pub(super) fn get_field(&self, mapper: FieldsMapper) -> PolarsResult<Field> {
         use StringFunction::*;
         match self {
+            #[cfg(feature = "string_unicode")]
+            Casefold | Normalize(_) | Reverse => mapper.with_same_dtype(),
+            #[cfg(feature = "string_unicode")]
+            NGraphemes => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "concat_str")]
             ConcatVertical(_) | ConcatHorizontal(_) => mapper.with_same_dtype(),
             #[cfg(feature = "regex")]
             Contains { .. } => mapper.with_dtype(DataType::Boolean),
+            #[cfg(feature = "find_many")]
+            ContainsAny { .. } => mapper.with_dtype(DataType::Boolean),
             CountMatch(_) => mapper.with_dtype(DataType::UInt32),
             EndsWith | StartsWith => mapper.with_dtype(DataType::Boolean),
             Explode => mapper.with_same_dtype(),
             Extract { .. } => mapper.with_same_dtype(),
             ExtractAll => mapper.with_dtype(DataType::List(Box::new(DataType::Utf8))),
+            #[cfg(feature = "extract_groups")]
+            ExtractGroups { dtype, .. } => mapper.with_dtype(dtype.clone()),
+            #[cfg(feature = "find_many")]
+            ExtractMany { .. } => mapper.with_dtype(DataType::List(Box::new(DataType::Utf8))),
             #[cfg(feature = "string_from_radix")]
             FromRadix { .. } => mapper.with_dtype(DataType::Int32),
+            #[cfg(feature = "string_distance")]
+            Hamming | Levenshtein => mapper.with_dtype(DataType::UInt32),
+            #[cfg(feature = "string_distance")]
+            JaccardNgram(_) | JaroWinkler => mapper.with_dtype(DataType::Float64),
             #[cfg(feature = "extract_jsonpath")]
             JsonExtract { dtype, .. } => mapper.with_opt_dtype(dtype.clone()),
             Length => mapper.with_dtype(DataType::UInt32),
             NChars => mapper.with_dtype(DataType::UInt32),
             #[cfg(feature = "regex")]
             Replace { .. } => mapper.with_same_dtype(),
+            #[cfg(feature = "find_many")]
+            ReplaceMany { .. } => mapper.with_same_dtype(),
             #[cfg(feature = "temporal")]
             Strptime(dtype, _) => mapper.with_dtype(dtype.clone()),
             #[cfg(feature = "nightly")]
@@ -116,8 +166,12 @@ impl StringFunction {
 impl Display for StringFunction {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let s = match self {
+            #[cfg(feature = "string_unicode")]
+            StringFunction::Casefold => "casefold",
             #[cfg(feature = "regex")]
             StringFunction::Contains { .. } => "contains",
+            #[cfg(feature = "find_many")]
+            StringFunction::ContainsAny { .. } => "contains_any",
             StringFunction::CountMatch(_) => "count_match",
             StringFunction::EndsWith { .. } => "ends_with",
             StringFunction::Extract { .. } => "extract",
@@ -127,8 +181,20 @@ impl Display for StringFunction {
             StringFunction::ConcatVertical(_) => "concat_vertical",
             StringFunction::Explode => "explode",
             StringFunction::ExtractAll => "extract_all",
+            #[cfg(feature = "extract_groups")]
+            StringFunction::ExtractGroups { .. } => "extract_groups",
+            #[cfg(feature = "find_many")]
+            StringFunction::ExtractMany { .. } => "extract_many",
             #[cfg(feature = "string_from_radix")]
             StringFunction::FromRadix { .. } => "from_radix",
+            #[cfg(feature = "string_distance")]
+            StringFunction::Hamming => "hamming",
+            #[cfg(feature = "string_distance")]
+            StringFunction::JaccardNgram(_) => "jaccard_ngram",
+            #[cfg(feature = "string_distance")]
+            StringFunction::JaroWinkler => "jaro_winkler",
+            #[cfg(feature = "string_distance")]
+            StringFunction::Levenshtein => "levenshtein",
             #[cfg(feature = "extract_jsonpath")]
             StringFunction::JsonExtract { .. } => "json_extract",
             #[cfg(feature = "string_justify")]
@@ -137,11 +203,19 @@ impl Display for StringFunction {
             StringFunction::Length => "str_lengths",
             StringFunction::Lowercase => "lowercase",
             StringFunction::NChars => "n_chars",
+            #[cfg(feature = "string_unicode")]
+            StringFunction::NGraphemes => "n_graphemes",
+            #[cfg(feature = "string_unicode")]
+            StringFunction::Normalize(_) => "normalize",
             #[cfg(feature = "string_justify")]
             StringFunction::RJust { .. } => "rjust",
             StringFunction::RStrip(_) => "rstrip",
+            #[cfg(feature = "string_unicode")]
+            StringFunction::Reverse => "reverse",
             #[cfg(feature = "regex")]
             StringFunction::Replace { .. } => "replace",
+            #[cfg(feature = "find_many")]
+            StringFunction::ReplaceMany { .. } => "replace_many",
             StringFunction::Slice(_, _) => "str_slice",
             StringFunction::StartsWith { .. } => "starts_with",
             StringFunction::Strip(_) => "strip",
@@ -175,6 +249,30 @@ pub(super) fn titlecase(s: &Series) -> PolarsResult<Series> {
     Ok(ca.to_titlecase().into_series())
 }
 
+#[cfg(feature = "string_unicode")]
+pub(super) fn casefold(s: &Series) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.casefold().into_series())
+}
+
+#[cfg(feature = "string_unicode")]
+pub(super) fn normalize(s: &Series, form: UnicodeForm) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.str_normalize(form).into_series())
+}
+
+#[cfg(feature = "string_unicode")]
+pub(super) fn n_graphemes(s: &Series) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.str_n_graphemes().into_series())
+}
+
+#[cfg(feature = "string_unicode")]
+pub(super) fn reverse(s: &Series) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    Ok(ca.str_reverse().into_series())
+}
+
 pub(super) fn n_chars(s: &Series) -> PolarsResult<Series> {
     let ca = s.utf8()?;
     Ok(ca.str_n_chars().into_series())
@@ -238,6 +336,59 @@ pub(super) fn contains(s: &[Series], literal: bool, strict: bool) -> PolarsResul
     Ok(out.into_series())
 }
 
+#[cfg(feature = "find_many")]
+pub(super) fn contains_any(s: &[Series], ascii_case_insensitive: bool) -> PolarsResult<Series> {
+    let ca = s[0].utf8()?;
+    ca.contains_any(&s[1], ascii_case_insensitive)
+        .map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "find_many")]
+pub(super) fn replace_many(s: &[Series], ascii_case_insensitive: bool) -> PolarsResult<Series> {
+    let ca = s[0].utf8()?;
+    ca.replace_many(&s[1], &s[2], ascii_case_insensitive)
+        .map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "find_many")]
+pub(super) fn extract_many(
+    s: &[Series],
+    ascii_case_insensitive: bool,
+    overlapping: bool,
+) -> PolarsResult<Series> {
+    let ca = s[0].utf8()?;
+    ca.extract_many(&s[1], ascii_case_insensitive, overlapping)
+        .map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "string_distance")]
+pub(super) fn levenshtein(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].utf8()?;
+    let other = s[1].utf8()?;
+    ca.levenshtein(other).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "string_distance")]
+pub(super) fn hamming(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].utf8()?;
+    let other = s[1].utf8()?;
+    ca.hamming(other).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "string_distance")]
+pub(super) fn jaro_winkler(s: &[Series]) -> PolarsResult<Series> {
+    let ca = s[0].utf8()?;
+    let other = s[1].utf8()?;
+    ca.jaro_winkler(other).map(|ca| ca.into_series())
+}
+
+#[cfg(feature = "string_distance")]
+pub(super) fn jaccard_ngram(s: &[Series], n: usize) -> PolarsResult<Series> {
+    let ca = s[0].utf8()?;
+    let other = s[1].utf8()?;
+    ca.jaccard_ngram(other, n).map(|ca| ca.into_series())
+}
+
 pub(super) fn ends_with(s: &[Series]) -> PolarsResult<Series> {
     let ca = s[0].utf8()?;
     let sub = s[1].utf8()?;
@@ -292,6 +443,13 @@ pub(super) fn extract(s: &Series, pat: &str, group_index: usize) -> PolarsResult
     ca.extract(&pat, group_index).map(|ca| ca.into_series())
 }
 
+#[cfg(feature = "extract_groups")]
+/// Extract all capture groups of a regex pattern into a struct.
+pub(super) fn extract_groups(s: &Series, pat: &str) -> PolarsResult<Series> {
+    let ca = s.utf8()?;
+    ca.extract_groups(pat)
+}
+
 #[cfg(feature = "string_justify")]
 pub(super) fn zfill(s: &Series, alignment: usize) -> PolarsResult<Series> {
     let ca = s.utf8()?;
@@ -7,6 +7,12 @@ use super::*;
 pub enum StructFunction {
     FieldByIndex(i64),
     FieldByName(Arc<str>),
+    /// Select all fields whose name matches a regex. This is expanded to a
+    /// [`StructFunction::FieldByName`] per matching field during projection.
+    #[cfg(feature = "regex")]
+    FieldByNames(Arc<str>),
+    #[cfg(feature = "json")]
+    JsonEncode,
 }
 
 impl Display for StructFunction {
@@ -15,6 +21,10 @@ impl Display for StructFunction {
         match self {
             StructFunction::FieldByIndex(_) => write!(f, "struct.field_by_name"),
             StructFunction::FieldByName(_) => write!(f, "struct.field_by_index"),
+            #[cfg(feature = "regex")]
+            StructFunction::FieldByNames(_) => write!(f, "struct.field_by_names"),
+            #[cfg(feature = "json")]
+            StructFunction::JsonEncode => write!(f, "struct.json_encode"),
         }
     }
 }
@@ -31,3 +41,32 @@ pub(super) fn get_by_name(s: &Series, name: Arc<str>) -> PolarsResult<Series> {
     let ca = s.struct_()?;
     ca.field_by_name(name.as_ref())
 }
+
+#[cfg(feature = "regex")]
+pub(super) fn get_by_names(_s: &Series, pattern: Arc<str>) -> PolarsResult<Series> {
+    polars_bail!(
+        ComputeError: "`struct.field_by_names` with pattern {} was not expanded", pattern.as_ref()
+    )
+}
+
+#[cfg(feature = "json")]
+pub(super) fn json_encode(s: &Series) -> PolarsResult<Series> {
+    use polars_arrow::export::arrow::io::ndjson::write::{FallibleStreamingIterator, Serializer};
+    use polars_core::error::to_compute_err;
+
+    // ensure we have a struct
+    s.struct_()?;
+    let s = s.rechunk();
+    let arr = s.to_arrow(0);
+
+    let mut builder = Utf8ChunkedBuilder::new(s.name(), s.len(), s.len() * 16);
+    let mut serializer = Serializer::new(std::iter::once(Ok(arr)), vec![]);
+    while let Some(block) = serializer.next().map_err(to_compute_err)? {
+        // every row is written as a single line, newlines in values are escaped
+        for row in block.split(|b| *b == b'\n').filter(|row| !row.is_empty()) {
+            // the serializer only writes valid utf8
+            builder.append_value(unsafe { std::str::from_utf8_unchecked(row) })
+        }
+    }
+    Ok(builder.finish().into_series())
+}
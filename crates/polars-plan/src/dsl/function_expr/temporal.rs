@@ -82,13 +82,15 @@ pub(super) fn combine(s: &[Series], tu: TimeUnit) -> PolarsResult<Series> {
         Some(tz) => Ok(polars_ops::prelude::replace_time_zone(
             result_naive.datetime().unwrap(),
             Some(tz),
-            None,
+            Ambiguous::Raise,
+            NonExistent::Raise,
         )?
         .into()),
         _ => Ok(result_naive),
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn temporal_range_dispatch(
     s: &[Series],
     name: &str,
@@ -96,6 +98,8 @@ pub(super) fn temporal_range_dispatch(
     closed: ClosedWindow,
     time_unit: Option<TimeUnit>,
     time_zone: Option<TimeZone>,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
 ) -> PolarsResult<Series> {
     let start = &s[0];
     let stop = &s[1];
@@ -136,7 +140,8 @@ pub(super) fn temporal_range_dispatch(
             polars_ops::prelude::replace_time_zone(
                 start.cast(&dtype)?.datetime().unwrap(),
                 None,
-                None,
+                Ambiguous::Raise,
+                NonExistent::Raise,
             )?
             .into_series()
             .to_physical_repr()
@@ -144,7 +149,8 @@ pub(super) fn temporal_range_dispatch(
             polars_ops::prelude::replace_time_zone(
                 stop.cast(&dtype)?.datetime().unwrap(),
                 None,
-                None,
+                Ambiguous::Raise,
+                NonExistent::Raise,
             )?
             .into_series()
             .to_physical_repr()
@@ -197,6 +203,8 @@ pub(super) fn temporal_range_dispatch(
                             closed,
                             TimeUnit::Milliseconds,
                             None,
+                            ambiguous,
+                            non_existent,
                         )?;
                         let rng = rng.cast(&DataType::Date).unwrap();
                         let rng = rng.to_physical_repr();
@@ -218,7 +226,17 @@ pub(super) fn temporal_range_dispatch(
             for (start, stop) in start.into_iter().zip(stop) {
                 match (start, stop) {
                     (Some(start), Some(stop)) => {
-                        let rng = date_range_impl("", start, stop, every, closed, tu, tz.as_ref())?;
+                        let rng = date_range_impl(
+                            "",
+                            start,
+                            stop,
+                            every,
+                            closed,
+                            tu,
+                            tz.as_ref(),
+                            ambiguous,
+                            non_existent,
+                        )?;
                         builder.append_slice(rng.cont_slice().unwrap())
                     }
                     _ => builder.append_null(),
@@ -244,6 +262,8 @@ pub(super) fn temporal_range_dispatch(
                             closed,
                             TimeUnit::Nanoseconds,
                             None,
+                            ambiguous,
+                            non_existent,
                         )?;
                         builder.append_slice(rng.cont_slice().unwrap())
                     }
@@ -76,6 +76,7 @@ pub fn range<T: Range<T>>(low: T, high: T) -> Expr {
 
 /// Create a date range from a `start` and `stop` expression.
 #[cfg(feature = "temporal")]
+#[allow(clippy::too_many_arguments)]
 pub fn date_range(
     start: Expr,
     end: Expr,
@@ -83,6 +84,8 @@ pub fn date_range(
     closed: ClosedWindow,
     time_unit: Option<TimeUnit>,
     time_zone: Option<TimeZone>,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
 ) -> Expr {
     let input = vec![start, end];
 
@@ -93,6 +96,8 @@ pub fn date_range(
             closed,
             time_unit,
             time_zone,
+            ambiguous,
+            non_existent,
         }),
         options: FunctionOptions {
             collect_groups: ApplyOptions::ApplyGroups,
@@ -105,6 +110,7 @@ pub fn date_range(
 
 /// Create a column of date ranges from a `start` and `stop` expression.
 #[cfg(feature = "temporal")]
+#[allow(clippy::too_many_arguments)]
 pub fn date_ranges(
     start: Expr,
     end: Expr,
@@ -112,6 +118,8 @@ pub fn date_ranges(
     closed: ClosedWindow,
     time_unit: Option<TimeUnit>,
     time_zone: Option<TimeZone>,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
 ) -> Expr {
     let input = vec![start, end];
 
@@ -122,6 +130,8 @@ pub fn date_ranges(
             closed,
             time_unit,
             time_zone,
+            ambiguous,
+            non_existent,
         }),
         options: FunctionOptions {
             collect_groups: ApplyOptions::ApplyGroups,
@@ -132,6 +142,34 @@ pub fn date_ranges(
     }
 }
 
+/// Create a date range of every `every`-th business day from a `start` and `stop` expression.
+#[cfg(feature = "business")]
+pub fn business_date_range(
+    start: Expr,
+    end: Expr,
+    every: i64,
+    closed: ClosedWindow,
+    week_mask: [bool; 7],
+    holidays: Vec<i32>,
+) -> Expr {
+    let input = vec![start, end];
+
+    Expr::Function {
+        input,
+        function: FunctionExpr::TemporalExpr(TemporalFunction::BusinessDateRange {
+            every,
+            closed,
+            week_mask,
+            holidays,
+        }),
+        options: FunctionOptions {
+            collect_groups: ApplyOptions::ApplyGroups,
+            allow_rename: true,
+            ..Default::default()
+        },
+    }
+}
+
 /// Generate a time range.
 #[cfg(feature = "temporal")]
 pub fn time_range(start: Expr, end: Expr, every: Duration, closed: ClosedWindow) -> Expr {
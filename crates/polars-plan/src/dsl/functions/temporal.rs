@@ -356,3 +356,21 @@ pub fn duration(args: DurationArgs) -> Expr {
     }
     .alias("duration")
 }
+
+/// Count the business days between `start` (inclusive) and `end` (exclusive).
+#[cfg(feature = "business")]
+pub fn business_day_count(
+    start: Expr,
+    end: Expr,
+    week_mask: [bool; 7],
+    holidays: Vec<i32>,
+) -> Expr {
+    start.map_many_private(
+        FunctionExpr::TemporalExpr(TemporalFunction::BusinessDayCount {
+            week_mask,
+            holidays,
+        }),
+        &[end],
+        false,
+    )
+}
@@ -934,7 +934,23 @@ impl Expr {
             function: Box::new(self),
             partition_by,
             order_by: None,
-            options,
+            options: options.into(),
+        }
+    }
+
+    /// Evaluate the expression over the look-back window of every row.
+    ///
+    /// The windows are defined by the `index_column`, `period`, `offset` and
+    /// `closed_window` of the `options`, just like the groups of a `groupby_rolling`.
+    /// The expression should reduce every window to a single value, e.g.
+    /// `col("a").n_unique().rolling(options)`. The `index_column` should be sorted.
+    #[cfg(feature = "dynamic_groupby")]
+    pub fn rolling(self, options: RollingGroupOptions) -> Self {
+        Expr::Window {
+            function: Box::new(self),
+            partition_by: vec![col(&options.index_column)],
+            order_by: None,
+            options: WindowType::Rolling(options),
         }
     }
 
@@ -1200,7 +1216,21 @@ impl Expr {
     // Interpolate None values
     #[cfg(feature = "interpolate")]
     pub fn interpolate(self, method: InterpolationMethod) -> Expr {
-        self.apply_private(FunctionExpr::Interpolate(method))
+        self.apply_private(FunctionExpr::Interpolate(method.into()))
+    }
+
+    /// Interpolate None values, filling at most `limit` consecutive None values
+    /// if a limit is set.
+    #[cfg(feature = "interpolate")]
+    pub fn interpolate_with_options(self, options: InterpolationOptions) -> Expr {
+        self.apply_private(FunctionExpr::Interpolate(options))
+    }
+
+    /// Interpolate None values at the positions given by `by`, e.g. a time column,
+    /// rather than assuming the values are equally spaced.
+    #[cfg(feature = "interpolate")]
+    pub fn interpolate_by(self, by: Expr, method: InterpolationMethod) -> Expr {
+        self.apply_many_private(FunctionExpr::InterpolateBy(method), &[by], false, false)
     }
 
     #[cfg(feature = "rolling_window")]
@@ -1222,10 +1252,6 @@ impl Expr {
                     by = by.rechunk();
                     let s = &s[0];
 
-                    polars_ensure!(
-                        options.weights.is_none(),
-                        ComputeError: "`weights` is not supported in 'rolling by' expression"
-                    );
                     let (by, tz) = match by.dtype() {
                         DataType::Datetime(_, tz) => (
                             by.cast(&DataType::Datetime(TimeUnit::Microseconds, None))?,
@@ -1245,7 +1271,7 @@ impl Expr {
                     let options = RollingOptionsImpl {
                         window_size: options.window_size,
                         min_periods: options.min_periods,
-                        weights: None,
+                        weights: options.weights.clone(),
                         center: options.center,
                         by: Some(by_values),
                         tu: Some(tu),
@@ -1381,6 +1407,79 @@ impl Expr {
         )
     }
 
+    /// Evaluate the aggregation `agg` over the windows defined by the `by` time column
+    /// of `options`.
+    ///
+    /// Windows with less than `min_periods` non-null values are set to null.
+    #[cfg(all(feature = "rolling_window", feature = "dynamic_groupby"))]
+    fn finish_rolling_by_groups<F>(self, options: RollingOptions, agg: F) -> Expr
+    where
+        F: FnOnce(Expr) -> Expr,
+    {
+        let Some(index_column) = options.by else {
+            return self.map(
+                |_| {
+                    polars_bail!(
+                        InvalidOperation: "the 'by' argument must be set in a time-based rolling aggregation"
+                    )
+                },
+                GetOutput::same_type(),
+            );
+        };
+        let rolling_options = RollingGroupOptions {
+            index_column: index_column.into(),
+            period: options.window_size,
+            // t is at the right endpoint of the window
+            offset: options.window_size * -1,
+            closed_window: options.closed_window.unwrap_or(ClosedWindow::Right),
+            check_sorted: true,
+        };
+        let out = agg(self.clone());
+        let out = if options.min_periods > 0 {
+            let n_valid = self.is_not_null().sum();
+            when(n_valid.gt_eq(lit(options.min_periods as IdxSize)))
+                .then(out)
+                .otherwise(lit(Null {}))
+        } else {
+            out
+        };
+        out.rolling(rolling_options)
+    }
+
+    /// Apply a rolling count over the windows of a time column.
+    #[cfg(all(feature = "rolling_window", feature = "dynamic_groupby"))]
+    pub fn rolling_count_by(self, options: RollingOptions) -> Expr {
+        self.finish_rolling_by_groups(options, |e| e.count())
+    }
+
+    /// Apply a rolling count of the unique values over the windows of a time column.
+    #[cfg(all(feature = "rolling_window", feature = "dynamic_groupby"))]
+    pub fn rolling_n_unique_by(self, options: RollingOptions) -> Expr {
+        self.finish_rolling_by_groups(options, |e| e.n_unique())
+    }
+
+    /// Get the first value of the windows of a time column.
+    #[cfg(all(feature = "rolling_window", feature = "dynamic_groupby"))]
+    pub fn rolling_first_by(self, options: RollingOptions) -> Expr {
+        self.finish_rolling_by_groups(options, |e| e.first())
+    }
+
+    /// Get the last value of the windows of a time column.
+    #[cfg(all(feature = "rolling_window", feature = "dynamic_groupby"))]
+    pub fn rolling_last_by(self, options: RollingOptions) -> Expr {
+        self.finish_rolling_by_groups(options, |e| e.last())
+    }
+
+    /// Apply a rolling skew over the windows of a time column.
+    #[cfg(all(
+        feature = "rolling_window",
+        feature = "dynamic_groupby",
+        feature = "moment"
+    ))]
+    pub fn rolling_skew_by(self, bias: bool, options: RollingOptions) -> Expr {
+        self.finish_rolling_by_groups(options, |e| e.skew(bias))
+    }
+
     /// Apply a rolling skew
     #[cfg(feature = "rolling_window")]
     #[cfg(feature = "moment")]
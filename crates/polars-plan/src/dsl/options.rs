@@ -1,4 +1,6 @@
 use polars_core::prelude::{JoinArgs, JoinType};
+#[cfg(feature = "dynamic_groupby")]
+use polars_time::RollingGroupOptions;
 use polars_utils::IdxSize;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -81,3 +83,26 @@ pub enum WindowMapping {
     /// warning: this can be memory intensive
     Join,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum WindowType {
+    /// Evaluate the function over the groups of the `partition_by` keys.
+    Over(WindowOptions),
+    /// Evaluate the function over the look-back window of every row of a time
+    /// or index column.
+    #[cfg(feature = "dynamic_groupby")]
+    Rolling(RollingGroupOptions),
+}
+
+impl Default for WindowType {
+    fn default() -> Self {
+        Self::Over(WindowOptions::default())
+    }
+}
+
+impl From<WindowOptions> for WindowType {
+    fn from(options: WindowOptions) -> Self {
+        Self::Over(options)
+    }
+}
@@ -36,6 +36,92 @@ impl StringNameSpace {
         )
     }
 
+    /// Check if a string value contains any of the literal `patterns`, using an Aho-Corasick
+    /// automaton. `patterns` is either a `Utf8` expression whose values are the patterns, or a
+    /// `List(Utf8)` expression holding a list of patterns per row.
+    #[cfg(feature = "find_many")]
+    pub fn contains_any(self, patterns: Expr, ascii_case_insensitive: bool) -> Expr {
+        self.0.map_many_private(
+            StringFunction::ContainsAny {
+                ascii_case_insensitive,
+            }
+            .into(),
+            &[patterns],
+            false,
+        )
+    }
+
+    /// Replace all non-overlapping occurrences of the literal `patterns` with the value at the
+    /// same position in `replace_with`. A single replacement is used for every pattern.
+    /// `patterns` accepts the same inputs as [`StringNameSpace::contains_any`]; the
+    /// replacements are shared by all rows.
+    #[cfg(feature = "find_many")]
+    pub fn replace_many(
+        self,
+        patterns: Expr,
+        replace_with: Expr,
+        ascii_case_insensitive: bool,
+    ) -> Expr {
+        self.0.map_many_private(
+            StringFunction::ReplaceMany {
+                ascii_case_insensitive,
+            }
+            .into(),
+            &[patterns, replace_with],
+            false,
+        )
+    }
+
+    /// Extract all occurrences of the literal `patterns` as a list, in the order in which they
+    /// occur in the string. If `overlapping` is set, matches may overlap.
+    /// `patterns` accepts the same inputs as [`StringNameSpace::contains_any`].
+    #[cfg(feature = "find_many")]
+    pub fn extract_many(
+        self,
+        patterns: Expr,
+        ascii_case_insensitive: bool,
+        overlapping: bool,
+    ) -> Expr {
+        self.0.map_many_private(
+            StringFunction::ExtractMany {
+                ascii_case_insensitive,
+                overlapping,
+            }
+            .into(),
+            &[patterns],
+            false,
+        )
+    }
+
+    /// Levenshtein edit distance, in chars, to the strings of `other`.
+    #[cfg(feature = "string_distance")]
+    pub fn levenshtein(self, other: Expr) -> Expr {
+        self.0
+            .map_many_private(StringFunction::Levenshtein.into(), &[other], false)
+    }
+
+    /// Hamming distance, in chars, to the strings of `other`.
+    /// Null if the strings don't have the same number of chars.
+    #[cfg(feature = "string_distance")]
+    pub fn hamming(self, other: Expr) -> Expr {
+        self.0
+            .map_many_private(StringFunction::Hamming.into(), &[other], false)
+    }
+
+    /// Jaro-Winkler similarity to the strings of `other`, between 0 and 1.
+    #[cfg(feature = "string_distance")]
+    pub fn jaro_winkler(self, other: Expr) -> Expr {
+        self.0
+            .map_many_private(StringFunction::JaroWinkler.into(), &[other], false)
+    }
+
+    /// Jaccard similarity of the sets of char `n`-grams of these strings and those of `other`.
+    #[cfg(feature = "string_distance")]
+    pub fn jaccard_ngram(self, other: Expr, n: usize) -> Expr {
+        self.0
+            .map_many_private(StringFunction::JaccardNgram(n).into(), &[other], false)
+    }
+
     /// Check if a string value ends with the `sub` string.
     pub fn ends_with(self, sub: Expr) -> Expr {
         self.0.map_many_private(
@@ -61,6 +147,21 @@ impl StringNameSpace {
             .map_private(StringFunction::Extract { pat, group_index }.into())
     }
 
+    /// Extract all capture groups of a regex pattern into a `Struct` with one `Utf8` field per
+    /// group. Fields are named after the named groups, unnamed groups are named by their index.
+    /// The output schema is inferred from the pattern, which is why this can fail.
+    #[cfg(feature = "extract_groups")]
+    pub fn extract_groups(self, pat: &str) -> PolarsResult<Expr> {
+        let dtype = extract_groups_dtype(pat)?;
+        Ok(self.0.map_private(
+            StringFunction::ExtractGroups {
+                dtype,
+                pat: pat.to_string(),
+            }
+            .into(),
+        ))
+    }
+
     /// Return a copy of the string left filled with ASCII '0' digits to make a string of length width.
     /// A leading sign prefix ('+'/'-') is handled by inserting the padding after the sign character
     /// rather than before.
@@ -454,6 +555,29 @@ impl StringNameSpace {
             .map_private(FunctionExpr::StringExpr(StringFunction::Titlecase))
     }
 
+    /// Fold the case of all characters for caseless comparison. Unlike
+    /// [`StringNameSpace::to_lowercase`] this maps e.g. 'ß' to "ss" and 'ς' to 'σ'.
+    #[cfg(feature = "string_unicode")]
+    pub fn casefold(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Casefold))
+    }
+
+    /// Bring the strings into the given Unicode normalization form.
+    #[cfg(feature = "string_unicode")]
+    pub fn normalize(self, form: UnicodeForm) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Normalize(form)))
+    }
+
+    /// Reverse the strings. Grapheme clusters, such as a char with its combining marks, are
+    /// kept intact.
+    #[cfg(feature = "string_unicode")]
+    pub fn reverse(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::Reverse))
+    }
+
     #[cfg(feature = "string_from_radix")]
     /// Parse string in base radix into decimal.
     pub fn from_radix(self, radix: u32, strict: bool) -> Expr {
@@ -469,6 +593,13 @@ impl StringNameSpace {
             .map_private(FunctionExpr::StringExpr(StringFunction::NChars))
     }
 
+    /// Return the number of user-perceived characters (extended grapheme clusters) in the string.
+    #[cfg(feature = "string_unicode")]
+    pub fn n_graphemes(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StringExpr(StringFunction::NGraphemes))
+    }
+
     /// Return the number of bytes in the string (not characters).
     pub fn lengths(self) -> Expr {
         self.0
@@ -2,7 +2,7 @@ use super::*;
 use crate::dsl::function_expr::StructFunction;
 
 /// Specialized expressions for Struct dtypes.
-pub struct StructNameSpace(pub(crate) Expr);
+pub struct StructNameSpace(pub Expr);
 
 impl StructNameSpace {
     pub fn field_by_index(self, index: i64) -> Expr {
@@ -28,6 +28,28 @@ impl StructNameSpace {
             })
     }
 
+    /// Retrieve all fields of this [`StructChunked`] whose name matches the regex `pattern`, as
+    /// separate columns. The pattern should start with `^` and end with `$`.
+    #[cfg(feature = "regex")]
+    pub fn field_by_names(self, pattern: &str) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StructExpr(StructFunction::FieldByNames(
+                Arc::from(pattern),
+            )))
+            .with_function_options(|mut options| {
+                options.allow_rename = true;
+                options
+            })
+    }
+
+    /// Serialize every row of this [`StructChunked`] to a JSON object string.
+    /// This is the inverse of `str().json_extract`.
+    #[cfg(feature = "json")]
+    pub fn json_encode(self) -> Expr {
+        self.0
+            .map_private(FunctionExpr::StructExpr(StructFunction::JsonEncode))
+    }
+
     /// Rename the fields of the [`StructChunked`].
     pub fn rename_fields(self, names: Vec<String>) -> Expr {
         let names = Arc::new(names);
@@ -164,7 +164,7 @@ pub enum AExpr {
         function: Node,
         partition_by: Vec<Node>,
         order_by: Option<Node>,
-        options: WindowOptions,
+        options: WindowType,
     },
     #[default]
     Wildcard,
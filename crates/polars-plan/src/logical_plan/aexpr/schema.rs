@@ -194,7 +194,7 @@ impl AExpr {
                     // default context because `col()` would return a list in aggregation context
                     .map(|node| arena.get(*node).to_field(schema, Context::Default, arena))
                     .collect::<PolarsResult<Vec<_>>>()?;
-                Ok(output_type.get_field(schema, ctxt, &fields))
+                output_type.try_get_field(schema, ctxt, &fields)
             }
             Function {
                 function, input, ..
@@ -1,7 +1,7 @@
 #[cfg(feature = "csv")]
 use std::io::{Read, Seek};
 
-#[cfg(feature = "parquet")]
+#[cfg(any(feature = "parquet", feature = "csv", feature = "ipc"))]
 use polars_core::cloud::CloudOptions;
 use polars_core::frame::explode::MeltArgs;
 use polars_core::prelude::*;
@@ -20,6 +20,7 @@ use polars_io::parquet::ParquetReader;
 use polars_io::RowCount;
 #[cfg(feature = "csv")]
 use polars_io::{
+    bad_records::BadRecords,
     csv::utils::{get_reader_bytes, infer_file_schema, is_compressed},
     csv::CsvEncoding,
     csv::NullValues,
@@ -191,11 +192,12 @@ impl LogicalPlanBuilder {
         cache: bool,
         row_count: Option<RowCount>,
         rechunk: bool,
+        cloud_options: Option<CloudOptions>,
     ) -> PolarsResult<Self> {
         use polars_io::SerReader as _;
 
         let path = path.into();
-        let file = polars_utils::open_file(&path)?;
+        let file = polars_io::open_file_or_cloud(&path, cloud_options.as_ref())?;
         let mut reader = IpcReader::new(file);
 
         let mut schema = reader.schema()?;
@@ -223,7 +225,10 @@ impl LogicalPlanBuilder {
             file_info,
             file_options,
             predicate: None,
-            scan_type: FileScan::Ipc { options },
+            scan_type: FileScan::Ipc {
+                options,
+                cloud_options,
+            },
         }
         .into())
     }
@@ -232,7 +237,7 @@ impl LogicalPlanBuilder {
     #[cfg(feature = "csv")]
     pub fn scan_csv<P: Into<std::path::PathBuf>>(
         path: P,
-        delimiter: u8,
+        delimiter: Vec<u8>,
         has_header: bool,
         ignore_errors: bool,
         mut skip_rows: usize,
@@ -243,6 +248,7 @@ impl LogicalPlanBuilder {
         low_memory: bool,
         comment_char: Option<u8>,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
         eol_char: u8,
         null_values: Option<NullValues>,
         infer_schema_length: Option<usize>,
@@ -251,17 +257,21 @@ impl LogicalPlanBuilder {
         encoding: CsvEncoding,
         row_count: Option<RowCount>,
         try_parse_dates: bool,
+        strict: bool,
+        bad_records: Option<BadRecords>,
+        cloud_options: Option<CloudOptions>,
     ) -> PolarsResult<Self> {
         let path = path.into();
-        let mut file = polars_utils::open_file(&path).map_err(|e| {
-            let path = path.to_string_lossy();
-            if path.len() > 88 {
-                let path: String = path.chars().skip(path.len() - 88).collect();
-                polars_err!(ComputeError: "error open file: ...{}, {}", path, e)
-            } else {
-                polars_err!(ComputeError: "error open file: {}, {}", path, e)
-            }
-        })?;
+        let mut file =
+            polars_io::open_file_or_cloud(&path, cloud_options.as_ref()).map_err(|e| {
+                let path = path.to_string_lossy();
+                if path.len() > 88 {
+                    let path: String = path.chars().skip(path.len() - 88).collect();
+                    polars_err!(ComputeError: "error open file: ...{}, {}", path, e)
+                } else {
+                    polars_err!(ComputeError: "error open file: {}, {}", path, e)
+                }
+            })?;
         let mut magic_nr = [0u8; 2];
         file.read_exact(&mut magic_nr)
             .map_err(|_| polars_err!(NoData: "empty csv"))?;
@@ -270,13 +280,13 @@ impl LogicalPlanBuilder {
             ComputeError: "cannot scan compressed csv; use `read_csv` for compressed data",
         );
         file.rewind()?;
-        let reader_bytes = get_reader_bytes(&mut file).expect("could not mmap file");
+        let reader_bytes = get_reader_bytes(&mut file)?;
 
         // TODO! delay inferring schema until absolutely necessary
         // this needs a way to estimated bytes/rows.
         let (mut inferred_schema, rows_read, bytes_read) = infer_file_schema(
             &reader_bytes,
-            delimiter,
+            &delimiter,
             infer_schema_length,
             has_header,
             schema_overwrite,
@@ -284,6 +294,7 @@ impl LogicalPlanBuilder {
             skip_rows_after_header,
             comment_char,
             quote_char,
+            escape_char,
             eol_char,
             null_values.as_ref(),
             try_parse_dates,
@@ -336,11 +347,15 @@ impl LogicalPlanBuilder {
                     low_memory,
                     comment_char,
                     quote_char,
+                    escape_char,
                     eol_char,
                     null_values,
                     encoding,
                     try_parse_dates,
+                    strict,
+                    bad_records,
                 },
+                cloud_options,
             },
         }
         .into())
@@ -4,14 +4,20 @@ use super::*;
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub enum FileScan {
     #[cfg(feature = "csv")]
-    Csv { options: CsvParserOptions },
+    Csv {
+        options: CsvParserOptions,
+        cloud_options: Option<CloudOptions>,
+    },
     #[cfg(feature = "parquet")]
     Parquet {
         options: ParquetOptions,
         cloud_options: Option<CloudOptions>,
     },
     #[cfg(feature = "ipc")]
-    Ipc { options: IpcScanOptions },
+    Ipc {
+        options: IpcScanOptions,
+        cloud_options: Option<CloudOptions>,
+    },
 }
 
 impl FileScan {
@@ -19,7 +25,7 @@ impl FileScan {
         #[allow(unreachable_patterns)]
         match self {
             #[cfg(feature = "csv")]
-            Self::Csv { options } => options.skip_rows,
+            Self::Csv { options, .. } => options.skip_rows,
             _ => 0,
         }
     }
@@ -255,8 +255,15 @@ impl Debug for Expr {
             Window {
                 function,
                 partition_by,
+                options,
                 ..
-            } => write!(f, "{function:?}.over({partition_by:?})"),
+            } => match options {
+                WindowType::Over(_) => write!(f, "{function:?}.over({partition_by:?})"),
+                #[cfg(feature = "dynamic_groupby")]
+                WindowType::Rolling(options) => {
+                    write!(f, "{function:?}.rolling(by='{}')", options.index_column)
+                }
+            },
             Nth(i) => write!(f, "nth({i})"),
             Count => write!(f, "count()"),
             Explode(expr) => write!(f, "{expr:?}.explode()"),
@@ -28,8 +28,20 @@ fn add_keys_to_accumulated_state(
     }
 }
 
-#[cfg(feature = "asof_join")]
-pub(super) fn process_asof_join(
+#[cfg(any(feature = "asof_join", feature = "interval_join"))]
+fn is_non_equi_join(how: &JoinType) -> bool {
+    match how {
+        #[cfg(feature = "asof_join")]
+        JoinType::AsOf(_) => true,
+        #[cfg(feature = "interval_join")]
+        JoinType::Interval => true,
+        _ => false,
+    }
+}
+
+/// Asof and interval joins are not equi-joins, so the join columns of both tables remain.
+#[cfg(any(feature = "asof_join", feature = "interval_join"))]
+pub(super) fn process_non_equi_join(
     proj_pd: &mut ProjectionPushDown,
     input_left: Node,
     input_right: Node,
@@ -51,10 +63,6 @@ pub(super) fn process_asof_join(
     let mut names_right = PlHashSet::with_capacity(n);
     let mut local_projection = Vec::with_capacity(n);
 
-    let JoinType::AsOf(asof_options) = &options.args.how else {
-        unreachable!()
-    };
-
     // if there are no projections we don't have to do anything (all columns are projected)
     // otherwise we build local projections to sort out proper column names due to the
     // join operation
@@ -68,7 +76,13 @@ pub(super) fn process_asof_join(
         let schema_right = lp_arena.get(input_right).schema(lp_arena);
 
         // make sure that the asof join 'by' columns are projected
-        if let (Some(left_by), Some(right_by)) = (&asof_options.left_by, &asof_options.right_by) {
+        #[cfg(feature = "asof_join")]
+        if let JoinType::AsOf(AsOfOptions {
+            left_by: Some(left_by),
+            right_by: Some(right_by),
+            ..
+        }) = &options.args.how
+        {
             for name in left_by {
                 let add = _projected_names.contains(name.as_str());
 
@@ -94,6 +108,25 @@ pub(super) fn process_asof_join(
                 );
             }
         }
+        // and the tolerance column
+        #[cfg(feature = "asof_join")]
+        if let JoinType::AsOf(AsOfOptions {
+            tolerance_column: Some(name),
+            ..
+        }) = &options.args.how
+        {
+            let add = _projected_names.contains(name.as_str());
+
+            let node = expr_arena.add(AExpr::Column(Arc::from(name.as_str())));
+            add_keys_to_accumulated_state(
+                node,
+                &mut pushdown_left,
+                &mut local_projection,
+                &mut names_left,
+                expr_arena,
+                add,
+            );
+        }
 
         // The join on keys can lead that columns are already added, we don't want to create
         // duplicates so store the names.
@@ -112,7 +145,7 @@ pub(super) fn process_asof_join(
             .unwrap();
             already_added_local_to_local_projected.insert(local_name);
         }
-        // this differs from normal joins, as in `asof_joins`
+        // this differs from normal joins, as in `asof_joins` and `interval_joins`
         // both columns remain. So `add_local=true` also for the right table
         for e in &right_on {
             if let Some(local_name) = add_keys_to_accumulated_state(
@@ -205,9 +238,9 @@ pub(super) fn process_join(
     lp_arena: &mut Arena<ALogicalPlan>,
     expr_arena: &mut Arena<AExpr>,
 ) -> PolarsResult<ALogicalPlan> {
-    #[cfg(feature = "asof_join")]
-    if matches!(options.args.how, JoinType::AsOf(_)) {
-        return process_asof_join(
+    #[cfg(any(feature = "asof_join", feature = "interval_join"))]
+    if is_non_equi_join(&options.args.how) {
+        return process_non_equi_join(
             proj_pd,
             input_left,
             input_right,
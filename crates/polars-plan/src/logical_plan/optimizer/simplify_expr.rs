@@ -690,9 +690,8 @@ fn inline_cast(input: &AExpr, dtype: &DataType, strict: bool) -> PolarsResult<Op
                     #[cfg(feature = "dtype-duration")]
                     (AnyValue::Duration(_, _), _) => return Ok(None),
                     #[cfg(feature = "dtype-categorical")]
-                    (AnyValue::Categorical(_, _, _), _) | (_, DataType::Categorical(_)) => {
-                        return Ok(None)
-                    }
+                    (AnyValue::Categorical(_, _, _) | AnyValue::Enum(_, _, _), _)
+                    | (_, DataType::Categorical(_) | DataType::Enum(_)) => return Ok(None),
                     #[cfg(feature = "dtype-struct")]
                     (_, DataType::Struct(_)) => return Ok(None),
                     (av, _) => {
@@ -148,7 +148,7 @@ impl SlicePushDown {
                 output_schema,
                 file_options: mut options,
                 predicate,
-                scan_type: FileScan::Csv {options: mut csv_options}
+                scan_type: FileScan::Csv {options: mut csv_options, cloud_options}
             }, Some(state)) if predicate.is_none() && state.offset >= 0 =>  {
                 options.n_rows = Some(state.len as usize);
                 csv_options.skip_rows += state.offset as usize;
@@ -157,7 +157,7 @@ impl SlicePushDown {
                     path,
                     file_info,
                     output_schema,
-                    scan_type: FileScan::Csv {options: csv_options},
+                    scan_type: FileScan::Csv {options: csv_options, cloud_options},
                     file_options: options,
                     predicate,
                 };
@@ -20,7 +20,7 @@ fn compares_cat_to_string(type_left: &DataType, type_right: &DataType, op: Opera
                 type_left,
                 type_right,
                 DataType::Utf8,
-                DataType::Categorical(_)
+                DataType::Categorical(_) | DataType::Enum(_)
             )
     }
     #[cfg(not(feature = "dtype-categorical"))]
@@ -64,7 +64,7 @@ fn is_cat_str_binary(type_left: &DataType, type_right: &DataType) -> bool {
             type_left,
             type_right,
             DataType::Utf8,
-            DataType::Categorical(_)
+            DataType::Categorical(_) | DataType::Enum(_)
         )
     }
     #[cfg(not(feature = "dtype-categorical"))]
@@ -223,7 +223,7 @@ pub(super) fn process_binary(
             return Ok(None)
         }
         #[cfg(feature = "dtype-categorical")]
-        (Utf8 | Categorical(_), dt, op) | (dt, Utf8 | Categorical(_), op)
+        (Utf8 | Categorical(_) | Enum(_), dt, op) | (dt, Utf8 | Categorical(_) | Enum(_), op)
             if op.is_comparison() && dt.is_numeric() =>
         {
             return Ok(None)
@@ -239,6 +239,9 @@ pub(super) fn process_binary(
         // structs can be arbitrarily nested, leave the complexity to the caller for now.
         #[cfg(feature = "dtype-struct")]
         (Struct(_), Struct(_), _op) => return Ok(None),
+        // arrays are broadcast against numeric values by the arithmetic itself
+        #[cfg(feature = "dtype-array")]
+        (Array(_, _), _, op) | (_, Array(_, _), op) if op.is_arithmetic() => return Ok(None),
         _ => {}
     }
     let compare_cat_to_string = compares_cat_to_string(&type_left, &type_right, op);
@@ -222,6 +222,11 @@ fn modify_supertype(
             | (Utf8, Categorical(_), AExpr::Literal(_), _) => {
                 st = Categorical(None);
             }
+            // a literal string compared to an Enum must be one of its categories
+            #[cfg(feature = "dtype-categorical")]
+            (Enum(_), Utf8, _, AExpr::Literal(_)) => st = type_left.clone(),
+            #[cfg(feature = "dtype-categorical")]
+            (Utf8, Enum(_), AExpr::Literal(_), _) => st = type_right.clone(),
             // when then expression literals can have a different list type.
             // so we cast the literal to the other hand side.
             (List(inner), List(other), _, AExpr::Literal(_))
@@ -360,6 +365,12 @@ impl OptimizationRule for TypeCoercionRule {
                             strict: false,
                         }
                     }
+                    #[cfg(feature = "dtype-categorical")]
+                    (DataType::Enum(_), DataType::Utf8) => AExpr::Cast {
+                        expr: other_node,
+                        data_type: type_left.clone(),
+                        strict: true,
+                    },
                     (dt, DataType::Utf8) => {
                         polars_bail!(ComputeError: "cannot compare {:?} to {:?} type in 'is_in' operation", dt, type_other)
                     }
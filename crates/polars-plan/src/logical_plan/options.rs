@@ -1,6 +1,11 @@
 use std::path::PathBuf;
 
+use polars_core::cloud::CloudOptions;
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroCompression;
+#[cfg(feature = "csv")]
+use polars_io::bad_records::BadRecords;
 #[cfg(feature = "csv")]
 use polars_io::csv::{CsvEncoding, NullValues};
 #[cfg(feature = "ipc")]
@@ -23,9 +28,10 @@ pub type FileCount = u32;
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct CsvParserOptions {
-    pub delimiter: u8,
+    pub delimiter: Vec<u8>,
     pub comment_char: Option<u8>,
     pub quote_char: Option<u8>,
+    pub escape_char: Option<u8>,
     pub eol_char: u8,
     pub has_header: bool,
     pub skip_rows: usize,
@@ -34,6 +40,10 @@ pub struct CsvParserOptions {
     pub null_values: Option<NullValues>,
     pub encoding: CsvEncoding,
     pub try_parse_dates: bool,
+    pub strict: bool,
+    /// Rows that cannot be parsed are quarantined here; not part of a serialized plan.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub bad_records: Option<BadRecords>,
 }
 
 #[cfg(feature = "parquet")]
@@ -71,6 +81,16 @@ pub struct IpcWriterOptions {
     pub maintain_order: bool,
 }
 
+#[cfg(feature = "avro")]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AvroWriterOptions {
+    /// Block compression
+    pub compression: Option<AvroCompression>,
+    /// maintain the order the data was processed
+    pub maintain_order: bool,
+}
+
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct IpcScanOptions {
@@ -290,6 +310,8 @@ pub struct AnonymousScanOptions {
 pub struct FileSinkOptions {
     pub path: Arc<PathBuf>,
     pub file_type: FileType,
+    /// Used if `path` is a cloud url.
+    pub cloud_options: Option<CloudOptions>,
 }
 
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -299,5 +321,7 @@ pub enum FileType {
     Parquet(ParquetWriteOptions),
     #[cfg(feature = "ipc")]
     Ipc(IpcWriterOptions),
+    #[cfg(feature = "avro")]
+    Avro(AvroWriterOptions),
     Memory,
 }
@@ -3,6 +3,8 @@ use polars_arrow::index::IndexToUsize;
 use polars_core::utils::get_supertype;
 
 use super::*;
+#[cfg(all(feature = "dtype-struct", feature = "regex"))]
+use crate::dsl::function_expr::StructFunction;
 use crate::prelude::function_expr::FunctionExpr;
 
 /// This replace the wildcard Expr with a Column Expr. It also removes the Exclude Expr from the
@@ -130,6 +132,48 @@ fn expand_regex(
     Ok(())
 }
 
+#[cfg(all(feature = "dtype-struct", feature = "regex"))]
+/// This function expands a `struct.field_by_names` regex to a `struct.field_by_name` for every
+/// field of the struct that matches the regex and adds the expanded expressions to `result`.
+fn expand_struct_fields(expr: Expr, result: &mut Vec<Expr>, schema: &Schema) -> PolarsResult<()> {
+    let Some((input, pattern)) = expr.into_iter().find_map(|e| match e {
+        Expr::Function {
+            input,
+            function: FunctionExpr::StructExpr(StructFunction::FieldByNames(pattern)),
+            ..
+        } => Some((input[0].clone(), pattern.clone())),
+        _ => None,
+    }) else {
+        result.push(expr);
+        return Ok(());
+    };
+
+    let dtype = input.to_field(schema, Context::Default)?.dtype;
+    let DataType::Struct(fields) = dtype else {
+        polars_bail!(
+            ComputeError: "expected struct dtype in `struct.field_by_names`, got: `{}`", dtype
+        )
+    };
+    let re = regex::Regex::new(&pattern)
+        .map_err(|e| polars_err!(ComputeError: "invalid regex {}", e))?;
+    for fld in fields.iter().filter(|fld| re.is_match(fld.name())) {
+        let mut new_expr = expr.clone();
+        new_expr.mutate().apply(|e| {
+            if let Expr::Function {
+                function: FunctionExpr::StructExpr(function @ StructFunction::FieldByNames(_)),
+                ..
+            } = e
+            {
+                *function = StructFunction::FieldByName(Arc::from(fld.name().as_str()));
+            }
+            true
+        });
+        // there can be more patterns
+        expand_struct_fields(new_expr, result, schema)?;
+    }
+    Ok(())
+}
+
 pub(crate) fn is_regex_projection(name: &str) -> bool {
     name.starts_with('^') && name.ends_with('$')
 }
@@ -380,6 +424,8 @@ struct ExpansionFlags {
     replace_fill_null_type: bool,
     has_selector: bool,
     has_exclude: bool,
+    #[cfg(all(feature = "dtype-struct", feature = "regex"))]
+    has_struct_field_pattern: bool,
 }
 
 fn find_flags(expr: &Expr) -> ExpansionFlags {
@@ -389,6 +435,8 @@ fn find_flags(expr: &Expr) -> ExpansionFlags {
     let mut replace_fill_null_type = false;
     let mut has_selector = false;
     let mut has_exclude = false;
+    #[cfg(all(feature = "dtype-struct", feature = "regex"))]
+    let mut has_struct_field_pattern = false;
 
     // do a single pass and collect all flags at once.
     // supertypes/modification that can be done in place are also don e in that pass
@@ -403,6 +451,11 @@ fn find_flags(expr: &Expr) -> ExpansionFlags {
                 ..
             } => replace_fill_null_type = true,
             Expr::Exclude(_, _) => has_exclude = true,
+            #[cfg(all(feature = "dtype-struct", feature = "regex"))]
+            Expr::Function {
+                function: FunctionExpr::StructExpr(StructFunction::FieldByNames(_)),
+                ..
+            } => has_struct_field_pattern = true,
             _ => {}
         }
     }
@@ -413,6 +466,8 @@ fn find_flags(expr: &Expr) -> ExpansionFlags {
         replace_fill_null_type,
         has_selector,
         has_exclude,
+        #[cfg(all(feature = "dtype-struct", feature = "regex"))]
+        has_struct_field_pattern,
     }
 }
 
@@ -440,6 +495,14 @@ pub(crate) fn rewrite_projections(
 
         replace_and_add_to_results(expr, flags, &mut result, schema, keys)?;
 
+        // the struct fields can only be expanded once the input columns are known
+        #[cfg(all(feature = "dtype-struct", feature = "regex"))]
+        if flags.has_struct_field_pattern {
+            for e in result.drain(result_offset..).collect::<Vec<_>>() {
+                expand_struct_fields(e, &mut result, schema)?;
+            }
+        }
+
         // this is done after all expansion (wildcard, column, dtypes)
         // have been done. This will ensure the conversion to aexpr does
         // not panic because of an unexpected wildcard etc.
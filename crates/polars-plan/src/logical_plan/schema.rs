@@ -264,10 +264,18 @@ pub(crate) fn det_join_schema(
                 }
             }
 
+            // the interval bounds of both sides differ, so interval joins keep the right ones
+            #[cfg(feature = "interval_join")]
+            let drop_right_keys = !matches!(options.args.how, JoinType::Interval);
+            #[cfg(not(feature = "interval_join"))]
+            let drop_right_keys = true;
+
             let mut right_names: PlHashSet<_> = PlHashSet::with_capacity(right_on.len());
-            for e in right_on {
-                let field = e.to_field_amortized(schema_right, Context::Default, &mut arena)?;
-                right_names.insert(field.name);
+            if drop_right_keys {
+                for e in right_on {
+                    let field = e.to_field_amortized(schema_right, Context::Default, &mut arena)?;
+                    right_names.insert(field.name);
+                }
             }
 
             for (name, dtype) in schema_right.iter() {
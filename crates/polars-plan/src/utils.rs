@@ -429,3 +429,44 @@ pub fn rename_cse_tmp_series(s: &mut Series) {
         s.rename(name);
     }
 }
+
+/// Convert the timedelta string of an asof join to a tolerance in the unit of the asof key.
+#[cfg(feature = "asof_join")]
+pub fn resolve_asof_tolerance(options: &mut AsOfOptions, key_dtype: &DataType) -> PolarsResult<()> {
+    use polars_core::utils::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
+    if let Some(tol) = &options.tolerance_str {
+        let duration = polars_time::Duration::parse(tol);
+        polars_ensure!(
+            duration.months() == 0,
+            ComputeError: "cannot use month offset in timedelta of an asof join; \
+            consider using 4 weeks"
+        );
+        polars_ensure!(
+            duration.business_days() == 0,
+            ComputeError: "cannot use business day offset in timedelta of an asof join"
+        );
+        use DataType::*;
+        match key_dtype {
+            Datetime(tu, _) | Duration(tu) => {
+                let tolerance = match tu {
+                    TimeUnit::Nanoseconds => duration.duration_ns(),
+                    TimeUnit::Microseconds => duration.duration_us(),
+                    TimeUnit::Milliseconds => duration.duration_ms(),
+                };
+                options.tolerance = Some(AnyValue::from(tolerance))
+            }
+            Date => {
+                let days = (duration.duration_ms() / MILLISECONDS_IN_DAY) as i32;
+                options.tolerance = Some(AnyValue::from(days))
+            }
+            Time => {
+                let tolerance = duration.duration_ns();
+                options.tolerance = Some(AnyValue::from(tolerance))
+            }
+            _ => polars_bail!(
+                InvalidOperation: "can only use timedelta string language with Date/Datetime/Duration/Time dtypes"
+            ),
+        }
+    }
+    Ok(())
+}
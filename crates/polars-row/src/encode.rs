@@ -1,8 +1,11 @@
 use arrow::array::{
-    Array, BinaryArray, BooleanArray, DictionaryArray, PrimitiveArray, StructArray, Utf8Array,
+    Array, BinaryArray, BooleanArray, DictionaryArray, FixedSizeListArray, ListArray,
+    PrimitiveArray, StructArray, Utf8Array,
 };
+use arrow::bitmap::Bitmap;
 use arrow::compute::cast::cast;
 use arrow::datatypes::{DataType as ArrowDataType, DataType};
+use arrow::offset::Offsets;
 use arrow::types::NativeType;
 use polars_utils::vec::PushUnchecked;
 
@@ -37,33 +40,21 @@ pub fn convert_columns_amortized<'a, I: IntoIterator<Item = &'a SortField>>(
 ) {
     let fields = fields.into_iter();
     assert_eq!(fields.size_hint().0, columns.len());
-    if columns
-        .iter()
-        .any(|arr| matches!(arr.data_type(), DataType::Struct(_) | DataType::LargeUtf8))
-    {
+    if columns.iter().any(|arr| {
+        matches!(
+            arr.data_type(),
+            DataType::Struct(_)
+                | DataType::LargeUtf8
+                | DataType::LargeList(_)
+                | DataType::FixedSizeList(_, _)
+                | DataType::Null
+        )
+    }) {
         let mut flattened_columns = Vec::with_capacity(columns.len() * 5);
         let mut flattened_fields = Vec::with_capacity(columns.len() * 5);
 
         for (arr, field) in columns.iter().zip(fields) {
-            match arr.data_type() {
-                DataType::Struct(_) => {
-                    let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
-                    for arr in arr.values() {
-                        flattened_columns.push(arr.clone() as ArrayRef);
-                        flattened_fields.push(field.clone())
-                    }
-                }
-                DataType::LargeUtf8 => {
-                    flattened_columns.push(
-                        cast(arr.as_ref(), &DataType::LargeBinary, Default::default()).unwrap(),
-                    );
-                    flattened_fields.push(field.clone());
-                }
-                _ => {
-                    flattened_columns.push(arr.clone());
-                    flattened_fields.push(field.clone());
-                }
-            }
+            flatten_column(arr, field, &mut flattened_columns, &mut flattened_fields)
         }
         let values_size =
             allocate_rows_buf(&flattened_columns, &mut rows.values, &mut rows.offsets);
@@ -86,6 +77,97 @@ pub fn convert_columns_amortized<'a, I: IntoIterator<Item = &'a SortField>>(
     }
 }
 
+/// Flattens nested columns into columns the row encoder can encode directly.
+///
+/// Struct fields are encoded one after another and lists are
+/// encoded to a binary column (see [`encode_list`]).
+fn flatten_column(
+    arr: &ArrayRef,
+    field: &SortField,
+    columns: &mut Vec<ArrayRef>,
+    fields: &mut Vec<SortField>,
+) {
+    match arr.data_type() {
+        DataType::Struct(_) => {
+            let arr = arr.as_any().downcast_ref::<StructArray>().unwrap();
+            for arr in arr.values() {
+                flatten_column(arr, field, columns, fields)
+            }
+        }
+        DataType::LargeUtf8 => {
+            columns.push(cast(arr.as_ref(), &DataType::LargeBinary, Default::default()).unwrap());
+            fields.push(field.clone());
+        }
+        // e.g. the values of an all-null list
+        DataType::Null => {
+            columns.push(BooleanArray::new_null(DataType::Boolean, arr.len()).boxed());
+            fields.push(field.clone());
+        }
+        DataType::LargeList(_) => {
+            let arr = arr.as_any().downcast_ref::<ListArray<i64>>().unwrap();
+            let encoded = encode_list(
+                arr.values(),
+                arr.offsets().as_slice(),
+                arr.validity(),
+                field,
+            );
+            columns.push(encoded.boxed());
+            fields.push(field.clone());
+        }
+        DataType::FixedSizeList(_, width) => {
+            let arr = arr.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let offsets = (0..=arr.len())
+                .map(|i| (i * width) as i64)
+                .collect::<Vec<_>>();
+            let encoded = encode_list(arr.values(), &offsets, arr.validity(), field);
+            columns.push(encoded.boxed());
+            fields.push(field.clone());
+        }
+        _ => {
+            columns.push(arr.clone());
+            fields.push(field.clone());
+        }
+    }
+}
+
+/// Encodes every list as the concatenation of the row encodings of its elements.
+///
+/// The row encoding of a single value is prefix-free, so comparing the concatenated
+/// bytes compares the lists element by element. The result is then encoded as a
+/// variable length value, which sorts a list before any list it is a prefix of.
+/// The elements are always encoded ascending; `descending` is applied once on the
+/// outer encoding.
+fn encode_list(
+    values: &ArrayRef,
+    offsets: &[i64],
+    validity: Option<&Bitmap>,
+    field: &SortField,
+) -> BinaryArray<i64> {
+    let inner_field = SortField {
+        descending: false,
+        // the outer encoding inverts the null sentinel of the elements if descending
+        nulls_last: field.nulls_last != field.descending,
+    };
+    let rows = convert_columns(std::slice::from_ref(values), &[inner_field]);
+
+    let start = rows.offsets[offsets[0] as usize];
+    let end = rows.offsets[offsets[offsets.len() - 1] as usize];
+    let new_offsets = offsets
+        .iter()
+        .map(|o| (rows.offsets[*o as usize] - start) as i64)
+        .collect::<Vec<_>>();
+    let values = rows.values[start..end].to_vec();
+
+    // Safety: the row offsets are monotonically increasing.
+    let new_offsets = unsafe { Offsets::new_unchecked(new_offsets) };
+    BinaryArray::new(
+        DataType::LargeBinary,
+        new_offsets.into(),
+        values.into(),
+        validity.cloned(),
+    )
+}
+
 fn encode_primitive<T: NativeType + FixedLengthEncoding>(
     arr: &PrimitiveArray<T>,
     field: &SortField,
@@ -385,4 +467,39 @@ mod test {
         let decoded = unsafe { decode_binary(&mut rows, &field) };
         assert_eq!(decoded, arr);
     }
+
+    #[test]
+    fn test_list_encode_order() {
+        use arrow::array::{MutableListArray, MutablePrimitiveArray, TryExtend};
+
+        let mut list = MutableListArray::<i64, MutablePrimitiveArray<i32>>::new();
+        list.try_extend([
+            Some(vec![Some(1), Some(2)]),
+            Some(vec![Some(1)]),
+            None,
+            Some(vec![]),
+            Some(vec![Some(1), None]),
+            Some(vec![Some(0), Some(5), Some(5)]),
+        ])
+        .unwrap();
+        let arr: ListArray<i64> = list.into();
+        let arr = arr.boxed();
+
+        let sorted_idx = |field: SortField| {
+            let rows = convert_columns(&[arr.clone()], &[field]);
+            let mut idx = (0..arr.len()).collect::<Vec<_>>();
+            idx.sort_by_key(|i| rows.get(*i));
+            idx
+        };
+        let asc = sorted_idx(SortField {
+            descending: false,
+            nulls_last: false,
+        });
+        assert_eq!(asc, &[2, 3, 5, 1, 4, 0]);
+        let desc = sorted_idx(SortField {
+            descending: true,
+            nulls_last: false,
+        });
+        assert_eq!(desc, &[2, 4, 0, 1, 5, 3]);
+    }
 }
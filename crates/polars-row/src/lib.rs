@@ -195,18 +195,13 @@
 //!
 //! ## List Encoding
 //!
-//! Lists are encoded by first encoding all child elements to the row format.
+//! Lists (and fixed size lists) are encoded by first encoding all child elements to the
+//! row format. The child elements are always encoded in ascending order.
 //!
 //! A "canonical byte array" is then constructed by concatenating the row
-//! encodings of all their elements into a single binary array, followed
-//! by the lengths of each encoded row, and the number of elements, encoded
-//! as big endian `u32`.
-//!
-//! This canonical byte array is then encoded using the variable length byte
-//! encoding described above.
-//!
-//! _The lengths are not strictly necessary but greatly simplify decode, they
-//! may be removed in a future iteration_.
+//! encodings of the elements of a list. As the row encoding of a single value is
+//! prefix-free, comparing two canonical byte arrays compares the lists element
+//! by element.
 //!
 //! For example given:
 //!
@@ -228,20 +223,20 @@
 //! Which would be grouped into the following canonical byte arrays:
 //!
 //! ```text
-//!                         ┌──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┐
-//!  [1_u8, 2_u8, 3_u8]     │01│01│01│02│01│03│00│00│00│02│00│00│00│02│00│00│00│02│00│00│00│03│
-//!                         └──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┘
-//!                          └──── rows ────┘   └───────── row lengths ─────────┘  └─ count ─┘
-//!
-//!                         ┌──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┬──┐
-//!  [1_u8, null]           │01│01│00│00│00│00│00│02│00│00│00│02│00│00│00│02│
-//!                         └──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┴──┘
+//!                         ┌──┬──┬──┬──┬──┬──┐
+//!  [1_u8, 2_u8, 3_u8]     │01│01│01│02│01│03│
+//!                         └──┴──┴──┴──┴──┴──┘
+//!
+//!                         ┌──┬──┬──┬──┐
+//!  [1_u8, null]           │01│01│00│00│
+//!                         └──┴──┴──┴──┘
 //!```
 //!
 //! With `[]` represented by an empty byte array, and `null` a null byte array.
 //!
 //! These byte arrays will then be encoded using the variable length byte encoding
-//! described above.
+//! described above. That encoding orders a list before every list it is a prefix of
+//! and applies the sort order of the column.
 //!
 //! # Ordering
 //!
@@ -0,0 +1,441 @@
+use polars_arrow::export::arrow::temporal_conversions::{
+    MICROSECONDS, MILLISECONDS, NANOSECONDS, SECONDS_IN_DAY,
+};
+use polars_core::prelude::*;
+use polars_core::series::IsSorted;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "timezones")]
+use crate::utils::{localize_timestamp, unlocalize_timestamp};
+use crate::windows::groupby::ClosedWindow;
+
+/// Monday to Friday.
+pub const DEFAULT_WEEK_MASK: [bool; 7] = [true, true, true, true, true, false, false];
+
+/// What to do with a date that does not fall on a business day before
+/// business days are added to it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Roll {
+    /// Raise an error.
+    #[default]
+    Raise,
+    /// Roll forward to the next business day.
+    Forward,
+    /// Roll backward to the previous business day.
+    Backward,
+}
+
+/// Weekday of a date given in days since the unix epoch, Monday is `0`.
+#[inline]
+fn weekday(date: i32) -> usize {
+    // 1970-01-01 was a Thursday
+    (date + 3).rem_euclid(7) as usize
+}
+
+pub(crate) struct BusinessCalendar {
+    week_mask: [bool; 7],
+    days_per_week: i32,
+    // sorted, deduplicated and restricted to days in `week_mask`
+    holidays: Vec<i32>,
+}
+
+impl BusinessCalendar {
+    pub(crate) fn new(week_mask: [bool; 7], holidays: &[i32]) -> PolarsResult<Self> {
+        let days_per_week = week_mask.iter().filter(|b| **b).count() as i32;
+        polars_ensure!(
+            days_per_week > 0,
+            ComputeError: "'week_mask' should contain at least one business day"
+        );
+        let mut holidays = holidays
+            .iter()
+            .copied()
+            .filter(|d| week_mask[weekday(*d)])
+            .collect::<Vec<_>>();
+        holidays.sort_unstable();
+        holidays.dedup();
+        Ok(Self {
+            week_mask,
+            days_per_week,
+            holidays,
+        })
+    }
+
+    /// Number of holidays in `[start, end)`.
+    fn n_holidays(&self, start: i32, end: i32) -> i32 {
+        let lower = self.holidays.partition_point(|h| *h < start);
+        let upper = self.holidays.partition_point(|h| *h < end);
+        (upper - lower) as i32
+    }
+
+    fn is_business_day(&self, date: i32) -> bool {
+        self.week_mask[weekday(date)] && self.holidays.binary_search(&date).is_err()
+    }
+
+    /// Number of business days in `[start, end)`, negative if `end < start`.
+    pub(crate) fn count(&self, start: i32, end: i32) -> i32 {
+        if end < start {
+            return -self.count(end, start);
+        }
+        let n_weeks = (end - start) / 7;
+        let mut n = n_weeks * self.days_per_week;
+        for date in (start + n_weeks * 7)..end {
+            n += self.week_mask[weekday(date)] as i32;
+        }
+        n - self.n_holidays(start, end)
+    }
+
+    fn roll(&self, mut date: i32, roll: Roll) -> PolarsResult<i32> {
+        if self.is_business_day(date) {
+            return Ok(date);
+        }
+        match roll {
+            Roll::Raise => {
+                polars_bail!(ComputeError: "date {} is not a business day; set 'roll' to 'forward' or 'backward' to roll it to the nearest business day", date_to_str(date))
+            }
+            Roll::Forward => {
+                while !self.is_business_day(date) {
+                    date += 1
+                }
+            }
+            Roll::Backward => {
+                while !self.is_business_day(date) {
+                    date -= 1
+                }
+            }
+        }
+        Ok(date)
+    }
+
+    /// Move `n` business days away from the business day `date`.
+    fn offset(&self, mut date: i32, n: i64) -> PolarsResult<i32> {
+        let step = if n < 0 { -1 } else { 1 };
+        let mut remaining = i32::try_from(n.unsigned_abs())
+            .map_err(|_| polars_err!(ComputeError: "business day offset {} is out of range", n))?;
+        while remaining > 0 {
+            let previous = date;
+            date += step * (remaining / self.days_per_week) * 7;
+            remaining %= self.days_per_week;
+            while remaining > 0 {
+                date += step;
+                remaining -= self.week_mask[weekday(date)] as i32;
+            }
+            // every holiday we stepped over costs one more business day
+            remaining = if step > 0 {
+                self.n_holidays(previous + 1, date + 1)
+            } else {
+                self.n_holidays(date, previous)
+            };
+        }
+        Ok(date)
+    }
+}
+
+/// Shift a date by `n` business days, Monday to Friday. A date in the weekend first
+/// moves to the adjacent business day in the direction of the shift, which counts
+/// as one of the `n` days.
+pub(crate) fn shift_weekdays(date: i32, n: i64) -> PolarsResult<i32> {
+    let calendar = BusinessCalendar::new(DEFAULT_WEEK_MASK, &[])?;
+    if n == 0 || calendar.is_business_day(date) {
+        calendar.offset(date, n)
+    } else if n > 0 {
+        calendar.offset(calendar.roll(date, Roll::Forward)?, n - 1)
+    } else {
+        calendar.offset(calendar.roll(date, Roll::Backward)?, n + 1)
+    }
+}
+
+/// Roll a date in the weekend forward to the next Monday.
+pub(crate) fn roll_forward_weekday(date: i32) -> PolarsResult<i32> {
+    BusinessCalendar::new(DEFAULT_WEEK_MASK, &[])?.roll(date, Roll::Forward)
+}
+
+/// Truncate a date to a multiple of `n` business days, Monday to Friday, counted
+/// from the unix epoch.
+pub(crate) fn truncate_weekdays(date: i32, n: i64) -> PolarsResult<i32> {
+    let calendar = BusinessCalendar::new(DEFAULT_WEEK_MASK, &[])?;
+    let date = calendar.roll(date, Roll::Backward)?;
+    let remainder = (calendar.count(0, date) as i64).rem_euclid(n);
+    calendar.offset(date, -remainder)
+}
+
+fn date_to_str(date: i32) -> String {
+    AnyValue::Date(date).to_string()
+}
+
+pub(crate) fn units_per_day(tu: TimeUnit) -> i64 {
+    match tu {
+        TimeUnit::Nanoseconds => NANOSECONDS * SECONDS_IN_DAY,
+        TimeUnit::Microseconds => MICROSECONDS * SECONDS_IN_DAY,
+        TimeUnit::Milliseconds => MILLISECONDS * SECONDS_IN_DAY,
+    }
+}
+
+#[cfg(feature = "timezones")]
+fn parse_time_zone(tz: &str) -> PolarsResult<polars_arrow::time_zone::Tz> {
+    tz.parse()
+        .map_err(|_| polars_err!(ComputeError: "unable to parse time zone: '{}'", tz))
+}
+
+/// The wall-clock timestamps of a `Datetime` column.
+pub(crate) fn to_local_timestamps(ca: &DatetimeChunked) -> PolarsResult<Int64Chunked> {
+    match ca.time_zone() {
+        #[cfg(feature = "timezones")]
+        Some(tz) => {
+            let tz = parse_time_zone(tz)?;
+            let tu = ca.time_unit();
+            Ok(ca.0.apply(|t| unlocalize_timestamp(t, tu, tz)))
+        }
+        _ => Ok(ca.0.clone()),
+    }
+}
+
+/// The calendar days of a `Date` or `Datetime` column. Time zone aware datetimes
+/// are interpreted in their local time.
+fn to_local_days(s: &Series) -> PolarsResult<Int32Chunked> {
+    match s.dtype() {
+        DataType::Date => Ok(s.date().unwrap().0.clone()),
+        DataType::Datetime(tu, _) => {
+            let units_per_day = units_per_day(*tu);
+            let local = to_local_timestamps(s.datetime().unwrap())?;
+            Ok(local.apply_cast_numeric::<_, Int32Type>(|t| t.div_euclid(units_per_day) as i32))
+        }
+        dt => polars_bail!(InvalidOperation: "expected Date or Datetime, got {}", dt),
+    }
+}
+
+/// Check which values of a `Date` or `Datetime` column fall on a business day.
+///
+/// `week_mask` flags the business days of the week, starting at Monday. `holidays`
+/// are given in days since the unix epoch.
+pub fn is_business_day(
+    s: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<BooleanChunked> {
+    let calendar = BusinessCalendar::new(week_mask, holidays)?;
+    let days = to_local_days(s)?;
+    let mut out: BooleanChunked = (&days)
+        .into_iter()
+        .map(|opt_d| opt_d.map(|d| calendar.is_business_day(d)))
+        .collect();
+    out.rename(s.name());
+    Ok(out)
+}
+
+/// Count the business days in `[start, end)`.
+///
+/// The count is negative if `end` lies before `start`. Both inputs should be
+/// `Date` or `Datetime` and either have equal length or length `1`.
+pub fn business_day_count(
+    start: &Series,
+    end: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<Int32Chunked> {
+    let calendar = BusinessCalendar::new(week_mask, holidays)?;
+    let start_days = to_local_days(start)?;
+    let end_days = to_local_days(end)?;
+    let count = |s: Option<i32>, e: Option<i32>| match (s, e) {
+        (Some(s), Some(e)) => Some(calendar.count(s, e)),
+        _ => None,
+    };
+    let mut out: Int32Chunked = match (start_days.len(), end_days.len()) {
+        (1, _) => {
+            let s = start_days.get(0);
+            (&end_days).into_iter().map(|e| count(s, e)).collect()
+        }
+        (_, 1) => {
+            let e = end_days.get(0);
+            (&start_days).into_iter().map(|s| count(s, e)).collect()
+        }
+        (a, b) if a == b => (&start_days)
+            .into_iter()
+            .zip(&end_days)
+            .map(|(s, e)| count(s, e))
+            .collect(),
+        (a, b) => {
+            polars_bail!(ShapeMismatch: "'start' and 'end' should have the same length, got {} and {}", a, b)
+        }
+    };
+    out.rename(start.name());
+    Ok(out)
+}
+
+/// Add `n` business days to a `Date` or `Datetime` column.
+///
+/// Values that do not fall on a business day are first handled according to
+/// `roll`. The time of day of datetimes is preserved. `n` should have the same
+/// length as `s` or length `1`.
+pub fn add_business_days(
+    s: &Series,
+    n: &Series,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+    roll: Roll,
+) -> PolarsResult<Series> {
+    let calendar = BusinessCalendar::new(week_mask, holidays)?;
+    polars_ensure!(
+        n.dtype().is_integer(),
+        InvalidOperation: "expected an integer number of business days, got {}", n.dtype()
+    );
+    let n = n.cast(&DataType::Int64)?;
+    let n = n.i64().unwrap();
+    polars_ensure!(
+        n.len() == 1 || n.len() == s.len(),
+        ShapeMismatch: "'n' should have length 1 or the same length as the series, got {} and {}", n.len(), s.len()
+    );
+    let shift = |d: i32, n: i64| calendar.roll(d, roll).and_then(|d| calendar.offset(d, n));
+
+    let out = match s.dtype() {
+        DataType::Date => {
+            let ca = &s.date().unwrap().0;
+            let out: Int32Chunked = if n.len() == 1 {
+                match n.get(0) {
+                    Some(n) => ca.try_apply(|d| shift(d, n))?,
+                    None => Int32Chunked::full_null(s.name(), s.len()),
+                }
+            } else {
+                ca.into_iter()
+                    .zip(n)
+                    .map(|(d, n)| match (d, n) {
+                        (Some(d), Some(n)) => shift(d, n).map(Some),
+                        _ => Ok(None),
+                    })
+                    .collect::<PolarsResult<_>>()?
+            };
+            out.into_date().into_series()
+        }
+        DataType::Datetime(tu, tz) => {
+            let units_per_day = units_per_day(*tu);
+            let local = to_local_timestamps(s.datetime().unwrap())?;
+            let shift = |t: i64, n: i64| -> PolarsResult<i64> {
+                let date = t.div_euclid(units_per_day) as i32;
+                let time = t.rem_euclid(units_per_day);
+                Ok(shift(date, n)? as i64 * units_per_day + time)
+            };
+            let out: Int64Chunked = if n.len() == 1 {
+                match n.get(0) {
+                    Some(n) => local.try_apply(|t| shift(t, n))?,
+                    None => Int64Chunked::full_null(s.name(), s.len()),
+                }
+            } else {
+                (&local)
+                    .into_iter()
+                    .zip(n)
+                    .map(|(t, n)| match (t, n) {
+                        (Some(t), Some(n)) => shift(t, n).map(Some),
+                        _ => Ok(None),
+                    })
+                    .collect::<PolarsResult<_>>()?
+            };
+            let out = match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => {
+                    let parsed = parse_time_zone(tz)?;
+                    out.try_apply(|t| localize_timestamp(t, *tu, parsed))?
+                }
+                _ => out,
+            };
+            out.into_datetime(*tu, tz.clone()).into_series()
+        }
+        dt => polars_bail!(
+            opq = add_business_days,
+            got = dt,
+            expected = "date/datetime"
+        ),
+    };
+    let mut out = out;
+    out.rename(s.name());
+    if n.len() == 1 {
+        // rolling and shifting by the same number of business days is monotonic
+        out.set_sorted_flag(s.is_sorted_flag());
+    }
+    Ok(out)
+}
+
+/// Create a [`DateChunked`] of every `every`-th business day between `start` and
+/// `stop`, where both bounds are given in days since the unix epoch.
+///
+/// The range starts at the first business day on or after `start`.
+pub fn business_date_range(
+    name: &str,
+    start: i32,
+    stop: i32,
+    every: i64,
+    closed: ClosedWindow,
+    week_mask: [bool; 7],
+    holidays: &[i32],
+) -> PolarsResult<DateChunked> {
+    polars_ensure!(start <= stop, ComputeError: "'start' cannot be greater than 'stop'");
+    polars_ensure!(every > 0, ComputeError: "'interval' should be a positive number of business days");
+    let calendar = BusinessCalendar::new(week_mask, holidays)?;
+
+    let mut out = Vec::with_capacity(((stop - start) as usize * 5 / 7) / every as usize + 1);
+    let mut date = calendar.roll(start, Roll::Forward)?;
+    if date == start && matches!(closed, ClosedWindow::Right | ClosedWindow::None) {
+        date = calendar.offset(date, every)?;
+    }
+    let include_stop = matches!(closed, ClosedWindow::Right | ClosedWindow::Both);
+    while date < stop || (include_stop && date == stop) {
+        out.push(date);
+        date = calendar.offset(date, every)?;
+    }
+    let mut out = Int32Chunked::from_vec(name, out).into_date();
+    out.set_sorted_flag(IsSorted::Ascending);
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> i32 {
+        let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let date = chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        date.signed_duration_since(epoch).num_days() as i32
+    }
+
+    #[test]
+    fn test_business_days() -> PolarsResult<()> {
+        // Friday 2023-07-07, Monday 2023-07-10 is a holiday
+        let holidays = [date(2023, 7, 10)];
+        let s = Int32Chunked::new("d", &[date(2023, 7, 7), date(2023, 7, 8)])
+            .into_date()
+            .into_series();
+
+        let out = is_business_day(&s, DEFAULT_WEEK_MASK, &holidays)?;
+        assert_eq!(Vec::from(&out), &[Some(true), Some(false)]);
+
+        let n = Series::new("n", &[1i64]);
+        assert!(add_business_days(&s, &n, DEFAULT_WEEK_MASK, &holidays, Roll::Raise).is_err());
+        let out = add_business_days(&s, &n, DEFAULT_WEEK_MASK, &holidays, Roll::Forward)?;
+        let expected = [Some(date(2023, 7, 11)), Some(date(2023, 7, 12))];
+        assert_eq!(Vec::from(&out.date()?.0), &expected);
+
+        let n = Series::new("n", &[-6i64, 0]);
+        let out = add_business_days(&s, &n, DEFAULT_WEEK_MASK, &holidays, Roll::Backward)?;
+        let expected = [Some(date(2023, 6, 29)), Some(date(2023, 7, 7))];
+        assert_eq!(Vec::from(&out.date()?.0), &expected);
+
+        let end = Int32Chunked::new("d", &[date(2023, 7, 21), date(2023, 7, 1)])
+            .into_date()
+            .into_series();
+        let out = business_day_count(&s, &end, DEFAULT_WEEK_MASK, &holidays)?;
+        assert_eq!(Vec::from(&out), &[Some(9), Some(-5)]);
+
+        let out = business_date_range(
+            "d",
+            date(2023, 7, 8),
+            date(2023, 7, 14),
+            2,
+            ClosedWindow::Both,
+            DEFAULT_WEEK_MASK,
+            &holidays,
+        )?;
+        let expected = [Some(date(2023, 7, 11)), Some(date(2023, 7, 13))];
+        assert_eq!(Vec::from(&out.0), &expected);
+        Ok(())
+    }
+}
@@ -31,6 +31,7 @@ where
             &rolling::no_nulls::rolling_mean,
             &rolling::nulls::rolling_mean,
             Some(&super::rolling_kernels::no_nulls::rolling_mean),
+            Some(&super::rolling_kernels::nulls::rolling_mean),
         )
     }
 
@@ -45,6 +46,7 @@ where
             &rolling::no_nulls::rolling_sum,
             &rolling::nulls::rolling_sum,
             Some(&super::rolling_kernels::no_nulls::rolling_sum),
+            Some(&super::rolling_kernels::nulls::rolling_sum),
         )
     }
 
@@ -59,6 +61,7 @@ where
             &rolling::no_nulls::rolling_min,
             &rolling::nulls::rolling_min,
             Some(&super::rolling_kernels::no_nulls::rolling_min),
+            Some(&super::rolling_kernels::nulls::rolling_min),
         )
     }
 
@@ -73,6 +76,7 @@ where
             &rolling::no_nulls::rolling_max,
             &rolling::nulls::rolling_max,
             Some(&super::rolling_kernels::no_nulls::rolling_max),
+            Some(&super::rolling_kernels::nulls::rolling_max),
         )
     }
 
@@ -95,6 +99,7 @@ where
             &rolling::no_nulls::rolling_quantile,
             &rolling::nulls::rolling_quantile,
             Some(&super::rolling_kernels::no_nulls::rolling_quantile),
+            Some(&super::rolling_kernels::nulls::rolling_quantile),
         )
     }
 
@@ -108,6 +113,7 @@ where
             &rolling::no_nulls::rolling_quantile,
             &rolling::nulls::rolling_quantile,
             Some(&super::rolling_kernels::no_nulls::rolling_quantile),
+            Some(&super::rolling_kernels::nulls::rolling_quantile),
         )
     }
 
@@ -118,6 +124,7 @@ where
             &rolling::no_nulls::rolling_var,
             &rolling::nulls::rolling_var,
             Some(&super::rolling_kernels::no_nulls::rolling_var),
+            Some(&super::rolling_kernels::nulls::rolling_var),
         )
     }
 
@@ -132,6 +139,7 @@ where
             &rolling::no_nulls::rolling_var,
             &rolling::nulls::rolling_var,
             Some(&super::rolling_kernels::no_nulls::rolling_var),
+            Some(&super::rolling_kernels::nulls::rolling_var),
         )
         .map(|mut s| {
             match s.dtype().clone() {
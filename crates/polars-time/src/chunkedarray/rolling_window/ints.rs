@@ -25,6 +25,7 @@ where
             &rolling::no_nulls::rolling_sum,
             &rolling::nulls::rolling_sum,
             Some(&super::rolling_kernels::no_nulls::rolling_sum),
+            Some(&super::rolling_kernels::nulls::rolling_sum),
         )
     }
 
@@ -46,6 +47,7 @@ where
             &rolling::no_nulls::rolling_min,
             &rolling::nulls::rolling_min,
             Some(&super::rolling_kernels::no_nulls::rolling_min),
+            Some(&super::rolling_kernels::nulls::rolling_min),
         )
     }
 
@@ -59,6 +61,7 @@ where
             &rolling::no_nulls::rolling_max,
             &rolling::nulls::rolling_max,
             Some(&super::rolling_kernels::no_nulls::rolling_max),
+            Some(&super::rolling_kernels::nulls::rolling_max),
         )
     }
 
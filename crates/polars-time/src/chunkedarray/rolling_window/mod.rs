@@ -29,6 +29,8 @@ pub struct RollingOptions {
     pub min_periods: usize,
     /// An optional slice with the same length as the window that will be multiplied
     ///              elementwise with the values in the window.
+    ///              If the window is defined by a time column, the weights are spread evenly
+    ///              over the window duration and interpolated at the time of every value.
     pub weights: Option<Vec<f64>>,
     /// Set the labels at the center of the window.
     pub center: bool,
@@ -64,6 +66,10 @@ pub struct RollingOptionsImpl<'a> {
     pub min_periods: usize,
     /// An optional slice with the same length as the window that will be multiplied
     ///              elementwise with the values in the window.
+    ///              If the window is defined by a time column (`by`), the weights are spread
+    ///              evenly over the window duration and linearly interpolated at the time of
+    ///              every value. There is no exponential weighting option; exponential decay
+    ///              has to be sampled into the weights.
     pub weights: Option<Vec<f64>>,
     /// Set the labels at the center of the window.
     pub center: bool,
@@ -242,6 +248,23 @@ fn rolling_agg<T>(
             Duration,
             &[i64],
             ClosedWindow,
+            usize,
+            bool,
+            Option<&[f64]>,
+            TimeUnit,
+            Option<&TimeZone>,
+            DynArgs,
+        ) -> PolarsResult<ArrayRef>,
+    >,
+    rolling_agg_fn_dynamic_nulls: Option<
+        &dyn Fn(
+            &PrimitiveArray<T::Native>,
+            Duration,
+            &[i64],
+            ClosedWindow,
+            usize,
+            bool,
+            Option<&[f64]>,
             TimeUnit,
             Option<&TimeZone>,
             DynArgs,
@@ -281,28 +304,54 @@ where
             ),
         })
     } else {
-        if arr.null_count() > 0 {
-            panic!("'rolling by' not yet supported for series with null values, consider using 'groupby_rolling'")
-        }
-        let values = arr.values().as_slice();
         let duration = options.window_size;
         polars_ensure!(duration.duration_ns() > 0 && !duration.negative, ComputeError:"window size should be strictly positive");
+        polars_ensure!(
+            !options.center || duration.is_constant_duration(),
+            ComputeError: "a centered 'rolling by' window should have a fixed duration, \
+            without calendar days, weeks or months"
+        );
         let tu = options.tu.unwrap();
         let by = options.by.unwrap();
         let closed_window = options.closed_window.expect("closed window  must be set");
-        let func = rolling_agg_fn_dynamic.expect(
-            "'rolling by' not yet supported for this expression, consider using 'groupby_rolling'",
-        );
+        let weights = options.weights.as_deref();
 
-        func(
-            values,
-            duration,
-            by,
-            closed_window,
-            tu,
-            options.tz,
-            options.fn_params,
-        )
+        match arr.null_count() {
+            0 => {
+                let func = rolling_agg_fn_dynamic.expect(
+                    "'rolling by' not yet supported for this expression, consider using 'groupby_rolling'",
+                );
+                func(
+                    arr.values().as_slice(),
+                    duration,
+                    by,
+                    closed_window,
+                    options.min_periods,
+                    options.center,
+                    weights,
+                    tu,
+                    options.tz,
+                    options.fn_params,
+                )
+            }
+            _ => {
+                let func = rolling_agg_fn_dynamic_nulls.expect(
+                    "'rolling by' not yet supported for this expression, consider using 'groupby_rolling'",
+                );
+                func(
+                    arr,
+                    duration,
+                    by,
+                    closed_window,
+                    options.min_periods,
+                    options.center,
+                    weights,
+                    tu,
+                    options.tz,
+                    options.fn_params,
+                )
+            }
+        }
     }?;
     Series::try_from((ca.name(), arr))
 }
@@ -1,14 +1,167 @@
 pub(super) mod no_nulls;
+pub(super) mod nulls;
 use std::fmt::Debug;
-use std::ops::{AddAssign, Mul, SubAssign};
+use std::ops::{Add, AddAssign, Mul, Sub, SubAssign};
 
 use arrow::array::PrimitiveArray;
+use arrow::bitmap::Bitmap;
 use arrow::types::NativeType;
 use polars_arrow::data_types::IsFloat;
 use polars_arrow::export::arrow;
 use polars_arrow::index::IdxSize;
+use polars_arrow::kernels::rolling::no_nulls::compute_wq;
+use polars_arrow::kernels::rolling::{
+    compare_fn_nan_max, compare_fn_nan_min, DynArgs, RollingQuantileParams,
+};
+use polars_arrow::time_zone::Tz;
 use polars_arrow::trusted_len::TrustedLen;
-use polars_core::export::num::{Bounded, Float, NumCast};
+use polars_core::export::num::{Bounded, Float, NumCast, ToPrimitive};
 use polars_core::prelude::*;
 
 use crate::prelude::*;
+
+fn parse_time_zone(tz: Option<&TimeZone>) -> Option<Tz> {
+    match tz {
+        #[cfg(feature = "timezones")]
+        Some(tz) => tz.parse::<Tz>().ok(),
+        _ => None,
+    }
+}
+
+/// The `(offset, len)` of the windows ending at, or centered on, every value in `time`.
+pub(super) fn window_offsets<'a>(
+    period: Duration,
+    time: &'a [i64],
+    closed_window: ClosedWindow,
+    center: bool,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+) -> PolarsResult<Box<dyn TrustedLen<Item = PolarsResult<(IdxSize, IdxSize)>> + 'a>> {
+    groupby_values_iter(period, time, closed_window, center, tu, parse_time_zone(tz))
+}
+
+/// Interpolate the `weights`, spread evenly from `lower` to `upper`, at time `t`.
+fn interpolate_weight(weights: &[f64], lower: i64, upper: i64, t: i64) -> f64 {
+    let last = weights.len() - 1;
+    if last == 0 || upper == lower {
+        return weights[last];
+    }
+    let pos = ((t - lower) as f64 / (upper - lower) as f64 * last as f64).clamp(0.0, last as f64);
+    let idx = (pos as usize).min(last - 1);
+    let frac = pos - idx as f64;
+    weights[idx] * (1.0 - frac) + weights[idx + 1] * frac
+}
+
+/// Apply a weighted aggregation to the `(value, weight)` pairs of the non-null values in every
+/// window. The `weights` are spread evenly over the window, from its start to its end, and
+/// linearly interpolated at the time of every value. A weighted window therefore doesn't
+/// depend on how many values fall into it. Aggregates that can't be represented in `T` give
+/// null.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn rolling_apply_weights<T, F>(
+    values: &[T],
+    validity: Option<&Bitmap>,
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    center: bool,
+    weights: &[f64],
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    aggregator: F,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + NumCast,
+    F: Fn(&mut [(f64, f64)]) -> Option<f64>,
+{
+    polars_ensure!(!weights.is_empty(), ComputeError: "`weights` should not be empty");
+    let add = match tu {
+        TimeUnit::Nanoseconds => Duration::add_ns,
+        TimeUnit::Microseconds => Duration::add_us,
+        TimeUnit::Milliseconds => Duration::add_ms,
+    };
+    let offset = rolling_window_offset(period, center)?;
+    let parsed_tz = parse_time_zone(tz);
+    let offsets = window_offsets(period, time, closed_window, center, tu, tz)?;
+
+    let mut buf = Vec::new();
+    let out = offsets
+        .zip(time)
+        .map(|(result, &t)| {
+            let (start, len) = result?;
+            let lower = add(&offset, t, parsed_tz.as_ref())?;
+            let upper = add(&period, lower, parsed_tz.as_ref())?;
+
+            buf.clear();
+            for idx in start as usize..(start + len) as usize {
+                // safety: the window is in bounds
+                let valid = validity.map_or(true, |v| unsafe { v.get_bit_unchecked(idx) });
+                if valid {
+                    let value = unsafe { values.get_unchecked(idx) }.to_f64().unwrap();
+                    let weight = interpolate_weight(weights, lower, upper, time[idx]);
+                    buf.push((value, weight));
+                }
+            }
+            if buf.is_empty() || buf.len() < min_periods {
+                Ok(None)
+            } else {
+                // an aggregate that doesn't fit in `T`, e.g. an overflowing or non-finite weighted
+                // sum of integers, gives a null window
+                Ok(aggregator(&mut buf).and_then(<T as NumCast>::from))
+            }
+        })
+        .collect::<PolarsResult<PrimitiveArray<T>>>()?;
+
+    Ok(Box::new(out))
+}
+
+fn weighted_sum(buf: &mut [(f64, f64)]) -> Option<f64> {
+    Some(buf.iter().map(|(v, w)| v * w).sum())
+}
+
+fn weighted_mean(buf: &mut [(f64, f64)]) -> Option<f64> {
+    let wsum: f64 = buf.iter().map(|(_, w)| w).sum();
+    (wsum != 0.0).then(|| buf.iter().map(|(v, w)| v * w).sum::<f64>() / wsum)
+}
+
+fn weighted_var(buf: &mut [(f64, f64)]) -> Option<f64> {
+    // like the fixed window kernels, the weights are standardized and no bias correction is done
+    let mean = weighted_mean(buf)?;
+    let wsum: f64 = buf.iter().map(|(_, w)| w).sum();
+    Some(buf.iter().map(|(v, w)| v * v * w).sum::<f64>() / wsum - mean * mean)
+}
+
+fn weighted_min(buf: &mut [(f64, f64)]) -> Option<f64> {
+    buf.iter()
+        .map(|(v, w)| v * w)
+        .min_by(|a, b| compare_fn_nan_min(a, b))
+}
+
+fn weighted_max(buf: &mut [(f64, f64)]) -> Option<f64> {
+    buf.iter()
+        .map(|(v, w)| v * w)
+        .max_by(|a, b| compare_fn_nan_max(a, b))
+}
+
+fn weighted_quantile(params: &DynArgs) -> impl Fn(&mut [(f64, f64)]) -> Option<f64> + '_ {
+    let params = params
+        .as_ref()
+        .unwrap()
+        .downcast_ref::<RollingQuantileParams>()
+        .unwrap();
+    move |buf| {
+        let wsum: f64 = buf.iter().map(|(_, w)| w).sum();
+        if wsum == 0.0 {
+            return None;
+        }
+        // values without weight don't take part, sort them to the front and skip them
+        buf.sort_unstable_by(|a, b| {
+            (a.1 != 0.0)
+                .cmp(&(b.1 != 0.0))
+                .then_with(|| compare_fn_nan_max(&a.0, &b.0))
+        });
+        let buf = &buf[buf.partition_point(|(_, w)| *w == 0.0)..];
+        Some(compute_wq(buf, params.prob, wsum, params.interpol))
+    }
+}
@@ -1,5 +1,3 @@
-#[cfg(feature = "timezones")]
-use chrono_tz::Tz;
 use polars_arrow::kernels::rolling::no_nulls::{self, RollingAggWindowNoNulls};
 
 use super::*;
@@ -8,6 +6,7 @@ use super::*;
 pub(crate) fn rolling_apply_agg_window<'a, Agg, T, O>(
     values: &'a [T],
     offsets: O,
+    min_periods: usize,
     params: DynArgs,
 ) -> PolarsResult<ArrayRef>
 where
@@ -32,7 +31,7 @@ where
             result.map(|(start, len)| {
                 let end = start + len;
 
-                if start == end {
+                if start == end || (len as usize) < min_periods {
                     None
                 } else {
                     // safety:
@@ -52,6 +51,9 @@ pub(crate) fn rolling_min<T>(
     period: Duration,
     time: &[i64],
     closed_window: ClosedWindow,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
     tu: TimeUnit,
     tz: Option<&TimeZone>,
     _params: DynArgs,
@@ -59,12 +61,23 @@ pub(crate) fn rolling_min<T>(
 where
     T: NativeType + PartialOrd + IsFloat + Bounded + NumCast + Mul<Output = T>,
 {
-    let offset_iter = match tz {
-        #[cfg(feature = "timezones")]
-        Some(tz) => groupby_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
-        _ => groupby_values_iter(period, time, closed_window, tu, None),
-    };
-    rolling_apply_agg_window::<no_nulls::MinWindow<_>, _, _>(values, offset_iter, None)
+    if let Some(weights) = weights {
+        return rolling_apply_weights(
+            values,
+            None,
+            period,
+            time,
+            closed_window,
+            min_periods,
+            center,
+            weights,
+            tu,
+            tz,
+            weighted_min,
+        );
+    }
+    let offset_iter = window_offsets(period, time, closed_window, center, tu, tz)?;
+    rolling_apply_agg_window::<no_nulls::MinWindow<_>, _, _>(values, offset_iter, min_periods, None)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -73,6 +86,9 @@ pub(crate) fn rolling_max<T>(
     period: Duration,
     time: &[i64],
     closed_window: ClosedWindow,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
     tu: TimeUnit,
     tz: Option<&TimeZone>,
     _params: DynArgs,
@@ -80,12 +96,23 @@ pub(crate) fn rolling_max<T>(
 where
     T: NativeType + PartialOrd + IsFloat + Bounded + NumCast + Mul<Output = T>,
 {
-    let offset_iter = match tz {
-        #[cfg(feature = "timezones")]
-        Some(tz) => groupby_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
-        _ => groupby_values_iter(period, time, closed_window, tu, None),
-    };
-    rolling_apply_agg_window::<no_nulls::MaxWindow<_>, _, _>(values, offset_iter, None)
+    if let Some(weights) = weights {
+        return rolling_apply_weights(
+            values,
+            None,
+            period,
+            time,
+            closed_window,
+            min_periods,
+            center,
+            weights,
+            tu,
+            tz,
+            weighted_max,
+        );
+    }
+    let offset_iter = window_offsets(period, time, closed_window, center, tu, tz)?;
+    rolling_apply_agg_window::<no_nulls::MaxWindow<_>, _, _>(values, offset_iter, min_periods, None)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -94,6 +121,9 @@ pub(crate) fn rolling_sum<T>(
     period: Duration,
     time: &[i64],
     closed_window: ClosedWindow,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
     tu: TimeUnit,
     tz: Option<&TimeZone>,
     _params: DynArgs,
@@ -101,12 +131,23 @@ pub(crate) fn rolling_sum<T>(
 where
     T: NativeType + std::iter::Sum + NumCast + Mul<Output = T> + AddAssign + SubAssign + IsFloat,
 {
-    let offset_iter = match tz {
-        #[cfg(feature = "timezones")]
-        Some(tz) => groupby_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
-        _ => groupby_values_iter(period, time, closed_window, tu, None),
-    };
-    rolling_apply_agg_window::<no_nulls::SumWindow<_>, _, _>(values, offset_iter, None)
+    if let Some(weights) = weights {
+        return rolling_apply_weights(
+            values,
+            None,
+            period,
+            time,
+            closed_window,
+            min_periods,
+            center,
+            weights,
+            tu,
+            tz,
+            weighted_sum,
+        );
+    }
+    let offset_iter = window_offsets(period, time, closed_window, center, tu, tz)?;
+    rolling_apply_agg_window::<no_nulls::SumWindow<_>, _, _>(values, offset_iter, min_periods, None)
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -115,6 +156,9 @@ pub(crate) fn rolling_mean<T>(
     period: Duration,
     time: &[i64],
     closed_window: ClosedWindow,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
     tu: TimeUnit,
     tz: Option<&TimeZone>,
     _params: DynArgs,
@@ -122,12 +166,28 @@ pub(crate) fn rolling_mean<T>(
 where
     T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
 {
-    let offset_iter = match tz {
-        #[cfg(feature = "timezones")]
-        Some(tz) => groupby_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
-        _ => groupby_values_iter(period, time, closed_window, tu, None),
-    };
-    rolling_apply_agg_window::<no_nulls::MeanWindow<_>, _, _>(values, offset_iter, None)
+    if let Some(weights) = weights {
+        return rolling_apply_weights(
+            values,
+            None,
+            period,
+            time,
+            closed_window,
+            min_periods,
+            center,
+            weights,
+            tu,
+            tz,
+            weighted_mean,
+        );
+    }
+    let offset_iter = window_offsets(period, time, closed_window, center, tu, tz)?;
+    rolling_apply_agg_window::<no_nulls::MeanWindow<_>, _, _>(
+        values,
+        offset_iter,
+        min_periods,
+        None,
+    )
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -136,6 +196,9 @@ pub(crate) fn rolling_var<T>(
     period: Duration,
     time: &[i64],
     closed_window: ClosedWindow,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
     tu: TimeUnit,
     tz: Option<&TimeZone>,
     params: DynArgs,
@@ -143,12 +206,28 @@ pub(crate) fn rolling_var<T>(
 where
     T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
 {
-    let offset_iter = match tz {
-        #[cfg(feature = "timezones")]
-        Some(tz) => groupby_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
-        _ => groupby_values_iter(period, time, closed_window, tu, None),
-    };
-    rolling_apply_agg_window::<no_nulls::VarWindow<_>, _, _>(values, offset_iter, params)
+    if let Some(weights) = weights {
+        return rolling_apply_weights(
+            values,
+            None,
+            period,
+            time,
+            closed_window,
+            min_periods,
+            center,
+            weights,
+            tu,
+            tz,
+            weighted_var,
+        );
+    }
+    let offset_iter = window_offsets(period, time, closed_window, center, tu, tz)?;
+    rolling_apply_agg_window::<no_nulls::VarWindow<_>, _, _>(
+        values,
+        offset_iter,
+        min_periods,
+        params,
+    )
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -157,6 +236,9 @@ pub(crate) fn rolling_quantile<T>(
     period: Duration,
     time: &[i64],
     closed_window: ClosedWindow,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
     tu: TimeUnit,
     tz: Option<&TimeZone>,
     params: DynArgs,
@@ -164,10 +246,26 @@ pub(crate) fn rolling_quantile<T>(
 where
     T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
 {
-    let offset_iter = match tz {
-        #[cfg(feature = "timezones")]
-        Some(tz) => groupby_values_iter(period, time, closed_window, tu, tz.parse::<Tz>().ok()),
-        _ => groupby_values_iter(period, time, closed_window, tu, None),
-    };
-    rolling_apply_agg_window::<no_nulls::QuantileWindow<_>, _, _>(values, offset_iter, params)
+    if let Some(weights) = weights {
+        return rolling_apply_weights(
+            values,
+            None,
+            period,
+            time,
+            closed_window,
+            min_periods,
+            center,
+            weights,
+            tu,
+            tz,
+            weighted_quantile(&params),
+        );
+    }
+    let offset_iter = window_offsets(period, time, closed_window, center, tu, tz)?;
+    rolling_apply_agg_window::<no_nulls::QuantileWindow<_>, _, _>(
+        values,
+        offset_iter,
+        min_periods,
+        params,
+    )
 }
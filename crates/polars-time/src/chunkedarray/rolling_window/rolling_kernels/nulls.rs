@@ -0,0 +1,309 @@
+use polars_arrow::kernels::rolling::nulls::{self, RollingAggWindowNulls};
+
+use super::*;
+
+// Use an aggregation window that maintains the state
+pub(crate) fn rolling_apply_agg_window<'a, Agg, T, O>(
+    values: &'a [T],
+    validity: &'a Bitmap,
+    offsets: O,
+    min_periods: usize,
+    params: DynArgs,
+) -> PolarsResult<ArrayRef>
+where
+    // items (offset, len) -> so offsets are offset, offset + len
+    Agg: RollingAggWindowNulls<'a, T>,
+    O: Iterator<Item = PolarsResult<(IdxSize, IdxSize)>> + TrustedLen,
+    T: Debug + IsFloat + NativeType,
+{
+    if values.is_empty() {
+        let out: Vec<T> = vec![];
+        return Ok(Box::new(PrimitiveArray::new(
+            T::PRIMITIVE.into(),
+            out.into(),
+            None,
+        )));
+    }
+    // start with a dummy index, will be overwritten on first iteration.
+    // safety:
+    // we are in bounds
+    let mut agg_window = unsafe { Agg::new(values, validity, 0, 0, params) };
+
+    let out = offsets
+        .map(|result| {
+            result.map(|(start, len)| {
+                let end = start + len;
+
+                if start == end {
+                    None
+                } else {
+                    // safety:
+                    // we are in bounds
+                    let agg = unsafe { agg_window.update(start as usize, end as usize) };
+                    // a window of only nulls is null
+                    agg.filter(|_| agg_window.is_valid(min_periods.max(1)))
+                }
+            })
+        })
+        .collect::<PolarsResult<PrimitiveArray<T>>>()?;
+
+    Ok(Box::new(out))
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_min<T>(
+    arr: &PrimitiveArray<T>,
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    _params: DynArgs,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + PartialOrd + IsFloat + Bounded + NumCast + Mul<Output = T>,
+{
+    let values = arr.values().as_slice();
+    let validity = arr.validity().unwrap();
+    if let Some(weights) = weights {
+        return rolling_apply_weights(
+            values,
+            Some(validity),
+            period,
+            time,
+            closed_window,
+            min_periods,
+            center,
+            weights,
+            tu,
+            tz,
+            weighted_min,
+        );
+    }
+    let offset_iter = window_offsets(period, time, closed_window, center, tu, tz)?;
+    rolling_apply_agg_window::<nulls::MinWindow<_>, _, _>(
+        values,
+        validity,
+        offset_iter,
+        min_periods,
+        None,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_max<T>(
+    arr: &PrimitiveArray<T>,
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    _params: DynArgs,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + PartialOrd + IsFloat + Bounded + NumCast + Mul<Output = T>,
+{
+    let values = arr.values().as_slice();
+    let validity = arr.validity().unwrap();
+    if let Some(weights) = weights {
+        return rolling_apply_weights(
+            values,
+            Some(validity),
+            period,
+            time,
+            closed_window,
+            min_periods,
+            center,
+            weights,
+            tu,
+            tz,
+            weighted_max,
+        );
+    }
+    let offset_iter = window_offsets(period, time, closed_window, center, tu, tz)?;
+    rolling_apply_agg_window::<nulls::MaxWindow<_>, _, _>(
+        values,
+        validity,
+        offset_iter,
+        min_periods,
+        None,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_sum<T>(
+    arr: &PrimitiveArray<T>,
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    _params: DynArgs,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + IsFloat + PartialOrd + NumCast + Add<Output = T> + Sub<Output = T>,
+{
+    let values = arr.values().as_slice();
+    let validity = arr.validity().unwrap();
+    if let Some(weights) = weights {
+        return rolling_apply_weights(
+            values,
+            Some(validity),
+            period,
+            time,
+            closed_window,
+            min_periods,
+            center,
+            weights,
+            tu,
+            tz,
+            weighted_sum,
+        );
+    }
+    let offset_iter = window_offsets(period, time, closed_window, center, tu, tz)?;
+    rolling_apply_agg_window::<nulls::SumWindow<_>, _, _>(
+        values,
+        validity,
+        offset_iter,
+        min_periods,
+        None,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_mean<T>(
+    arr: &PrimitiveArray<T>,
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    _params: DynArgs,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
+{
+    let values = arr.values().as_slice();
+    let validity = arr.validity().unwrap();
+    if let Some(weights) = weights {
+        return rolling_apply_weights(
+            values,
+            Some(validity),
+            period,
+            time,
+            closed_window,
+            min_periods,
+            center,
+            weights,
+            tu,
+            tz,
+            weighted_mean,
+        );
+    }
+    let offset_iter = window_offsets(period, time, closed_window, center, tu, tz)?;
+    rolling_apply_agg_window::<nulls::MeanWindow<_>, _, _>(
+        values,
+        validity,
+        offset_iter,
+        min_periods,
+        None,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_var<T>(
+    arr: &PrimitiveArray<T>,
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: DynArgs,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
+{
+    let values = arr.values().as_slice();
+    let validity = arr.validity().unwrap();
+    if let Some(weights) = weights {
+        return rolling_apply_weights(
+            values,
+            Some(validity),
+            period,
+            time,
+            closed_window,
+            min_periods,
+            center,
+            weights,
+            tu,
+            tz,
+            weighted_var,
+        );
+    }
+    let offset_iter = window_offsets(period, time, closed_window, center, tu, tz)?;
+    rolling_apply_agg_window::<nulls::VarWindow<_>, _, _>(
+        values,
+        validity,
+        offset_iter,
+        min_periods,
+        params,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn rolling_quantile<T>(
+    arr: &PrimitiveArray<T>,
+    period: Duration,
+    time: &[i64],
+    closed_window: ClosedWindow,
+    min_periods: usize,
+    center: bool,
+    weights: Option<&[f64]>,
+    tu: TimeUnit,
+    tz: Option<&TimeZone>,
+    params: DynArgs,
+) -> PolarsResult<ArrayRef>
+where
+    T: NativeType + Float + std::iter::Sum<T> + SubAssign + AddAssign + IsFloat,
+{
+    let values = arr.values().as_slice();
+    let validity = arr.validity().unwrap();
+    if let Some(weights) = weights {
+        return rolling_apply_weights(
+            values,
+            Some(validity),
+            period,
+            time,
+            closed_window,
+            min_periods,
+            center,
+            weights,
+            tu,
+            tz,
+            weighted_quantile(&params),
+        );
+    }
+    let offset_iter = window_offsets(period, time, closed_window, center, tu, tz)?;
+    rolling_apply_agg_window::<nulls::QuantileWindow<_>, _, _>(
+        values,
+        validity,
+        offset_iter,
+        min_periods,
+        params,
+    )
+}
@@ -523,14 +523,24 @@ pub(crate) fn to_datetime(
                 Pattern::DatetimeYMDZ => infer.coerce_utf8(ca).datetime().map(|ca| {
                     let mut ca = ca.clone();
                     ca.set_time_unit(tu);
-                    polars_ops::prelude::replace_time_zone(&ca, Some("UTC"), None)
+                    polars_ops::prelude::replace_time_zone(
+                        &ca,
+                        Some("UTC"),
+                        Ambiguous::Raise,
+                        NonExistent::Raise,
+                    )
                 })?,
                 _ => infer.coerce_utf8(ca).datetime().map(|ca| {
                     let mut ca = ca.clone();
                     ca.set_time_unit(tu);
                     match tz {
                         #[cfg(feature = "timezones")]
-                        Some(tz) => polars_ops::prelude::replace_time_zone(&ca, Some(tz), None),
+                        Some(tz) => polars_ops::prelude::replace_time_zone(
+                            &ca,
+                            Some(tz),
+                            Ambiguous::Raise,
+                            NonExistent::Raise,
+                        ),
                         _ => Ok(ca),
                     }
                 })?,
@@ -303,9 +303,12 @@ pub trait Utf8Methods: AsUtf8 {
         ca.rename(utf8_ca.name());
         match (tz_aware, tz) {
             #[cfg(feature = "timezones")]
-            (false, Some(tz)) => {
-                polars_ops::prelude::replace_time_zone(&ca.into_datetime(tu, None), Some(tz), None)
-            }
+            (false, Some(tz)) => polars_ops::prelude::replace_time_zone(
+                &ca.into_datetime(tu, None),
+                Some(tz),
+                Ambiguous::Raise,
+                NonExistent::Raise,
+            ),
             #[cfg(feature = "timezones")]
             (true, _) => Ok(ca.into_datetime(tu, Some("UTC".to_string()))),
             _ => Ok(ca.into_datetime(tu, None)),
@@ -521,7 +524,8 @@ pub trait Utf8Methods: AsUtf8 {
                 Some(tz) => polars_ops::prelude::replace_time_zone(
                     &ca.into_datetime(tu, None),
                     Some(tz),
-                    None,
+                    Ambiguous::Raise,
+                    NonExistent::Raise,
                 ),
                 _ => Ok(ca.into_datetime(tu, None)),
             }
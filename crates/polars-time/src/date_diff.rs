@@ -0,0 +1,215 @@
+use chrono::{Datelike, NaiveDateTime};
+use polars_core::prelude::*;
+use polars_core::utils::arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::business::{to_local_timestamps, units_per_day, BusinessCalendar, DEFAULT_WEEK_MASK};
+use crate::windows::duration::Duration;
+
+/// The calendar unit in which the difference between two dates is expressed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum DateDiffUnit {
+    Years,
+    Quarters,
+    Months,
+    Weeks,
+    Days,
+    /// Monday to Friday.
+    BusinessDays,
+}
+
+/// The wall-clock timestamps of a `Date` or `Datetime` column in time unit `tu`.
+fn to_local(s: &Series, tu: TimeUnit) -> PolarsResult<Int64Chunked> {
+    match s.dtype() {
+        DataType::Date => Ok(s
+            .cast(&DataType::Datetime(tu, None))?
+            .datetime()
+            .unwrap()
+            .0
+            .clone()),
+        DataType::Datetime(_, tz) => {
+            let s = s.cast(&DataType::Datetime(tu, tz.clone()))?;
+            to_local_timestamps(s.datetime().unwrap())
+        }
+        dt => polars_bail!(InvalidOperation: "expected Date or Datetime, got {}", dt),
+    }
+}
+
+/// Add `n` months, clamping the day to the end of the month like `"1mo_saturating"`.
+fn add_months(t: NaiveDateTime, n: i64) -> PolarsResult<NaiveDateTime> {
+    Duration::add_month(t, n, false, true)
+}
+
+/// The whole periods of `n_months` from `start` to `end`, truncated towards zero, and the
+/// signed fraction of the next period that has passed as well.
+fn month_periods(
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    n_months: i64,
+) -> PolarsResult<(i64, f64)> {
+    let sign = if end >= start { 1 } else { -1 };
+    let mut months =
+        (end.year() - start.year()) as i64 * 12 + end.month() as i64 - start.month() as i64;
+    // within the month of `end` the shifted day may still lie beyond `end`
+    let shifted = add_months(start, months)?;
+    if (sign > 0 && shifted > end) || (sign < 0 && shifted < end) {
+        months -= sign;
+    }
+    let periods = months / n_months;
+    let lower = add_months(start, periods * n_months)?;
+    let upper = add_months(start, (periods + sign) * n_months)?;
+    let nanos = |d: chrono::Duration| d.num_nanoseconds().unwrap() as f64;
+    let fraction = nanos(end - lower) / nanos(upper - lower);
+    Ok((periods, sign as f64 * fraction))
+}
+
+/// The difference between `end` and `start` in calendar `unit`s.
+///
+/// Months, quarters and years are counted the way `Duration` adds `"1mo_saturating"`: one
+/// month after January 31st is February 28th (or 29th). Truncated differences are rounded
+/// towards zero and returned as `Int64`. Fractional differences are returned as `Float64` and
+/// add the part of the next unit that has passed. Business days, Monday to Friday, are counted
+/// on the dates in `[start, end)` and are always whole.
+///
+/// Time zone aware datetimes are compared in their local time. Both inputs should either
+/// have equal length or length `1`.
+pub fn date_diff(
+    end: &Series,
+    start: &Series,
+    unit: DateDiffUnit,
+    fractional: bool,
+) -> PolarsResult<Series> {
+    let tu = match (end.dtype(), start.dtype()) {
+        (DataType::Datetime(tu, _), _) | (_, DataType::Datetime(tu, _)) => *tu,
+        _ => TimeUnit::Milliseconds,
+    };
+    let end_ts = to_local(end, tu)?;
+    let start_ts = to_local(start, tu)?;
+    let units_per_day = units_per_day(tu);
+    let calendar = BusinessCalendar::new(DEFAULT_WEEK_MASK, &[])?;
+    let to_datetime: fn(i64) -> NaiveDateTime = match tu {
+        TimeUnit::Nanoseconds => timestamp_ns_to_datetime,
+        TimeUnit::Microseconds => timestamp_us_to_datetime,
+        TimeUnit::Milliseconds => timestamp_ms_to_datetime,
+    };
+
+    let diff = |e: i64, s: i64| -> PolarsResult<(i64, f64)> {
+        let in_days = |n_days: i64| {
+            let units = n_days * units_per_day;
+            let d = e - s;
+            (d / units, (d % units) as f64 / units as f64)
+        };
+        Ok(match unit {
+            DateDiffUnit::Years => month_periods(to_datetime(s), to_datetime(e), 12)?,
+            DateDiffUnit::Quarters => month_periods(to_datetime(s), to_datetime(e), 3)?,
+            DateDiffUnit::Months => month_periods(to_datetime(s), to_datetime(e), 1)?,
+            DateDiffUnit::Weeks => in_days(7),
+            DateDiffUnit::Days => in_days(1),
+            DateDiffUnit::BusinessDays => {
+                let day = |t: i64| t.div_euclid(units_per_day) as i32;
+                (calendar.count(day(s), day(e)) as i64, 0.0)
+            }
+        })
+    };
+    let apply = |e: Option<i64>, s: Option<i64>| match (e, s) {
+        (Some(e), Some(s)) => diff(e, s).map(Some),
+        _ => Ok(None),
+    };
+
+    let out: Vec<_> = match (end_ts.len(), start_ts.len()) {
+        (1, _) => {
+            let e = end_ts.get(0);
+            (&start_ts)
+                .into_iter()
+                .map(|s| apply(e, s))
+                .collect::<PolarsResult<_>>()?
+        }
+        (_, 1) => {
+            let s = start_ts.get(0);
+            (&end_ts)
+                .into_iter()
+                .map(|e| apply(e, s))
+                .collect::<PolarsResult<_>>()?
+        }
+        (a, b) if a == b => (&end_ts)
+            .into_iter()
+            .zip(&start_ts)
+            .map(|(e, s)| apply(e, s))
+            .collect::<PolarsResult<_>>()?,
+        (a, b) => {
+            polars_bail!(ShapeMismatch: "'end' and 'start' should have the same length, got {} and {}", a, b)
+        }
+    };
+
+    let mut out = if fractional {
+        out.into_iter()
+            .map(|opt_d| opt_d.map(|(whole, fraction)| whole as f64 + fraction))
+            .collect::<Float64Chunked>()
+            .into_series()
+    } else {
+        out.into_iter()
+            .map(|opt_d| opt_d.map(|(whole, _)| whole))
+            .collect::<Int64Chunked>()
+            .into_series()
+    };
+    out.rename(end.name());
+    Ok(out)
+}
+
+/// The age in whole years at `reference` of dates of birth `birth`.
+///
+/// Like [`date_diff`] a birthday on February 29th is reached on February 28th in
+/// common years.
+pub fn age(birth: &Series, reference: &Series) -> PolarsResult<Series> {
+    let mut out = date_diff(reference, birth, DateDiffUnit::Years, false)?;
+    out.rename(birth.name());
+    Ok(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> i32 {
+        let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        let date = chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        date.signed_duration_since(epoch).num_days() as i32
+    }
+
+    fn dates(name: &str, dates: &[i32]) -> Series {
+        Int32Chunked::new(name, dates).into_date().into_series()
+    }
+
+    #[test]
+    fn test_date_diff() -> PolarsResult<()> {
+        let end = dates(
+            "end",
+            &[date(2023, 2, 28), date(2024, 2, 29), date(2023, 1, 15)],
+        );
+        let start = dates("start", &[date(2023, 1, 31)]);
+
+        let out = date_diff(&end, &start, DateDiffUnit::Months, false)?;
+        assert_eq!(Vec::from(out.i64()?), &[Some(1), Some(13), Some(0)]);
+        let out = date_diff(&end, &start, DateDiffUnit::Months, true)?;
+        let out = out.f64()?;
+        assert_eq!(out.get(0), Some(1.0));
+        assert_eq!(out.get(1), Some(13.0));
+        assert!((out.get(2).unwrap() + 16.0 / 31.0).abs() < 1e-12);
+        let out = date_diff(&end, &start, DateDiffUnit::Years, false)?;
+        assert_eq!(Vec::from(out.i64()?), &[Some(0), Some(1), Some(0)]);
+        let out = date_diff(&end, &start, DateDiffUnit::Days, false)?;
+        assert_eq!(Vec::from(out.i64()?), &[Some(28), Some(394), Some(-16)]);
+        let out = date_diff(&end, &start, DateDiffUnit::BusinessDays, false)?;
+        assert_eq!(Vec::from(out.i64()?), &[Some(20), Some(282), Some(-11)]);
+
+        let birth = dates("birth", &[date(2000, 2, 29)]);
+        let reference = dates("reference", &[date(2001, 2, 28), date(2023, 7, 1)]);
+        let out = age(&birth, &reference)?;
+        assert_eq!(Vec::from(out.i64()?), &[Some(1), Some(23)]);
+        Ok(())
+    }
+}
@@ -1,11 +1,18 @@
 use chrono::{Datelike, NaiveDateTime, NaiveTime};
+#[cfg(feature = "timezones")]
+use polars_arrow::export::arrow::temporal_conversions::{
+    timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime,
+};
+#[cfg(feature = "timezones")]
+use polars_arrow::time_zone::Tz;
 use polars_core::chunked_array::temporal::time_to_time64ns;
 use polars_core::prelude::*;
 use polars_core::series::IsSorted;
 
 use crate::prelude::*;
 #[cfg(feature = "timezones")]
-use crate::utils::localize_timestamp;
+use crate::utils::localize_datetime_opt;
+use crate::windows::calendar::temporal_range_localized;
 
 pub fn in_nanoseconds_window(ndt: &NaiveDateTime) -> bool {
     // ~584 year around 1970
@@ -13,6 +20,7 @@ pub fn in_nanoseconds_window(ndt: &NaiveDateTime) -> bool {
 }
 
 #[doc(hidden)]
+#[allow(clippy::too_many_arguments)]
 pub fn date_range_impl(
     name: &str,
     start: i64,
@@ -21,6 +29,8 @@ pub fn date_range_impl(
     closed: ClosedWindow,
     tu: TimeUnit,
     _tz: Option<&TimeZone>,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
 ) -> PolarsResult<DatetimeChunked> {
     if start > stop {
         polars_bail!(ComputeError: "'start' cannot be greater than 'stop'")
@@ -32,11 +42,20 @@ pub fn date_range_impl(
         #[cfg(feature = "timezones")]
         Some(tz) => match tz.parse::<chrono_tz::Tz>() {
             Ok(tz) => {
-                let start = localize_timestamp(start, tu, tz);
-                let stop = localize_timestamp(stop, tu, tz);
+                let start = localize_bound(start, tu, &tz, ambiguous, non_existent, "start")?;
+                let stop = localize_bound(stop, tu, &tz, ambiguous, non_existent, "stop")?;
                 Int64Chunked::new_vec(
                     name,
-                    temporal_range_vec(start?, stop?, every, closed, tu, Some(&tz))?,
+                    temporal_range_localized(
+                        start,
+                        stop,
+                        every,
+                        closed,
+                        tu,
+                        Some(&tz),
+                        ambiguous,
+                        non_existent,
+                    )?,
                 )
                 .into_datetime(tu, _tz.cloned())
             }
@@ -53,7 +72,39 @@ pub fn date_range_impl(
     Ok(out)
 }
 
+/// Localize the `start` or `stop` wall clock time of a range in time zone `tz`.
+#[cfg(feature = "timezones")]
+fn localize_bound(
+    t: i64,
+    tu: TimeUnit,
+    tz: &Tz,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
+    bound: &str,
+) -> PolarsResult<i64> {
+    let (timestamp_to_datetime, datetime_to_timestamp): (
+        fn(i64) -> NaiveDateTime,
+        fn(NaiveDateTime) -> i64,
+    ) = match tu {
+        TimeUnit::Nanoseconds => (timestamp_ns_to_datetime, datetime_to_timestamp_ns),
+        TimeUnit::Microseconds => (timestamp_us_to_datetime, datetime_to_timestamp_us),
+        TimeUnit::Milliseconds => (timestamp_ms_to_datetime, datetime_to_timestamp_ms),
+    };
+    let ndt = timestamp_to_datetime(t);
+    match localize_datetime_opt(ndt, tz, ambiguous, non_existent)? {
+        Some(ndt) => Ok(datetime_to_timestamp(ndt)),
+        None => polars_bail!(
+            ComputeError: "'{}' datetime '{}' is ambiguous or non-existent in time zone '{}' \
+            and cannot be set to null", bound, ndt, tz
+        ),
+    }
+}
+
 /// Create a [`DatetimeChunked`] from a given `start` and `stop` date and a given `every` interval.
+///
+/// In a time zone, `ambiguous` and `non_existent` tell how to localize the datetimes
+/// around daylight saving time transitions. Datetimes that are set to null are left out.
+#[allow(clippy::too_many_arguments)]
 pub fn date_range(
     name: &str,
     start: NaiveDateTime,
@@ -62,13 +113,25 @@ pub fn date_range(
     closed: ClosedWindow,
     tu: TimeUnit,
     tz: Option<TimeZone>,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
 ) -> PolarsResult<DatetimeChunked> {
     let (start, stop) = match tu {
         TimeUnit::Nanoseconds => (start.timestamp_nanos(), stop.timestamp_nanos()),
         TimeUnit::Microseconds => (start.timestamp_micros(), stop.timestamp_micros()),
         TimeUnit::Milliseconds => (start.timestamp_millis(), stop.timestamp_millis()),
     };
-    date_range_impl(name, start, stop, every, closed, tu, tz.as_ref())
+    date_range_impl(
+        name,
+        start,
+        stop,
+        every,
+        closed,
+        tu,
+        tz.as_ref(),
+        ambiguous,
+        non_existent,
+    )
 }
 
 #[doc(hidden)]
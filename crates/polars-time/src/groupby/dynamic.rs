@@ -38,6 +38,10 @@ pub struct DynamicGroupOptions {
     /// the sorted flag, traverse the data to
     /// check sortedness
     pub check_sorted: bool,
+    /// How to deal with window boundaries that are ambiguous in the time zone
+    pub ambiguous: Ambiguous,
+    /// How to deal with window boundaries that do not exist in the time zone
+    pub non_existent: NonExistent,
 }
 
 impl Default for DynamicGroupOptions {
@@ -52,6 +56,8 @@ impl Default for DynamicGroupOptions {
             closed_window: ClosedWindow::Left,
             start_by: Default::default(),
             check_sorted: true,
+            ambiguous: Default::default(),
+            non_existent: Default::default(),
         }
     }
 }
@@ -271,6 +277,10 @@ impl Wrap<&DataFrame> {
         time_type: &DataType,
     ) -> PolarsResult<(Series, Vec<Series>, GroupsProxy)> {
         polars_ensure!(!options.every.negative, ComputeError: "'every' argument must be positive");
+        polars_ensure!(
+            options.ambiguous != Ambiguous::Null && options.non_existent != NonExistent::Null,
+            InvalidOperation: "window boundaries of 'groupby_dynamic' cannot be null; use another 'ambiguous'/'non_existent' strategy"
+        );
         if dt.is_empty() {
             return dt.cast(time_type).map(|s| (s, by, GroupsProxy::default()));
         }
@@ -279,7 +289,8 @@ impl Wrap<&DataFrame> {
         // so we can set this such that downstream code has this info
         dt.set_sorted_flag(IsSorted::Ascending);
 
-        let w = Window::new(options.every, options.period, options.offset);
+        let w = Window::new(options.every, options.period, options.offset)
+            .with_dst_handling(options.ambiguous, options.non_existent);
         let dt = dt.datetime().unwrap();
         let tz = dt.time_zone();
 
@@ -801,6 +812,8 @@ mod test {
             ClosedWindow::Both,
             TimeUnit::Milliseconds,
             None,
+            Ambiguous::Raise,
+            NonExistent::Raise,
         )?
         .into_series();
 
@@ -854,6 +867,8 @@ mod test {
             ClosedWindow::Both,
             TimeUnit::Milliseconds,
             None,
+            Ambiguous::Raise,
+            NonExistent::Raise,
         )?
         .into_series();
         assert_eq!(&upper, &range);
@@ -877,6 +892,8 @@ mod test {
             ClosedWindow::Both,
             TimeUnit::Milliseconds,
             None,
+            Ambiguous::Raise,
+            NonExistent::Raise,
         )?
         .into_series();
         assert_eq!(&upper, &range);
@@ -916,6 +933,8 @@ mod test {
             ClosedWindow::Both,
             TimeUnit::Milliseconds,
             None,
+            Ambiguous::Raise,
+            NonExistent::Raise,
         )?
         .into_series();
 
@@ -1,6 +1,8 @@
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 mod base_utc_offset;
+mod business;
 pub mod chunkedarray;
+mod date_diff;
 mod date_range;
 mod dst_offset;
 mod groupby;
@@ -16,6 +18,8 @@ mod windows;
 
 #[cfg(feature = "timezones")]
 pub use base_utc_offset::*;
+pub use business::*;
+pub use date_diff::*;
 pub use date_range::*;
 #[cfg(feature = "timezones")]
 pub use dst_offset::*;
@@ -23,6 +27,7 @@ pub use dst_offset::*;
 pub use groupby::dynamic::*;
 pub use month_end::*;
 pub use month_start::*;
+pub use polars_arrow::time_zone::{Ambiguous, NonExistent};
 pub use round::*;
 pub use truncate::*;
 pub use upsample::*;
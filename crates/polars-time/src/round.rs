@@ -5,35 +5,66 @@ use polars_core::prelude::*;
 use crate::prelude::*;
 
 pub trait PolarsRound {
-    fn round(&self, every: Duration, offset: Duration, tz: Option<&Tz>) -> PolarsResult<Self>
+    /// Round to the window boundaries given by `every` and `offset`.
+    ///
+    /// `ambiguous` and `non_existent` decide how rounded datetimes that fall on a
+    /// DST transition in `tz` are resolved.
+    fn round(
+        &self,
+        every: Duration,
+        offset: Duration,
+        tz: Option<&Tz>,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Self>
     where
         Self: Sized;
 }
 
 #[cfg(feature = "dtype-datetime")]
 impl PolarsRound for DatetimeChunked {
-    fn round(&self, every: Duration, offset: Duration, tz: Option<&Tz>) -> PolarsResult<Self> {
-        let w = Window::new(every, every, offset);
+    fn round(
+        &self,
+        every: Duration,
+        offset: Duration,
+        tz: Option<&Tz>,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Self> {
+        let w = Window::new(every, every, offset).with_dst_handling(ambiguous, non_existent);
 
         let func = match self.time_unit() {
             TimeUnit::Nanoseconds => Window::round_ns,
             TimeUnit::Microseconds => Window::round_us,
             TimeUnit::Milliseconds => Window::round_ms,
         };
-        Ok(self
-            .try_apply(|t| func(&w, t, tz))?
-            .into_datetime(self.time_unit(), self.time_zone().clone()))
+        let mut out = self
+            .into_iter()
+            .map(|opt_t| match opt_t {
+                Some(t) => func(&w, t, tz),
+                None => Ok(None),
+            })
+            .collect::<PolarsResult<Int64Chunked>>()?;
+        out.rename(self.name());
+        Ok(out.into_datetime(self.time_unit(), self.time_zone().clone()))
     }
 }
 
 #[cfg(feature = "dtype-date")]
 impl PolarsRound for DateChunked {
-    fn round(&self, every: Duration, offset: Duration, _tz: Option<&Tz>) -> PolarsResult<Self> {
+    fn round(
+        &self,
+        every: Duration,
+        offset: Duration,
+        _tz: Option<&Tz>,
+        _ambiguous: Ambiguous,
+        _non_existent: NonExistent,
+    ) -> PolarsResult<Self> {
         let w = Window::new(every, every, offset);
         Ok(self
             .try_apply(|t| {
                 const MSECS_IN_DAY: i64 = MILLISECONDS * SECONDS_IN_DAY;
-                Ok((w.round_ms(MSECS_IN_DAY * t as i64, None)? / MSECS_IN_DAY) as i32)
+                Ok((w.round_ms(MSECS_IN_DAY * t as i64, None)?.unwrap() / MSECS_IN_DAY) as i32)
             })?
             .into_date())
     }
@@ -31,6 +31,8 @@ impl IntoSeriesOps for Series {
             DataType::Float64 => self.f64().unwrap().to_ops(),
             #[cfg(feature = "dtype-categorical")]
             DataType::Categorical(_) => self.categorical().unwrap().to_ops(),
+            #[cfg(feature = "dtype-categorical")]
+            DataType::Enum(_) => self.categorical().unwrap().to_ops(),
             DataType::Boolean => self.bool().unwrap().to_ops(),
             DataType::Utf8 => self.utf8().unwrap().to_ops(),
             #[cfg(feature = "dtype-date")]
@@ -343,3 +345,23 @@ pub trait TemporalMethods: AsSeries {
 }
 
 impl<T: ?Sized + AsSeries> TemporalMethods for T {}
+
+#[cfg(test)]
+#[cfg(all(feature = "rolling_window", feature = "dtype-categorical"))]
+mod test {
+    use polars_core::utils::arrow::array::Utf8Array;
+
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_rolling_enum() -> PolarsResult<()> {
+        let dtype = create_enum_dtype(Utf8Array::<i64>::from_slice(["low", "mid", "high"]))?;
+        let s = Series::new("a", &["high", "low", "mid"]).cast(&dtype)?;
+
+        // rolling aggregations are not defined on enums, but should not panic
+        let out = s.rolling_min(RollingOptionsImpl::default());
+        assert!(matches!(out, Err(PolarsError::InvalidOperation(_))));
+        Ok(())
+    }
+}
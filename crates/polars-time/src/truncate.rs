@@ -14,7 +14,9 @@ pub struct TruncateOptions {
     /// Offset of the window
     pub offset: String,
     /// How to deal with ambiguous datetimes
-    pub use_earliest: Option<bool>,
+    pub ambiguous: Ambiguous,
+    /// How to deal with non-existent datetimes
+    pub non_existent: NonExistent,
 }
 
 pub trait PolarsTruncate {
@@ -28,7 +30,8 @@ impl PolarsTruncate for DatetimeChunked {
     fn truncate(&self, options: &TruncateOptions, tz: Option<&Tz>) -> PolarsResult<Self> {
         let every = Duration::parse(&options.every);
         let offset = Duration::parse(&options.offset);
-        let w = Window::new(every, every, offset);
+        let w = Window::new(every, every, offset)
+            .with_dst_handling(options.ambiguous, options.non_existent);
 
         let func = match self.time_unit() {
             TimeUnit::Nanoseconds => Window::truncate_ns,
@@ -36,9 +39,15 @@ impl PolarsTruncate for DatetimeChunked {
             TimeUnit::Milliseconds => Window::truncate_ms,
         };
 
-        Ok(self
-            .try_apply(|t| func(&w, t, tz, options.use_earliest))?
-            .into_datetime(self.time_unit(), self.time_zone().clone()))
+        let mut out = self
+            .into_iter()
+            .map(|opt_t| match opt_t {
+                Some(t) => func(&w, t, tz),
+                None => Ok(None),
+            })
+            .collect::<PolarsResult<Int64Chunked>>()?;
+        out.rename(self.name());
+        Ok(out.into_datetime(self.time_unit(), self.time_zone().clone()))
     }
 }
 
@@ -51,7 +60,7 @@ impl PolarsTruncate for DateChunked {
         Ok(self
             .try_apply(|t| {
                 const MSECS_IN_DAY: i64 = MILLISECONDS * SECONDS_IN_DAY;
-                Ok((w.truncate_ms(MSECS_IN_DAY * t as i64, None, None)? / MSECS_IN_DAY) as i32)
+                Ok((w.truncate_ms(MSECS_IN_DAY * t as i64, None)?.unwrap() / MSECS_IN_DAY) as i32)
             })?
             .into_date())
     }
@@ -4,11 +4,32 @@ use polars_core::frame::hash_join::JoinArgs;
 use polars_core::prelude::*;
 use polars_core::utils::ensure_sorted_arg;
 use polars_ops::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 #[cfg(feature = "timezones")]
 use crate::utils::unlocalize_timestamp;
 
+/// Marks the rows of the source of an upsample, so that the inserted rows can be told apart.
+const SOURCE_ROW: &str = "__POLARS_UPSAMPLE_SOURCE_ROW";
+
+/// How the rows inserted by an upsample are filled.
+///
+/// The strategy is applied within every `by` group.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum UpsampleFillStrategy {
+    /// Fill with the last value before the inserted row.
+    Forward,
+    /// Fill with the first value after the inserted row.
+    Backward,
+    /// Interpolate linearly in time between the surrounding values.
+    Linear,
+    /// Fill with the surrounding value that is closest in time.
+    Nearest,
+}
+
 pub trait PolarsUpsample {
     /// Upsample a DataFrame at a regular frequency.
     ///
@@ -28,6 +49,7 @@ pub trait PolarsUpsample {
     /// - 1m    (1 minute)
     /// - 1h    (1 hour)
     /// - 1d    (1 calendar day)
+    /// - 1bd   (1 business day, Monday to Friday)
     /// - 1w    (1 calendar week)
     /// - 1mo   (1 calendar month)
     /// - 1q    (1 calendar quarter)
@@ -68,6 +90,7 @@ pub trait PolarsUpsample {
     /// - 1m    (1 minute)
     /// - 1h    (1 hour)
     /// - 1d    (1 calendar day)
+    /// - 1bd   (1 business day, Monday to Friday)
     /// - 1w    (1 calendar week)
     /// - 1mo   (1 calendar month)
     /// - 1q    (1 calendar quarter)
@@ -89,6 +112,33 @@ pub trait PolarsUpsample {
         every: Duration,
         offset: Duration,
     ) -> PolarsResult<DataFrame>;
+
+    /// Upsample a DataFrame at a regular frequency and fill the inserted rows.
+    ///
+    /// The arguments are the same as those of [`PolarsUpsample::upsample`]. The inserted
+    /// rows get the keys of their `by` group and the other columns are filled according
+    /// to `fill_strategy`, without crossing the boundaries of a group.
+    fn upsample_fill<I: IntoVec<String>>(
+        &self,
+        by: I,
+        time_column: &str,
+        every: Duration,
+        offset: Duration,
+        fill_strategy: UpsampleFillStrategy,
+    ) -> PolarsResult<DataFrame>;
+
+    /// Upsample a DataFrame at a regular frequency and fill the inserted rows, maintaining
+    /// the order of the `by` groups.
+    ///
+    /// See [`PolarsUpsample::upsample_fill`].
+    fn upsample_stable_fill<I: IntoVec<String>>(
+        &self,
+        by: I,
+        time_column: &str,
+        every: Duration,
+        offset: Duration,
+        fill_strategy: UpsampleFillStrategy,
+    ) -> PolarsResult<DataFrame>;
 }
 
 impl PolarsUpsample for DataFrame {
@@ -100,7 +150,7 @@ impl PolarsUpsample for DataFrame {
         offset: Duration,
     ) -> PolarsResult<DataFrame> {
         let by = by.into_vec();
-        upsample_impl(self, by, time_column, every, offset, false)
+        upsample_impl(self, by, time_column, every, offset, false, None)
     }
 
     fn upsample_stable<I: IntoVec<String>>(
@@ -111,7 +161,47 @@ impl PolarsUpsample for DataFrame {
         offset: Duration,
     ) -> PolarsResult<DataFrame> {
         let by = by.into_vec();
-        upsample_impl(self, by, time_column, every, offset, true)
+        upsample_impl(self, by, time_column, every, offset, true, None)
+    }
+
+    fn upsample_fill<I: IntoVec<String>>(
+        &self,
+        by: I,
+        time_column: &str,
+        every: Duration,
+        offset: Duration,
+        fill_strategy: UpsampleFillStrategy,
+    ) -> PolarsResult<DataFrame> {
+        let by = by.into_vec();
+        upsample_impl(
+            self,
+            by,
+            time_column,
+            every,
+            offset,
+            false,
+            Some(fill_strategy),
+        )
+    }
+
+    fn upsample_stable_fill<I: IntoVec<String>>(
+        &self,
+        by: I,
+        time_column: &str,
+        every: Duration,
+        offset: Duration,
+        fill_strategy: UpsampleFillStrategy,
+    ) -> PolarsResult<DataFrame> {
+        let by = by.into_vec();
+        upsample_impl(
+            self,
+            by,
+            time_column,
+            every,
+            offset,
+            true,
+            Some(fill_strategy),
+        )
     }
 }
 
@@ -122,6 +212,7 @@ fn upsample_impl(
     every: Duration,
     offset: Duration,
     stable: bool,
+    fill_strategy: Option<UpsampleFillStrategy>,
 ) -> PolarsResult<DataFrame> {
     let s = source.column(index_column)?;
     ensure_sorted_arg(s, "upsample")?;
@@ -131,13 +222,48 @@ fn upsample_impl(
             s.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
         })
         .unwrap();
-        let mut out = upsample_impl(&df, by, index_column, every, offset, stable).unwrap();
+        let mut out = upsample_impl(&df, by, index_column, every, offset, stable, fill_strategy)?;
         out.try_apply(index_column, |s| s.cast(&DataType::Date))
             .unwrap();
         Ok(out)
-    } else if by.is_empty() {
-        let index_column = source.column(index_column)?;
-        upsample_single_impl(source, index_column, every, offset)
+    } else {
+        match fill_strategy {
+            Some(strategy) => {
+                let mut source = source.clone();
+                source.with_column(BooleanChunked::full(SOURCE_ROW, true, source.height()))?;
+                upsample_groups(
+                    &source,
+                    by,
+                    index_column,
+                    every,
+                    offset,
+                    stable,
+                    Some(strategy),
+                )
+            }
+            None => upsample_groups(source, by, index_column, every, offset, stable, None),
+        }
+    }
+}
+
+/// Upsample every `by` group of `source`. If the inserted rows are filled, the rows of the
+/// `source` should be marked with a [`SOURCE_ROW`] column.
+fn upsample_groups(
+    source: &DataFrame,
+    by: Vec<String>,
+    index_column: &str,
+    every: Duration,
+    offset: Duration,
+    stable: bool,
+    fill_strategy: Option<UpsampleFillStrategy>,
+) -> PolarsResult<DataFrame> {
+    if by.is_empty() {
+        let index_col = source.column(index_column)?;
+        let out = upsample_single_impl(source, index_col, every, offset)?;
+        match fill_strategy {
+            Some(strategy) => fill_upsampled(&out, index_column, &by, strategy),
+            None => Ok(out),
+        }
     } else {
         let gb = if stable {
             source.groupby_stable(by)
@@ -146,12 +272,58 @@ fn upsample_impl(
         };
         // don't parallelize this, this may SO on large data.
         gb?.apply(|df| {
-            let index_column = df.column(index_column)?;
-            upsample_single_impl(&df, index_column, every, offset)
+            let index_col = df.column(index_column)?;
+            let out = upsample_single_impl(&df, index_col, every, offset)?;
+            match fill_strategy {
+                Some(strategy) => fill_upsampled(&out, index_column, &by, strategy),
+                None => Ok(out),
+            }
         })
     }
 }
 
+/// Fill the rows that were inserted in the upsampled (group) `df`. The nulls of the
+/// source rows, which are marked by the [`SOURCE_ROW`] column, are kept.
+fn fill_upsampled(
+    df: &DataFrame,
+    index_column: &str,
+    by: &[String],
+    strategy: UpsampleFillStrategy,
+) -> PolarsResult<DataFrame> {
+    let is_source_row = df.column(SOURCE_ROW)?.is_not_null();
+    let df = df.drop(SOURCE_ROW)?;
+    let time = df.column(index_column)?;
+    let columns = df
+        .get_columns()
+        .iter()
+        .map(|s| {
+            let filled = if s.name() == index_column {
+                return Ok(s.clone());
+            } else if by.iter().any(|name| name == s.name()) {
+                // a group has a single key, which the inserted rows get as well
+                let is_valid = s.is_not_null();
+                match (&is_valid).into_iter().position(|v| v == Some(true)) {
+                    Some(idx) => s.new_from_index(idx, s.len()),
+                    None => return Ok(s.clone()),
+                }
+            } else {
+                match strategy {
+                    UpsampleFillStrategy::Forward => s.fill_null(FillNullStrategy::Forward(None)),
+                    UpsampleFillStrategy::Backward => s.fill_null(FillNullStrategy::Backward(None)),
+                    UpsampleFillStrategy::Linear => {
+                        interpolate_by(s, time, InterpolationMethod::Time)
+                    }
+                    UpsampleFillStrategy::Nearest => {
+                        interpolate_by(s, time, InterpolationMethod::Nearest)
+                    }
+                }?
+            };
+            s.zip_with(&is_source_row, &filled)
+        })
+        .collect::<PolarsResult<Vec<_>>>()?;
+    Ok(DataFrame::new_no_checks(columns))
+}
+
 fn upsample_single_impl(
     source: &DataFrame,
     index_column: &Series,
@@ -190,6 +362,8 @@ fn upsample_single_impl(
                         ClosedWindow::Both,
                         *tu,
                         tz.as_ref(),
+                        Ambiguous::Raise,
+                        NonExistent::Raise,
                     )?
                     .into_series()
                     .into_frame();
@@ -210,3 +384,99 @@ fn upsample_single_impl(
         ),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_upsample_fill() -> PolarsResult<()> {
+        // 2023-01-01 is day 19358, the groups follow each other in time
+        let time = Int32Chunked::new("time", &[19358, 19361, 19362, 19364])
+            .into_series()
+            .cast(&DataType::Date)?
+            .sort(false);
+        let df = DataFrame::new(vec![
+            Series::new("g", &["a", "a", "b", "b"]),
+            time,
+            Series::new("v", &[1.0, 4.0, 10.0, 30.0]),
+        ])?;
+
+        let out = df.upsample_stable_fill(
+            ["g"],
+            "time",
+            Duration::parse("1d"),
+            Duration::parse("0d"),
+            UpsampleFillStrategy::Linear,
+        )?;
+        assert_eq!(
+            Vec::from(out.column("g")?.utf8()?),
+            &[
+                Some("a"),
+                Some("a"),
+                Some("a"),
+                Some("a"),
+                Some("b"),
+                Some("b"),
+                Some("b")
+            ]
+        );
+        assert_eq!(
+            Vec::from(out.column("v")?.f64()?),
+            &[
+                Some(1.0),
+                Some(2.0),
+                Some(3.0),
+                Some(4.0),
+                Some(10.0),
+                Some(20.0),
+                Some(30.0)
+            ]
+        );
+
+        let out = df.upsample_stable_fill(
+            ["g"],
+            "time",
+            Duration::parse("1d"),
+            Duration::parse("0d"),
+            UpsampleFillStrategy::Forward,
+        )?;
+        assert_eq!(
+            Vec::from(out.column("v")?.f64()?),
+            &[
+                Some(1.0),
+                Some(1.0),
+                Some(1.0),
+                Some(4.0),
+                Some(10.0),
+                Some(10.0),
+                Some(30.0)
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_upsample_fill_keeps_source_nulls() -> PolarsResult<()> {
+        let time = Int32Chunked::new("time", &[19358, 19359, 19361])
+            .into_series()
+            .cast(&DataType::Date)?
+            .sort(false);
+        let df = DataFrame::new(vec![time, Series::new("v", &[Some(1.0), None, Some(4.0)])])?;
+
+        // only the inserted row is filled
+        let out = df.upsample_fill(
+            Vec::<String>::new(),
+            "time",
+            Duration::parse("1d"),
+            Duration::parse("0d"),
+            UpsampleFillStrategy::Linear,
+        )?;
+        assert_eq!(out.get_column_names(), &["time", "v"]);
+        assert_eq!(
+            Vec::from(out.column("v")?.f64()?),
+            &[Some(1.0), None, Some(3.0), Some(4.0)]
+        );
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use polars_arrow::time_zone::Tz;
+use polars_arrow::time_zone::{Ambiguous, NonExistent, Tz};
 use polars_core::prelude::*;
 
 use crate::prelude::*;
@@ -44,52 +44,70 @@ pub fn temporal_range(
     tu: TimeUnit,
     tz: Option<&Tz>,
 ) -> PolarsResult<Vec<i64>> {
-    let size: usize;
-    let offset_fn: fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>;
+    temporal_range_localized(
+        start,
+        stop,
+        every,
+        closed,
+        tu,
+        tz,
+        Ambiguous::Raise,
+        NonExistent::Raise,
+    )
+}
 
-    match tu {
-        TimeUnit::Nanoseconds => {
-            size = ((stop - start) / every.duration_ns() + 1) as usize;
-            offset_fn = Duration::add_ns;
-        }
-        TimeUnit::Microseconds => {
-            size = ((stop - start) / every.duration_us() + 1) as usize;
-            offset_fn = Duration::add_us;
-        }
-        TimeUnit::Milliseconds => {
-            size = ((stop - start) / every.duration_ms() + 1) as usize;
-            offset_fn = Duration::add_ms;
-        }
-    }
+/// vector of i64 representing temporal values
+///
+/// Values that are ambiguous or non-existent in the time zone `tz` are localized according
+/// to `ambiguous` and `non_existent`. Values that should be set to null are left out.
+#[allow(clippy::too_many_arguments)]
+pub fn temporal_range_localized(
+    start: i64,
+    stop: i64,
+    every: Duration,
+    closed: ClosedWindow,
+    tu: TimeUnit,
+    tz: Option<&Tz>,
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
+) -> PolarsResult<Vec<i64>> {
+    let size = match tu {
+        TimeUnit::Nanoseconds => (stop - start) / every.duration_ns() + 1,
+        TimeUnit::Microseconds => (stop - start) / every.duration_us() + 1,
+        TimeUnit::Milliseconds => (stop - start) / every.duration_ms() + 1,
+    } as usize;
     let mut ts = Vec::with_capacity(size);
 
-    let mut t = start;
-    match closed {
-        ClosedWindow::Both => {
-            while t <= stop {
-                ts.push(t);
-                t = offset_fn(&every, t, tz)?
-            }
-        }
-        ClosedWindow::Left => {
-            while t < stop {
-                ts.push(t);
-                t = offset_fn(&every, t, tz)?
-            }
-        }
-        ClosedWindow::Right => {
-            t = offset_fn(&every, t, tz)?;
-            while t <= stop {
-                ts.push(t);
-                t = offset_fn(&every, t, tz)?
-            }
-        }
-        ClosedWindow::None => {
-            t = offset_fn(&every, t, tz)?;
-            while t < stop {
-                ts.push(t);
-                t = offset_fn(&every, t, tz)?
+    // a business-day range starts at the first business day
+    let first = every.roll_forward_business_day(start, tu, tz)?;
+    let include_stop = matches!(closed, ClosedWindow::Both | ClosedWindow::Right);
+    // ranges that are open on the left leave out a value at the start
+    let skip_first = matches!(closed, ClosedWindow::Right | ClosedWindow::None) && first == start;
+    // values are stepped from the last value that did not need shifting out of a daylight
+    // saving time gap, so that a shifted value doesn't shift the values after it
+    let mut base = first;
+    // the number of steps from `base` to the next value
+    let mut n = 0;
+    loop {
+        let t = if n == 0 {
+            (!skip_first).then_some(first)
+        } else {
+            let step = every * n;
+            match step.add_localized(base, tu, tz, ambiguous, NonExistent::Null)? {
+                Some(t) => {
+                    base = t;
+                    n = 0;
+                    Some(t)
+                }
+                // non-existent or ambiguous and set to null
+                None => step.add_localized(base, tu, tz, ambiguous, non_existent)?,
             }
+        };
+        n += 1;
+        match t {
+            Some(t) if t < stop || (t == stop && include_stop) => ts.push(t),
+            Some(_) => break,
+            None => continue,
         }
     }
     debug_assert!(size >= ts.len());
@@ -6,11 +6,11 @@ use polars_arrow::error::polars_err;
 use polars_arrow::export::arrow::temporal_conversions::{
     timestamp_ms_to_datetime, timestamp_ns_to_datetime, timestamp_us_to_datetime, MILLISECONDS,
 };
-use polars_arrow::time_zone::Tz;
+use polars_arrow::time_zone::{Ambiguous, NonExistent, Tz};
 use polars_core::export::arrow::temporal_conversions::MICROSECONDS;
 use polars_core::prelude::{
     datetime_to_timestamp_ms, datetime_to_timestamp_ns, datetime_to_timestamp_us, polars_bail,
-    PolarsResult,
+    polars_ensure, PolarsResult, TimeUnit,
 };
 use polars_core::utils::arrow::temporal_conversions::NANOSECONDS;
 #[cfg(feature = "serde")]
@@ -19,10 +19,13 @@ use serde::{Deserialize, Serialize};
 use super::calendar::{
     NS_DAY, NS_HOUR, NS_MICROSECOND, NS_MILLISECOND, NS_MINUTE, NS_SECOND, NS_WEEK,
 };
+use crate::business::{roll_forward_weekday, shift_weekdays, truncate_weekdays};
 #[cfg(feature = "timezones")]
-use crate::utils::{localize_datetime, unlocalize_datetime};
+use crate::utils::{localize_datetime, localize_datetime_opt, unlocalize_datetime};
 use crate::windows::calendar::{is_leap_year, last_day_of_month};
 
+const EPOCH_DAYS_FROM_CE: i32 = 719_163;
+
 #[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Duration {
@@ -30,8 +33,10 @@ pub struct Duration {
     months: i64,
     // the number of weeks for the duration
     weeks: i64,
-    // the number of nanoseconds for the duration
+    // the number of days for the duration
     days: i64,
+    // the number of business days (Monday to Friday) for the duration
+    business_days: i64,
     // the number of nanoseconds for the duration
     nsecs: i64,
     // indicates if the duration is negative
@@ -62,6 +67,7 @@ impl Duration {
             months: 0,
             weeks: 0,
             days: 0,
+            business_days: 0,
             nsecs: fixed_slots.abs(),
             negative: fixed_slots < 0,
             parsed_int: true,
@@ -90,6 +96,7 @@ impl Duration {
     /// * `m`:  minute
     /// * `h`:  hour
     /// * `d`:  day
+    /// * `bd`: business day (Monday to Friday)
     /// * `w`:  week
     /// * `mo`: calendar month
     /// * `q`: calendar quarter
@@ -105,6 +112,9 @@ impl Duration {
     /// Similarly for "calendar week", "calendar month", "calendar quarter",
     /// and "calendar year".
     ///
+    /// Adding business days to a date in the weekend first moves it to the adjacent
+    /// business day in the direction of the shift, which counts as the first step.
+    ///
     /// # Panics
     /// If the given str is invalid for any reason.
     pub fn parse(duration: &str) -> Self {
@@ -119,6 +129,7 @@ impl Duration {
         let mut nsecs = 0;
         let mut weeks = 0;
         let mut days = 0;
+        let mut business_days = 0;
         let mut months = 0;
         let negative = duration.starts_with('-');
         let (saturating, mut iter) = match duration.ends_with("_saturating") {
@@ -173,6 +184,7 @@ impl Duration {
                     "m" => nsecs += n * NS_MINUTE,
                     "h" => nsecs += n * NS_HOUR,
                     "d" => days += n,
+                    "bd" => business_days += n,
                     "w" => weeks += n,
                     "mo" => {
                         months += n
@@ -184,7 +196,7 @@ impl Duration {
                         nsecs += n;
                         parsed_int = true;
                     }
-                    unit => panic!("unit: '{unit}' not supported. Available units are: 'ns', 'us', 'ms', 's', 'm', 'h', 'd', 'w', 'q', 'mo', 'y', 'i'"),
+                    unit => panic!("unit: '{unit}' not supported. Available units are: 'ns', 'us', 'ms', 's', 'm', 'h', 'd', 'bd', 'w', 'q', 'mo', 'y', 'i'"),
                 }
                 unit.clear();
             }
@@ -192,6 +204,7 @@ impl Duration {
         Duration {
             nsecs: nsecs.abs(),
             days: days.abs(),
+            business_days: business_days.abs(),
             weeks: weeks.abs(),
             months: months.abs(),
             negative,
@@ -254,12 +267,13 @@ impl Duration {
     }
 
     /// Creates a [`Duration`] that represents a fixed number of nanoseconds.
-    pub(crate) fn from_nsecs(v: i64) -> Self {
+    pub fn from_nsecs(v: i64) -> Self {
         let (negative, nsecs) = Self::to_positive(v);
         Self {
             months: 0,
             weeks: 0,
             days: 0,
+            business_days: 0,
             nsecs,
             negative,
             parsed_int: false,
@@ -268,12 +282,13 @@ impl Duration {
     }
 
     /// Creates a [`Duration`] that represents a fixed number of months.
-    pub(crate) fn from_months(v: i64) -> Self {
+    pub fn from_months(v: i64) -> Self {
         let (negative, months) = Self::to_positive(v);
         Self {
             months,
             weeks: 0,
             days: 0,
+            business_days: 0,
             nsecs: 0,
             negative,
             parsed_int: false,
@@ -282,12 +297,13 @@ impl Duration {
     }
 
     /// Creates a [`Duration`] that represents a fixed number of weeks.
-    pub(crate) fn from_weeks(v: i64) -> Self {
+    pub fn from_weeks(v: i64) -> Self {
         let (negative, weeks) = Self::to_positive(v);
         Self {
             months: 0,
             weeks,
             days: 0,
+            business_days: 0,
             nsecs: 0,
             negative,
             parsed_int: false,
@@ -296,12 +312,13 @@ impl Duration {
     }
 
     /// Creates a [`Duration`] that represents a fixed number of days.
-    pub(crate) fn from_days(v: i64) -> Self {
+    pub fn from_days(v: i64) -> Self {
         let (negative, days) = Self::to_positive(v);
         Self {
             months: 0,
             weeks: 0,
             days,
+            business_days: 0,
             nsecs: 0,
             negative,
             parsed_int: false,
@@ -309,13 +326,78 @@ impl Duration {
         }
     }
 
+    /// Creates a [`Duration`] that represents a fixed number of quarters.
+    pub fn from_quarters(v: i64) -> Self {
+        Self::from_months(v * 3)
+    }
+
+    /// Creates a [`Duration`] that represents a fixed number of business days.
+    pub fn from_business_days(v: i64) -> Self {
+        let (negative, business_days) = Self::to_positive(v);
+        Self {
+            months: 0,
+            weeks: 0,
+            days: 0,
+            business_days,
+            nsecs: 0,
+            negative,
+            parsed_int: false,
+            saturating: false,
+        }
+    }
+
+    /// Creates a [`Duration`] from its components, the programmatic equivalent of
+    /// [`Duration::parse`].
+    ///
+    /// A `Duration` has a single sign, so the non-zero components should either be
+    /// all positive or all negative.
+    pub fn from_parts(
+        months: i64,
+        weeks: i64,
+        days: i64,
+        business_days: i64,
+        nsecs: i64,
+    ) -> PolarsResult<Self> {
+        let parts = [months, weeks, days, business_days, nsecs];
+        let negative = parts.iter().any(|v| *v < 0);
+        polars_ensure!(
+            !negative || parts.iter().all(|v| *v <= 0),
+            ComputeError: "the components of a duration should all have the same sign"
+        );
+        Ok(Self {
+            months: months.abs(),
+            weeks: weeks.abs(),
+            days: days.abs(),
+            business_days: business_days.abs(),
+            nsecs: nsecs.abs(),
+            negative,
+            parsed_int: false,
+            saturating: false,
+        })
+    }
+
+    /// Set whether offsets to a non-existent date (e.g. 2022-02-29) saturate to the
+    /// end of the month instead of erroring.
+    pub fn with_saturating(mut self, saturating: bool) -> Self {
+        self.saturating = saturating;
+        self
+    }
+
     /// `true` if zero duration.
     pub fn is_zero(&self) -> bool {
-        self.months == 0 && self.weeks == 0 && self.days == 0 && self.nsecs == 0
+        self.months == 0
+            && self.weeks == 0
+            && self.days == 0
+            && self.business_days == 0
+            && self.nsecs == 0
     }
 
     pub fn months_only(&self) -> bool {
-        self.months != 0 && self.weeks == 0 && self.days == 0 && self.nsecs == 0
+        self.months != 0
+            && self.weeks == 0
+            && self.days == 0
+            && self.business_days == 0
+            && self.nsecs == 0
     }
 
     pub fn months(&self) -> i64 {
@@ -323,7 +405,11 @@ impl Duration {
     }
 
     pub fn weeks_only(&self) -> bool {
-        self.months == 0 && self.weeks != 0 && self.days == 0 && self.nsecs == 0
+        self.months == 0
+            && self.weeks != 0
+            && self.days == 0
+            && self.business_days == 0
+            && self.nsecs == 0
     }
 
     pub fn weeks(&self) -> i64 {
@@ -331,15 +417,31 @@ impl Duration {
     }
 
     pub fn days_only(&self) -> bool {
-        self.months == 0 && self.weeks == 0 && self.days != 0 && self.nsecs == 0
+        self.months == 0
+            && self.weeks == 0
+            && self.days != 0
+            && self.business_days == 0
+            && self.nsecs == 0
     }
 
     pub fn days(&self) -> i64 {
         self.days
     }
 
+    pub fn business_days_only(&self) -> bool {
+        self.months == 0
+            && self.weeks == 0
+            && self.days == 0
+            && self.business_days != 0
+            && self.nsecs == 0
+    }
+
+    pub fn business_days(&self) -> i64 {
+        self.business_days
+    }
+
     pub fn is_constant_duration(&self) -> bool {
-        self.months == 0 && self.weeks == 0 && self.days == 0
+        self.months == 0 && self.weeks == 0 && self.days == 0 && self.business_days == 0
     }
 
     /// Returns the nanoseconds from the `Duration` without the weeks or months part.
@@ -348,28 +450,30 @@ impl Duration {
     }
 
     /// Estimated duration of the window duration. Not a very good one if months != 0.
+    /// Business days are estimated as a single day.
     #[doc(hidden)]
     pub const fn duration_ns(&self) -> i64 {
         self.months * 28 * 24 * 3600 * NANOSECONDS
             + self.weeks * NS_WEEK
-            + self.days * NS_DAY
+            + (self.days + self.business_days) * NS_DAY
             + self.nsecs
     }
 
     #[doc(hidden)]
     pub const fn duration_us(&self) -> i64 {
         self.months * 28 * 24 * 3600 * MICROSECONDS
-            + (self.weeks * NS_WEEK + self.nsecs + self.days * NS_DAY) / 1000
+            + (self.weeks * NS_WEEK + self.nsecs + (self.days + self.business_days) * NS_DAY) / 1000
     }
 
     #[doc(hidden)]
     pub const fn duration_ms(&self) -> i64 {
         self.months * 28 * 24 * 3600 * MILLISECONDS
-            + (self.weeks * NS_WEEK + self.nsecs + self.days * NS_DAY) / 1_000_000
+            + (self.weeks * NS_WEEK + self.nsecs + (self.days + self.business_days) * NS_DAY)
+                / 1_000_000
     }
 
     #[doc(hidden)]
-    fn add_month(
+    pub(crate) fn add_month(
         ts: NaiveDateTime,
         n_months: i64,
         negative: bool,
@@ -431,6 +535,7 @@ impl Duration {
     }
 
     #[inline]
+    #[allow(clippy::too_many_arguments)]
     pub fn truncate_impl<F, G, J>(
         &self,
         t: i64,
@@ -438,13 +543,40 @@ impl Duration {
         nsecs_to_unit: F,
         timestamp_to_datetime: G,
         datetime_to_timestamp: J,
-        _use_earliest: Option<bool>,
-    ) -> PolarsResult<i64>
+        _ambiguous: Ambiguous,
+        _non_existent: NonExistent,
+    ) -> PolarsResult<Option<i64>>
     where
         F: Fn(i64) -> i64,
         G: Fn(i64) -> NaiveDateTime,
         J: Fn(NaiveDateTime) -> i64,
     {
+        // the truncated local datetime may be ambiguous or non-existent
+        let localize = |ndt: NaiveDateTime| -> PolarsResult<Option<i64>> {
+            match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => Ok(localize_datetime_opt(ndt, tz, _ambiguous, _non_existent)?
+                    .map(&datetime_to_timestamp)),
+                _ => Ok(Some(datetime_to_timestamp(ndt))),
+            }
+        };
+
+        // truncate by business days
+        if self.business_days != 0 {
+            polars_ensure!(
+                self.months == 0 && self.weeks == 0 && self.days == 0 && self.nsecs == 0,
+                ComputeError: "duration may not mix business days with other units"
+            );
+            let ts = match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => unlocalize_datetime(timestamp_to_datetime(t), tz),
+                _ => timestamp_to_datetime(t),
+            };
+            let dt = map_date(ts, |date| truncate_weekdays(date, self.business_days))?
+                .date()
+                .and_time(NaiveTime::default());
+            return localize(dt);
+        }
         match (self.months, self.weeks, self.days, self.nsecs) {
             (0, 0, 0, 0) => polars_bail!(ComputeError: "duration cannot be zero"),
             // truncate by ns/us/ms
@@ -463,12 +595,8 @@ impl Duration {
                 }
                 match tz {
                     #[cfg(feature = "timezones")]
-                    Some(tz) => Ok(datetime_to_timestamp(localize_datetime(
-                        timestamp_to_datetime(t - remainder),
-                        tz,
-                        _use_earliest,
-                    )?)),
-                    _ => Ok(t - remainder),
+                    Some(_) => localize(timestamp_to_datetime(t - remainder)),
+                    _ => Ok(Some(t - remainder)),
                 }
             }
             // truncate by weeks
@@ -481,17 +609,7 @@ impl Duration {
                 let week_timestamp = dt.week(Weekday::Mon);
                 let first_day_of_week =
                     week_timestamp.first_day() - chrono::Duration::weeks(self.weeks - 1);
-                match tz {
-                    #[cfg(feature = "timezones")]
-                    Some(tz) => Ok(datetime_to_timestamp(localize_datetime(
-                        first_day_of_week.and_time(NaiveTime::default()),
-                        tz,
-                        _use_earliest,
-                    )?)),
-                    _ => Ok(datetime_to_timestamp(
-                        first_day_of_week.and_time(NaiveTime::default()),
-                    )),
-                }
+                localize(first_day_of_week.and_time(NaiveTime::default()))
             }
             // truncate by days
             (0, 0, _, 0) => {
@@ -509,12 +627,8 @@ impl Duration {
                 }
                 match tz {
                     #[cfg(feature = "timezones")]
-                    Some(tz) => Ok(datetime_to_timestamp(localize_datetime(
-                        timestamp_to_datetime(t - remainder),
-                        tz,
-                        _use_earliest,
-                    )?)),
-                    _ => Ok(t - remainder),
+                    Some(_) => localize(timestamp_to_datetime(t - remainder)),
+                    _ => Ok(Some(t - remainder)),
                 }
             }
             // truncate by months
@@ -538,15 +652,7 @@ impl Duration {
                 let dt = new_datetime(year, month, 1, 0, 0, 0, 0).ok_or(polars_err!(
                     ComputeError: format!("date '{}-{}-1' does not exist", year, month)
                 ))?;
-                match tz {
-                    #[cfg(feature = "timezones")]
-                    Some(tz) => Ok(datetime_to_timestamp(localize_datetime(
-                        dt,
-                        tz,
-                        _use_earliest,
-                    )?)),
-                    _ => Ok(datetime_to_timestamp(dt)),
-                }
+                localize(dt)
             }
             _ => {
                 polars_bail!(ComputeError: "duration may not mix month, weeks and nanosecond units")
@@ -554,60 +660,70 @@ impl Duration {
         }
     }
 
-    // Truncate the given ns timestamp by the window boundary.
+    /// Truncate the given ns timestamp by the window boundary.
+    ///
+    /// Returns `None` if the truncated datetime is ambiguous or non-existent in `tz`
+    /// and should be set to null.
     #[inline]
     pub fn truncate_ns(
         &self,
         t: i64,
         tz: Option<&Tz>,
-        use_earliest: Option<bool>,
-    ) -> PolarsResult<i64> {
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Option<i64>> {
         self.truncate_impl(
             t,
             tz,
             |nsecs| nsecs,
             timestamp_ns_to_datetime,
             datetime_to_timestamp_ns,
-            use_earliest,
+            ambiguous,
+            non_existent,
         )
     }
 
-    // Truncate the given ns timestamp by the window boundary.
+    /// Truncate the given us timestamp by the window boundary.
     #[inline]
     pub fn truncate_us(
         &self,
         t: i64,
         tz: Option<&Tz>,
-        use_earliest: Option<bool>,
-    ) -> PolarsResult<i64> {
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Option<i64>> {
         self.truncate_impl(
             t,
             tz,
             |nsecs| nsecs / 1000,
             timestamp_us_to_datetime,
             datetime_to_timestamp_us,
-            use_earliest,
+            ambiguous,
+            non_existent,
         )
     }
 
-    // Truncate the given ms timestamp by the window boundary.
+    /// Truncate the given ms timestamp by the window boundary.
     #[inline]
     pub fn truncate_ms(
         &self,
         t: i64,
         tz: Option<&Tz>,
-        use_earliest: Option<bool>,
-    ) -> PolarsResult<i64> {
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Option<i64>> {
         self.truncate_impl(
             t,
             tz,
             |nsecs| nsecs / 1_000_000,
             timestamp_ms_to_datetime,
             datetime_to_timestamp_ms,
-            use_earliest,
+            ambiguous,
+            non_existent,
         )
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn add_impl_month_week_or_day<F, G, J>(
         &self,
         t: i64,
@@ -615,7 +731,9 @@ impl Duration {
         nsecs_to_unit: F,
         timestamp_to_datetime: G,
         datetime_to_timestamp: J,
-    ) -> PolarsResult<i64>
+        _ambiguous: Ambiguous,
+        _non_existent: NonExistent,
+    ) -> PolarsResult<Option<i64>>
     where
         F: Fn(i64) -> i64,
         G: Fn(i64) -> NaiveDateTime,
@@ -624,6 +742,12 @@ impl Duration {
         let d = self;
         let mut new_t = t;
 
+        #[cfg(feature = "timezones")]
+        let localize = |ndt: NaiveDateTime, tz: &Tz| -> PolarsResult<Option<i64>> {
+            Ok(localize_datetime_opt(ndt, tz, _ambiguous, _non_existent)?
+                .map(&datetime_to_timestamp))
+        };
+
         if d.months > 0 {
             let ts = match tz {
                 #[cfg(feature = "timezones")]
@@ -633,7 +757,10 @@ impl Duration {
             let dt = Self::add_month(ts, d.months, d.negative, d.saturating)?;
             new_t = match tz {
                 #[cfg(feature = "timezones")]
-                Some(tz) => datetime_to_timestamp(localize_datetime(dt, tz, None)?),
+                Some(tz) => match localize(dt, tz)? {
+                    Some(t) => t,
+                    None => return Ok(None),
+                },
                 _ => datetime_to_timestamp(dt),
             };
         }
@@ -646,11 +773,10 @@ impl Duration {
                     new_t =
                         datetime_to_timestamp(unlocalize_datetime(timestamp_to_datetime(t), tz));
                     new_t += if d.negative { -t_weeks } else { t_weeks };
-                    new_t = datetime_to_timestamp(localize_datetime(
-                        timestamp_to_datetime(new_t),
-                        tz,
-                        None,
-                    )?);
+                    new_t = match localize(timestamp_to_datetime(new_t), tz)? {
+                        Some(t) => t,
+                        None => return Ok(None),
+                    };
                 }
                 _ => new_t += if d.negative { -t_weeks } else { t_weeks },
             };
@@ -664,56 +790,154 @@ impl Duration {
                     new_t =
                         datetime_to_timestamp(unlocalize_datetime(timestamp_to_datetime(t), tz));
                     new_t += if d.negative { -t_days } else { t_days };
-                    new_t = datetime_to_timestamp(localize_datetime(
-                        timestamp_to_datetime(new_t),
-                        tz,
-                        None,
-                    )?);
+                    new_t = match localize(timestamp_to_datetime(new_t), tz)? {
+                        Some(t) => t,
+                        None => return Ok(None),
+                    };
                 }
                 _ => new_t += if d.negative { -t_days } else { t_days },
             };
         }
 
-        Ok(new_t)
+        if d.business_days > 0 {
+            let ts = match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => unlocalize_datetime(timestamp_to_datetime(new_t), tz),
+                _ => timestamp_to_datetime(new_t),
+            };
+            let n = if d.negative {
+                -d.business_days
+            } else {
+                d.business_days
+            };
+            let dt = map_date(ts, |date| shift_weekdays(date, n))?;
+            new_t = match tz {
+                #[cfg(feature = "timezones")]
+                Some(tz) => match localize(dt, tz)? {
+                    Some(t) => t,
+                    None => return Ok(None),
+                },
+                _ => datetime_to_timestamp(dt),
+            };
+        }
+
+        Ok(Some(new_t))
     }
 
-    pub fn add_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        let d = self;
+    /// Roll `t` forward to the next business day if this duration steps by business
+    /// days and `t` falls in a weekend. The time of day is preserved.
+    pub(crate) fn roll_forward_business_day(
+        &self,
+        t: i64,
+        tu: TimeUnit,
+        tz: Option<&Tz>,
+    ) -> PolarsResult<i64> {
+        if self.business_days == 0 {
+            return Ok(t);
+        }
+        let (timestamp_to_datetime, datetime_to_timestamp): (
+            fn(i64) -> NaiveDateTime,
+            fn(NaiveDateTime) -> i64,
+        ) = match tu {
+            TimeUnit::Nanoseconds => (timestamp_ns_to_datetime, datetime_to_timestamp_ns),
+            TimeUnit::Microseconds => (timestamp_us_to_datetime, datetime_to_timestamp_us),
+            TimeUnit::Milliseconds => (timestamp_ms_to_datetime, datetime_to_timestamp_ms),
+        };
+        let ts = match tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) => unlocalize_datetime(timestamp_to_datetime(t), tz),
+            _ => timestamp_to_datetime(t),
+        };
+        let dt = map_date(ts, roll_forward_weekday)?;
+        match tz {
+            #[cfg(feature = "timezones")]
+            Some(tz) => Ok(datetime_to_timestamp(localize_datetime(dt, tz)?)),
+            _ => Ok(datetime_to_timestamp(dt)),
+        }
+    }
+
+    /// Add this duration to the timestamp `t` of time unit `tu`.
+    ///
+    /// Calendar units are added to the local time in `tz`. Returns `None` if the resulting
+    /// local datetime is ambiguous or non-existent in `tz` and should be set to null.
+    pub fn add_localized(
+        &self,
+        t: i64,
+        tu: TimeUnit,
+        tz: Option<&Tz>,
+        ambiguous: Ambiguous,
+        non_existent: NonExistent,
+    ) -> PolarsResult<Option<i64>> {
+        let (nsecs_to_unit, timestamp_to_datetime, datetime_to_timestamp): (
+            fn(i64) -> i64,
+            fn(i64) -> NaiveDateTime,
+            fn(NaiveDateTime) -> i64,
+        ) = match tu {
+            TimeUnit::Nanoseconds => (
+                |nsecs| nsecs,
+                timestamp_ns_to_datetime,
+                datetime_to_timestamp_ns,
+            ),
+            TimeUnit::Microseconds => (
+                |nsecs| nsecs / 1_000,
+                timestamp_us_to_datetime,
+                datetime_to_timestamp_us,
+            ),
+            TimeUnit::Milliseconds => (
+                |nsecs| nsecs / 1_000_000,
+                timestamp_ms_to_datetime,
+                datetime_to_timestamp_ms,
+            ),
+        };
         let new_t = self.add_impl_month_week_or_day(
             t,
             tz,
-            |nsecs| nsecs,
-            timestamp_ns_to_datetime,
-            datetime_to_timestamp_ns,
-        );
-        let nsecs = if d.negative { -d.nsecs } else { d.nsecs };
-        Ok(new_t? + nsecs)
+            nsecs_to_unit,
+            timestamp_to_datetime,
+            datetime_to_timestamp,
+            ambiguous,
+            non_existent,
+        )?;
+        let nsecs = if self.negative {
+            -self.nsecs
+        } else {
+            self.nsecs
+        };
+        Ok(new_t.map(|t| t + nsecs_to_unit(nsecs)))
+    }
+
+    pub fn add_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+        // raising never sets the result to null
+        self.add_localized(
+            t,
+            TimeUnit::Nanoseconds,
+            tz,
+            Ambiguous::Raise,
+            NonExistent::Raise,
+        )
+        .map(Option::unwrap)
     }
 
     pub fn add_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        let d = self;
-        let new_t = self.add_impl_month_week_or_day(
+        self.add_localized(
             t,
+            TimeUnit::Microseconds,
             tz,
-            |nsecs| nsecs / 1000,
-            timestamp_us_to_datetime,
-            datetime_to_timestamp_us,
-        );
-        let nsecs = if d.negative { -d.nsecs } else { d.nsecs };
-        Ok(new_t? + nsecs / 1_000)
+            Ambiguous::Raise,
+            NonExistent::Raise,
+        )
+        .map(Option::unwrap)
     }
 
     pub fn add_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        let d = self;
-        let new_t = self.add_impl_month_week_or_day(
+        self.add_localized(
             t,
+            TimeUnit::Milliseconds,
             tz,
-            |nsecs| nsecs / 1_000_000,
-            timestamp_ms_to_datetime,
-            datetime_to_timestamp_ms,
-        );
-        let nsecs = if d.negative { -d.nsecs } else { d.nsecs };
-        Ok(new_t? + nsecs / 1_000_000)
+            Ambiguous::Raise,
+            NonExistent::Raise,
+        )
+        .map(Option::unwrap)
     }
 }
 
@@ -728,11 +952,23 @@ impl Mul<i64> for Duration {
         self.months *= rhs;
         self.weeks *= rhs;
         self.days *= rhs;
+        self.business_days *= rhs;
         self.nsecs *= rhs;
         self
     }
 }
 
+/// Replace the date of `ndt` by applying `f` to its number of days since the unix epoch.
+fn map_date<F>(ndt: NaiveDateTime, f: F) -> PolarsResult<NaiveDateTime>
+where
+    F: FnOnce(i32) -> PolarsResult<i32>,
+{
+    let date = f(ndt.date().num_days_from_ce() - EPOCH_DAYS_FROM_CE)?;
+    NaiveDate::from_num_days_from_ce_opt(date + EPOCH_DAYS_FROM_CE)
+        .map(|date| date.and_time(ndt.time()))
+        .ok_or_else(|| polars_err!(ComputeError: "date is out of range"))
+}
+
 fn new_datetime(
     year: i32,
     month: u32,
@@ -750,6 +986,8 @@ fn new_datetime(
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::windows::calendar::temporal_range;
+    use crate::windows::groupby::ClosedWindow;
 
     #[test]
     fn test_parse() {
@@ -791,4 +1029,119 @@ mod test {
             one_week_negative.add_ns(t, None).unwrap()
         );
     }
+
+    #[test]
+    fn test_business_days_and_quarters() {
+        let out = Duration::parse("-3bd");
+        assert!(out.negative);
+        assert_eq!(out.business_days(), 3);
+        assert!(out.business_days_only());
+        assert_eq!(Duration::parse("2q"), Duration::from_quarters(2));
+        assert_eq!(
+            Duration::parse("1mo2d").with_saturating(true),
+            Duration::parse("1mo2d_saturating")
+        );
+        assert_eq!(
+            Duration::from_parts(-1, 0, -2, 0, 0).unwrap(),
+            Duration::parse("-1mo2d")
+        );
+        assert!(Duration::from_parts(1, 0, -2, 0, 0).is_err());
+
+        let ms = |y, m, d, h| datetime_to_timestamp_ms(new_datetime(y, m, d, h, 0, 0, 0).unwrap());
+        // Friday 2023-07-07
+        let friday = ms(2023, 7, 7, 12);
+        let saturday = ms(2023, 7, 8, 12);
+        let one = Duration::parse("1bd");
+        assert_eq!(one.add_ms(friday, None).unwrap(), ms(2023, 7, 10, 12));
+        assert_eq!(one.add_ms(saturday, None).unwrap(), ms(2023, 7, 10, 12));
+        let minus_one = Duration::parse("-1bd");
+        assert_eq!(
+            minus_one.add_ms(saturday, None).unwrap(),
+            ms(2023, 7, 7, 12)
+        );
+        let six = Duration::parse("6bd");
+        assert_eq!(six.add_ms(friday, None).unwrap(), ms(2023, 7, 17, 12));
+
+        assert_eq!(
+            one.truncate_ms(saturday, None, Ambiguous::Raise, NonExistent::Raise)
+                .unwrap(),
+            Some(ms(2023, 7, 7, 0))
+        );
+        assert!(Duration::parse("1bd1h")
+            .truncate_ms(saturday, None, Ambiguous::Raise, NonExistent::Raise)
+            .is_err());
+
+        let rng = temporal_range(
+            saturday,
+            ms(2023, 7, 12, 12),
+            one,
+            ClosedWindow::Both,
+            TimeUnit::Milliseconds,
+            None,
+        )
+        .unwrap();
+        assert_eq!(
+            rng,
+            [
+                ms(2023, 7, 10, 12),
+                ms(2023, 7, 11, 12),
+                ms(2023, 7, 12, 12)
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "timezones")]
+    fn test_dst_handling() {
+        let tz: Tz = "Europe/Amsterdam".parse().unwrap();
+        let ms = |y, m, d, h, min, sec, milli| {
+            datetime_to_timestamp_ms(new_datetime(y, m, d, h, min, sec, milli * 1_000_000).unwrap())
+        };
+        let one_day = Duration::parse("1d");
+        let add = |t, ambiguous, non_existent| {
+            one_day.add_localized(
+                t,
+                TimeUnit::Milliseconds,
+                Some(&tz),
+                ambiguous,
+                non_existent,
+            )
+        };
+
+        // 02:30 local on 2023-03-26 does not exist, clocks jump from 02:00 to 03:00 CEST
+        let t = ms(2023, 3, 25, 1, 30, 0, 0);
+        assert!(add(t, Ambiguous::Raise, NonExistent::Raise).is_err());
+        assert_eq!(add(t, Ambiguous::Raise, NonExistent::Null).unwrap(), None);
+        assert_eq!(
+            add(t, Ambiguous::Raise, NonExistent::ShiftForward).unwrap(),
+            Some(ms(2023, 3, 26, 1, 0, 0, 0))
+        );
+        assert_eq!(
+            add(t, Ambiguous::Raise, NonExistent::ShiftBackward).unwrap(),
+            Some(ms(2023, 3, 26, 0, 59, 59, 999))
+        );
+
+        // 02:30 local on 2023-10-29 occurs twice, clocks fall back from 03:00 CEST to 02:00 CET
+        let t = ms(2023, 10, 28, 0, 30, 0, 0);
+        assert!(add(t, Ambiguous::Raise, NonExistent::Raise).is_err());
+        assert_eq!(add(t, Ambiguous::Null, NonExistent::Raise).unwrap(), None);
+        assert_eq!(
+            add(t, Ambiguous::Earliest, NonExistent::Raise).unwrap(),
+            Some(ms(2023, 10, 29, 0, 30, 0, 0))
+        );
+        assert_eq!(
+            add(t, Ambiguous::Latest, NonExistent::Raise).unwrap(),
+            Some(ms(2023, 10, 29, 1, 30, 0, 0))
+        );
+
+        // truncating to the hour lands on the ambiguous 02:00 local
+        let t = ms(2023, 10, 29, 1, 15, 0, 0);
+        let one_hour = Duration::parse("1h");
+        assert_eq!(
+            one_hour
+                .truncate_ms(t, Some(&tz), Ambiguous::Earliest, NonExistent::Raise)
+                .unwrap(),
+            Some(ms(2023, 10, 29, 0, 0, 0, 0))
+        );
+    }
 }
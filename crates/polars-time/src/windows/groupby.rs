@@ -453,19 +453,45 @@ pub(crate) fn groupby_values_iter_full_lookahead(
         })
 }
 
+/// The offset of the rolling window of `t`, such that `t` is at the right endpoint of the
+/// window, or at its center.
+#[cfg(feature = "rolling_window")]
+pub(crate) fn rolling_window_offset(period: Duration, center: bool) -> PolarsResult<Duration> {
+    if center {
+        // only fixed durations can be halved
+        polars_ensure!(
+            period.is_constant_duration(),
+            ComputeError: "a centered 'rolling by' window should have a fixed duration, \
+            without calendar days, weeks or months"
+        );
+        Ok(Duration::from_nsecs(-period.duration_ns() / 2))
+    } else {
+        let mut offset = period;
+        offset.negative = true;
+        Ok(offset)
+    }
+}
+
 #[cfg(feature = "rolling_window")]
 pub(crate) fn groupby_values_iter<'a>(
     period: Duration,
     time: &'a [i64],
     closed_window: ClosedWindow,
+    center: bool,
     tu: TimeUnit,
     tz: Option<Tz>,
-) -> Box<dyn TrustedLen<Item = PolarsResult<(IdxSize, IdxSize)>> + 'a> {
-    let mut offset = period;
-    offset.negative = true;
-    // t is at the right endpoint of the window
-    let iter = groupby_values_iter_lookbehind(period, offset, time, closed_window, tu, tz, 0);
-    Box::new(iter)
+) -> PolarsResult<Box<dyn TrustedLen<Item = PolarsResult<(IdxSize, IdxSize)>> + 'a>> {
+    let offset = rolling_window_offset(period, center)?;
+    if center {
+        // t is at the center of the window
+        let iter =
+            groupby_values_iter_partial_lookbehind(period, offset, time, closed_window, tu, tz);
+        Ok(Box::new(iter))
+    } else {
+        // t is at the right endpoint of the window
+        let iter = groupby_values_iter_lookbehind(period, offset, time, closed_window, tu, tz, 0);
+        Ok(Box::new(iter))
+    }
 }
 
 /// Different from `groupby_windows`, where define window buckets and search which values fit that
@@ -65,6 +65,76 @@ fn test_feb_date_range() {
     assert_eq!(dates, expected);
 }
 
+#[test]
+fn test_saturating_month_date_range() {
+    let start = NaiveDate::from_ymd_opt(2022, 1, 31)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let end = NaiveDate::from_ymd_opt(2022, 4, 30)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap();
+    let dates = temporal_range_vec(
+        start.timestamp_nanos(),
+        end.timestamp_nanos(),
+        Duration::parse("1mo_saturating"),
+        ClosedWindow::Both,
+        TimeUnit::Nanoseconds,
+        None,
+    )
+    .unwrap();
+    // every value is a month after the previous one
+    let expected = [
+        NaiveDate::from_ymd_opt(2022, 1, 31).unwrap(),
+        NaiveDate::from_ymd_opt(2022, 2, 28).unwrap(),
+        NaiveDate::from_ymd_opt(2022, 3, 28).unwrap(),
+        NaiveDate::from_ymd_opt(2022, 4, 28).unwrap(),
+    ]
+    .iter()
+    .map(|d| d.and_hms_opt(0, 0, 0).unwrap().timestamp_nanos())
+    .collect::<Vec<_>>();
+    assert_eq!(dates, expected);
+}
+
+#[test]
+#[cfg(feature = "timezones")]
+fn test_date_range_shift_forward_dst_gap() {
+    use polars_arrow::time_zone::{Ambiguous, NonExistent, Tz};
+
+    use crate::windows::calendar::temporal_range_localized;
+
+    let tz: Tz = "Europe/Amsterdam".parse().unwrap();
+    let utc = |d: u32, h: u32, m: u32| {
+        NaiveDate::from_ymd_opt(2023, 3, d)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+            .timestamp_millis()
+    };
+    // daily at 02:30 local, which does not exist on 2023-03-26
+    let dates = temporal_range_localized(
+        utc(24, 1, 30),
+        utc(28, 0, 30),
+        Duration::parse("1d"),
+        ClosedWindow::Both,
+        TimeUnit::Milliseconds,
+        Some(&tz),
+        Ambiguous::Raise,
+        NonExistent::ShiftForward,
+    )
+    .unwrap();
+    // only the non-existent value is shifted to 03:00 local, the later ones are at 02:30 again
+    let expected = [
+        utc(24, 1, 30),
+        utc(25, 1, 30),
+        utc(26, 1, 0),
+        utc(27, 0, 30),
+        utc(28, 0, 30),
+    ];
+    assert_eq!(dates, expected);
+}
+
 fn print_ns(ts: &[i64]) {
     for ts in ts {
         println!("{}", timestamp_ns_to_datetime(*ts));
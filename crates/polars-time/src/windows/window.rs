@@ -18,6 +18,9 @@ pub struct Window {
     every: Duration,
     period: Duration,
     pub offset: Duration,
+    // How to localize window boundaries that fall on a DST transition.
+    ambiguous: Ambiguous,
+    non_existent: NonExistent,
 }
 
 impl Window {
@@ -27,95 +30,144 @@ impl Window {
             every,
             period,
             offset,
+            ambiguous: Ambiguous::default(),
+            non_existent: NonExistent::default(),
         }
     }
 
-    /// Truncate the given ns timestamp by the window boundary.
-    pub fn truncate_ns(
+    /// Set how window boundaries that are ambiguous or non-existent in the
+    /// time zone are resolved. By default both raise.
+    pub fn with_dst_handling(mut self, ambiguous: Ambiguous, non_existent: NonExistent) -> Self {
+        self.ambiguous = ambiguous;
+        self.non_existent = non_existent;
+        self
+    }
+
+    /// Add `duration` to `t`, localizing the result with the window's DST handling.
+    /// Window boundaries may not be null.
+    fn add_duration(
         &self,
+        duration: &Duration,
         t: i64,
+        tu: TimeUnit,
         tz: Option<&Tz>,
-        use_earliest: Option<bool>,
     ) -> PolarsResult<i64> {
-        let t = self.every.truncate_ns(t, tz, use_earliest)?;
-        self.offset.add_ns(t, tz)
+        duration
+            .add_localized(t, tu, tz, self.ambiguous, self.non_existent)?
+            .ok_or_else(|| {
+                polars_err!(ComputeError: "window boundary does not exist in the time zone; use a non-null `non_existent`/`ambiguous` strategy")
+            })
+    }
+
+    fn apply_offset(
+        &self,
+        t: Option<i64>,
+        tu: TimeUnit,
+        tz: Option<&Tz>,
+    ) -> PolarsResult<Option<i64>> {
+        match t {
+            Some(t) => self
+                .offset
+                .add_localized(t, tu, tz, self.ambiguous, self.non_existent),
+            None => Ok(None),
+        }
+    }
+
+    /// Truncate the given ns timestamp by the window boundary.
+    ///
+    /// Returns `None` if the result is ambiguous or non-existent in `tz` and
+    /// the window resolves such datetimes to null.
+    pub fn truncate_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<Option<i64>> {
+        let t = self
+            .every
+            .truncate_ns(t, tz, self.ambiguous, self.non_existent)?;
+        self.apply_offset(t, TimeUnit::Nanoseconds, tz)
     }
 
     pub fn truncate_no_offset_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        self.every.truncate_ns(t, tz, None)
+        self.every
+            .truncate_ns(t, tz, Ambiguous::Raise, NonExistent::Raise)
+            .map(Option::unwrap)
     }
 
     /// Truncate the given us timestamp by the window boundary.
-    pub fn truncate_us(
-        &self,
-        t: i64,
-        tz: Option<&Tz>,
-        use_earliest: Option<bool>,
-    ) -> PolarsResult<i64> {
-        let t = self.every.truncate_us(t, tz, use_earliest)?;
-        self.offset.add_us(t, tz)
+    pub fn truncate_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<Option<i64>> {
+        let t = self
+            .every
+            .truncate_us(t, tz, self.ambiguous, self.non_existent)?;
+        self.apply_offset(t, TimeUnit::Microseconds, tz)
     }
 
     pub fn truncate_no_offset_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        self.every.truncate_us(t, tz, None)
+        self.every
+            .truncate_us(t, tz, Ambiguous::Raise, NonExistent::Raise)
+            .map(Option::unwrap)
     }
 
-    pub fn truncate_ms(
-        &self,
-        t: i64,
-        tz: Option<&Tz>,
-        use_earliest: Option<bool>,
-    ) -> PolarsResult<i64> {
-        let t = self.every.truncate_ms(t, tz, use_earliest)?;
-        self.offset.add_ms(t, tz)
+    /// Truncate the given ms timestamp by the window boundary.
+    pub fn truncate_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<Option<i64>> {
+        let t = self
+            .every
+            .truncate_ms(t, tz, self.ambiguous, self.non_existent)?;
+        self.apply_offset(t, TimeUnit::Milliseconds, tz)
     }
 
     #[inline]
     pub fn truncate_no_offset_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
-        self.every.truncate_ms(t, tz, None)
+        self.every
+            .truncate_ms(t, tz, Ambiguous::Raise, NonExistent::Raise)
+            .map(Option::unwrap)
     }
 
     /// Round the given ns timestamp by the window boundary.
-    pub fn round_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+    pub fn round_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<Option<i64>> {
         let t = t + self.every.duration_ns() / 2_i64;
-        self.truncate_ns(t, tz, None)
+        self.truncate_ns(t, tz)
     }
 
     /// Round the given us timestamp by the window boundary.
-    pub fn round_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+    pub fn round_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<Option<i64>> {
         let t = t + self.every.duration_ns()
             / (2 * timeunit_scale(ArrowTimeUnit::Nanosecond, ArrowTimeUnit::Microsecond) as i64);
-        self.truncate_us(t, tz, None)
+        self.truncate_us(t, tz)
     }
 
     /// Round the given ms timestamp by the window boundary.
-    pub fn round_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<i64> {
+    pub fn round_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<Option<i64>> {
         let t = t + self.every.duration_ns()
             / (2 * timeunit_scale(ArrowTimeUnit::Nanosecond, ArrowTimeUnit::Millisecond) as i64);
-        self.truncate_ms(t, tz, None)
+        self.truncate_ms(t, tz)
+    }
+
+    fn get_earliest_bounds(
+        &self,
+        start: Option<i64>,
+        tu: TimeUnit,
+        tz: Option<&Tz>,
+    ) -> PolarsResult<Bounds> {
+        let start = start.ok_or_else(|| {
+            polars_err!(ComputeError: "window boundary does not exist in the time zone; use a non-null `non_existent`/`ambiguous` strategy")
+        })?;
+        let stop = self.add_duration(&self.period, start, tu, tz)?;
+        Ok(Bounds::new_checked(start, stop))
     }
 
     /// returns the bounds for the earliest window bounds
     /// that contains the given time t.  For underlapping windows that
     /// do not contain time t, the window directly after time t will be returned.
     pub fn get_earliest_bounds_ns(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<Bounds> {
-        let start = self.truncate_ns(t, tz, None)?;
-        let stop = self.period.add_ns(start, tz)?;
-
-        Ok(Bounds::new_checked(start, stop))
+        let start = self.truncate_ns(t, tz)?;
+        self.get_earliest_bounds(start, TimeUnit::Nanoseconds, tz)
     }
 
     pub fn get_earliest_bounds_us(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<Bounds> {
-        let start = self.truncate_us(t, tz, None)?;
-        let stop = self.period.add_us(start, tz)?;
-        Ok(Bounds::new_checked(start, stop))
+        let start = self.truncate_us(t, tz)?;
+        self.get_earliest_bounds(start, TimeUnit::Microseconds, tz)
     }
 
     pub fn get_earliest_bounds_ms(&self, t: i64, tz: Option<&Tz>) -> PolarsResult<Bounds> {
-        let start = self.truncate_ms(t, tz, None)?;
-        let stop = self.period.add_ms(start, tz)?;
-
-        Ok(Bounds::new_checked(start, stop))
+        let start = self.truncate_ms(t, tz)?;
+        self.get_earliest_bounds(start, TimeUnit::Milliseconds, tz)
     }
 
     pub(crate) fn estimate_overlapping_bounds_ns(&self, boundary: Bounds) -> usize {
@@ -164,12 +216,7 @@ impl<'a> BoundsIter<'a> {
         let bi = match start_by {
             StartBy::DataPoint => {
                 let mut boundary = boundary;
-                let offset_fn = match tu {
-                    TimeUnit::Nanoseconds => Duration::add_ns,
-                    TimeUnit::Microseconds => Duration::add_us,
-                    TimeUnit::Milliseconds => Duration::add_ms,
-                };
-                boundary.stop = offset_fn(&window.period, boundary.start, tz)?;
+                boundary.stop = window.add_duration(&window.period, boundary.start, tu, tz)?;
                 boundary
             }
             StartBy::WindowBound => match tu {
@@ -179,27 +226,20 @@ impl<'a> BoundsIter<'a> {
             },
             _ => {
                 {
-                    #[allow(clippy::type_complexity)]
-                    let (from, to, offset): (
-                        fn(i64) -> NaiveDateTime,
-                        fn(NaiveDateTime) -> i64,
-                        fn(&Duration, i64, Option<&Tz>) -> PolarsResult<i64>,
-                    ) = match tu {
-                        TimeUnit::Nanoseconds => (
-                            timestamp_ns_to_datetime,
-                            datetime_to_timestamp_ns,
-                            Duration::add_ns,
-                        ),
-                        TimeUnit::Microseconds => (
-                            timestamp_us_to_datetime,
-                            datetime_to_timestamp_us,
-                            Duration::add_us,
-                        ),
-                        TimeUnit::Milliseconds => (
-                            timestamp_ms_to_datetime,
-                            datetime_to_timestamp_ms,
-                            Duration::add_ms,
-                        ),
+                    let (from, to): (fn(i64) -> NaiveDateTime, fn(NaiveDateTime) -> i64) = match tu
+                    {
+                        TimeUnit::Nanoseconds => {
+                            (timestamp_ns_to_datetime, datetime_to_timestamp_ns)
+                        }
+                        TimeUnit::Microseconds => {
+                            (timestamp_us_to_datetime, datetime_to_timestamp_us)
+                        }
+                        TimeUnit::Milliseconds => {
+                            (timestamp_ms_to_datetime, datetime_to_timestamp_ms)
+                        }
+                    };
+                    let offset = |duration: &Duration, t: i64, tz: Option<&Tz>| {
+                        window.add_duration(duration, t, tu, tz)
                     };
                     // find beginning of the week.
                     let mut boundary = boundary;
@@ -263,22 +303,17 @@ impl<'a> Iterator for BoundsIter<'a> {
     fn next(&mut self) -> Option<Self::Item> {
         if self.bi.start < self.boundary.stop {
             let out = self.bi;
-            match self.tu {
-                // TODO: find some way to propagate error instead of unwrapping?
-                // Issue is that `next` needs to return `Option`.
-                TimeUnit::Nanoseconds => {
-                    self.bi.start = self.window.every.add_ns(self.bi.start, self.tz).unwrap();
-                    self.bi.stop = self.window.every.add_ns(self.bi.stop, self.tz).unwrap();
-                }
-                TimeUnit::Microseconds => {
-                    self.bi.start = self.window.every.add_us(self.bi.start, self.tz).unwrap();
-                    self.bi.stop = self.window.every.add_us(self.bi.stop, self.tz).unwrap();
-                }
-                TimeUnit::Milliseconds => {
-                    self.bi.start = self.window.every.add_ms(self.bi.start, self.tz).unwrap();
-                    self.bi.stop = self.window.every.add_ms(self.bi.stop, self.tz).unwrap();
-                }
-            }
+            let every = &self.window.every;
+            // TODO: find some way to propagate error instead of unwrapping?
+            // Issue is that `next` needs to return `Option`.
+            self.bi.start = self
+                .window
+                .add_duration(every, self.bi.start, self.tu, self.tz)
+                .unwrap();
+            self.bi.stop = self
+                .window
+                .add_duration(every, self.bi.stop, self.tu, self.tz)
+                .unwrap();
             Some(out)
         } else {
             None
@@ -177,6 +177,11 @@
 //! * `strings` - Extra string utilities for `Utf8Chunked`
 //!     - `string_justify` - `zfill`, `ljust`, `rjust`
 //!     - `string_from_radix` - `parse_int`
+//!     - `find_many` - `contains_any`, `replace_many`, `extract_many`
+//!     - `extract_groups` - extract regex capture groups into a struct
+//!     - `string_distance` - `levenshtein`, `hamming`, `jaro_winkler`, `jaccard_ngram`
+//!     - `string_unicode` - `normalize`, `casefold`, `n_graphemes`, `reverse`
+//! * `binary_encoding` - `hex`, `base64`, `base32` and `base85` codecs for `BinaryChunked`
 //! * `object` - Support for generic ChunkedArrays called `ObjectChunked<T>` (generic over `T`).
 //!              These are downcastable from Series through the [Any](https://doc.rust-lang.org/std/any/index.html) trait.
 //! * Performance related:
@@ -207,9 +212,11 @@
 //!     - `rows` - Create `DataFrame` from rows and extract rows from `DataFrames`.
 //!                And activates `pivot` and `transpose` operations
 //!     - `asof_join` - Join ASOF, to join on nearest keys instead of exact equality match.
+//!     - `interval_join` - Join rows whose `[start, end)` intervals overlap, and merge or split
+//!                         overlapping intervals.
 //!     - `cross_join` - Create the cartesian product of two DataFrames.
 //!     - `semi_anti_join` - SEMI and ANTI joins.
-//!     - `groupby_list` - Allow groupby operation on keys of type List.
+//!     - `groupby_list` - Deprecated: grouping on List keys is always available.
 //!     - `row_hash` - Utility to hash DataFrame rows to UInt64Chunked
 //!     - `diagonal_concat` - Concat diagonally thereby combining different schemas.
 //!     - `horizontal_concat` - Concat horizontally and extend with null values if lengths don't match
@@ -252,6 +259,8 @@
 //!     - `arg_where` - Get indices where condition holds.
 //!     - `search_sorted` - Find indices where elements should be inserted to maintain order.
 //!     - `date_offset` Add an offset to dates that take months and leap years into account.
+//!     - `business` Business-day arithmetic with custom week masks and holidays.
+//!     - `date_diff` Calendar-aware differences between dates in years, months, days or business days.
 //!     - `trigonometry` Trigonometric functions.
 //!     - `sign` Compute the element-wise sign of a Series.
 //!     - `propagate_nans` NaN propagating min/max aggregations.
@@ -280,6 +289,7 @@
 //! | UInt8                   | dtype-u8          |
 //! | UInt16                  | dtype-u16         |
 //! | Categorical             | dtype-categorical |
+//! | Enum                    | dtype-categorical |
 //! | Struct                  | dtype-struct      |
 //!
 //!
@@ -306,6 +306,48 @@ fn test_join_categorical() {
     assert!(out.is_err());
 }
 
+#[test]
+#[cfg_attr(miri, ignore)]
+#[cfg(feature = "dtype-categorical")]
+fn test_join_enum() -> PolarsResult<()> {
+    let (mut df_a, mut df_b) = get_dfs();
+    // independently created enums with the same categories join without a string cache
+    let categories = LargeStringArray::from_slice(["a", "b", "c"]);
+    let dtype_a = create_enum_dtype(categories.clone())?;
+    let dtype_b = create_enum_dtype(categories)?;
+    df_a.try_apply("b", |s| s.cast(&dtype_a))?;
+    df_b.try_apply("bar", |s| s.cast(&dtype_b))?;
+
+    let out = df_a.join(&df_b, ["b"], ["bar"], JoinType::Left.into())?;
+    assert_eq!(out.shape(), (6, 5));
+    let ham = out.column("ham")?.utf8()?;
+    assert_eq!(
+        Vec::from(ham),
+        &[
+            Some("let"),
+            None,
+            Some("var"),
+            Some("const"),
+            Some("var"),
+            Some("const"),
+        ]
+    );
+
+    for jt in [JoinType::Left, JoinType::Inner, JoinType::Outer] {
+        let out = df_a.join(&df_b, ["b"], ["bar"], jt.into())?;
+        assert_eq!(out.column("b")?.dtype(), &dtype_a);
+    }
+
+    // enums with other categories can't be joined
+    let dtype_c = create_enum_dtype(LargeStringArray::from_slice(["c", "b", "a"]))?;
+    let (_, mut df_c) = get_dfs();
+    df_c.try_apply("bar", |s| s.cast(&dtype_c))?;
+    assert!(df_a
+        .join(&df_c, ["b"], ["bar"], JoinType::Inner.into())
+        .is_err());
+    Ok(())
+}
+
 #[test]
 #[cfg_attr(miri, ignore)]
 fn empty_df_join() -> PolarsResult<()> {
@@ -614,3 +656,53 @@ fn test_4_threads_bit_offset() -> PolarsResult<()> {
     assert_eq!(out.shape(), (1, 2));
     Ok(())
 }
+
+#[test]
+#[cfg_attr(miri, ignore)]
+fn test_join_on_list_keys() -> PolarsResult<()> {
+    let left = DataFrame::new(vec![
+        Series::new(
+            "k",
+            &[
+                Series::new("", &[1, 2]),
+                Series::new("", &[1]),
+                Series::new("", &[3]),
+            ],
+        ),
+        Series::new("a", &["x", "y", "z"]),
+    ])?;
+    let right = DataFrame::new(vec![
+        Series::new(
+            "k",
+            &[
+                Series::new("", &[1]),
+                Series::new("", &[1, 2]),
+                Series::new("", &[2, 1]),
+            ],
+        ),
+        Series::new("b", &[10, 20, 30]),
+    ])?;
+
+    let out = left.inner_join(&right, ["k"], ["k"])?;
+    assert_eq!(out.shape(), (2, 3));
+    let out = out.sort(["b"], false, false)?;
+    assert_eq!(Vec::from(out.column("a")?.utf8()?), &[Some("y"), Some("x")]);
+
+    let out = left.left_join(&right, ["k"], ["k"])?;
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[Some(20), Some(10), None]
+    );
+
+    let out = left.outer_join(&right, ["k"], ["k"])?;
+    assert_eq!(out.height(), 4);
+    assert_eq!(out.column("k")?.null_count(), 0);
+    assert_eq!(out.column("k")?.n_unique()?, 4);
+
+    // multiple keys
+    let left = left.hstack(&[Series::new("c", &[1, 1, 1])])?;
+    let right = right.hstack(&[Series::new("c", &[1, 2, 1])])?;
+    let out = left.inner_join(&right, ["k", "c"], ["k", "c"])?;
+    assert_eq!(out.shape(), (1, 4));
+    Ok(())
+}
@@ -425,3 +425,70 @@ fn test_median_quantile_types() {
     assert_eq!(*rol_quantile.dtype(), DataType::Float64);
     assert_eq!(*rol_quantile_weighted.dtype(), DataType::Float64);
 }
+
+#[test]
+fn test_rolling_by_nulls_center_weights() -> PolarsResult<()> {
+    let time = [0i64, 1000, 2000, 4000, 5000];
+    let values = [Some(1), Some(2), None, Some(4), Some(5)];
+    let s = Float64Chunked::new("", &values.map(|v| v.map(|v| v as f64))).into_series();
+    let options = RollingOptionsImpl {
+        window_size: Duration::parse("2s"),
+        min_periods: 1,
+        by: Some(&time),
+        tu: Some(TimeUnit::Milliseconds),
+        closed_window: Some(ClosedWindow::Right),
+        ..Default::default()
+    };
+
+    let out = s.rolling_sum(options.clone())?;
+    assert_eq!(
+        Vec::from(out.f64()?),
+        &[Some(1.0), Some(3.0), Some(2.0), Some(4.0), Some(9.0)]
+    );
+    let out = s.rolling_sum(RollingOptionsImpl {
+        min_periods: 2,
+        ..options.clone()
+    })?;
+    assert_eq!(
+        Vec::from(out.f64()?),
+        &[None, Some(3.0), None, None, Some(9.0)]
+    );
+
+    // the windows are (t - 1s, t + 1s]
+    let centered = RollingOptionsImpl {
+        center: true,
+        ..options.clone()
+    };
+    let out = s.rolling_mean(centered.clone())?;
+    assert_eq!(
+        Vec::from(out.f64()?),
+        &[Some(1.5), Some(2.0), None, Some(4.5), Some(5.0)]
+    );
+    let out = Int32Chunked::new("", &values)
+        .into_series()
+        .rolling_sum(centered)?;
+    assert_eq!(
+        Vec::from(out.i32()?),
+        &[Some(3), Some(2), None, Some(9), Some(5)]
+    );
+
+    // the weight rises linearly from 0 at the start of the window to 1 at its end
+    let out = s.rolling_sum(RollingOptionsImpl {
+        weights: Some(vec![0.0, 1.0]),
+        ..options.clone()
+    })?;
+    assert_eq!(
+        Vec::from(out.f64()?),
+        &[Some(1.0), Some(2.5), Some(1.0), Some(4.0), Some(7.0)]
+    );
+
+    // calendar durations can't be halved
+    assert!(s
+        .rolling_mean(RollingOptionsImpl {
+            window_size: Duration::parse("1d"),
+            center: true,
+            ..options
+        })
+        .is_err());
+    Ok(())
+}
@@ -1104,3 +1104,120 @@ fn test_leading_whitespace_with_quote() -> PolarsResult<()> {
     assert_eq!(col_2.get(0)?, AnyValue::Float64(4.1));
     Ok(())
 }
+
+#[test]
+fn test_multi_byte_delimiter() -> PolarsResult<()> {
+    let csv = "a||b||c
+1||\"x||y\"||3.5
+2||z|w||4.5
+";
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_multi_byte_delimiter(b"||")
+        .finish()?;
+    assert_eq!(df.shape(), (2, 3));
+    assert_eq!(df.get_column_names(), &["a", "b", "c"]);
+    assert!(df
+        .column("b")?
+        .series_equal(&Series::new("b", &["x||y", "z|w"])));
+    assert_eq!(df.column("c")?.dtype(), &DataType::Float64);
+    Ok(())
+}
+
+#[test]
+fn test_multi_byte_delimiter_first_byte_in_field() -> PolarsResult<()> {
+    // enough rows to be split over threads, the chunk boundaries are searched by counting
+    // the delimiters in a line and those must not count the single '|' in the fields
+    let mut csv = String::from("a||b||c\n");
+    for i in 0..10_000 {
+        csv.push_str(&format!("{i}||x|y|z||{i}.5\n"));
+    }
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_multi_byte_delimiter(b"||")
+        .with_n_threads(Some(4))
+        .finish()?;
+    assert_eq!(df.shape(), (10_000, 3));
+    assert_eq!(df.column("a")?.dtype(), &DataType::Int64);
+    assert_eq!(df.column("a")?.sum::<i64>(), Some((0..10_000).sum()));
+    assert_eq!(df.column("b")?.utf8()?.get(9_999), Some("x|y|z"));
+    assert_eq!(df.column("b")?.n_unique()?, 1);
+    assert_eq!(df.column("c")?.dtype(), &DataType::Float64);
+    Ok(())
+}
+
+#[test]
+fn test_escape_char() -> PolarsResult<()> {
+    let csv = r#"a,b
+1,"with \"escaped\" quotes"
+2,"escaped \\ backslash, and comma"
+"#;
+    let file = Cursor::new(csv);
+    let df = CsvReader::new(file)
+        .with_escape_char(Some(b'\\'))
+        .finish()?;
+    assert_eq!(df.shape(), (2, 2));
+    assert!(df.column("b")?.series_equal(&Series::new(
+        "b",
+        &[
+            r#"with "escaped" quotes"#,
+            r#"escaped \ backslash, and comma"#
+        ]
+    )));
+    Ok(())
+}
+
+#[test]
+fn test_strict_mode() -> PolarsResult<()> {
+    let csv = "a,b\n1,\"x\"\n2,\"y\"z\n";
+    let df = CsvReader::new(Cursor::new(csv)).finish()?;
+    assert_eq!(df.shape(), (2, 2));
+
+    let err = CsvReader::new(Cursor::new(csv))
+        .with_strict(true)
+        .finish()
+        .unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("malformed CSV record at line 3 (byte offset 15)"));
+
+    let csv = "a,b\n1,2,3\n";
+    let err = CsvReader::new(Cursor::new(csv))
+        .with_strict(true)
+        .finish()
+        .unwrap_err();
+    assert!(err.to_string().contains("expected 2 fields, found 3"));
+    Ok(())
+}
+
+#[test]
+fn test_bad_records() -> PolarsResult<()> {
+    let csv = "a,b\n1,x\nfoo,y\n3,z\n";
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a", DataType::Int64),
+        Field::new("b", DataType::Utf8),
+    ]));
+
+    let bad_records = BadRecords::new();
+    let df = CsvReader::new(Cursor::new(csv))
+        .with_schema(schema)
+        .with_bad_records(Some(bad_records.clone()))
+        .finish()?;
+    let expected = df![
+        "a" => [1i64, 3],
+        "b" => ["x", "z"],
+    ]?;
+    assert!(df.frame_equal(&expected));
+
+    assert_eq!(bad_records.len(), 1);
+    let bad = bad_records.take()?;
+    assert_eq!(bad.column("line_number")?.u64()?.get(0), Some(3));
+    assert_eq!(bad.column("raw")?.utf8()?.get(0), Some("foo,y"));
+    assert!(bad
+        .column("error")?
+        .utf8()?
+        .get(0)
+        .unwrap()
+        .contains("could not parse `foo` as dtype `i64` at column 'a'"));
+    Ok(())
+}
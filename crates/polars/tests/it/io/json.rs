@@ -209,3 +209,60 @@ fn test_read_ndjson_iss_6148() {
     let df = JsonLineReader::new(cursor).finish();
     assert!(df.is_ok());
 }
+
+#[test]
+fn test_read_ndjson_bad_records() -> PolarsResult<()> {
+    let json = r#"{"a":1,"b":"x"}
+{"a":2,"b":
+{"a":3,"b":"z"}
+"#;
+    let schema = Arc::new(Schema::from_iter([
+        Field::new("a", DataType::Int64),
+        Field::new("b", DataType::Utf8),
+    ]));
+
+    let bad_records = BadRecords::new();
+    let df = JsonLineReader::new(Cursor::new(json))
+        .with_schema(schema)
+        .with_bad_records(Some(bad_records.clone()))
+        .finish()?;
+    let expected = df![
+        "a" => [1i64, 3],
+        "b" => ["x", "z"],
+    ]?;
+    assert!(df.frame_equal(&expected));
+
+    let bad = bad_records.take()?;
+    assert_eq!(bad.height(), 1);
+    assert_eq!(bad.column("line_number")?.u64()?.get(0), Some(2));
+    assert_eq!(bad.column("raw")?.utf8()?.get(0), Some(r#"{"a":2,"b":"#));
+    Ok(())
+}
+
+#[test]
+fn test_read_ndjson_bad_records_non_object() -> PolarsResult<()> {
+    let json = r#"{"a":1}
+[1,2]
+3
+{"a":4}
+"#;
+    let schema = Arc::new(Schema::from_iter([Field::new("a", DataType::Int64)]));
+
+    let bad_records = BadRecords::new();
+    let df = JsonLineReader::new(Cursor::new(json))
+        .with_schema(schema)
+        .with_bad_records(Some(bad_records.clone()))
+        .finish()?;
+    assert!(df.frame_equal(&df!["a" => [1i64, 4]]?));
+
+    let bad = bad_records.take()?;
+    assert_eq!(bad.height(), 2);
+    let line_numbers = bad.column("line_number")?.u64()?;
+    assert_eq!(
+        line_numbers.into_no_null_iter().collect::<Vec<_>>(),
+        &[2, 3]
+    );
+    assert_eq!(bad.column("raw")?.utf8()?.get(0), Some("[1,2]"));
+    assert_eq!(bad.column("raw")?.utf8()?.get(1), Some("3"));
+    Ok(())
+}
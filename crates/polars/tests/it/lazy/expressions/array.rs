@@ -0,0 +1,188 @@
+use super::*;
+
+fn array_frame() -> PolarsResult<DataFrame> {
+    let a = Series::new(
+        "a",
+        &[
+            Series::new("", &[3.0, 1.0, 2.0]),
+            Series::new("", &[4.0, 6.0, 5.0]),
+        ],
+    )
+    .cast(&DataType::Array(Box::new(DataType::Float64), 3))?;
+    DataFrame::new(vec![a])
+}
+
+#[test]
+fn test_array_namespace() -> PolarsResult<()> {
+    let out = array_frame()?
+        .lazy()
+        .select([
+            col("a").arr().get(lit(-1)).alias("last"),
+            col("a").arr().contains(lit(6.0)).alias("contains"),
+            col("a").arr().mean().alias("mean"),
+            col("a").arr().std(1).alias("std"),
+            col("a").arr().arg_max().alias("arg_max"),
+            col("a")
+                .arr()
+                .sort(SortOptions::default())
+                .arr()
+                .get(lit(0))
+                .alias("sorted_first"),
+            col("a")
+                .arr()
+                .reverse()
+                .arr()
+                .get(lit(0))
+                .alias("reversed_first"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("last")?.f64()?),
+        &[Some(2.0), Some(5.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("contains")?.bool()?),
+        &[Some(false), Some(true)]
+    );
+    assert_eq!(
+        Vec::from(out.column("mean")?.f64()?),
+        &[Some(2.0), Some(5.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("std")?.f64()?),
+        &[Some(1.0), Some(1.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("arg_max")?.idx()?),
+        &[Some(0), Some(1)]
+    );
+    assert_eq!(
+        Vec::from(out.column("sorted_first")?.f64()?),
+        &[Some(1.0), Some(4.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("reversed_first")?.f64()?),
+        &[Some(2.0), Some(5.0)]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_array_reshape() -> PolarsResult<()> {
+    let df = array_frame()?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").arr().explode()])
+        .collect()?;
+    assert_eq!(out.height(), 6);
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([col("a").arr().to_list()])
+        .collect()?;
+    assert_eq!(
+        out.column("a")?.dtype(),
+        &DataType::List(Box::new(DataType::Float64))
+    );
+
+    #[cfg(feature = "dtype-struct")]
+    {
+        let out = df
+            .lazy()
+            .select([col("a").arr().to_struct()])
+            .collect()?
+            .unnest(["a"])?;
+        assert_eq!(out.get_column_names(), &["field_0", "field_1", "field_2"]);
+        assert_eq!(
+            Vec::from(out.column("field_1")?.f64()?),
+            &[Some(1.0), Some(6.0)]
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_array_vector_ops() -> PolarsResult<()> {
+    let embedding = Series::new(
+        "embedding",
+        &[
+            Series::new("", &[1.0f32, 0.0]),
+            Series::new("", &[0.0f32, 2.0]),
+            Series::new("", &[3.0f32, 4.0]),
+        ],
+    )
+    .cast(&DataType::Array(Box::new(DataType::Float32), 2))?;
+    let df = DataFrame::new(vec![Series::new("id", &[1, 2, 3]), embedding])?;
+
+    let out = df
+        .clone()
+        .lazy()
+        .select([
+            col("embedding").arr().l2_norm().alias("norm"),
+            col("embedding").arr().dot(col("embedding")).alias("dot"),
+            (col("embedding") * lit(2.0f32))
+                .arr()
+                .sum()
+                .alias("doubled_sum"),
+            (col("embedding") - col("embedding"))
+                .arr()
+                .l2_norm()
+                .alias("zero"),
+        ])
+        .collect()?;
+    assert_eq!(out.column("norm")?.dtype(), &DataType::Float32);
+    assert_eq!(
+        Vec::from(out.column("norm")?.f32()?),
+        &[Some(1.0), Some(2.0), Some(5.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("dot")?.f32()?),
+        &[Some(1.0), Some(4.0), Some(25.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("doubled_sum")?.f32()?),
+        &[Some(2.0), Some(4.0), Some(14.0)]
+    );
+    assert_eq!(
+        Vec::from(out.column("zero")?.f32()?),
+        &[Some(0.0), Some(0.0), Some(0.0)]
+    );
+
+    let out = df
+        .clone()
+        .lazy()
+        .top_k_nearest("embedding", &[0.0, 1.0], 2, VectorMetric::Cosine)?
+        .collect()?;
+    assert_eq!(Vec::from(out.column("id")?.i32()?), &[Some(2), Some(3)]);
+
+    let out = df
+        .lazy()
+        .top_k_nearest("embedding", &[3.0, 3.0], 1, VectorMetric::Euclidean)?
+        .collect()?;
+    assert_eq!(Vec::from(out.column("id")?.i32()?), &[Some(3)]);
+    Ok(())
+}
+
+#[test]
+fn test_array_arithmetic_width_mismatch() -> PolarsResult<()> {
+    let a = Series::new("a", &[Series::new("", &[1.0, 2.0])])
+        .cast(&DataType::Array(Box::new(DataType::Float64), 2))?;
+    let b = Series::new("b", &[Series::new("", &[1.0, 2.0, 3.0])])
+        .cast(&DataType::Array(Box::new(DataType::Float64), 3))?;
+    assert!(a.try_add(&b).is_err());
+    assert!(a.try_sub(&b).is_err());
+    assert!(a.try_mul(&b).is_err());
+    assert!(a.try_div(&b).is_err());
+    assert!(a.try_rem(&b).is_err());
+
+    let df = DataFrame::new(vec![a, b])?;
+    for expr in [col("a") - col("b"), col("a") % col("b")] {
+        let out = df.clone().lazy().select([expr]).collect();
+        assert!(out.is_err());
+    }
+    Ok(())
+}
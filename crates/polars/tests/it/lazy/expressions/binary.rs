@@ -0,0 +1,118 @@
+use super::*;
+
+#[test]
+fn test_binary_frame_decoding() -> PolarsResult<()> {
+    let frames: [Option<&[u8]>; 3] = [
+        Some(&[0x01, 0x00, 0x00, 0x00, 0x2a, b'o', b'k']),
+        Some(&[0x02, 0x00, 0x00, 0x01, 0x00, 0xff]),
+        None,
+    ];
+    let df = DataFrame::new(vec![Series::new("frame", &frames)])?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("frame").binary().lengths().alias("len"),
+            col("frame")
+                .binary()
+                .slice(1, Some(4))
+                .binary()
+                .reinterpret(DataType::Int32, Endianness::Big)
+                .alias("be"),
+            col("frame")
+                .binary()
+                .slice(1, Some(4))
+                .binary()
+                .reinterpret(DataType::Int32, Endianness::Little)
+                .alias("le"),
+            col("frame")
+                .binary()
+                .slice(0, Some(2))
+                .binary()
+                .reinterpret(DataType::Int32, Endianness::Big)
+                .alias("wrong_width"),
+            col("frame")
+                .binary()
+                .slice(-2, None)
+                .binary()
+                .decode_utf8(false)
+                .alias("tail"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("len")?.u32()?),
+        &[Some(7), Some(6), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("be")?.i32()?),
+        &[Some(42), Some(256), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("le")?.i32()?),
+        &[Some(42 << 24), Some(1 << 16), None]
+    );
+    assert_eq!(out.column("wrong_width")?.null_count(), 3);
+    assert_eq!(
+        Vec::from(out.column("tail")?.utf8()?),
+        &[Some("ok"), None, None]
+    );
+
+    let df = DataFrame::new(vec![Series::new("frame", &frames)])?;
+    let strict = df
+        .lazy()
+        .select([col("frame")
+            .binary()
+            .slice(-1, None)
+            .binary()
+            .decode_utf8(true)])
+        .collect();
+    assert!(strict.is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "binary_encoding")]
+fn test_binary_base32_base85() -> PolarsResult<()> {
+    let values: [&[u8]; 2] = [b"foob", b"hello"];
+    let df = DataFrame::new(vec![Series::new("a", &values)])?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").binary().base32_encode().alias("b32"),
+            col("a").binary().base85_encode().alias("b85"),
+        ])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("b32")?.utf8()?),
+        &[Some("MZXW6YQ="), Some("NBSWY3DP")]
+    );
+    assert_eq!(
+        Vec::from(out.column("b85")?.utf8()?),
+        &[Some("W^Zp|"), Some("Xk~0{Zv")]
+    );
+
+    let out = out
+        .lazy()
+        .select([
+            col("b32")
+                .cast(DataType::Binary)
+                .binary()
+                .base32_decode(true)
+                .alias("b32"),
+            col("b85")
+                .cast(DataType::Binary)
+                .binary()
+                .base85_decode(true)
+                .alias("b85"),
+        ])
+        .collect()?;
+    assert!(out
+        .column("b32")?
+        .series_equal(&Series::new("b32", &values)));
+    assert!(out
+        .column("b85")?
+        .series_equal(&Series::new("b85", &values)));
+    Ok(())
+}
@@ -20,6 +20,8 @@ fn test_expand_datetimes_3042() -> PolarsResult<()> {
         ClosedWindow::Left,
         TimeUnit::Milliseconds,
         None,
+        Ambiguous::Raise,
+        NonExistent::Raise,
     )?
     .into_series();
 
@@ -1,10 +1,19 @@
 mod apply;
 mod arity;
+#[cfg(feature = "dtype-array")]
+mod array;
+mod binary;
 mod expand;
 mod filter;
 #[cfg(feature = "is_in")]
 mod is_in;
 mod slice;
+#[cfg(feature = "strings")]
+mod strings;
+#[cfg(feature = "dtype-struct")]
+mod struct_;
+#[cfg(any(feature = "business", feature = "date_diff"))]
+mod temporal;
 mod window;
 
 use super::*;
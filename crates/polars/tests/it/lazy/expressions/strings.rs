@@ -0,0 +1,125 @@
+use super::*;
+
+#[test]
+#[cfg(feature = "extract_groups")]
+fn test_extract_groups() -> PolarsResult<()> {
+    let df = df![
+        "log" => [Some("GET /index 200"), Some("oops"), None]
+    ]?;
+
+    let lf = df.lazy().select([col("log")
+        .str()
+        .extract_groups(r"(?P<method>\w+) (?P<path>\S+) (\d+)")?
+        .alias("parts")]);
+    assert_eq!(
+        lf.schema()?.get("parts"),
+        Some(&DataType::Struct(vec![
+            Field::new("method", DataType::Utf8),
+            Field::new("path", DataType::Utf8),
+            Field::new("3", DataType::Utf8),
+        ]))
+    );
+
+    let out = lf.collect()?.unnest(["parts"])?;
+    assert_eq!(
+        Vec::from(out.column("method")?.utf8()?),
+        &[Some("GET"), None, None]
+    );
+    assert_eq!(
+        Vec::from(out.column("3")?.utf8()?),
+        &[Some("200"), None, None]
+    );
+
+    assert!(col("log").str().extract_groups(r"\w+").is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "string_distance")]
+fn test_string_distances() -> PolarsResult<()> {
+    let df = df![
+        "a" => [Some("kitten"), Some("martha"), None],
+        "b" => ["sitting", "marhta", "foo"]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("a").str().levenshtein(col("b")).alias("levenshtein"),
+            col("a").str().hamming(lit("kitten")).alias("hamming"),
+            col("a").str().jaro_winkler(col("b")).alias("jaro_winkler"),
+        ])
+        .collect()?;
+
+    assert_eq!(out.column("levenshtein")?.dtype(), &DataType::UInt32);
+    assert_eq!(
+        Vec::from(out.column("levenshtein")?.u32()?),
+        &[Some(3), Some(2), None]
+    );
+    assert_eq!(
+        Vec::from(out.column("hamming")?.u32()?),
+        &[Some(0), Some(5), None]
+    );
+    let jw = out.column("jaro_winkler")?.f64()?;
+    assert!((jw.get(1).unwrap() - 0.9611).abs() < 1e-4);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "string_unicode")]
+fn test_string_unicode() -> PolarsResult<()> {
+    // the first value is "Amélie" with a combining acute accent
+    let df = df![
+        "name" => ["Ame\u{301}lie", "STRASSE", "Straße"]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([
+            col("name").str().normalize(UnicodeForm::NFC).alias("nfc"),
+            col("name").str().casefold().alias("folded"),
+            col("name").str().n_graphemes().alias("n_graphemes"),
+            col("name").str().reverse().alias("reversed"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("nfc")?.utf8()?),
+        &[Some("Am\u{e9}lie"), Some("STRASSE"), Some("Straße")]
+    );
+    assert_eq!(
+        Vec::from(out.column("folded")?.utf8()?),
+        &[Some("ame\u{301}lie"), Some("strasse"), Some("strasse")]
+    );
+    assert_eq!(
+        Vec::from(out.column("n_graphemes")?.u32()?),
+        &[Some(6), Some(7), Some(6)]
+    );
+    assert_eq!(
+        Vec::from(out.column("reversed")?.utf8()?),
+        &[Some("eile\u{301}mA"), Some("ESSARTS"), Some("eßartS")]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "find_many")]
+fn test_replace_many() -> PolarsResult<()> {
+    let df = df![
+        "text" => [Some("cats and dogs"), None, Some("a bird")]
+    ]?;
+    let patterns = Series::new("", &[Some("cat"), None, Some("dog"), Some("bird")]);
+    let replace_with = Series::new("", &[Some("lion"), Some("x"), None, Some("fish")]);
+
+    let out = df
+        .lazy()
+        .select([col("text")
+            .str()
+            .replace_many(lit(patterns), lit(replace_with), false)])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("text")?.utf8()?),
+        &[Some("lions and dogs"), None, Some("a fish")]
+    );
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+fn struct_frame() -> PolarsResult<DataFrame> {
+    let payload = df![
+        "user_id" => [1, 2],
+        "user_name" => ["a", "b"],
+        "amount" => [10, 20],
+    ]?
+    .into_struct("payload")
+    .into_series();
+    DataFrame::new(vec![payload])
+}
+
+#[test]
+fn test_struct_with_fields() -> PolarsResult<()> {
+    let lf = struct_frame()?
+        .lazy()
+        .select([col("payload").struct_().with_fields(vec![
+            (col("amount") * lit(2)).alias("amount"),
+            col("user_id").cast(DataType::Utf8).alias("user_key"),
+        ])]);
+    assert_eq!(
+        lf.schema()?.get("payload"),
+        Some(&DataType::Struct(vec![
+            Field::new("user_id", DataType::Int32),
+            Field::new("user_name", DataType::Utf8),
+            Field::new("amount", DataType::Int32),
+            Field::new("user_key", DataType::Utf8),
+        ]))
+    );
+
+    let out = lf.collect()?.unnest(["payload"])?;
+    assert_eq!(
+        Vec::from(out.column("amount")?.i32()?),
+        &[Some(20), Some(40)]
+    );
+    assert_eq!(
+        Vec::from(out.column("user_key")?.utf8()?),
+        &[Some("1"), Some("2")]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_struct_with_fields_unknown_field() -> PolarsResult<()> {
+    let lf = struct_frame()?.lazy().select([col("payload")
+        .struct_()
+        .with_fields(vec![col("missing").alias("x")])]);
+    // the schema error of the field expression is not swallowed
+    assert!(lf.schema().is_err());
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "lazy_regex")]
+fn test_struct_field_by_names() -> PolarsResult<()> {
+    let out = struct_frame()?
+        .lazy()
+        .select([col("payload").struct_().field_by_names("^user_.*$")])
+        .collect()?;
+    assert_eq!(out.get_column_names(), &["user_id", "user_name"]);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "json")]
+fn test_struct_json_encode() -> PolarsResult<()> {
+    let out = struct_frame()?
+        .lazy()
+        .select([col("payload").struct_().json_encode()])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("payload")?.utf8()?),
+        &[
+            Some(r#"{"user_id":1,"user_name":"a","amount":10}"#),
+            Some(r#"{"user_id":2,"user_name":"b","amount":20}"#),
+        ]
+    );
+    Ok(())
+}
@@ -0,0 +1,210 @@
+use polars::export::chrono::{NaiveDate, NaiveDateTime};
+
+use super::*;
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+fn datetime(y: i32, m: u32, d: u32, h: u32) -> NaiveDateTime {
+    date(y, m, d).and_hms_opt(h, 0, 0).unwrap()
+}
+
+#[cfg(feature = "business")]
+fn days_since_epoch(date: NaiveDate) -> i32 {
+    date.signed_duration_since(NaiveDate::from_ymd_opt(1970, 1, 1).unwrap())
+        .num_days() as i32
+}
+
+#[test]
+#[cfg(feature = "business")]
+fn test_business_days_lazy() -> PolarsResult<()> {
+    // Friday, Saturday and Tuesday; Monday 2023-07-10 is a holiday
+    let holidays = vec![days_since_epoch(date(2023, 7, 10))];
+    let df = df![
+        "date" => [date(2023, 7, 7), date(2023, 7, 8), date(2023, 7, 11)],
+        "datetime" => [datetime(2023, 7, 7, 12), datetime(2023, 7, 8, 12), datetime(2023, 7, 11, 12)],
+        "end" => [date(2023, 7, 14), date(2023, 7, 14), date(2023, 7, 14)]
+    ]?;
+
+    let q = df.lazy().select([
+        col("date")
+            .dt()
+            .add_business_days(lit(1), DEFAULT_WEEK_MASK, holidays.clone(), Roll::Forward)
+            .alias("next_date"),
+        col("datetime")
+            .dt()
+            .add_business_days(lit(1), DEFAULT_WEEK_MASK, holidays.clone(), Roll::Forward)
+            .alias("next_datetime"),
+        col("date")
+            .dt()
+            .is_business_day(DEFAULT_WEEK_MASK, holidays.clone())
+            .alias("is_business_day"),
+        polars::lazy::dsl::business_day_count(col("date"), col("end"), DEFAULT_WEEK_MASK, holidays)
+            .alias("count"),
+    ]);
+
+    assert_eq!(
+        q.schema()?.as_ref(),
+        &Schema::from_iter([
+            Field::new("next_date", DataType::Date),
+            Field::new(
+                "next_datetime",
+                DataType::Datetime(TimeUnit::Milliseconds, None)
+            ),
+            Field::new("is_business_day", DataType::Boolean),
+            Field::new("count", DataType::Int32),
+        ])
+    );
+
+    // the weekend and the holiday are skipped, the time of day is kept
+    let out = q.collect()?;
+    let expected = df![
+        "next_date" => [date(2023, 7, 11), date(2023, 7, 12), date(2023, 7, 12)],
+        "next_datetime" => [datetime(2023, 7, 11, 12), datetime(2023, 7, 12, 12), datetime(2023, 7, 12, 12)],
+        "is_business_day" => [true, false, true],
+        "count" => [4, 3, 3]
+    ]?;
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "business")]
+fn test_business_date_range_lazy() -> PolarsResult<()> {
+    let holidays = vec![days_since_epoch(date(2023, 7, 10))];
+    let df = df![
+        "start" => [date(2023, 7, 7)],
+        "end" => [date(2023, 7, 12)]
+    ]?;
+
+    let q = df.lazy().select([polars::lazy::dsl::business_date_range(
+        col("start"),
+        col("end"),
+        1,
+        ClosedWindow::Both,
+        DEFAULT_WEEK_MASK,
+        holidays,
+    )
+    .alias("range")]);
+
+    assert_eq!(
+        q.schema()?.as_ref(),
+        &Schema::from_iter([Field::new(
+            "range",
+            DataType::List(Box::new(DataType::Date))
+        )])
+    );
+
+    let out = q.collect()?;
+    let range = out.column("range")?.explode()?;
+    let expected = Series::new(
+        "range",
+        [date(2023, 7, 7), date(2023, 7, 11), date(2023, 7, 12)],
+    );
+    assert!(range.series_equal(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "date_diff")]
+fn test_diff_in_and_age_lazy() -> PolarsResult<()> {
+    let df = df![
+        "start_date" => [date(2023, 1, 31), date(2000, 2, 29)],
+        "end_date" => [date(2023, 2, 28), date(2001, 2, 28)],
+        "start_datetime" => [datetime(2023, 1, 31, 12), datetime(2000, 2, 29, 12)],
+        "end_datetime" => [datetime(2023, 2, 28, 12), datetime(2001, 2, 28, 12)]
+    ]?;
+
+    let q = df.lazy().select([
+        col("end_date")
+            .dt()
+            .diff_in(col("start_date"), DateDiffUnit::Months, false)
+            .alias("months_date"),
+        col("end_date")
+            .dt()
+            .diff_in(col("start_date"), DateDiffUnit::Days, true)
+            .alias("days_date"),
+        col("end_datetime")
+            .dt()
+            .diff_in(col("start_datetime"), DateDiffUnit::Months, false)
+            .alias("months_datetime"),
+        col("end_datetime")
+            .dt()
+            .diff_in(col("start_datetime"), DateDiffUnit::Weeks, false)
+            .alias("weeks_datetime"),
+        col("start_date")
+            .dt()
+            .age(col("end_date"))
+            .alias("age_date"),
+        col("start_datetime")
+            .dt()
+            .age(col("end_datetime"))
+            .alias("age_datetime"),
+    ]);
+
+    assert_eq!(
+        q.schema()?.as_ref(),
+        &Schema::from_iter([
+            Field::new("months_date", DataType::Int64),
+            Field::new("days_date", DataType::Float64),
+            Field::new("months_datetime", DataType::Int64),
+            Field::new("weeks_datetime", DataType::Int64),
+            Field::new("age_date", DataType::Int64),
+            Field::new("age_datetime", DataType::Int64),
+        ])
+    );
+
+    // one month after January 31st is the last day of February
+    let out = q.collect()?;
+    let expected = df![
+        "months_date" => [1i64, 12],
+        "days_date" => [28.0, 365.0],
+        "months_datetime" => [1i64, 12],
+        "weeks_datetime" => [4i64, 52],
+        "age_date" => [0i64, 1],
+        "age_datetime" => [0i64, 1]
+    ]?;
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "date_diff", feature = "timezones"))]
+fn test_diff_in_and_age_time_zone_lazy() -> PolarsResult<()> {
+    let zoned = |name: &str, values: &[NaiveDateTime]| -> PolarsResult<Series> {
+        let mut ca = DatetimeChunked::new(name, values);
+        ca.set_time_zone("Europe/Amsterdam".to_string())?;
+        Ok(ca.into_series())
+    };
+    // in UTC these lie half an hour before midnight, locally half an hour after it
+    let utc = |y, m, d| date(y, m, d).and_hms_opt(23, 30, 0).unwrap();
+    let start = zoned("start", &[utc(2023, 1, 30), utc(2000, 2, 28)])?;
+    let end = zoned("end", &[utc(2023, 2, 27), utc(2001, 2, 27)])?;
+    let df = DataFrame::new(vec![start, end])?;
+
+    let q = df.lazy().select([
+        col("end")
+            .dt()
+            .diff_in(col("start"), DateDiffUnit::Months, false)
+            .alias("months"),
+        col("start").dt().age(col("end")).alias("age"),
+    ]);
+
+    assert_eq!(
+        q.schema()?.as_ref(),
+        &Schema::from_iter([
+            Field::new("months", DataType::Int64),
+            Field::new("age", DataType::Int64),
+        ])
+    );
+
+    // compared in UTC this would be 0 and 11 months and ages 0 and 0
+    let out = q.collect()?;
+    let expected = df![
+        "months" => [1i64, 12],
+        "age" => [0i64, 1]
+    ]?;
+    assert!(out.frame_equal(&expected));
+    Ok(())
+}
@@ -414,3 +414,87 @@ fn test_window_map_empty_df_3542() -> PolarsResult<()> {
     assert_eq!(out.height(), 0);
     Ok(())
 }
+
+#[test]
+#[cfg(feature = "interpolate")]
+fn test_interpolate_over() -> PolarsResult<()> {
+    let df = df![
+        "groups" => ["a", "a", "a", "b", "b", "b", "b"],
+        "values" => [Some(1.0), None, Some(3.0), Some(10.0), None, None, Some(40.0)]
+    ]?;
+
+    let out = df
+        .lazy()
+        .select([col("values")
+            .interpolate_with_options(InterpolationOptions {
+                method: InterpolationMethod::Previous,
+                limit: Some(1),
+                limit_direction: LimitDirection::Forward,
+            })
+            .over([col("groups")])])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("values")?.f64()?),
+        &[
+            Some(1.0),
+            Some(1.0),
+            Some(3.0),
+            Some(10.0),
+            Some(10.0),
+            None,
+            Some(40.0)
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "interpolate")]
+fn test_interpolate_by_over() -> PolarsResult<()> {
+    let df = df![
+        "groups" => ["a", "a", "a", "b", "b", "b"],
+        "x" => [0i64, 1, 4, 0, 3, 4],
+        "values" => [Some(1.0), None, Some(9.0), Some(10.0), None, Some(14.0)]
+    ]?;
+
+    let lf = df.lazy().select([
+        col("values")
+            .interpolate_by(col("x"), InterpolationMethod::Time)
+            .over([col("groups")])
+            .alias("time"),
+        col("values")
+            .interpolate_by(col("x"), InterpolationMethod::Nearest)
+            .alias("nearest"),
+    ]);
+    assert_eq!(lf.schema()?.get("time"), Some(&DataType::Float64));
+    let out = lf.clone().collect()?;
+    assert_eq!(
+        Vec::from(out.column("time")?.f64()?),
+        &[
+            Some(1.0),
+            Some(3.0),
+            Some(9.0),
+            Some(10.0),
+            Some(13.0),
+            Some(14.0)
+        ]
+    );
+    assert_eq!(
+        Vec::from(out.column("nearest")?.f64()?),
+        &[
+            Some(1.0),
+            Some(1.0),
+            Some(9.0),
+            Some(10.0),
+            Some(14.0),
+            Some(14.0)
+        ]
+    );
+
+    let out = lf
+        .select([col("time").interpolate_by(col("time"), InterpolationMethod::Cubic)])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
@@ -29,6 +29,8 @@ fn test_groupby_dynamic_week_bounds() -> PolarsResult<()> {
         ClosedWindow::Left,
         TimeUnit::Milliseconds,
         None,
+        Ambiguous::Raise,
+        NonExistent::Raise,
     )?
     .into_series();
 
@@ -97,6 +97,96 @@ fn test_special_groupby_schemas() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(all(feature = "dynamic_groupby", feature = "rolling_window"))]
+fn test_rolling_expr() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3, 5, 6],
+        "b" => [1, 2, 2, 4, 5],
+    ]?;
+
+    let out = df
+        .lazy()
+        .with_column(col("a").set_sorted_flag(IsSorted::Ascending))
+        .select([
+            col("b")
+                .sum()
+                .rolling(RollingGroupOptions {
+                    index_column: "a".into(),
+                    period: Duration::parse("2i"),
+                    offset: Duration::parse("-2i"),
+                    closed_window: ClosedWindow::Right,
+                    check_sorted: true,
+                })
+                .alias("sum"),
+            col("b")
+                .rolling_n_unique_by(RollingOptions {
+                    window_size: Duration::parse("2i"),
+                    by: Some("a".into()),
+                    ..Default::default()
+                })
+                .alias("n_unique"),
+            col("b")
+                .rolling_first_by(RollingOptions {
+                    window_size: Duration::parse("2i"),
+                    min_periods: 2,
+                    by: Some("a".into()),
+                    ..Default::default()
+                })
+                .alias("first"),
+        ])
+        .collect()?;
+
+    assert_eq!(
+        Vec::from(out.column("sum")?.i32()?),
+        &[Some(1), Some(3), Some(4), Some(4), Some(9)]
+    );
+    assert_eq!(
+        Vec::from(out.column("n_unique")?.idx()?),
+        &[Some(1), Some(2), Some(1), Some(1), Some(2)]
+    );
+    assert_eq!(
+        Vec::from(out.column("first")?.i32()?),
+        &[None, Some(1), Some(2), None, Some(4)]
+    );
+    Ok(())
+}
+
+#[test]
+#[cfg(all(feature = "dynamic_groupby", feature = "rolling_window"))]
+fn test_rolling_expr_min_periods_nulls() -> PolarsResult<()> {
+    let df = df![
+        "a" => [1, 2, 3, 4],
+        "b" => [Some(1), None, Some(3), Some(4)],
+    ]?
+    .lazy()
+    .with_column(col("a").set_sorted_flag(IsSorted::Ascending));
+
+    // nulls don't count towards `min_periods`
+    let out = df
+        .clone()
+        .select([col("b").rolling_first_by(RollingOptions {
+            window_size: Duration::parse("2i"),
+            min_periods: 2,
+            by: Some("a".into()),
+            ..Default::default()
+        })])
+        .collect()?;
+    assert_eq!(
+        Vec::from(out.column("b")?.i32()?),
+        &[None, None, None, Some(3)]
+    );
+
+    let out = df
+        .select([col("b").rolling_count_by(RollingOptions {
+            window_size: Duration::parse("2i"),
+            ..Default::default()
+        })])
+        .collect();
+    assert!(out.is_err());
+    Ok(())
+}
+
 #[test]
 fn max_on_empty_df_3027() -> PolarsResult<()> {
     let df = df! {
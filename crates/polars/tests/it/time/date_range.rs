@@ -20,6 +20,8 @@ fn test_time_units_9413() {
         ClosedWindow::Both,
         TimeUnit::Milliseconds,
         None,
+        Ambiguous::Raise,
+        NonExistent::Raise,
     )
     .map(|date_range| date_range.into_series());
     let result = format!("{:?}", actual);
@@ -41,6 +43,8 @@ Series: 'date' [datetime[ms]]
         ClosedWindow::Both,
         TimeUnit::Microseconds,
         None,
+        Ambiguous::Raise,
+        NonExistent::Raise,
     )
     .map(|date_range| date_range.into_series());
     let result = format!("{:?}", actual);
@@ -62,6 +66,8 @@ Series: 'date' [datetime[μs]]
         ClosedWindow::Both,
         TimeUnit::Nanoseconds,
         None,
+        Ambiguous::Raise,
+        NonExistent::Raise,
     )
     .map(|date_range| date_range.into_series());
     let result = format!("{:?}", actual);
@@ -41,6 +41,7 @@ from polars.datatypes import (
     Datetime,
     Decimal,
     Duration,
+    Enum,
     Field,
     Float32,
     Float64,
@@ -225,6 +226,7 @@ __all__ = [
     "Datetime",
     "Decimal",
     "Duration",
+    "Enum",
     "Field",
     "Float32",
     "Float64",
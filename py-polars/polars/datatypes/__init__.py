@@ -10,6 +10,7 @@ from polars.datatypes.classes import (
     Datetime,
     Decimal,
     Duration,
+    Enum,
     Field,
     Float32,
     Float64,
@@ -85,6 +86,7 @@ __all__ = [
     "Datetime",
     "Decimal",
     "Duration",
+    "Enum",
     "Field",
     "Float32",
     "Float64",
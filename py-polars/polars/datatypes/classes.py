@@ -414,6 +414,10 @@ class Categorical(DataType):
     """A categorical encoding of a set of strings."""
 
 
+class Enum(DataType):
+    """A categorical encoding of a fixed set of strings."""
+
+
 class Object(DataType):
     """Type for wrapping arbitrary Python objects."""
 
@@ -217,7 +217,7 @@ impl IntoPy<PyObject> for Wrap<AnyValue<'_>> {
             AnyValue::Boolean(v) => v.into_py(py),
             AnyValue::Utf8(v) => v.into_py(py),
             AnyValue::Utf8Owned(v) => v.into_py(py),
-            AnyValue::Categorical(idx, rev, arr) => {
+            AnyValue::Categorical(idx, rev, arr) | AnyValue::Enum(idx, rev, arr) => {
                 let s = if arr.is_null() {
                     rev.get(idx)
                 } else {
@@ -331,6 +331,7 @@ impl ToPyObject for Wrap<DataType> {
             #[cfg(feature = "object")]
             DataType::Object(_) => pl.getattr(intern!(py, "Object")).unwrap().into(),
             DataType::Categorical(_) => pl.getattr(intern!(py, "Categorical")).unwrap().into(),
+            DataType::Enum(_) => pl.getattr(intern!(py, "Enum")).unwrap().into(),
             DataType::Time => pl.getattr(intern!(py, "Time")).unwrap().into(),
             DataType::Struct(fields) => {
                 let field_class = pl.getattr(intern!(py, "Field")).unwrap();
@@ -382,6 +383,7 @@ impl FromPyObject<'_> for Wrap<DataType> {
                     "Binary" => DataType::Binary,
                     "Boolean" => DataType::Boolean,
                     "Categorical" => DataType::Categorical(None),
+                    "Enum" => DataType::Enum(None),
                     "Date" => DataType::Date,
                     "Datetime" => DataType::Datetime(TimeUnit::Microseconds, None),
                     "Time" => DataType::Time,
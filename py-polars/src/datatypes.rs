@@ -32,6 +32,7 @@ pub(crate) enum PyDataType {
     Binary,
     Decimal(Option<usize>, usize),
     Array(usize),
+    Enum,
 }
 
 impl From<&DataType> for PyDataType {
@@ -61,6 +62,7 @@ impl From<&DataType> for PyDataType {
             #[cfg(feature = "object")]
             DataType::Object(_) => Object,
             DataType::Categorical(_) => Categorical,
+            DataType::Enum(_) => Enum,
             DataType::Struct(_) => Struct,
             DataType::Null | DataType::Unknown => {
                 panic!("null or unknown not expected here")
@@ -103,6 +105,7 @@ impl From<PyDataType> for DataType {
             PyDataType::Struct => Struct(vec![]),
             PyDataType::Decimal(p, s) => Decimal(p, Some(s)),
             PyDataType::Array(width) => Array(DataType::Null.into(), width),
+            PyDataType::Enum => Enum(None),
         }
     }
 }
@@ -4,6 +4,14 @@ use pyo3::prelude::*;
 use crate::conversion::Wrap;
 use crate::PyExpr;
 
+fn ambiguous_from_use_earliest(use_earliest: Option<bool>) -> Ambiguous {
+    match use_earliest {
+        Some(true) => Ambiguous::Earliest,
+        Some(false) => Ambiguous::Latest,
+        None => Ambiguous::Raise,
+    }
+}
+
 #[pymethods]
 impl PyExpr {
     fn dt_to_string(&self, format: &str) -> Self {
@@ -46,7 +54,11 @@ impl PyExpr {
         self.inner
             .clone()
             .dt()
-            .replace_time_zone(time_zone, use_earliest)
+            .replace_time_zone(
+                time_zone,
+                ambiguous_from_use_earliest(use_earliest),
+                NonExistent::Raise,
+            )
             .into()
     }
 
@@ -57,7 +69,8 @@ impl PyExpr {
             .truncate(TruncateOptions {
                 every,
                 offset,
-                use_earliest,
+                ambiguous: ambiguous_from_use_earliest(use_earliest),
+                non_existent: NonExistent::Raise,
             })
             .into()
     }
@@ -80,7 +93,11 @@ impl PyExpr {
     }
 
     fn dt_round(&self, every: &str, offset: &str) -> Self {
-        self.inner.clone().dt().round(every, offset).into()
+        self.inner
+            .clone()
+            .dt()
+            .round(every, offset, Ambiguous::Raise, NonExistent::Raise)
+            .into()
     }
 
     fn dt_combine(&self, time: Self, time_unit: Wrap<TimeUnit>) -> Self {
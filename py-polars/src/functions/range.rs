@@ -49,7 +49,17 @@ pub fn date_range(
     let every = Duration::parse(every);
     let closed = closed.0;
     let time_unit = time_unit.map(|x| x.0);
-    dsl::date_range(start, end, every, closed, time_unit, time_zone).into()
+    dsl::date_range(
+        start,
+        end,
+        every,
+        closed,
+        time_unit,
+        time_zone,
+        Ambiguous::Raise,
+        NonExistent::Raise,
+    )
+    .into()
 }
 
 #[pyfunction]
@@ -66,7 +76,17 @@ pub fn date_ranges(
     let every = Duration::parse(every);
     let closed = closed.0;
     let time_unit = time_unit.map(|x| x.0);
-    dsl::date_ranges(start, end, every, closed, time_unit, time_zone).into()
+    dsl::date_ranges(
+        start,
+        end,
+        every,
+        closed,
+        time_unit,
+        time_zone,
+        Ambiguous::Raise,
+        NonExistent::Raise,
+    )
+    .into()
 }
 
 #[pyfunction]
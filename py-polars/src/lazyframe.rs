@@ -285,6 +285,7 @@ impl PyLazyFrame {
             rechunk,
             row_count,
             memmap: memory_map,
+            cloud_options: None,
         };
         let lf = LazyFrame::scan_ipc(path, args).map_err(PyPolarsErr::from)?;
         Ok(lf.into())
@@ -636,6 +637,8 @@ impl PyLazyFrame {
                 right_by: right_by.map(strings_to_smartstrings),
                 tolerance: tolerance.map(|t| t.0.into_static().unwrap()),
                 tolerance_str: tolerance_str.map(|s| s.into()),
+                tolerance_column: None,
+                allow_exact_matches: true,
             }))
             .suffix(suffix)
             .finish()
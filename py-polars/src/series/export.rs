@@ -95,7 +95,7 @@ impl PySeries {
                     DataType::Int64 => PyList::new(py, series.i64().unwrap()),
                     DataType::Float32 => PyList::new(py, series.f32().unwrap()),
                     DataType::Float64 => PyList::new(py, series.f64().unwrap()),
-                    DataType::Categorical(_) => {
+                    DataType::Categorical(_) | DataType::Enum(_) => {
                         PyList::new(py, series.categorical().unwrap().iter_str())
                     }
                     #[cfg(feature = "object")]